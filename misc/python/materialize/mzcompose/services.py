@@ -588,6 +588,31 @@ class Localstack(Service):
         )
 
 
+class PubSubEmulator(Service):
+    def __init__(
+        self,
+        name: str = "pubsub-emulator",
+        image: str = "gcr.io/google.com/cloudsdktool/cloud-sdk:405.0.0-emulators",
+        port: int = 8085,
+    ) -> None:
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "command": [
+                    "gcloud",
+                    "beta",
+                    "emulators",
+                    "pubsub",
+                    "start",
+                    "--project=testdrive",
+                    f"--host-port=0.0.0.0:{port}",
+                ],
+                "ports": [port],
+            },
+        )
+
+
 class Testdrive(Service):
     def __init__(
         self,
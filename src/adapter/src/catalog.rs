@@ -15,6 +15,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use anyhow::bail;
+use futures::future::LocalBoxFuture;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -63,7 +64,9 @@ use mz_sql::plan::{
     StorageHostConfig as PlanStorageHostConfig,
 };
 use mz_sql::{plan, DEFAULT_SCHEMA};
-use mz_sql_parser::ast::{CreateSourceOption, Statement, WithOptionValue};
+use mz_sql_parser::ast::{
+    CreateSourceOption, IndexOption, IndexOptionName, Statement, WithOptionValue,
+};
 use mz_stash::{Append, Postgres, Sqlite};
 use mz_storage::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
 use mz_storage::types::sinks::{SinkEnvelope, StorageSinkConnection, StorageSinkConnectionBuilder};
@@ -80,6 +83,7 @@ pub use crate::catalog::config::{ClusterReplicaSizeMap, Config, StorageHostSizeM
 pub use crate::catalog::error::{AmbiguousRename, Error, ErrorKind};
 use crate::catalog::storage::BootstrapArgs;
 use crate::client::ConnectionId;
+use crate::coord::timeline::TimestampPersistence;
 use crate::session::vars::SystemVars;
 use crate::session::{PreparedStatement, Session, DEFAULT_DATABASE_NAME};
 use crate::util::index_sql;
@@ -139,6 +143,25 @@ impl<S> Clone for Catalog<S> {
     }
 }
 
+/// A [`TimestampPersistence`] that persists the global timestamps of a timeline
+/// in the catalog's stash.
+pub struct StashTimestampPersistence<S> {
+    storage: Arc<Mutex<storage::Connection<S>>>,
+    timeline: Timeline,
+}
+
+impl<S: Append> TimestampPersistence<mz_repr::Timestamp> for StashTimestampPersistence<S> {
+    fn persist(&self, timestamp: mz_repr::Timestamp) -> LocalBoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            self.storage
+                .lock()
+                .await
+                .persist_timestamp(&self.timeline, timestamp)
+                .await
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CatalogState {
     database_by_name: BTreeMap<String, DatabaseId>,
@@ -157,6 +180,7 @@ pub struct CatalogState {
     default_storage_host_size: Option<String>,
     availability_zones: Vec<String>,
     system_configuration: SystemVars,
+    comments: BTreeMap<(GlobalId, Option<usize>), String>,
 }
 
 impl CatalogState {
@@ -249,6 +273,29 @@ impl CatalogState {
         }
     }
 
+    /// Adds the tables that `id` transitively depends on to `tables`.
+    pub fn tables_used_by(&self, id: GlobalId, tables: &mut BTreeSet<GlobalId>) {
+        match self.get_entry(&id).item() {
+            CatalogItem::Table(_) => {
+                tables.insert(id);
+            }
+            item @ (CatalogItem::View(_) | CatalogItem::MaterializedView(_)) => {
+                for id in item.uses() {
+                    self.tables_used_by(*id, tables);
+                }
+            }
+            CatalogItem::Index(idx) => self.tables_used_by(idx.on, tables),
+            CatalogItem::Source(_)
+            | CatalogItem::Log(_)
+            | CatalogItem::Func(_)
+            | CatalogItem::Sink(_)
+            | CatalogItem::Type(_)
+            | CatalogItem::Secret(_)
+            | CatalogItem::Connection(_)
+            | CatalogItem::StorageCollection(_) => (),
+        }
+    }
+
     pub fn resolve_full_name(
         &self,
         name: &QualifiedObjectName,
@@ -562,6 +609,7 @@ impl CatalogState {
             .items
             .remove(&metadata.name().item)
             .expect("catalog out of sync");
+        self.comments.retain(|(object_id, _), _| *object_id != id);
 
         if let CatalogItem::Index(Index {
             compute_instance, ..
@@ -646,6 +694,7 @@ impl CatalogState {
                             conn_id: None,
                             depends_on: vec![log_id],
                             compute_instance: id,
+                            options: vec![],
                         }),
                     );
                     active_logs.insert(log.variant.clone(), index_id);
@@ -751,6 +800,14 @@ impl CatalogState {
         self.system_configuration = SystemVars::default();
     }
 
+    /// Returns the comments on the item identified by `id` and on its columns,
+    /// keyed by column position.
+    fn comments_on(&self, id: GlobalId) -> impl Iterator<Item = (Option<usize>, &str)> {
+        self.comments
+            .range((id, None)..=(id, Some(usize::MAX)))
+            .map(|((_, column_position), comment)| (*column_position, comment.as_str()))
+    }
+
     /// Gets the schema map for the database matching `database_spec`.
     fn resolve_schema_in_database(
         &self,
@@ -1243,6 +1300,9 @@ pub struct Source {
     pub timeline: Timeline,
     pub depends_on: Vec<GlobalId>,
     pub host_config: StorageHostConfig,
+    /// The logical compaction window set with `LOGICAL COMPACTION WINDOW`, if
+    /// any. `Some(None)` disables logical compaction entirely.
+    pub logical_compaction_window: Option<Option<Duration>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1252,6 +1312,12 @@ pub struct Sink {
     pub connection: StorageSinkConnectionState,
     pub envelope: SinkEnvelope,
     pub with_snapshot: bool,
+    /// The timestamp requested with `AS OF`, if any.
+    ///
+    /// This is only known when the sink is first created. The storage
+    /// controller durably records the timestamp at which the sink started, so
+    /// it is not needed when the sink is recreated after a restart.
+    pub as_of: Option<mz_repr::Timestamp>,
     pub depends_on: Vec<GlobalId>,
 }
 
@@ -1277,6 +1343,9 @@ pub struct MaterializedView {
     pub desc: RelationDesc,
     pub depends_on: Vec<GlobalId>,
     pub compute_instance: ComputeInstanceId,
+    /// The logical compaction window set with `LOGICAL COMPACTION WINDOW`, if
+    /// any. `Some(None)` disables logical compaction entirely.
+    pub logical_compaction_window: Option<Option<Duration>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1287,6 +1356,9 @@ pub struct Index {
     pub conn_id: Option<ConnectionId>,
     pub depends_on: Vec<GlobalId>,
     pub compute_instance: ComputeInstanceId,
+    /// The options that were explicitly set on the index, either when it was
+    /// created or by a later `ALTER INDEX`.
+    pub options: Vec<plan::IndexOption>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1782,6 +1854,7 @@ impl<S: Append> Catalog<S> {
                 default_storage_host_size: config.default_storage_host_size,
                 availability_zones: config.availability_zones,
                 system_configuration: SystemVars::default(),
+                comments: BTreeMap::new(),
             },
             transient_revision: 0,
             storage: Arc::new(Mutex::new(config.storage)),
@@ -2164,6 +2237,20 @@ impl<S: Append> Catalog<S> {
             builtin_table_updates.push(catalog.state.pack_storage_usage_update(&event)?);
         }
 
+        let comments = catalog.storage().await.load_comments().await?;
+        for (object_id, column_position, comment) in comments {
+            builtin_table_updates.push(catalog.state.pack_comment_update(
+                object_id,
+                column_position,
+                &comment,
+                1,
+            ));
+            catalog
+                .state
+                .comments
+                .insert((object_id, column_position), comment);
+        }
+
         Ok((catalog, builtin_migration_metadata, builtin_table_updates))
     }
 
@@ -2726,16 +2813,13 @@ impl<S: Append> Catalog<S> {
         self.storage().await.get_persisted_timestamp(timeline).await
     }
 
-    /// Persist new global timestamp for a timeline to disk.
-    pub async fn persist_timestamp(
-        &mut self,
-        timeline: &Timeline,
-        timestamp: mz_repr::Timestamp,
-    ) -> Result<(), Error> {
-        self.storage()
-            .await
-            .persist_timestamp(timeline, timestamp)
-            .await
+    /// Returns a [`TimestampPersistence`] that persists the global timestamps of
+    /// `timeline` to disk.
+    pub fn timestamp_persistence(&self, timeline: &Timeline) -> StashTimestampPersistence<S> {
+        StashTimestampPersistence {
+            storage: Arc::clone(&self.storage),
+            timeline: timeline.clone(),
+        }
     }
 
     pub fn resolve_database(&self, database_name: &str) -> Result<&Database, SqlCatalogError> {
@@ -3050,8 +3134,8 @@ impl<S: Append> Catalog<S> {
         builtin_table_updates: &mut Vec<BuiltinTableUpdate>,
         object_id: Option<String>,
         size_bytes: u64,
+        collection_timestamp: EpochMillis,
     ) -> Result<(), Error> {
-        let collection_timestamp = (self.state.config.now)();
         let id = tx.get_and_increment_id(storage::STORAGE_USAGE_ID_ALLOC_KEY.to_string())?;
 
         let event_details =
@@ -3242,6 +3326,11 @@ impl<S: Append> Catalog<S> {
                 compute_id: ComputeInstanceId,
             },
             DropItem(GlobalId),
+            UpdateComment {
+                object_id: GlobalId,
+                column_position: Option<usize>,
+                comment: Option<String>,
+            },
             UpdateItem {
                 id: GlobalId,
                 to_name: QualifiedObjectName,
@@ -3300,7 +3389,12 @@ impl<S: Append> Catalog<S> {
 
         for op in ops {
             actions.extend(match op {
-                Op::AlterSource { id, size, remote } => {
+                Op::AlterSource {
+                    id,
+                    size,
+                    remote,
+                    logical_compaction_window,
+                } => {
                     use mz_sql::ast::Value;
                     use mz_sql_parser::ast::CreateSourceOptionName::*;
                     use AlterSourceItem::*;
@@ -3341,33 +3435,60 @@ impl<S: Append> Catalog<S> {
                         (_, _, _) => None,
                     };
 
-                    if let Some(config) = new_config {
-                        create_stmt
-                            .with_options
-                            .retain(|x| ![Size, Remote].contains(&x.name));
+                    let new_window = match logical_compaction_window {
+                        Set(window) => Some(Some(window)),
+                        Reset => Some(None),
+                        Unchanged => None,
+                    };
 
-                        let new_host_option = match &config {
-                            plan::StorageHostConfig::Managed { size } => Some((Size, size.clone())),
-                            plan::StorageHostConfig::Remote { addr } => {
-                                Some((Remote, addr.clone()))
+                    if new_config.is_some() || new_window.is_some() {
+                        let mut source = old_source;
+
+                        if let Some(config) = new_config {
+                            create_stmt
+                                .with_options
+                                .retain(|x| ![Size, Remote].contains(&x.name));
+
+                            let new_host_option = match &config {
+                                plan::StorageHostConfig::Managed { size } => {
+                                    Some((Size, size.clone()))
+                                }
+                                plan::StorageHostConfig::Remote { addr } => {
+                                    Some((Remote, addr.clone()))
+                                }
+                                plan::StorageHostConfig::Undefined => None,
+                            };
+
+                            if let Some((name, value)) = new_host_option {
+                                create_stmt.with_options.push(CreateSourceOption {
+                                    name,
+                                    value: Some(WithOptionValue::Value(Value::String(value))),
+                                });
                             }
-                            plan::StorageHostConfig::Undefined => None,
-                        };
 
-                        if let Some((name, value)) = new_host_option {
-                            create_stmt.with_options.push(CreateSourceOption {
-                                name,
-                                value: Some(WithOptionValue::Value(Value::String(value))),
-                            });
+                            source.host_config = self.resolve_storage_host_config(config)?;
                         }
 
-                        let host_config = self.resolve_storage_host_config(config)?;
-                        let create_sql = stmt.to_ast_string_stable();
-                        let source = CatalogItem::Source(Source {
-                            create_sql,
-                            host_config: host_config.clone(),
-                            ..old_source
-                        });
+                        if let Some(window) = new_window {
+                            create_stmt
+                                .with_options
+                                .retain(|x| x.name != LogicalCompactionWindow);
+                            if let Some(window) = window {
+                                // A window of zero disables logical compaction.
+                                let window = window.unwrap_or(Duration::ZERO);
+                                create_stmt.with_options.push(CreateSourceOption {
+                                    name: LogicalCompactionWindow,
+                                    value: Some(WithOptionValue::Value(Value::String(format!(
+                                        "{}ms",
+                                        window.as_millis()
+                                    )))),
+                                });
+                            }
+                            source.logical_compaction_window = window;
+                        }
+
+                        source.create_sql = stmt.to_ast_string_stable();
+                        let source = CatalogItem::Source(source);
 
                         let ser = self.serialize_item(&source);
                         tx.update_item(id, &name.item, &ser)?;
@@ -3384,6 +3505,104 @@ impl<S: Append> Catalog<S> {
                         vec![]
                     }
                 }
+                Op::AlterIndexOptions { id, set, reset } => {
+                    let entry = self.get_entry(&id);
+                    let name = entry.name().clone();
+                    let old_index = match entry.item() {
+                        CatalogItem::Index(index) => index.clone(),
+                        other => {
+                            coord_bail!("ALTER INDEX entry was not an index: {}", other.typ())
+                        }
+                    };
+
+                    // As with sources, the catalog serializes indexes using
+                    // only their creation statement, so the options must be
+                    // rewritten in that statement to survive a restart.
+                    let mut stmt = mz_sql::parse::parse(&old_index.create_sql)
+                        .unwrap()
+                        .into_element();
+                    let create_stmt = match &mut stmt {
+                        Statement::CreateIndex(s) => s,
+                        _ => {
+                            coord_bail!("index {id} was not created with a CREATE INDEX statement")
+                        }
+                    };
+
+                    // Both setting and resetting an option replace any
+                    // previous value for it.
+                    let replaced = |name: &IndexOptionName| {
+                        reset.contains(name) || set.iter().any(|o| &o.name() == name)
+                    };
+                    create_stmt.with_options.retain(|o| !replaced(&o.name));
+                    let mut options = old_index.options.clone();
+                    options.retain(|o| !replaced(&o.name()));
+                    for option in set {
+                        let value = match &option {
+                            plan::IndexOption::LogicalCompactionWindow(window) => {
+                                // A window of zero disables logical compaction.
+                                let window = window.unwrap_or(Duration::ZERO);
+                                mz_sql::ast::Value::String(format!("{}ms", window.as_millis()))
+                            }
+                        };
+                        create_stmt.with_options.push(IndexOption {
+                            name: option.name(),
+                            value: Some(WithOptionValue::Value(value)),
+                        });
+                        options.push(option);
+                    }
+
+                    let index = CatalogItem::Index(Index {
+                        create_sql: stmt.to_ast_string_stable(),
+                        options,
+                        ..old_index
+                    });
+                    if !index.is_temporary() {
+                        let ser = self.serialize_item(&index);
+                        tx.update_item(id, &name.item, &ser)?;
+                    }
+
+                    // NB: this will be re-incremented by the action below.
+                    builtin_table_updates.extend(self.state.pack_item_update(id, -1));
+
+                    vec![Action::UpdateItem {
+                        id,
+                        to_name: name,
+                        to_item: index,
+                    }]
+                }
+                Op::Comment {
+                    object_id,
+                    column_position,
+                    comment,
+                } => {
+                    // Comments on temporary items are dropped along with the
+                    // item, so they are never persisted.
+                    if !self.get_entry(&object_id).item().is_temporary() {
+                        tx.update_comment(object_id, column_position, comment.clone())?;
+                    }
+                    let key = (object_id, column_position);
+                    if let Some(old_comment) = self.state.comments.get(&key) {
+                        builtin_table_updates.push(self.state.pack_comment_update(
+                            object_id,
+                            column_position,
+                            old_comment,
+                            -1,
+                        ));
+                    }
+                    if let Some(new_comment) = &comment {
+                        builtin_table_updates.push(self.state.pack_comment_update(
+                            object_id,
+                            column_position,
+                            new_comment,
+                            1,
+                        ));
+                    }
+                    vec![Action::UpdateComment {
+                        object_id,
+                        column_position,
+                        comment,
+                    }]
+                }
                 Op::CreateDatabase {
                     name,
                     oid,
@@ -3662,8 +3881,17 @@ impl<S: Append> Catalog<S> {
                     let entry = self.get_entry(&id);
                     if !entry.item().is_temporary() {
                         tx.remove_item(id)?;
+                        tx.remove_comments(id);
                     }
                     builtin_table_updates.extend(self.state.pack_item_update(id, -1));
+                    for (column_position, comment) in self.state.comments_on(id) {
+                        builtin_table_updates.push(self.state.pack_comment_update(
+                            id,
+                            column_position,
+                            comment,
+                            -1,
+                        ));
+                    }
                     if Self::should_audit_log_item(&entry.item) {
                         self.add_to_audit_log(
                             session,
@@ -3822,12 +4050,14 @@ impl<S: Append> Catalog<S> {
                 Op::UpdateStorageUsage {
                     object_id,
                     size_bytes,
+                    collection_timestamp,
                 } => {
                     self.add_to_storage_usage(
                         &mut tx,
                         &mut builtin_table_updates,
                         object_id,
                         size_bytes,
+                        collection_timestamp,
                     )?;
                     vec![]
                 }
@@ -4071,6 +4301,22 @@ impl<S: Append> Catalog<S> {
                     state.drop_item(id);
                 }
 
+                Action::UpdateComment {
+                    object_id,
+                    column_position,
+                    comment,
+                } => {
+                    let key = (object_id, column_position);
+                    match comment {
+                        Some(comment) => {
+                            state.comments.insert(key, comment);
+                        }
+                        None => {
+                            state.comments.remove(&key);
+                        }
+                    }
+                }
+
                 Action::UpdateItem {
                     id,
                     to_name,
@@ -4227,6 +4473,7 @@ impl<S: Append> Catalog<S> {
                 source,
                 timeline,
                 host_config,
+                logical_compaction_window,
                 ..
             }) => CatalogItem::Source(Source {
                 create_sql: source.create_sql,
@@ -4235,6 +4482,7 @@ impl<S: Append> Catalog<S> {
                 timeline,
                 depends_on,
                 host_config: self.resolve_storage_host_config(host_config)?,
+                logical_compaction_window,
             }),
             Plan::CreateView(CreateViewPlan { view, .. }) => {
                 let mut optimizer = Optimizer::logical_optimizer();
@@ -4260,16 +4508,20 @@ impl<S: Append> Catalog<S> {
                     desc,
                     depends_on,
                     compute_instance: materialized_view.compute_instance,
+                    logical_compaction_window: materialized_view.logical_compaction_window,
+                })
+            }
+            Plan::CreateIndex(CreateIndexPlan { index, options, .. }) => {
+                CatalogItem::Index(Index {
+                    create_sql: index.create_sql,
+                    on: index.on,
+                    keys: index.keys,
+                    conn_id: None,
+                    depends_on,
+                    compute_instance: index.compute_instance,
+                    options,
                 })
             }
-            Plan::CreateIndex(CreateIndexPlan { index, .. }) => CatalogItem::Index(Index {
-                create_sql: index.create_sql,
-                on: index.on,
-                keys: index.keys,
-                conn_id: None,
-                depends_on,
-                compute_instance: index.compute_instance,
-            }),
             Plan::CreateSink(CreateSinkPlan {
                 sink,
                 with_snapshot,
@@ -4280,6 +4532,7 @@ impl<S: Append> Catalog<S> {
                 connection: StorageSinkConnectionState::Pending(sink.connection_builder),
                 envelope: sink.envelope,
                 with_snapshot,
+                as_of: None,
                 depends_on,
             }),
             Plan::CreateType(CreateTypePlan { typ, .. }) => CatalogItem::Type(Type {
@@ -4308,6 +4561,11 @@ impl<S: Append> Catalog<S> {
         self.state.uses_tables(id)
     }
 
+    /// Adds the tables that `id` transitively depends on to `tables`.
+    pub fn tables_used_by(&self, id: GlobalId, tables: &mut BTreeSet<GlobalId>) {
+        self.state.tables_used_by(id, tables)
+    }
+
     /// Return the ids of all active log sources the given object depends on.
     pub fn arranged_introspection_dependencies(&self, id: GlobalId) -> Vec<GlobalId> {
         self.state.arranged_introspection_dependencies(id)
@@ -4458,6 +4716,20 @@ pub enum Op {
         id: GlobalId,
         size: AlterSourceItem,
         remote: AlterSourceItem,
+        logical_compaction_window: AlterSourceItem<Option<Duration>>,
+    },
+    AlterIndexOptions {
+        id: GlobalId,
+        set: Vec<plan::IndexOption>,
+        reset: Vec<IndexOptionName>,
+    },
+    /// Sets the comment on an item, or on one of its columns if
+    /// `column_position` is specified. A `comment` of `None` removes the
+    /// existing comment.
+    Comment {
+        object_id: GlobalId,
+        column_position: Option<usize>,
+        comment: Option<String>,
     },
     CreateDatabase {
         name: String,
@@ -4527,6 +4799,7 @@ pub enum Op {
     UpdateStorageUsage {
         object_id: Option<String>,
         size_bytes: u64,
+        collection_timestamp: EpochMillis,
     },
     UpdateSystemConfiguration {
         name: String,
@@ -4823,6 +5096,10 @@ impl SessionCatalog for ConnCatalog<'_> {
         self.state.is_system_schema(schema)
     }
 
+    fn search_path(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)] {
+        &self.search_path
+    }
+
     fn resolve_role(
         &self,
         role_name: &str,
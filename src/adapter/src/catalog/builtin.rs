@@ -1076,6 +1076,21 @@ pub static MZ_COLUMNS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("default", ScalarType::String.nullable(true))
         .with_column("type_oid", ScalarType::Oid.nullable(false)),
 });
+pub static MZ_COMMENTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_comments",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("column_position", ScalarType::UInt64.nullable(true))
+        .with_column("comment", ScalarType::String.nullable(false)),
+});
+pub static MZ_OBJECT_DEPENDENCIES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_object_dependencies",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column("referenced_object_id", ScalarType::String.nullable(false)),
+});
 pub static MZ_INDEXES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_indexes",
     schema: MZ_CATALOG_SCHEMA,
@@ -1275,6 +1290,49 @@ pub static MZ_CLUSTER_REPLICA_HEARTBEATS: Lazy<BuiltinTable> = Lazy::new(|| Buil
         .with_column("last_heartbeat", ScalarType::TimestampTz.nullable(false)),
 });
 
+pub static MZ_SESSIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_sessions",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::UInt32.nullable(false))
+        .with_column("user", ScalarType::String.nullable(false))
+        .with_column("application_name", ScalarType::String.nullable(false))
+        .with_column("client_addr", ScalarType::String.nullable(true))
+        .with_column("connected_at", ScalarType::TimestampTz.nullable(false)),
+});
+
+pub static MZ_GROUP_COMMIT_STATISTICS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_group_commit_statistics",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("group_commits", ScalarType::UInt64.nullable(false))
+        .with_column("transactions", ScalarType::UInt64.nullable(false))
+        .with_column("updates", ScalarType::UInt64.nullable(false))
+        .with_column("now_wait_seconds", ScalarType::Float64.nullable(false))
+        .with_column("append_seconds", ScalarType::Float64.nullable(false)),
+});
+
+pub static MZ_GROUP_COMMITS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_group_commits",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::UInt64.nullable(false))
+        .with_column("timestamp", ScalarType::UInt64.nullable(false))
+        .with_column("transactions", ScalarType::UInt64.nullable(false))
+        .with_column("updates", ScalarType::UInt64.nullable(false))
+        .with_column("now_wait_seconds", ScalarType::Float64.nullable(false))
+        .with_column("append_seconds", ScalarType::Float64.nullable(false)),
+});
+
+pub static MZ_GROUP_COMMIT_TABLE_UPDATES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_group_commit_table_updates",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("group_commit_id", ScalarType::UInt64.nullable(false))
+        .with_column("table_id", ScalarType::String.nullable(false))
+        .with_column("updates", ScalarType::UInt64.nullable(false)),
+});
+
 pub static MZ_AUDIT_EVENTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_audit_events",
     schema: MZ_CATALOG_SCHEMA,
@@ -1316,6 +1374,36 @@ pub static MZ_STORAGE_USAGE: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         ),
 });
 
+pub static MZ_STORAGE_ROW_COUNTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_storage_row_counts",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column("row_count", ScalarType::UInt64.nullable(false)),
+});
+
+pub static MZ_SINK_STATISTICS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_sink_statistics",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("sink_id", ScalarType::String.nullable(false))
+        .with_column("messages_sent", ScalarType::UInt64.nullable(false))
+        .with_column("bytes_sent", ScalarType::UInt64.nullable(false))
+        .with_column("producer_errors", ScalarType::UInt64.nullable(false))
+        .with_column("write_frontier", ScalarType::UInt64.nullable(true))
+        .with_column("lag", ScalarType::Interval.nullable(true)),
+});
+
+pub const MZ_RECENT_STORAGE_USAGE: BuiltinView = BuiltinView {
+    name: "mz_recent_storage_usage",
+    schema: MZ_CATALOG_SCHEMA,
+    sql: "CREATE VIEW mz_catalog.mz_recent_storage_usage (object_id, size_bytes, row_count) AS
+SELECT u.object_id, u.size_bytes, c.row_count
+FROM mz_catalog.mz_storage_usage u
+LEFT JOIN mz_catalog.mz_storage_row_counts c ON u.object_id = c.object_id
+WHERE u.collection_timestamp = (SELECT max(collection_timestamp) FROM mz_catalog.mz_storage_usage)",
+};
+
 pub const MZ_RELATIONS: BuiltinView = BuiltinView {
     name: "mz_relations",
     schema: MZ_CATALOG_SCHEMA,
@@ -1329,7 +1417,9 @@ UNION ALL SELECT id, oid, schema_id, name, 'materialized view' FROM mz_catalog.m
 pub const MZ_OBJECTS: BuiltinView = BuiltinView {
     name: "mz_objects",
     schema: MZ_CATALOG_SCHEMA,
-    sql: "CREATE VIEW mz_catalog.mz_objects (id, oid, schema_id, name, type) AS
+    sql: "CREATE VIEW mz_catalog.mz_objects (id, oid, schema_id, name, type, comment) AS
+SELECT o.id, o.oid, o.schema_id, o.name, o.type, c.comment
+FROM (
     SELECT id, oid, schema_id, name, type FROM mz_catalog.mz_relations
 UNION ALL
     SELECT id, oid, schema_id, name, 'sink' FROM mz_catalog.mz_sinks
@@ -1344,7 +1434,9 @@ UNION ALL
 UNION ALL
     SELECT id, oid, schema_id, name, 'function' FROM mz_catalog.mz_functions
 UNION ALL
-    SELECT id, NULL::pg_catalog.oid, schema_id, name, 'secret' FROM mz_catalog.mz_secrets",
+    SELECT id, NULL::pg_catalog.oid, schema_id, name, 'secret' FROM mz_catalog.mz_secrets
+) o
+LEFT JOIN mz_catalog.mz_comments c ON c.id = o.id AND c.column_position IS NULL",
 };
 
 // For historical reasons, this view does not properly escape identifiers. For
@@ -1551,6 +1643,7 @@ pub const PG_CLASS: BuiltinView = BuiltinView {
         WHEN class_objects.type = 'view' THEN 'v'
         WHEN class_objects.type = 'materialized view' THEN 'm'
     END relkind,
+    (SELECT count(*) FROM mz_catalog.mz_columns WHERE mz_columns.id = class_objects.id)::pg_catalog.int2 AS relnatts,
     -- MZ doesn't support CHECK constraints so relchecks is filled with 0
     0::pg_catalog.int2 AS relchecks,
     -- MZ doesn't support creating rules so relhasrules is filled with false
@@ -1631,12 +1724,15 @@ pub const PG_DESCRIPTION: BuiltinView = BuiltinView {
     name: "pg_description",
     schema: PG_CATALOG_SCHEMA,
     sql: "CREATE VIEW pg_catalog.pg_description AS SELECT
-    c.oid as objoid,
+    o.oid as objoid,
     NULL::pg_catalog.oid as classoid,
-    0::pg_catalog.int4 as objsubid,
-    NULL::pg_catalog.text as description
-FROM pg_catalog.pg_class c
-JOIN mz_catalog.mz_databases d ON (d.id IS NULL OR d.name = pg_catalog.current_database())",
+    COALESCE(c.column_position::pg_catalog.int4, 0) as objsubid,
+    c.comment as description
+FROM mz_catalog.mz_comments c
+JOIN mz_catalog.mz_objects o ON o.id = c.id
+JOIN mz_catalog.mz_schemas s ON s.id = o.schema_id
+LEFT JOIN mz_catalog.mz_databases d ON d.id = s.database_id
+WHERE s.database_id IS NULL OR d.name = pg_catalog.current_database()",
 };
 
 pub const PG_TYPE: BuiltinView = BuiltinView {
@@ -2047,12 +2143,16 @@ SELECT
     o.name AS table_name,
     c.name AS column_name,
     c.position::int8 AS ordinal_position,
+    c.default AS column_default,
+    CASE WHEN c.nullable THEN 'YES' ELSE 'NO' END AS is_nullable,
     c.type AS data_type,
     NULL::pg_catalog.int4 AS character_maximum_length,
     NULL::pg_catalog.int4 AS numeric_precision,
-    NULL::pg_catalog.int4 AS numeric_scale
+    NULL::pg_catalog.int4 AS numeric_scale,
+    cm.comment AS column_comment
 FROM mz_catalog.mz_columns c
 JOIN mz_catalog.mz_objects o ON o.id = c.id
+LEFT JOIN mz_catalog.mz_comments cm ON cm.id = c.id AND cm.column_position = c.position
 JOIN mz_catalog.mz_schemas s ON s.id = o.schema_id
 JOIN mz_catalog.mz_databases d on s.database_id = d.id",
 };
@@ -2067,10 +2167,12 @@ pub const INFORMATION_SCHEMA_TABLES: BuiltinView = BuiltinView {
     CASE r.type
         WHEN 'table' THEN 'BASE TABLE'
         ELSE pg_catalog.upper(r.type)
-    END AS table_type
+    END AS table_type,
+    cm.comment AS table_comment
 FROM mz_catalog.mz_relations r
 JOIN mz_catalog.mz_schemas s ON s.id = r.schema_id
-JOIN mz_catalog.mz_databases d on s.database_id = d.id",
+JOIN mz_catalog.mz_databases d on s.database_id = d.id
+LEFT JOIN mz_catalog.mz_comments cm ON cm.id = r.id AND cm.column_position IS NULL",
 };
 
 // MZ doesn't support COLLATE so the table is filled with NULLs and made empty. pg_database hard
@@ -2271,6 +2373,8 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_DATABASES),
         Builtin::Table(&MZ_SCHEMAS),
         Builtin::Table(&MZ_COLUMNS),
+        Builtin::Table(&MZ_COMMENTS),
+        Builtin::Table(&MZ_OBJECT_DEPENDENCIES),
         Builtin::Table(&MZ_INDEXES),
         Builtin::Table(&MZ_INDEX_COLUMNS),
         Builtin::Table(&MZ_TABLES),
@@ -2293,10 +2397,17 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_CLUSTER_REPLICAS_BASE),
         Builtin::Table(&MZ_CLUSTER_REPLICA_STATUSES),
         Builtin::Table(&MZ_CLUSTER_REPLICA_HEARTBEATS),
+        Builtin::Table(&MZ_SESSIONS),
+        Builtin::Table(&MZ_GROUP_COMMIT_STATISTICS),
+        Builtin::Table(&MZ_GROUP_COMMITS),
+        Builtin::Table(&MZ_GROUP_COMMIT_TABLE_UPDATES),
         Builtin::Table(&MZ_AUDIT_EVENTS),
         Builtin::Table(&MZ_STORAGE_USAGE),
+        Builtin::Table(&MZ_STORAGE_ROW_COUNTS),
+        Builtin::Table(&MZ_SINK_STATISTICS),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECTS),
+        Builtin::View(&MZ_RECENT_STORAGE_USAGE),
         Builtin::View(&MZ_CATALOG_NAMES),
         Builtin::View(&MZ_ARRANGEMENT_SHARING),
         Builtin::View(&MZ_ARRANGEMENT_SIZES),
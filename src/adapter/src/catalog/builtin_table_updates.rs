@@ -17,27 +17,33 @@ use mz_expr::MirScalarExpr;
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::{Datum, Diff, GlobalId, Row};
-use mz_sql::ast::{CreateIndexStatement, Statement};
+use mz_sql::ast::{CreateIndexStatement, Expr, Statement, Value};
 use mz_sql::catalog::{CatalogDatabase, CatalogType, TypeCategory};
 use mz_sql::names::{DatabaseId, ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier};
 use mz_sql_parser::ast::display::AstDisplay;
+use mz_storage::protocol::client::SinkStatistics;
 use mz_storage::types::sinks::{KafkaSinkConnection, StorageSinkConnection};
 
 use crate::catalog::builtin::{
     MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_BASE_TYPES, MZ_CLUSTERS, MZ_CLUSTER_REPLICAS_BASE,
-    MZ_CLUSTER_REPLICA_HEARTBEATS, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_CONNECTIONS,
-    MZ_DATABASES, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_SINKS, MZ_LIST_TYPES,
-    MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE, MZ_TABLES, MZ_TYPES,
+    MZ_CLUSTER_REPLICA_HEARTBEATS, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_COMMENTS,
+    MZ_CONNECTIONS, MZ_DATABASES, MZ_FUNCTIONS, MZ_GROUP_COMMITS, MZ_GROUP_COMMIT_STATISTICS,
+    MZ_GROUP_COMMIT_TABLE_UPDATES, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_SINKS, MZ_LIST_TYPES,
+    MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_PSEUDO_TYPES, MZ_ROLES,
+    MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SINK_STATISTICS, MZ_SOURCES,
+    MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_ROW_COUNTS, MZ_STORAGE_USAGE, MZ_TABLES, MZ_TYPES,
     MZ_VIEWS,
 };
 use crate::catalog::{
     CatalogItem, CatalogState, Connection, Error, ErrorKind, Func, Index, MaterializedView, Sink,
     StorageSinkConnectionState, Type, View, SYSTEM_CONN_ID,
 };
-use crate::coord::ReplicaMetadata;
+use crate::client::ConnectionId;
+use crate::coord::{GroupCommitMetadata, ReplicaMetadata, SessionMetadata};
+use crate::metrics::GroupCommitStatistics;
 
 /// An update to a built-in table.
 #[derive(Debug)]
@@ -102,6 +108,26 @@ impl CatalogState {
         }
     }
 
+    pub(super) fn pack_comment_update(
+        &self,
+        object_id: GlobalId,
+        column_position: Option<usize>,
+        comment: &str,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_COMMENTS),
+            row: Row::pack_slice(&[
+                Datum::String(&object_id.to_string()),
+                column_position
+                    .map(|position| Datum::UInt64(u64::cast_from(position)))
+                    .unwrap_or(Datum::Null),
+                Datum::String(comment),
+            ]),
+            diff,
+        }
+    }
+
     pub(super) fn pack_compute_instance_update(
         &self,
         name: &str,
@@ -212,13 +238,29 @@ impl CatalogState {
             }
         };
 
+        for dependency in entry.uses() {
+            updates.push(BuiltinTableUpdate {
+                id: self.resolve_builtin_table(&MZ_OBJECT_DEPENDENCIES),
+                row: Row::pack_slice(&[
+                    Datum::String(&id.to_string()),
+                    Datum::String(&dependency.to_string()),
+                ]),
+                diff,
+            });
+        }
+
         if let Ok(desc) = entry.desc(&self.resolve_full_name(entry.name(), entry.conn_id())) {
             let defaults = match entry.item() {
                 CatalogItem::Table(table) => Some(&table.defaults),
                 _ => None,
             };
             for (i, (column_name, column_type)) in desc.iter().enumerate() {
-                let default: Option<String> = defaults.map(|d| d[i].to_ast_string_stable());
+                // Columns without a default expression are stored with a
+                // default of `NULL`, which is reported as no default.
+                let default: Option<String> = defaults.and_then(|d| match &d[i] {
+                    Expr::Value(Value::Null) => None,
+                    default => Some(default.to_ast_string_stable()),
+                });
                 let default: Datum = default
                     .as_ref()
                     .map(|d| Datum::String(d))
@@ -308,6 +350,7 @@ impl CatalogState {
                     }
                     mz_storage::types::connections::Connection::Postgres { .. } => "postgres",
                     mz_storage::types::connections::Connection::Aws(..) => "aws",
+                    mz_storage::types::connections::Connection::Gcp(..) => "gcp",
                     mz_storage::types::connections::Connection::Ssh { .. } => "ssh",
                 }),
             ]),
@@ -725,6 +768,81 @@ impl CatalogState {
         }
     }
 
+    pub fn pack_session_update(
+        &self,
+        conn_id: ConnectionId,
+        md: &SessionMetadata,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let client_addr = md.client_addr.map(|addr| addr.to_string());
+        let row = Row::pack_slice(&[
+            Datum::UInt32(conn_id),
+            Datum::String(&md.user),
+            Datum::String(&md.application_name),
+            match &client_addr {
+                Some(addr) => Datum::String(addr),
+                None => Datum::Null,
+            },
+            Datum::TimestampTz(md.connected_at),
+        ]);
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_SESSIONS),
+            row,
+            diff,
+        }
+    }
+
+    pub fn pack_group_commit_statistics_update(
+        &self,
+        stats: &GroupCommitStatistics,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let row = Row::pack_slice(&[
+            Datum::UInt64(stats.group_commits),
+            Datum::UInt64(stats.transactions),
+            Datum::UInt64(stats.updates),
+            Datum::Float64(stats.now_wait_seconds.into()),
+            Datum::Float64(stats.append_seconds.into()),
+        ]);
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_GROUP_COMMIT_STATISTICS),
+            row,
+            diff,
+        }
+    }
+
+    pub fn pack_group_commit_update(
+        &self,
+        md: &GroupCommitMetadata,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate> {
+        let mut updates = vec![BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_GROUP_COMMITS),
+            row: Row::pack_slice(&[
+                Datum::UInt64(md.id),
+                Datum::UInt64(md.timestamp),
+                Datum::UInt64(md.transactions),
+                Datum::UInt64(md.table_updates.values().sum()),
+                Datum::Float64(md.now_wait.as_secs_f64().into()),
+                Datum::Float64(md.append_duration.as_secs_f64().into()),
+            ]),
+            diff,
+        }];
+        let table = self.resolve_builtin_table(&MZ_GROUP_COMMIT_TABLE_UPDATES);
+        for (table_id, table_updates) in &md.table_updates {
+            updates.push(BuiltinTableUpdate {
+                id: table,
+                row: Row::pack_slice(&[
+                    Datum::UInt64(md.id),
+                    Datum::String(&table_id.to_string()),
+                    Datum::UInt64(*table_updates),
+                ]),
+                diff,
+            });
+        }
+        updates
+    }
+
     pub fn pack_storage_usage_update(
         &self,
         event: &VersionedStorageUsage,
@@ -756,4 +874,48 @@ impl CatalogState {
             diff,
         })
     }
+
+    pub fn pack_storage_row_count_update(
+        &self,
+        id: GlobalId,
+        row_count: u64,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_STORAGE_ROW_COUNTS),
+            row: Row::pack_slice(&[Datum::String(&id.to_string()), Datum::UInt64(row_count)]),
+            diff,
+        }
+    }
+
+    /// Packs the statistics of sink `id` into a row of `mz_sink_statistics`.
+    ///
+    /// `write_frontier` is `None` once the sink has finished writing, and
+    /// `lag_ms` is the number of milliseconds by which the sink's write
+    /// frontier trails that of the collection it reads from.
+    pub fn pack_sink_statistics_update(
+        &self,
+        id: GlobalId,
+        statistics: &SinkStatistics,
+        write_frontier: Option<mz_repr::Timestamp>,
+        lag_ms: Option<u64>,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        let lag = lag_ms.map(|ms| {
+            let micros = i64::try_from(ms.saturating_mul(1_000)).unwrap_or(i64::MAX);
+            Interval::new(0, 0, micros)
+        });
+        BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_SINK_STATISTICS),
+            row: Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt64(statistics.messages_sent),
+                Datum::UInt64(statistics.bytes_sent),
+                Datum::UInt64(statistics.producer_errors),
+                Datum::from(write_frontier),
+                Datum::from(lag),
+            ]),
+            diff,
+        }
+    }
 }
@@ -552,6 +552,16 @@ impl<S: Append> Connection<S> {
             .collect()
     }
 
+    /// Load the persisted comments on catalog items and their columns.
+    pub async fn load_comments(&mut self) -> Result<Vec<(GlobalId, Option<usize>, String)>, Error> {
+        Ok(COLLECTION_COMMENTS
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(|(k, v)| (k.object_id, k.column_position, v.comment))
+            .collect())
+    }
+
     /// Persist mapping from system objects to global IDs and fingerprints.
     ///
     /// Panics if provided id is not a system id.
@@ -751,6 +761,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
     let timestamps = COLLECTION_TIMESTAMP.peek_one(stash).await?;
     let system_gid_mapping = COLLECTION_SYSTEM_GID_MAPPING.peek_one(stash).await?;
     let system_configurations = COLLECTION_SYSTEM_CONFIGURATION.peek_one(stash).await?;
+    let comments = COLLECTION_COMMENTS.peek_one(stash).await?;
 
     Ok(Transaction {
         stash,
@@ -771,6 +782,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
         timestamps: TableTransaction::new(timestamps, |_a, _b| false),
         system_gid_mapping: TableTransaction::new(system_gid_mapping, |_a, _b| false),
         system_configurations: TableTransaction::new(system_configurations, |_a, _b| false),
+        comments: TableTransaction::new(comments, |_a, _b| false),
         audit_log_updates: Vec::new(),
         storage_usage_updates: Vec::new(),
     })
@@ -793,6 +805,7 @@ pub struct Transaction<'a, S> {
     timestamps: TableTransaction<TimestampKey, TimestampValue>,
     system_gid_mapping: TableTransaction<GidMappingKey, GidMappingValue>,
     system_configurations: TableTransaction<ServerConfigurationKey, ServerConfigurationValue>,
+    comments: TableTransaction<CommentKey, CommentValue>,
     // Don't make this a table transaction so that it's not read into the stash
     // memory cache.
     audit_log_updates: Vec<(AuditLogKey, (), i64)>,
@@ -1206,6 +1219,31 @@ impl<'a, S: Append> Transaction<'a, S> {
         self.system_configurations.delete(|_k, _v| true);
     }
 
+    /// Sets the comment on an item, or on one of its columns if
+    /// `column_position` is specified. A `comment` of `None` removes the
+    /// existing comment.
+    pub fn update_comment(
+        &mut self,
+        object_id: GlobalId,
+        column_position: Option<usize>,
+        comment: Option<String>,
+    ) -> Result<(), Error> {
+        let key = CommentKey {
+            object_id,
+            column_position,
+        };
+        self.comments.delete(|k, _v| k == &key);
+        if let Some(comment) = comment {
+            self.comments.insert(key, CommentValue { comment })?;
+        }
+        Ok(())
+    }
+
+    /// Removes all comments on an item and its columns.
+    pub fn remove_comments(&mut self, object_id: GlobalId) {
+        self.comments.delete(|k, _v| k.object_id == object_id);
+    }
+
     pub fn remove_timestamp(&mut self, timeline: Timeline) {
         let timeline_str = timeline.to_string();
         let n = self.timestamps.delete(|k, _v| k.id == timeline_str).len();
@@ -1337,6 +1375,13 @@ impl<'a, S: Append> Transaction<'a, S> {
             self.system_configurations.pending(),
         )
         .await?;
+        add_batch(
+            self.stash,
+            &mut batches,
+            &COLLECTION_COMMENTS,
+            self.comments.pending(),
+        )
+        .await?;
         add_batch(
             self.stash,
             &mut batches,
@@ -1417,6 +1462,7 @@ pub async fn initialize_stash<S: Append>(stash: &mut S) -> Result<(), Error> {
     add_batch(stash, &mut batches, &COLLECTION_ROLE).await?;
     add_batch(stash, &mut batches, &COLLECTION_TIMESTAMP).await?;
     add_batch(stash, &mut batches, &COLLECTION_SYSTEM_CONFIGURATION).await?;
+    add_batch(stash, &mut batches, &COLLECTION_COMMENTS).await?;
     add_batch(stash, &mut batches, &COLLECTION_AUDIT_LOG).await?;
     add_batch(stash, &mut batches, &COLLECTION_STORAGE_USAGE).await?;
     stash.append(&batches).await.map_err(|e| e.into())
@@ -1566,6 +1612,17 @@ struct ServerConfigurationValue {
     value: String,
 }
 
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash)]
+struct CommentKey {
+    object_id: GlobalId,
+    column_position: Option<usize>,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
+struct CommentValue {
+    comment: String,
+}
+
 static COLLECTION_CONFIG: TypedCollection<String, ConfigValue> = TypedCollection::new("config");
 static COLLECTION_SETTING: TypedCollection<SettingKey, SettingValue> =
     TypedCollection::new("setting");
@@ -1597,3 +1654,5 @@ static COLLECTION_SYSTEM_CONFIGURATION: TypedCollection<
 static COLLECTION_AUDIT_LOG: TypedCollection<AuditLogKey, ()> = TypedCollection::new("audit_log");
 static COLLECTION_STORAGE_USAGE: TypedCollection<StorageUsageKey, ()> =
     TypedCollection::new("storage_usage");
+static COLLECTION_COMMENTS: TypedCollection<CommentKey, CommentValue> =
+    TypedCollection::new("comments");
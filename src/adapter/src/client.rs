@@ -18,17 +18,19 @@ use uuid::Uuid;
 use mz_ore::collections::CollectionExt;
 use mz_ore::id_gen::IdAllocator;
 use mz_ore::thread::JoinOnDropHandle;
-use mz_repr::{GlobalId, Row, ScalarType};
+use mz_repr::{GlobalId, RelationDesc, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement};
 
 use crate::catalog::SYSTEM_USER;
 use crate::command::{
-    Canceled, Command, ExecuteResponse, Response, SimpleExecuteResponse, SimpleResult,
-    StartupResponse,
+    Canceled, Command, ExecuteResponse, Response, SimpleDescription, SimpleExecuteResponse,
+    SimpleResult, StartupResponse,
 };
 use crate::coord::peek::PeekResponseUnary;
 use crate::error::AdapterError;
-use crate::session::{EndTransactionAction, PreparedStatement, Session};
+use crate::session::{
+    verify_implicit_transaction, EndTransactionAction, PreparedStatement, RowBatchStream, Session,
+};
 
 /// An abstraction allowing us to name different connections.
 pub type ConnectionId = u32;
@@ -400,6 +402,7 @@ impl SessionClient {
     ) -> Result<SimpleExecuteResponse, AdapterError> {
         let stmts =
             mz_sql::parse::parse(&stmts).map_err(|e| AdapterError::Unstructured(e.into()))?;
+        verify_implicit_transaction(&stmts)?;
         let num_stmts = stmts.len();
         const EMPTY_PORTAL: &str = "";
         let mut results = vec![];
@@ -424,7 +427,14 @@ impl SessionClient {
                 continue;
             }
 
-            let res = match self.execute(EMPTY_PORTAL.into()).await {
+            let res = self.execute(EMPTY_PORTAL.into()).await;
+            let notices: Vec<_> = self
+                .session()
+                .drain_notices()
+                .into_iter()
+                .map(|notice| notice.to_string())
+                .collect();
+            let res = match res {
                 Ok(res) => res,
                 Err(e) => {
                     results.push(SimpleResult::err(e));
@@ -480,9 +490,10 @@ impl SessionClient {
                 | ExecuteResponse::AlteredObject(_)
                 | ExecuteResponse::AlteredIndexLogicalCompaction
                 | ExecuteResponse::AlteredSystemConfiguraion
+                | ExecuteResponse::Comment
                 | ExecuteResponse::Deallocate { all: _ }
                 | ExecuteResponse::Prepare) => {
-                    results.push(SimpleResult::ok(res));
+                    results.push(SimpleResult::ok(res, notices));
                 }
                 ExecuteResponse::SendingRows {
                     future: rows,
@@ -499,12 +510,18 @@ impl SessionClient {
                                 .push(SimpleResult::err("statement canceled due to user request"));
                             continue;
                         }
+                        PeekResponseUnary::TimedOut => {
+                            results.push(SimpleResult::err(AdapterError::StatementTimeout));
+                            continue;
+                        }
                     };
                     let mut sql_rows: Vec<Vec<serde_json::Value>> = vec![];
-                    let col_names = match desc.relation_desc {
-                        Some(desc) => desc.iter_names().map(|name| name.to_string()).collect(),
-                        None => vec![],
-                    };
+                    let desc = SimpleDescription::new(
+                        desc.relation_desc
+                            .as_ref()
+                            .unwrap_or(&RelationDesc::empty()),
+                    );
+                    let col_names = desc.columns.iter().map(|c| c.name.clone()).collect();
                     let mut datum_vec = mz_repr::DatumVec::new();
                     for row in rows {
                         let datums = datum_vec.borrow_with(&row);
@@ -513,6 +530,8 @@ impl SessionClient {
                     results.push(SimpleResult::Rows {
                         rows: sql_rows,
                         col_names,
+                        desc,
+                        notices,
                     });
                 }
                 ExecuteResponse::Fetch { .. }
@@ -538,6 +557,49 @@ impl SessionClient {
         Ok(SimpleExecuteResponse { results })
     }
 
+    /// Starts a `TAIL` using a simple protocol that does not involve portals.
+    ///
+    /// `stmt` must contain exactly one `TAIL` statement. Returns the names of
+    /// the columns in the rows the `TAIL` produces, and the stream of those
+    /// rows. The `TAIL` runs until the caller ends the transaction that this
+    /// function starts.
+    pub async fn simple_tail(
+        &mut self,
+        stmt: &str,
+    ) -> Result<(Vec<String>, RowBatchStream), AdapterError> {
+        let mut stmts =
+            mz_sql::parse::parse(stmt).map_err(|e| AdapterError::Unstructured(e.into()))?;
+        if stmts.len() != 1 || !matches!(stmts[0], Statement::Tail(_)) {
+            coord_bail!("expected a single TAIL statement");
+        }
+        let stmt = stmts.remove(0);
+        const EMPTY_PORTAL: &str = "";
+        self.start_transaction(Some(1)).await?;
+        self.declare(EMPTY_PORTAL.into(), stmt, vec![]).await?;
+
+        let desc = self
+            .session()
+            // We do not need to verify here because `self.execute` verifies below.
+            .get_portal_unverified(EMPTY_PORTAL)
+            .map(|portal| portal.desc.clone())
+            .expect("unnamed portal should be present");
+        if !desc.param_types.is_empty() {
+            coord_bail!("query parameters are not supported");
+        }
+        let col_names = match desc.relation_desc {
+            Some(desc) => desc.iter_names().map(|name| name.to_string()).collect(),
+            None => vec![],
+        };
+
+        match self.execute(EMPTY_PORTAL.into()).await? {
+            ExecuteResponse::Tailing { rx } => Ok((col_names, rx)),
+            ExecuteResponse::Canceled => {
+                coord_bail!("statement canceled due to user request")
+            }
+            _ => coord_bail!("expected TAIL to produce a stream of rows"),
+        }
+    }
+
     /// Returns a mutable reference to the session bound to this client.
     pub fn session(&mut self) -> &mut Session {
         self.session.as_mut().unwrap()
@@ -20,7 +20,7 @@ use tokio_postgres::error::SqlState;
 
 use mz_ore::str::StrExt;
 use mz_pgcopy::CopyFormatParams;
-use mz_repr::{GlobalId, Row, ScalarType};
+use mz_repr::{GlobalId, RelationDesc, Row, ScalarType};
 use mz_sql::ast::{FetchDirection, NoticeSeverity, ObjectType, Raw, Statement};
 use mz_sql::plan::ExecuteTimeout;
 
@@ -183,6 +183,8 @@ pub enum ExecuteResponse {
     Canceled,
     /// The requested cursor was closed.
     ClosedCursor,
+    /// The requested comment was set.
+    Comment,
     CopyTo {
         format: mz_sql::plan::CopyFormat,
         resp: Box<ExecuteResponse>,
@@ -316,6 +318,9 @@ pub enum ExecuteResponse {
     SetVariable {
         name: String,
         tag: &'static str,
+        /// The name of the session database, if the variable changed it to a
+        /// database that does not exist.
+        unknown_database: Option<String>,
     },
     /// A new transaction was started.
     StartedTransaction {
@@ -360,6 +365,7 @@ impl ExecuteResponse {
             AlteredSystemConfiguraion => Some("ALTER SYSTEM".into()),
             Canceled => None,
             ClosedCursor => Some("CLOSE CURSOR".into()),
+            Comment => Some("COMMENT".into()),
             CopyTo { .. } => None,
             CopyFrom { .. } => None,
             CreatedConnection { .. } => created!("connection"),
@@ -526,6 +532,15 @@ impl ExecuteResponse {
                 }
             }
 
+            SetVariable {
+                unknown_database: Some(name),
+                ..
+            } => Some(ExecuteResponsePartialError {
+                severity: ClientSeverity::Notice,
+                code: SqlState::INVALID_CATALOG_NAME,
+                message: format!("session database {} does not exist", name.quoted()),
+            }),
+
             TransactionExited { was_implicit, .. } => {
                 // In Postgres, if a user sends a COMMIT or ROLLBACK in an implicit
                 // transaction, a warning is sent warning them. (The transaction is still closed
@@ -548,6 +563,7 @@ impl ExecuteResponse {
             | AlteredSystemConfiguraion
             | Canceled
             | ClosedCursor
+            | Comment
             | CopyTo { .. }
             | CopyFrom { .. }
             | Deallocate { .. }
@@ -610,12 +626,20 @@ pub enum SimpleResult {
         rows: Vec<Vec<serde_json::Value>>,
         /// The name of the columns in the row.
         col_names: Vec<String>,
+        /// The description of the columns in the row.
+        desc: SimpleDescription,
+        /// The notices produced by the query.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        notices: Vec<String>,
     },
     /// The query executed successfully but did not return rows.
     Ok {
         ok: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         partial_err: Option<ExecuteResponsePartialError>,
+        /// The notices produced by the query.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        notices: Vec<String>,
     },
     /// The query returned an error.
     Err { error: String },
@@ -633,13 +657,56 @@ impl SimpleResult {
     /// # Panics
     /// - If [`ExecuteResponse::partial_err`] returns an error with
     ///   [`ClientSeverity::Error`].
-    pub(crate) fn ok(res: ExecuteResponse) -> SimpleResult {
+    pub(crate) fn ok(res: ExecuteResponse, notices: Vec<String>) -> SimpleResult {
         let ok = res.tag();
         let partial_err = res.partial_err();
-        SimpleResult::Ok { ok, partial_err }
+        SimpleResult::Ok {
+            ok,
+            partial_err,
+            notices,
+        }
     }
 }
 
+/// The description of the rows returned by a query executed with
+/// `simple_execute`.
+#[derive(Debug, Serialize)]
+pub struct SimpleDescription {
+    pub columns: Vec<SimpleColumn>,
+}
+
+impl SimpleDescription {
+    pub(crate) fn new(desc: &RelationDesc) -> SimpleDescription {
+        let columns = desc
+            .iter()
+            .map(|(name, typ)| {
+                let pg_type = mz_pgrepr::Type::from(&typ.scalar_type);
+                SimpleColumn {
+                    name: name.to_string(),
+                    type_oid: pg_type.oid(),
+                    type_len: pg_type.typlen(),
+                    type_mod: pg_type.typmod(),
+                    nullable: typ.nullable,
+                }
+            })
+            .collect();
+        SimpleDescription { columns }
+    }
+}
+
+/// The description of a single column in a [`SimpleDescription`].
+///
+/// The type fields match those in a PostgreSQL `RowDescription` message.
+#[derive(Debug, Serialize)]
+pub struct SimpleColumn {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_len: i16,
+    pub type_mod: i32,
+    /// Whether the column may contain `NULL`.
+    pub nullable: bool,
+}
+
 /// The state of a cancellation request.
 #[derive(Debug, Clone, Copy)]
 pub enum Canceled {
@@ -67,11 +67,12 @@
 //!
 
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::net::IpAddr;
 use std::num::NonZeroUsize;
 use std::ops::Neg;
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Context;
 use chrono::{DateTime, Utc};
@@ -104,6 +105,7 @@ use mz_sql::names::Aug;
 use mz_sql::plan::{MutationKind, Params};
 use mz_stash::Append;
 use mz_storage::controller::CollectionDescription;
+use mz_storage::protocol::client::SinkStatistics;
 use mz_storage::types::connections::ConnectionContext;
 use mz_storage::types::sinks::StorageSinkConnection;
 use mz_storage::types::sources::{IngestionDescription, Timeline};
@@ -122,6 +124,7 @@ use crate::coord::peek::PendingPeek;
 use crate::coord::read_policy::{ReadCapability, ReadHolds};
 use crate::coord::timeline::{TimelineState, WriteTimestamp};
 use crate::error::AdapterError;
+use crate::metrics::{GroupCommitStatistics, Metrics};
 use crate::session::{EndTransactionAction, Session};
 use crate::sink_connection;
 use crate::tail::PendingTail;
@@ -139,15 +142,15 @@ mod message_handler;
 mod read_policy;
 mod sequencer;
 mod sql;
-mod timeline;
+pub(crate) mod timeline;
 mod timestamp_selection;
 
-/// The default is set to a second to track the default timestamp frequency for sources.
-pub const DEFAULT_LOGICAL_COMPACTION_WINDOW_MS: Option<u64> = Some(1_000);
-
 /// The default interval at which to collect storage usage information.
 pub const DEFAULT_STORAGE_USAGE_COLLECTION_INTERVAL: Duration = Duration::from_secs(3600);
 
+/// The interval at which `mz_sink_statistics` is refreshed.
+const SINK_STATISTICS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
 /// A dummy availability zone to use when no availability zones are explicitly
 /// specified.
 pub const DUMMY_AVAILABILITY_ZONE: &str = "";
@@ -160,6 +163,11 @@ pub enum Message<T = mz_repr::Timestamp> {
     SinkConnectionReady(SinkConnectionReady),
     SendDiffs(SendDiffs),
     WriteLockGrant(tokio::sync::OwnedMutexGuard<()>),
+    /// The deferred plan with the given `DeferredPlan::id` waited for the write
+    /// lock for longer than its session's `lock_timeout`.
+    WriteLockTimeout {
+        id: u64,
+    },
     /// Initiates a group commit.
     GroupCommitInitiate,
     /// Makes a group commit visible to all clients.
@@ -170,6 +178,11 @@ pub enum Message<T = mz_repr::Timestamp> {
         Vec<CompletedClientTransmitter<ExecuteResponse>>,
         /// Optional lock if the group commit contained writes to user tables.
         Option<OwnedMutexGuard<()>>,
+        /// Metadata for `mz_group_commits` if the group commit contained
+        /// writes to user tables.
+        Option<GroupCommitMetadata>,
+        /// User tables whose appends have an indeterminate outcome.
+        Vec<GlobalId>,
     ),
     ComputeInstanceStatus(ComputeInstanceEvent),
     RemovePendingPeeks {
@@ -177,8 +190,14 @@ pub enum Message<T = mz_repr::Timestamp> {
     },
     LinearizeReads(Vec<PendingTxn>),
     StorageUsageFetch,
-    StorageUsageUpdate(HashMap<Option<ShardId>, u64>),
+    StorageUsageUpdate(
+        /// The size of each shard, as reported by persist.
+        HashMap<Option<ShardId>, u64>,
+        /// The number of updates stored for each storage collection.
+        BTreeMap<GlobalId, u64>,
+    ),
     Consolidate(Vec<mz_stash::Id>),
+    SinkStatisticsRefresh,
 }
 
 #[derive(Derivative)]
@@ -214,6 +233,9 @@ pub struct SinkConnectionReady {
     pub tx: ClientTransmitter<ExecuteResponse>,
     pub id: GlobalId,
     pub oid: u32,
+    /// Read holds on the sinked collection at the timestamp requested with
+    /// `AS OF`, to be released once the sink has been created.
+    pub(crate) read_holds: Option<read_policy::ReadHolds<Timestamp>>,
     pub result: Result<StorageSinkConnection, AdapterError>,
 }
 
@@ -232,6 +254,9 @@ pub struct Config<S> {
     pub default_storage_host_size: Option<String>,
     pub connection_context: ConnectionContext,
     pub storage_usage_client: StorageUsageClient,
+    /// The PostgreSQL URL of the store shared by the timestamp oracles of
+    /// coordinators that run at the same time, if any.
+    pub timestamp_oracle_url: Option<String>,
 }
 
 /// Soft-state metadata about a compute replica
@@ -241,6 +266,37 @@ pub struct ReplicaMetadata {
     pub last_heartbeat: DateTime<Utc>,
 }
 
+/// Metadata about a session that is exposed in `mz_sessions`.
+#[derive(Clone, Debug)]
+pub struct SessionMetadata {
+    /// The user that the session is authenticated as.
+    pub user: String,
+    /// The value of `application_name` when the session started.
+    pub application_name: String,
+    /// The address of the client, if known.
+    pub client_addr: Option<IpAddr>,
+    /// The time at which the session started.
+    pub connected_at: DateTime<Utc>,
+}
+
+/// Metadata about a group commit that is exposed in `mz_group_commits`.
+#[derive(Clone, Debug)]
+pub struct GroupCommitMetadata {
+    /// A sequence number that identifies the group commit.
+    pub id: u64,
+    /// The timestamp at which the group commit wrote.
+    pub timestamp: Timestamp,
+    /// The number of transactions that wrote to user tables.
+    pub transactions: u64,
+    /// The number of updates written to each user table.
+    pub table_updates: BTreeMap<GlobalId, u64>,
+    /// The time the group commit waited for the system clock to catch up with
+    /// the timestamp of its writes.
+    pub now_wait: Duration,
+    /// The time the group commit waited for its writes to be durably recorded.
+    pub append_duration: Duration,
+}
+
 /// Metadata about an active connection.
 struct ConnMeta {
     /// A watch channel shared with the client to inform the client of
@@ -255,6 +311,11 @@ struct ConnMeta {
     /// requests are required to authenticate with the secret of the connection
     /// that they are targeting.
     secret_key: u32,
+    session_metadata: SessionMetadata,
+    /// The timestamp of the most recent write or DDL statement that this
+    /// connection committed, if any. Used to choose timestamps for reads when
+    /// the `read_your_writes` session variable is set.
+    last_write_ts: Option<Timestamp>,
 }
 
 struct TxnReads {
@@ -299,6 +360,9 @@ pub struct Coordinator<S> {
     /// Mechanism for totally ordering write and read timestamps, so that all reads
     /// reflect exactly the set of writes that precede them, and no writes that follow.
     global_timelines: BTreeMap<Timeline, TimelineState<Timestamp>>,
+    /// Connection to the store shared by timestamp oracles, if timelines are
+    /// shared with other coordinators.
+    timestamp_oracle_client: Option<Arc<tokio_postgres::Client>>,
 
     transient_id_counter: u64,
     /// A map from connection ID to metadata about that connection for all
@@ -335,8 +399,43 @@ pub struct Coordinator<S> {
     write_lock: Arc<tokio::sync::Mutex<()>>,
     /// Holds plans deferred due to write lock.
     write_lock_wait_group: VecDeque<Deferred>,
+    /// Whether a task is acquiring the write lock on behalf of
+    /// `write_lock_wait_group`.
+    write_lock_waiting: bool,
+    /// The identifier of the next plan deferred due to the write lock.
+    next_deferred_plan_id: u64,
     /// Pending writes waiting for a group commit
     pending_writes: Vec<PendingWriteTxn>,
+    /// Whether a `GroupCommitInitiate` message has been sent by
+    /// `trigger_group_commit` and not yet processed.
+    group_commit_triggered: bool,
+    /// Whether a group commit has been scheduled to run once the
+    /// `group_commit_interval` elapses.
+    group_commit_scheduled: bool,
+    /// Tables that the next group commit must advance because a read is
+    /// waiting for them, even though they were not written to.
+    tables_to_advance: BTreeSet<GlobalId>,
+    /// When the last group commit that advanced all tables ran.
+    last_full_table_advancement: Instant,
+    /// Tables of the current full table advancement that remain to be
+    /// advanced by subsequent group commits.
+    table_advancement_backlog: Vec<GlobalId>,
+    /// The group commit statistics most recently written to
+    /// `mz_group_commit_statistics`, if any.
+    group_commit_statistics: Option<GroupCommitStatistics>,
+    /// The time that the next group commit has spent waiting for the system
+    /// clock so far.
+    group_commit_now_wait: Duration,
+    /// The ID of the next group commit written to `mz_group_commits`.
+    next_group_commit_id: u64,
+    /// Group commits that have completed but are not yet written to
+    /// `mz_group_commits`.
+    completed_group_commits: Vec<GroupCommitMetadata>,
+    /// The group commits currently written to `mz_group_commits`, oldest
+    /// first.
+    recent_group_commits: VecDeque<GroupCommitMetadata>,
+
+    metrics: Metrics,
 
     /// Handle to secret manager that can create and delete secrets from
     /// an arbitrary secret storage engine.
@@ -354,8 +453,13 @@ pub struct Coordinator<S> {
 
     // Persist client for fetching storage metadata such as size metrics.
     storage_usage_client: StorageUsageClient,
+    /// The row counts currently written to `mz_storage_row_counts`.
+    storage_row_counts: BTreeMap<GlobalId, u64>,
     /// The interval at which to collect storage usage information.
     storage_usage_collection_interval: Duration,
+    /// The statistics currently written to `mz_sink_statistics`, along with
+    /// the write frontier and lag they were written with.
+    sink_statistics: BTreeMap<GlobalId, (SinkStatistics, Option<Timestamp>, Option<u64>)>,
 }
 
 impl<S: Append + 'static> Coordinator<S> {
@@ -401,7 +505,7 @@ impl<S: Append + 'static> Coordinator<S> {
 
         self.initialize_storage_read_policies(
             persisted_source_ids,
-            DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+            self.default_logical_compaction_window_ms(),
         )
         .await;
 
@@ -454,6 +558,9 @@ impl<S: Append + 'static> Coordinator<S> {
 
         // Capture identifiers that need to have their read holds relaxed once the bootstrap completes.
         let mut policies_to_set: CollectionIdBundle = Default::default();
+        // Sources and materialized views that override the default logical
+        // compaction window.
+        let mut storage_policies_to_set = Vec::new();
 
         // This is disabled for the moment because it has unusual upper
         // advancement behavior.
@@ -503,7 +610,14 @@ impl<S: Append + 'static> Coordinator<S> {
                         .await
                         .unwrap();
 
-                    policies_to_set.storage_ids.insert(entry.id());
+                    if source.logical_compaction_window.is_some() {
+                        storage_policies_to_set.push((
+                            entry.id(),
+                            self.logical_compaction_window_ms(source.logical_compaction_window),
+                        ));
+                    } else {
+                        policies_to_set.storage_ids.insert(entry.id());
+                    }
                 }
                 CatalogItem::Table(table) => {
                     let collection_desc = table.desc.clone().into();
@@ -553,7 +667,14 @@ impl<S: Append + 'static> Coordinator<S> {
                         .await
                         .unwrap();
 
-                    policies_to_set.storage_ids.insert(entry.id());
+                    if mview.logical_compaction_window.is_some() {
+                        storage_policies_to_set.push((
+                            entry.id(),
+                            self.logical_compaction_window_ms(mview.logical_compaction_window),
+                        ));
+                    } else {
+                        policies_to_set.storage_ids.insert(entry.id());
+                    }
 
                     // Re-create the sink on the compute instance.
                     let id_bundle = self
@@ -616,8 +737,23 @@ impl<S: Append + 'static> Coordinator<S> {
         }
 
         // Having installed all entries, creating all constraints, we can now relax read policies.
-        self.initialize_read_policies(policies_to_set, DEFAULT_LOGICAL_COMPACTION_WINDOW_MS)
+        self.initialize_read_policies(policies_to_set, self.default_logical_compaction_window_ms())
             .await;
+        for (id, compaction_window_ms) in storage_policies_to_set {
+            self.initialize_storage_read_policies(vec![id], compaction_window_ms)
+                .await;
+        }
+
+        // Re-apply any options that were explicitly set on indexes, which
+        // override the default read policies installed above.
+        for entry in &entries {
+            if let CatalogItem::Index(idx) = entry.item() {
+                if !idx.options.is_empty() {
+                    self.set_index_options(entry.id(), idx.options.clone())
+                        .await?;
+                }
+            }
+        }
 
         // Announce the completion of initialization.
         self.controller.initialization_complete();
@@ -750,6 +886,9 @@ impl<S: Append + 'static> Coordinator<S> {
         let mut storage_usage_update_interval =
             tokio::time::interval(self.storage_usage_collection_interval);
 
+        // Refresh the statistics reported by sinks on a regular interval.
+        let mut sink_statistics_interval = tokio::time::interval(SINK_STATISTICS_REFRESH_INTERVAL);
+
         loop {
             // Before adding a branch to this select loop, please ensure that the branch is
             // cancellation safe and add a comment explaining why. You can refer here for more
@@ -789,6 +928,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 // https://docs.rs/tokio/1.19.2/tokio/time/struct.Interval.html#cancel-safety
                 _ = advance_timelines_interval.tick() => Message::GroupCommitInitiate,
                 _ = storage_usage_update_interval.tick() => Message::StorageUsageFetch,
+                _ = sink_statistics_interval.tick() => Message::SinkStatisticsRefresh,
                 // `recv()` on `UnboundedReceiver` is cancellation safe:
                 // https://docs.rs/tokio/1.8.0/tokio/sync/mpsc/struct.UnboundedReceiver.html#cancel-safety
                 Some(collections) = consolidations_rx.recv() => {
@@ -832,6 +972,7 @@ pub async fn serve<S: Append + 'static>(
         mut availability_zones,
         connection_context,
         storage_usage_client,
+        timestamp_oracle_url,
     }: Config<S>,
 ) -> Result<(Handle, Client), AdapterError> {
     let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
@@ -869,6 +1010,7 @@ pub async fn serve<S: Append + 'static>(
         })
         .await?;
     let cluster_id = catalog.config().cluster_id;
+    let metrics = Metrics::register_with(&metrics_registry);
     let session_id = catalog.config().session_id;
     let start_instant = catalog.config().start_instant;
 
@@ -879,6 +1021,10 @@ pub async fn serve<S: Append + 'static>(
     let handle = TokioHandle::current();
 
     let initial_timestamps = catalog.get_all_persisted_timestamps().await?;
+    let timestamp_oracle_client = match timestamp_oracle_url {
+        Some(url) => Some(timeline::connect_timestamp_oracle(&url).await?),
+        None => None,
+    };
     let thread = thread::Builder::new()
         // The Coordinator thread tends to keep a lot of data on its stack. To
         // prevent a stack overflow we allocate a stack twice as big as the default
@@ -890,18 +1036,20 @@ pub async fn serve<S: Append + 'static>(
             for (timeline, initial_timestamp) in initial_timestamps {
                 let oracle = if timeline == Timeline::EpochMilliseconds {
                     let now = now.clone();
-                    handle.block_on(timeline::DurableTimestampOracle::new(
+                    handle.block_on(timeline::new_timestamp_oracle(
+                        &catalog,
+                        timestamp_oracle_client.as_ref(),
+                        &timeline,
                         initial_timestamp,
                         move || (*&(now))(),
-                        *timeline::TIMESTAMP_PERSIST_INTERVAL,
-                        |ts| catalog.persist_timestamp(&timeline, ts),
                     ))
                 } else {
-                    handle.block_on(timeline::DurableTimestampOracle::new(
+                    handle.block_on(timeline::new_timestamp_oracle(
+                        &catalog,
+                        timestamp_oracle_client.as_ref(),
+                        &timeline,
                         initial_timestamp,
                         Timestamp::minimum,
-                        *timeline::TIMESTAMP_PERSIST_INTERVAL,
-                        |ts| catalog.persist_timestamp(&timeline, ts),
                     ))
                 };
                 timestamp_oracles.insert(
@@ -921,6 +1069,7 @@ pub async fn serve<S: Append + 'static>(
                 strict_serializable_reads_tx,
                 consolidations_tx,
                 global_timelines: timestamp_oracles,
+                timestamp_oracle_client,
                 transient_id_counter: 1,
                 active_conns: HashMap::new(),
                 read_capability: Default::default(),
@@ -930,12 +1079,27 @@ pub async fn serve<S: Append + 'static>(
                 pending_tails: HashMap::new(),
                 write_lock: Arc::new(tokio::sync::Mutex::new(())),
                 write_lock_wait_group: VecDeque::new(),
+                write_lock_waiting: false,
+                next_deferred_plan_id: 0,
                 pending_writes: Vec::new(),
+                group_commit_triggered: false,
+                group_commit_scheduled: false,
+                tables_to_advance: BTreeSet::new(),
+                last_full_table_advancement: Instant::now(),
+                table_advancement_backlog: Vec::new(),
+                group_commit_statistics: None,
+                group_commit_now_wait: Duration::ZERO,
+                next_group_commit_id: 0,
+                completed_group_commits: Vec::new(),
+                recent_group_commits: VecDeque::new(),
+                metrics,
                 secrets_controller,
                 connection_context,
                 transient_replica_metadata: HashMap::new(),
                 storage_usage_client,
+                storage_row_counts: BTreeMap::new(),
                 storage_usage_collection_interval: DEFAULT_STORAGE_USAGE_COLLECTION_INTERVAL,
+                sink_statistics: BTreeMap::new(),
             };
             let bootstrap =
                 handle.block_on(coord.bootstrap(builtin_migration_metadata, builtin_table_updates));
@@ -9,28 +9,53 @@
 
 //! Logic and types for all appends executed by the [`Coordinator`].
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use derivative::Derivative;
+use futures::future;
+use itertools::Itertools;
 use timely::PartialOrder;
 use tokio::sync::OwnedMutexGuard;
 use tracing::warn;
 
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_repr::{Diff, GlobalId, Row, Timestamp};
 use mz_sql::plan::Plan;
 use mz_stash::Append;
+use mz_storage::controller::StorageError;
 use mz_storage::protocol::client::Update;
 use mz_storage::types::sources::Timeline;
 
 use crate::catalog::BuiltinTableUpdate;
+use crate::client::ConnectionId;
+use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::timeline::{TimelineState, WriteTimestamp};
-use crate::coord::{Coordinator, Message, PendingTxn};
+use crate::coord::{Coordinator, GroupCommitMetadata, Message, PendingTxn};
 use crate::session::{Session, WriteOp};
 use crate::util::{ClientTransmitter, CompletedClientTransmitter};
-use crate::ExecuteResponse;
+use crate::{AdapterError, ExecuteResponse};
+
+/// The maximum number of tables that a single group commit advances as part of
+/// a full table advancement.
+///
+/// The remaining tables are advanced by subsequent group commits, so that
+/// advancing thousands of tables does not monopolize the coordinator.
+const MAX_TABLE_ADVANCEMENTS_PER_GROUP_COMMIT: usize = 1_000;
+
+/// The maximum number of updates to a single table that a group commit hands to storage in one
+/// chunk.
+const MAX_UPDATES_PER_APPEND_CHUNK: usize = 10_000;
+
+/// The maximum number of tables that a group commit advances in one append.
+const MAX_TABLES_PER_ADVANCEMENT_CHUNK: usize = 100;
+
+/// The number of completed group commits that `mz_group_commits` retains.
+///
+/// Only group commits that commit writes to user tables are retained.
+const MAX_RECENT_GROUP_COMMITS: usize = 100;
 
 /// An operation that is deferred while waiting for a lock.
 pub(crate) enum Deferred {
@@ -47,6 +72,9 @@ pub(crate) struct DeferredPlan {
     pub tx: ClientTransmitter<ExecuteResponse>,
     pub session: Session,
     pub plan: Plan,
+    /// Uniquely identifies this deferral, so that its lock timeout does not
+    /// apply to later deferrals of the same session.
+    pub id: u64,
 }
 
 /// Describes what action triggered an update to a builtin table.
@@ -77,6 +105,16 @@ pub(crate) enum PendingWriteTxn {
 }
 
 impl PendingWriteTxn {
+    /// Returns whether this transaction holds the coordinator's write lock.
+    fn has_write_lock(&self) -> bool {
+        match self {
+            PendingWriteTxn::User {
+                write_lock_guard, ..
+            } => write_lock_guard.is_some(),
+            PendingWriteTxn::System { .. } => false,
+        }
+    }
+
     fn take_write_lock(&mut self) -> Option<OwnedMutexGuard<()>> {
         match self {
             PendingWriteTxn::User {
@@ -103,7 +141,8 @@ impl PendingWriteTxn {
 /// tables, e.g. `INSERT`, `UPDATE`.
 ///
 /// If the provided session doesn't currently hold the write lock, attempts to
-/// grant it. If the coord cannot immediately grant the write lock, defers
+/// grant it. If the coord cannot immediately grant the write lock, or other
+/// operations are already waiting for it, defers
 /// executing the provided plan until the write lock is available, and exits the
 /// function.
 ///
@@ -120,11 +159,17 @@ impl PendingWriteTxn {
 macro_rules! guard_write_critical_section {
     ($coord:expr, $tx:expr, $session:expr, $plan_to_defer: expr) => {
         if !$session.has_write_lock() {
-            if $coord.try_grant_session_write_lock(&mut $session).is_err() {
+            // Don't jump ahead of operations that are already waiting for the lock.
+            $coord.prune_write_lock_wait_group();
+            if !$coord.write_lock_wait_group.is_empty()
+                || $coord.try_grant_session_write_lock(&mut $session).is_err()
+            {
+                let id = $coord.allocate_deferred_plan_id();
                 $coord.defer_write(Deferred::Plan(DeferredPlan {
                     tx: $tx,
                     session: $session,
                     plan: $plan_to_defer,
+                    id,
                 }));
                 return;
             }
@@ -147,6 +192,11 @@ impl<S: Append + 'static> Coordinator<S> {
             // large amount of time in case the system clock then advances back to near
             // what it was.
             let remaining_ms = std::cmp::min(timestamp.saturating_sub(now), 1_000);
+            let now_wait = Duration::from_millis(remaining_ms);
+            self.metrics
+                .group_commit_now_wait_seconds
+                .inc_by(now_wait.as_secs_f64());
+            self.group_commit_now_wait += now_wait;
             let internal_cmd_tx = self.internal_cmd_tx.clone();
             task::spawn(|| "group_commit_initiate", async move {
                 tokio::time::sleep(Duration::from_millis(remaining_ms)).await;
@@ -157,7 +207,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 }
             });
         } else {
-            self.group_commit_initiate().await;
+            self.group_commit_initiate(None).await;
         }
     }
 
@@ -169,9 +219,19 @@ impl<S: Append + 'static> Coordinator<S> {
     /// All applicable pending writes will be combined into a single Append command and sent to
     /// STORAGE as a single batch. All applicable writes will happen at the same timestamp and all
     /// involved tables will be advanced to some timestamp larger than the timestamp of the write.
+    ///
+    /// `write_lock_guard` is the write lock if it has already been granted to this group commit,
+    /// i.e., if this is a deferred group commit.
     #[tracing::instrument(level = "debug", skip_all)]
-    pub(crate) async fn group_commit_initiate(&mut self) {
-        let (write_lock_guard, pending_writes): (_, Vec<_>) = if self
+    pub(crate) async fn group_commit_initiate(
+        &mut self,
+        write_lock_guard: Option<OwnedMutexGuard<()>>,
+    ) {
+        self.group_commit_scheduled = false;
+        let (write_lock_guard, pending_writes): (_, Vec<_>) = if let Some(guard) = write_lock_guard
+        {
+            (Some(guard), self.pending_writes.drain(..).collect())
+        } else if self
             .pending_writes
             .iter()
             .all(|write| matches!(write, PendingWriteTxn::System { .. }))
@@ -180,14 +240,15 @@ impl<S: Append + 'static> Coordinator<S> {
             // If none of the pending transactions are for user tables, then we don't need the
             // write lock.
             (None, self.pending_writes.drain(..).collect())
-        } else if let Some(guard) = self
+        } else if self
             .pending_writes
-            .iter_mut()
-            .find_map(|write| write.take_write_lock())
+            .iter()
+            .any(|write| write.has_write_lock())
         {
             // If some pending transaction already holds the write lock, then we can execute a group
-            // commit.
-            (Some(guard), self.pending_writes.drain(..).collect())
+            // commit. The lock is taken from the transaction once the group commit has been
+            // limited, which never defers the transaction holding it.
+            (None, self.pending_writes.drain(..).collect())
         } else if let Ok(guard) = Arc::clone(&self.write_lock).try_lock_owned() {
             // If no pending transaction holds the write lock, then we need to acquire it.
             (Some(guard), self.pending_writes.drain(..).collect())
@@ -215,6 +276,19 @@ impl<S: Append + 'static> Coordinator<S> {
             }
             (None, pending_writes)
         };
+        if write_lock_guard.is_some() || pending_writes.iter().any(|write| write.has_write_lock()) {
+            // This group commit commits all pending user writes, including those of a deferred
+            // group commit that is still waiting for the write lock, so that waiter can be
+            // dropped rather than commit nothing once it gets the lock.
+            self.write_lock_wait_group
+                .retain(|deferred| !matches!(deferred, Deferred::GroupCommit));
+        }
+        let mut pending_writes = self.limit_group_commit(pending_writes);
+        let write_lock_guard = write_lock_guard.or_else(|| {
+            pending_writes
+                .iter_mut()
+                .find_map(|write| write.take_write_lock())
+        });
 
         // The value returned here still might be ahead of `now()` if `now()` has gone backwards at
         // any point during this method or if this was triggered from DDL. We will still commit the
@@ -227,9 +301,14 @@ impl<S: Append + 'static> Coordinator<S> {
             timestamp,
             advance_to,
         } = self.get_local_write_ts().await;
+        let mut group_commit = self.record_group_commit(&pending_writes, timestamp);
+        pending_writes.extend(self.group_commit_statistics_updates(group_commit.is_some()));
+        pending_writes.extend(self.group_commit_history_updates());
         let mut appends: HashMap<GlobalId, Vec<(Row, Diff)>> =
             HashMap::with_capacity(self.pending_writes.len());
         let mut responses = Vec::with_capacity(self.pending_writes.len());
+        // The user tables that each transaction in `responses` wrote to.
+        let mut response_tables = Vec::with_capacity(self.pending_writes.len());
         let should_block = pending_writes.iter().any(|write| write.should_block());
         for pending_write_txn in pending_writes {
             match pending_write_txn {
@@ -244,6 +323,8 @@ impl<S: Append + 'static> Coordinator<S> {
                             action,
                         },
                 } => {
+                    self.record_session_write(session.conn_id(), timestamp);
+                    let mut tables = BTreeSet::new();
                     for WriteOp { id, rows } in writes {
                         // If the table that some write was targeting has been deleted while the
                         // write was waiting, then the write will be ignored and we respond to the
@@ -252,6 +333,7 @@ impl<S: Append + 'static> Coordinator<S> {
                         // write before the delete without violating any consistency guarantees.
                         if self.catalog.try_get_entry(&id).is_some() {
                             appends.entry(id).or_default().extend(rows);
+                            tables.insert(id);
                         }
                     }
                     responses.push(CompletedClientTransmitter::new(
@@ -260,6 +342,7 @@ impl<S: Append + 'static> Coordinator<S> {
                         session,
                         action,
                     ));
+                    response_tables.push(tables);
                 }
                 PendingWriteTxn::System { update, .. } => {
                     appends
@@ -273,49 +356,109 @@ impl<S: Append + 'static> Coordinator<S> {
         for (_, updates) in &mut appends {
             differential_dataflow::consolidation::consolidate(updates);
         }
-        // Add table advancements for all tables.
-        for table in self.catalog.entries().filter(|entry| entry.is_table()) {
-            appends.entry(table.id()).or_default();
+        // Tables that were written to are advanced above. Additionally advance the tables that
+        // reads are waiting for, and every `table_advancement_interval` all tables. Advancing all
+        // tables keeps the frontiers of idle tables, and of the dataflows and sinks that depend on
+        // them, from falling behind. Advancing every table on every group commit would produce a
+        // constant stream of empty appends in deployments with many idle tables.
+        let full_table_advancement_interval =
+            self.catalog.system_config().table_advancement_interval();
+        if self.table_advancement_backlog.is_empty()
+            && self.last_full_table_advancement.elapsed() >= full_table_advancement_interval
+        {
+            self.last_full_table_advancement = Instant::now();
+            self.table_advancement_backlog = self
+                .catalog
+                .entries()
+                .filter(|entry| entry.is_table())
+                .map(|entry| entry.id())
+                .collect();
         }
-        let appends = appends
+        let chunk = std::cmp::min(
+            self.table_advancement_backlog.len(),
+            MAX_TABLE_ADVANCEMENTS_PER_GROUP_COMMIT,
+        );
+        let backlog = self.table_advancement_backlog.drain(..chunk);
+        for id in std::mem::take(&mut self.tables_to_advance)
             .into_iter()
-            .map(|(id, updates)| {
-                let updates = updates
-                    .into_iter()
-                    .map(|(row, diff)| Update {
-                        row,
-                        diff,
-                        timestamp,
-                    })
-                    .collect();
-                (id, updates, advance_to)
-            })
-            .collect();
+            .chain(backlog)
+        {
+            // The table may have been dropped since it was marked for advancement.
+            if self.catalog.try_get_entry(&id).is_some() {
+                appends.entry(id).or_default();
+            }
+        }
+        if !self.table_advancement_backlog.is_empty() {
+            self.trigger_group_commit();
+        }
 
+        // Tables that are only advanced are appended separately, so that responding to the
+        // writers does not wait for the advancement of many idle tables. Reads of a table wait for
+        // its upper, so responses need not wait for advancements to complete.
+        let (appends, advancements): (Vec<_>, Vec<_>) = appends
+            .into_iter()
+            .partition(|(_, updates)| !updates.is_empty());
+        let mut commands = Vec::with_capacity(appends.len());
+        for (id, updates) in appends {
+            commands.extend(chunk_appends(id, updates, timestamp, advance_to));
+        }
+
+        let append_start = Instant::now();
+        let append_seconds = self.metrics.group_commit_append_seconds.clone();
         let append_fut = self
             .controller
             .storage_mut()
-            .append(appends)
+            .append(commands)
             .expect("invalid updates");
+        // Advancements are sent after the writes, so that storage does not process them first.
+        self.advance_tables(
+            advancements.into_iter().map(|(id, _)| id).collect(),
+            advance_to,
+        );
         if should_block {
             // We may panic here if the storage controller has shut down, because we cannot
             // correctly return control, nor can we simply hang here.
             // TODO: Clean shutdown.
-            append_fut
+            let result = append_fut
                 .await
-                .expect("One-shot dropped while waiting synchronously")
-                .unwrap();
-            self.group_commit_apply(timestamp, responses, true, write_lock_guard)
-                .await;
+                .expect("One-shot dropped while waiting synchronously");
+            let indeterminate_tables = match result {
+                Ok(()) => Vec::new(),
+                Err(e) => fail_group_commit(e, &mut responses, &response_tables),
+            };
+            let append_duration = append_start.elapsed();
+            append_seconds.inc_by(append_duration.as_secs_f64());
+            if let Some(group_commit) = &mut group_commit {
+                group_commit.append_duration = append_duration;
+            }
+            self.group_commit_apply(
+                timestamp,
+                responses,
+                true,
+                write_lock_guard,
+                group_commit,
+                indeterminate_tables,
+            )
+            .await;
         } else {
             let internal_cmd_tx = self.internal_cmd_tx.clone();
             task::spawn(|| "group_commit_apply", async move {
-                if let Ok(response) = append_fut.await {
-                    response.unwrap();
+                if let Ok(result) = append_fut.await {
+                    let indeterminate_tables = match result {
+                        Ok(()) => Vec::new(),
+                        Err(e) => fail_group_commit(e, &mut responses, &response_tables),
+                    };
+                    let append_duration = append_start.elapsed();
+                    append_seconds.inc_by(append_duration.as_secs_f64());
+                    if let Some(group_commit) = &mut group_commit {
+                        group_commit.append_duration = append_duration;
+                    }
                     if let Err(e) = internal_cmd_tx.send(Message::GroupCommitApply(
                         timestamp,
                         responses,
                         write_lock_guard,
+                        group_commit,
+                        indeterminate_tables,
                     )) {
                         warn!("Server closed with non-responded writes, {e}");
                     }
@@ -326,6 +469,45 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
+    /// Advances the uppers of `tables` to `advance_to` without writing to them.
+    ///
+    /// The advancements are appended separately from the writes of the group commit, in chunks of
+    /// at most `MAX_TABLES_PER_ADVANCEMENT_CHUNK` tables. All chunks are in flight at once, and
+    /// nothing waits for them to complete.
+    fn advance_tables(&mut self, tables: Vec<GlobalId>, advance_to: Timestamp) {
+        if tables.is_empty() {
+            return;
+        }
+        let append_futs: Vec<_> = tables
+            .chunks(MAX_TABLES_PER_ADVANCEMENT_CHUNK)
+            .map(|chunk| {
+                let advancements = chunk
+                    .iter()
+                    .map(|id| (*id, Vec::new(), advance_to))
+                    .collect();
+                self.controller
+                    .storage_mut()
+                    .append(advancements)
+                    .expect("invalid updates")
+            })
+            .collect();
+        task::spawn(|| "group_commit_advance_tables", async move {
+            for result in future::join_all(append_futs).await {
+                match result {
+                    Ok(Ok(())) => (),
+                    // The tables are advanced again by a later group commit.
+                    Ok(Err(StorageError::IndeterminateAppends(ids))) => {
+                        warn!("Table advancements in indefinite state: {ids:?}")
+                    }
+                    Ok(Err(e)) => panic!("advancing tables failed: {e}"),
+                    Err(_) => {
+                        warn!("Writer terminated with table advancements in indefinite state")
+                    }
+                }
+            }
+        });
+    }
+
     /// Applies the results of a completed group commit. The read timestamp of the timeline
     /// containing user tables will be advanced to the timestamp of the completed write, the read
     /// hold on the timeline containing user tables is advanced to the new time, and responses are
@@ -336,6 +518,11 @@ impl<S: Append + 'static> Coordinator<S> {
     ///
     /// We also advance all other timelines and update the read holds of non-realtime
     /// timelines.
+    ///
+    /// Whether the appends to `indeterminate_tables` succeeded is unknown, so their uppers might
+    /// not have advanced past `timestamp`. They are advanced by another group commit, which is
+    /// triggered right away, so that reads at `timestamp` do not wait for the next full table
+    /// advancement.
     #[tracing::instrument(level = "debug", skip(self, responses))]
     pub(crate) async fn group_commit_apply(
         &mut self,
@@ -343,8 +530,15 @@ impl<S: Append + 'static> Coordinator<S> {
         responses: Vec<CompletedClientTransmitter<ExecuteResponse>>,
         in_ddl: bool,
         _write_lock_guard: Option<OwnedMutexGuard<()>>,
+        group_commit: Option<GroupCommitMetadata>,
+        indeterminate_tables: Vec<GlobalId>,
     ) {
+        if !indeterminate_tables.is_empty() {
+            self.tables_to_advance.extend(indeterminate_tables);
+            self.trigger_group_commit();
+        }
         self.apply_local_write(timestamp).await;
+        self.completed_group_commits.extend(group_commit);
 
         // If we're in the middle of DDL then the catalog and COMPUTE/STORAGE may be out of sync,
         // so we don't advance other timelines and we don't update read holds.
@@ -367,9 +561,7 @@ impl<S: Append + 'static> Coordinator<S> {
                     let id_bundle = self.ids_in_timeline(&timeline);
                     self.largest_not_in_advance_of_upper(&id_bundle)
                 };
-                oracle
-                    .apply_write(now, |ts| self.catalog.persist_timestamp(&timeline, ts))
-                    .await;
+                oracle.apply_write(now).await;
                 let read_ts = oracle.read_ts();
                 if read_holds.time.less_than(&read_ts) {
                     read_holds = self.update_read_hold(read_holds, read_ts).await;
@@ -384,12 +576,251 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
+    /// Limits the user transactions in `pending_writes` to `max_group_commit_rows` rows. The
+    /// transactions that don't fit are returned to the front of `self.pending_writes` and another
+    /// group commit is triggered for them.
+    ///
+    /// Writes to system tables are never deferred, since DDL relies on its writes being applied
+    /// in the group commit it triggers. Neither is the transaction holding the write lock: its
+    /// writes are based on reads that are only valid while it holds the lock. At least one user
+    /// transaction is always committed, even if it is larger than the limit by itself, so that
+    /// no transaction is deferred forever.
+    fn limit_group_commit(&mut self, pending_writes: Vec<PendingWriteTxn>) -> Vec<PendingWriteTxn> {
+        let max_rows = usize::cast_from(self.catalog.system_config().max_group_commit_rows());
+        let mut rows = 0;
+        let mut has_user_write = false;
+        let mut committed = Vec::with_capacity(pending_writes.len());
+        let mut deferred = Vec::new();
+        for pending_write_txn in pending_writes {
+            match &pending_write_txn {
+                PendingWriteTxn::System { .. } => committed.push(pending_write_txn),
+                PendingWriteTxn::User { writes, .. } => {
+                    let txn_rows: usize = writes.iter().map(|write| write.rows.len()).sum();
+                    let over_limit =
+                        !deferred.is_empty() || (has_user_write && rows + txn_rows > max_rows);
+                    if over_limit && !pending_write_txn.has_write_lock() {
+                        deferred.push(pending_write_txn);
+                    } else {
+                        rows += txn_rows;
+                        has_user_write = true;
+                        committed.push(pending_write_txn);
+                    }
+                }
+            }
+        }
+        if !deferred.is_empty() {
+            self.pending_writes.splice(0..0, deferred);
+            self.trigger_group_commit();
+        }
+        committed
+    }
+
+    /// Records the size of a group commit that will commit `pending_writes` at `timestamp`.
+    ///
+    /// If the group commit commits any user transactions, returns its metadata for
+    /// `mz_group_commits`. The append duration is filled in once the append completes.
+    fn record_group_commit(
+        &mut self,
+        pending_writes: &[PendingWriteTxn],
+        timestamp: Timestamp,
+    ) -> Option<GroupCommitMetadata> {
+        let mut transactions = 0;
+        let mut table_updates: BTreeMap<GlobalId, u64> = BTreeMap::new();
+        for pending_write_txn in pending_writes {
+            if let PendingWriteTxn::User { writes, .. } = pending_write_txn {
+                transactions += 1;
+                for WriteOp { id, rows } in writes {
+                    *table_updates.entry(*id).or_default() += u64::cast_from(rows.len());
+                }
+            }
+        }
+        self.metrics.group_commits.inc();
+        self.metrics.group_commit_transactions.inc_by(transactions);
+        self.metrics
+            .group_commit_updates
+            .inc_by(table_updates.values().sum());
+
+        let now_wait = std::mem::take(&mut self.group_commit_now_wait);
+        if transactions == 0 {
+            return None;
+        }
+        let id = self.next_group_commit_id;
+        self.next_group_commit_id += 1;
+        Some(GroupCommitMetadata {
+            id,
+            timestamp,
+            transactions,
+            table_updates,
+            now_wait,
+            append_duration: Duration::ZERO,
+        })
+    }
+
+    /// Returns the writes that replace the contents of `mz_group_commit_statistics` with the
+    /// current group commit metrics.
+    ///
+    /// Unless the group commit commits user transactions, the row is only replaced if the
+    /// statistics changed other than by counting group commits. Otherwise every idle tick would
+    /// write to `mz_group_commit_statistics`.
+    fn group_commit_statistics_updates(
+        &mut self,
+        commits_user_transactions: bool,
+    ) -> Vec<PendingWriteTxn> {
+        let stats = self.metrics.group_commit_statistics();
+        if !commits_user_transactions
+            && self
+                .group_commit_statistics
+                .as_ref()
+                .map_or(false, |prev| !prev.differs_in_activity(&stats))
+        {
+            return Vec::new();
+        }
+        let mut updates = Vec::new();
+        if let Some(prev) = self.group_commit_statistics.replace(stats.clone()) {
+            updates.push(
+                self.catalog
+                    .state()
+                    .pack_group_commit_statistics_update(&prev, -1),
+            );
+        }
+        updates.push(
+            self.catalog
+                .state()
+                .pack_group_commit_statistics_update(&stats, 1),
+        );
+        updates
+            .into_iter()
+            .map(|update| PendingWriteTxn::System {
+                update,
+                source: BuiltinTableUpdateSource::Background,
+            })
+            .collect()
+    }
+
+    /// Returns the writes that add the group commits that completed since the last group commit to
+    /// `mz_group_commits` and `mz_group_commit_table_updates`, and that remove the group commits
+    /// that exceed [`MAX_RECENT_GROUP_COMMITS`].
+    fn group_commit_history_updates(&mut self) -> Vec<PendingWriteTxn> {
+        let mut updates = Vec::new();
+        for group_commit in std::mem::take(&mut self.completed_group_commits) {
+            updates.extend(
+                self.catalog
+                    .state()
+                    .pack_group_commit_update(&group_commit, 1),
+            );
+            self.recent_group_commits.push_back(group_commit);
+        }
+        while self.recent_group_commits.len() > MAX_RECENT_GROUP_COMMITS {
+            let group_commit = self
+                .recent_group_commits
+                .pop_front()
+                .expect("recent group commits is not empty");
+            updates.extend(
+                self.catalog
+                    .state()
+                    .pack_group_commit_update(&group_commit, -1),
+            );
+        }
+        updates
+            .into_iter()
+            .map(|update| PendingWriteTxn::System {
+                update,
+                source: BuiltinTableUpdateSource::Background,
+            })
+            .collect()
+    }
+
+    /// Reports whether the writes to user tables that are waiting for a group commit have reached
+    /// `max_pending_write_rows`, in which case new write transactions must be rejected.
+    ///
+    /// Without this limit, a flood of writes that arrive faster than they can be committed could
+    /// exhaust the coordinator's memory.
+    pub(crate) fn pending_writes_exhausted(&self) -> bool {
+        let limit = usize::cast_from(self.catalog.system_config().max_pending_write_rows());
+        let mut rows = 0;
+        for pending_write_txn in &self.pending_writes {
+            if let PendingWriteTxn::User { writes, .. } = pending_write_txn {
+                rows += writes.iter().map(|write| write.rows.len()).sum::<usize>();
+            }
+        }
+        rows >= limit
+    }
+
+    /// Ensures that the tables that `id_bundle` depends on are advanced past `timestamp`.
+    ///
+    /// Group commit only advances the tables that it writes to, so the upper of an idle table
+    /// may lag behind the timestamps chosen for reads. If a table that a read at `timestamp`
+    /// depends on has not been advanced past `timestamp`, it is advanced by the next group commit
+    /// and a group commit is triggered, so that the read does not have to wait for the next full
+    /// table advancement.
+    pub(crate) fn advance_tables_for_read(
+        &mut self,
+        id_bundle: &CollectionIdBundle,
+        timestamp: Timestamp,
+    ) {
+        let mut tables = BTreeSet::new();
+        for id in id_bundle.iter() {
+            self.catalog.tables_used_by(id, &mut tables);
+        }
+        let storage = self.controller.storage();
+        let lagging: Vec<_> = tables
+            .into_iter()
+            .filter(|id| {
+                storage
+                    .collection(*id)
+                    .expect("table exists")
+                    .write_frontier
+                    .less_equal(&timestamp)
+            })
+            .collect();
+        if !lagging.is_empty() {
+            self.tables_to_advance.extend(lagging);
+            self.trigger_group_commit();
+        }
+    }
+
+    /// Records that the connection identified by `conn_id` committed a write at `timestamp`, so
+    /// that its subsequent reads can be made to observe the write.
+    pub(crate) fn record_session_write(&mut self, conn_id: ConnectionId, timestamp: Timestamp) {
+        if let Some(conn_meta) = self.active_conns.get_mut(&conn_id) {
+            conn_meta.last_write_ts = Some(timestamp);
+        }
+    }
+
+    /// Triggers a group commit, unless one is already triggered and has not run yet.
+    ///
+    /// A group commit commits all pending writes, so there is no need to queue more than one.
+    pub(crate) fn trigger_group_commit(&mut self) {
+        if !self.group_commit_triggered {
+            self.group_commit_triggered = true;
+            self.internal_cmd_tx
+                .send(Message::GroupCommitInitiate)
+                .expect("sending to self.internal_cmd_tx cannot fail");
+        }
+    }
+
     /// Submit a write to be executed during the next group commit.
+    ///
+    /// If `group_commit_interval` is set, the group commit is delayed by that interval so that
+    /// more writes can accumulate and be committed together.
     pub(crate) fn submit_write(&mut self, pending_write_txn: PendingWriteTxn) {
-        self.internal_cmd_tx
-            .send(Message::GroupCommitInitiate)
-            .expect("sending to self.internal_cmd_tx cannot fail");
         self.pending_writes.push(pending_write_txn);
+        let interval = self.catalog.system_config().group_commit_interval();
+        // An interval of zero is parsed as the maximum duration.
+        if interval == Duration::from_secs(u64::MAX) {
+            self.trigger_group_commit();
+        } else if !self.group_commit_scheduled {
+            self.group_commit_scheduled = true;
+            let internal_cmd_tx = self.internal_cmd_tx.clone();
+            task::spawn(|| "group_commit_interval", async move {
+                tokio::time::sleep(interval).await;
+                // It is not an error for this task to be running after `internal_cmd_rx` is dropped.
+                let result = internal_cmd_tx.send(Message::GroupCommitInitiate);
+                if let Err(e) = result {
+                    warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                }
+            });
+        }
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(updates = updates.len()))]
@@ -398,34 +829,107 @@ impl<S: Append + 'static> Coordinator<S> {
         updates: Vec<BuiltinTableUpdate>,
         source: BuiltinTableUpdateSource,
     ) {
-        // Most DDL queries cause writes to system tables. Unlike writes to user tables, system
-        // table writes do not wait for a group commit, they explicitly trigger one. There is a
-        // possibility that if a user is executing DDL at a rate faster than 1 query per
-        // millisecond, then the global timeline will unboundedly advance past the system clock.
-        // This can cause future queries to block, but will not affect correctness. Since this
-        // rate of DDL is unlikely, we allow DDL to explicitly trigger group commit.
         self.pending_writes
             .extend(updates.into_iter().map(|update| PendingWriteTxn::System {
                 update,
                 source: source.clone(),
             }));
-        self.group_commit_initiate().await;
+        match source {
+            // Most DDL queries cause writes to system tables. Unlike writes to user tables, these
+            // writes do not wait for a group commit, they explicitly trigger one, because the
+            // catalog and the system tables must be in sync before the DDL returns. There is a
+            // possibility that if a user is executing DDL at a rate faster than 1 query per
+            // millisecond, then the global timeline will unboundedly advance past the system
+            // clock. This can cause future queries to block, but will not affect correctness.
+            // Since this rate of DDL is unlikely, we allow DDL to explicitly trigger group commit.
+            BuiltinTableUpdateSource::DDL => self.group_commit_initiate(None).await,
+            // Background writes, like replica heartbeats and session starts and ends, can happen
+            // at an arbitrary rate and nobody waits for them. They go through the same path as
+            // writes to user tables, which waits for `now()` to catch up with the global timeline
+            // before committing, so that they cannot advance the timeline past the system clock.
+            BuiltinTableUpdateSource::Background => self.try_group_commit().await,
+        }
     }
 
     /// Defers executing `deferred` until the write lock becomes available; waiting
     /// occurs in a green-thread, so callers of this function likely want to
     /// return after calling it.
+    ///
+    /// Deferred operations are granted the write lock in the order in which they were deferred.
     pub(crate) fn defer_write(&mut self, deferred: Deferred) {
-        let id = match &deferred {
-            Deferred::Plan(plan) => plan.session.conn_id().to_string(),
-            Deferred::GroupCommit => "group_commit".to_string(),
-        };
+        match &deferred {
+            Deferred::Plan(plan) => {
+                // A timeout of zero is parsed as the maximum duration, which disables it.
+                let timeout = *plan.session.vars().lock_timeout();
+                if timeout != Duration::from_secs(u64::MAX) {
+                    let conn_id = plan.session.conn_id();
+                    let id = plan.id;
+                    let internal_cmd_tx = self.internal_cmd_tx.clone();
+                    task::spawn(|| format!("lock_timeout:{conn_id}"), async move {
+                        tokio::time::sleep(timeout).await;
+                        // It is not an error for this task to be running after `internal_cmd_rx`
+                        // is dropped.
+                        let result = internal_cmd_tx.send(Message::WriteLockTimeout { id });
+                        if let Err(e) = result {
+                            warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                        }
+                    });
+                }
+            }
+            Deferred::GroupCommit => {
+                // A deferred group commit commits all writes that are pending when it is granted
+                // the write lock, so a single deferred group commit covers all later ones.
+                if self
+                    .write_lock_wait_group
+                    .iter()
+                    .any(|deferred| matches!(deferred, Deferred::GroupCommit))
+                {
+                    return;
+                }
+            }
+        }
         self.write_lock_wait_group.push_back(deferred);
+        self.wait_for_write_lock();
+    }
+
+    /// Returns a new identifier for a [`DeferredPlan`].
+    pub(crate) fn allocate_deferred_plan_id(&mut self) -> u64 {
+        let id = self.next_deferred_plan_id;
+        self.next_deferred_plan_id += 1;
+        id
+    }
 
+    /// Removes the deferred plans of clients that went away while they waited for the write lock.
+    ///
+    /// Executing these plans would perform writes that nobody is waiting for, and would hold up
+    /// the operations queued behind them. The sessions of the removed plans are terminated.
+    pub(crate) fn prune_write_lock_wait_group(&mut self) {
+        let (closed, open): (Vec<_>, VecDeque<_>) = self
+            .write_lock_wait_group
+            .drain(..)
+            .partition(|deferred| matches!(deferred, Deferred::Plan(plan) if plan.tx.is_closed()));
+        self.write_lock_wait_group = open;
+        for deferred in closed {
+            if let Deferred::Plan(plan) = deferred {
+                // Sending to a closed client terminates its session.
+                plan.tx.send(Ok(ExecuteResponse::Canceled), plan.session);
+            }
+        }
+    }
+
+    /// Acquires the write lock on behalf of the operation at the front of
+    /// `write_lock_wait_group`, unless the lock is already being acquired.
+    ///
+    /// Only one task waits for the lock at a time, and the lock is handed to the deferred
+    /// operations strictly in order, so that no operation can be starved by later ones.
+    pub(crate) fn wait_for_write_lock(&mut self) {
+        if self.write_lock_waiting || self.write_lock_wait_group.is_empty() {
+            return;
+        }
+        self.write_lock_waiting = true;
         let internal_cmd_tx = self.internal_cmd_tx.clone();
         let write_lock = Arc::clone(&self.write_lock);
-        // TODO(guswynn): see if there is more relevant info to add to this name
-        task::spawn(|| format!("defer_write:{id}"), async move {
+        task::spawn(|| "wait_for_write_lock", async move {
             let guard = write_lock.lock_owned().await;
             // It is not an error for this lock to be released after `internal_cmd_rx` to be dropped.
             let result = internal_cmd_tx.send(Message::WriteLockGrant(guard));
@@ -446,3 +950,104 @@ impl<S: Append + 'static> Coordinator<S> {
         })
     }
 }
+
+/// Handles the failure of storage to append the writes of a group commit.
+///
+/// Storage retries appends whose outcome is indeterminate for a while before giving up. The
+/// transactions that wrote to user tables whose appends are still indeterminate are answered with
+/// the error, since whether they committed is unknown, and the coordinator carries on. The error
+/// names every table the transaction wrote to: the appends to its other tables may well have
+/// succeeded, so the transaction might have been applied only partially. Returns the tables whose
+/// appends are indeterminate, so that they can be advanced again. Any other failure, including an
+/// indeterminate write to a system table, is fatal, because the coordinator's view of the tables
+/// can no longer be trusted.
+fn fail_group_commit(
+    error: StorageError,
+    responses: &mut [CompletedClientTransmitter<ExecuteResponse>],
+    response_tables: &[BTreeSet<GlobalId>],
+) -> Vec<GlobalId> {
+    let ids = match error {
+        StorageError::IndeterminateAppends(ids) if ids.iter().all(|id| id.is_user()) => ids,
+        error => panic!("group commit failed: {error}"),
+    };
+    for (response, tables) in responses.iter_mut().zip(response_tables) {
+        if ids.iter().any(|id| tables.contains(id)) {
+            response.set_response(Err(AdapterError::Storage(
+                StorageError::IndeterminateAppends(tables.iter().copied().collect()),
+            )));
+        }
+    }
+    ids
+}
+
+/// Splits the `updates` to the table `id` into append commands of at most
+/// `MAX_UPDATES_PER_APPEND_CHUNK` updates each, all at `timestamp`.
+///
+/// Storage writes the chunks out as they arrive, instead of first copying them into one allocation
+/// per table. The rows of the write are still all held by the coordinator until the chunks are
+/// handed to storage.
+fn chunk_appends(
+    id: GlobalId,
+    updates: Vec<(Row, Diff)>,
+    timestamp: Timestamp,
+    advance_to: Timestamp,
+) -> Vec<(GlobalId, Vec<Update<Timestamp>>, Timestamp)> {
+    let updates = updates.into_iter().map(|(row, diff)| Update {
+        row,
+        diff,
+        timestamp,
+    });
+    let mut commands = Vec::new();
+    for chunk in &updates.chunks(MAX_UPDATES_PER_APPEND_CHUNK) {
+        commands.push((id, chunk.collect(), advance_to));
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, GlobalId, Row};
+
+    use super::{chunk_appends, MAX_UPDATES_PER_APPEND_CHUNK};
+
+    #[test]
+    fn test_chunk_appends() {
+        let id = GlobalId::User(1);
+        let updates: Vec<_> = (0..2 * MAX_UPDATES_PER_APPEND_CHUNK + 1)
+            .map(|i| {
+                (
+                    Row::pack_slice(&[Datum::Int64(i64::try_from(i).unwrap())]),
+                    1,
+                )
+            })
+            .collect();
+        let commands = chunk_appends(id, updates, 5, 6);
+
+        let sizes: Vec<_> = commands.iter().map(|(_, chunk, _)| chunk.len()).collect();
+        assert_eq!(
+            sizes,
+            vec![
+                MAX_UPDATES_PER_APPEND_CHUNK,
+                MAX_UPDATES_PER_APPEND_CHUNK,
+                1
+            ]
+        );
+        for (chunk_id, chunk, advance_to) in &commands {
+            assert_eq!(*chunk_id, id);
+            assert_eq!(*advance_to, 6);
+            assert!(chunk.iter().all(|update| update.timestamp == 5));
+        }
+        // The chunks retain the order of the updates.
+        let rows: Vec<_> = commands
+            .into_iter()
+            .flat_map(|(_, chunk, _)| chunk)
+            .map(|update| update.row.unpack_first().unwrap_int64())
+            .collect();
+        assert!(rows
+            .iter()
+            .copied()
+            .eq(0..i64::try_from(rows.len()).unwrap()));
+
+        assert!(chunk_appends(id, Vec::new(), 5, 6).is_empty());
+    }
+}
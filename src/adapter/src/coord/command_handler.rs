@@ -10,14 +10,16 @@
 //! Logic for  processing client [`Command`]s. Each [`Command`] is initiated by a
 //! client via some external Materialize API (ex: HTTP and psql).
 
+use std::future;
 use std::sync::Arc;
 
 use mz_ore::tracing::OpenTelemetryContext;
 use rand::Rng;
 use tokio::sync::{oneshot, watch};
-use tracing::Instrument;
+use tracing::{info, Instrument};
 
 use mz_compute_client::response::PeekResponse;
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_repr::ScalarType;
 use mz_sql::ast::{InsertSource, Query, Raw, SetExpr, Statement};
@@ -25,15 +27,18 @@ use mz_sql::catalog::SessionCatalog as _;
 use mz_sql::plan::{CreateRolePlan, Params};
 use mz_stash::Append;
 
+use crate::catalog::SYSTEM_USER;
 use crate::client::ConnectionId;
 use crate::command::{
     Canceled, Command, ExecuteResponse, Response, StartupMessage, StartupResponse,
 };
-use crate::coord::appends::{Deferred, PendingWriteTxn};
+use crate::coord::appends::{BuiltinTableUpdateSource, Deferred, PendingWriteTxn};
 use crate::coord::peek::PendingPeek;
-use crate::coord::{ConnMeta, Coordinator, CreateSourceStatementReady, Message, PendingTxn};
+use crate::coord::{
+    ConnMeta, Coordinator, CreateSourceStatementReady, Message, PendingTxn, SessionMetadata,
+};
 use crate::error::AdapterError;
-use crate::session::{PreparedStatement, Session, TransactionStatus};
+use crate::session::{PreparedStatement, Session, TransactionOps, TransactionStatus};
 use crate::util::ClientTransmitter;
 
 impl<S: Append + 'static> Coordinator<S> {
@@ -160,6 +165,14 @@ impl<S: Append + 'static> Coordinator<S> {
         cancel_tx: Arc<watch::Sender<Canceled>>,
         tx: oneshot::Sender<Response<StartupResponse>>,
     ) {
+        if let Err(e) = self.validate_connection_limit(&session) {
+            let _ = tx.send(Response {
+                result: Err(e),
+                session,
+            });
+            return;
+        }
+
         if let Err(e) = self
             .catalog
             .create_temporary_schema(session.conn_id())
@@ -207,11 +220,33 @@ impl<S: Append + 'static> Coordinator<S> {
 
         let secret_key = rand::thread_rng().gen();
 
+        let session_metadata = SessionMetadata {
+            user: session.user().into(),
+            application_name: session.vars().application_name().into(),
+            client_addr: session.client_addr(),
+            connected_at: self.now_datetime(),
+        };
+        info!(
+            conn_id = session.conn_id(),
+            user = %session_metadata.user,
+            application_name = %session_metadata.application_name,
+            client_addr = ?session_metadata.client_addr,
+            "session started"
+        );
+        let update =
+            self.catalog
+                .state()
+                .pack_session_update(session.conn_id(), &session_metadata, 1);
+        self.send_builtin_table_updates(vec![update], BuiltinTableUpdateSource::Background)
+            .await;
+
         self.active_conns.insert(
             session.conn_id(),
             ConnMeta {
                 cancel_tx,
                 secret_key,
+                session_metadata,
+                last_write_ts: None,
             },
         );
 
@@ -224,6 +259,26 @@ impl<S: Append + 'static> Coordinator<S> {
         )
     }
 
+    /// Returns an error if a new connection for `session` would exceed the
+    /// `max_connections` limit. The last `superuser_reserved_connections` of
+    /// those connections are only available to the system user, so that
+    /// operators can always connect.
+    fn validate_connection_limit(&self, session: &Session) -> Result<(), AdapterError> {
+        let system_config = self.catalog.system_config();
+        let max_connections = usize::cast_from(system_config.max_connections());
+        let reserved = usize::cast_from(system_config.superuser_reserved_connections());
+        let active = self.active_conns.len();
+        if active >= max_connections {
+            Err(AdapterError::TooManyConnections { reserved: false })
+        } else if session.user() != SYSTEM_USER
+            && active >= max_connections.saturating_sub(reserved)
+        {
+            Err(AdapterError::TooManyConnections { reserved: true })
+        } else {
+            Ok(())
+        }
+    }
+
     /// Handles an execute command.
     #[tracing::instrument(level = "debug", skip_all)]
     async fn handle_execute(
@@ -306,6 +361,16 @@ impl<S: Append + 'static> Coordinator<S> {
             // something disallowed in explicit transactions did not previously take place
             // in the implicit portion.
             TransactionStatus::InTransactionImplicit(_) | TransactionStatus::InTransaction(_) => {
+                // DDL is not transactional, but multi-statement queries made
+                // up only of DDL, which many tools use to run migrations, may
+                // run it in their implicit transaction. Queries that mix DDL
+                // with other statements are rejected up front by
+                // `verify_implicit_transaction`.
+                let ddl_allowed = matches!(
+                    session.transaction(),
+                    TransactionStatus::InTransactionImplicit(txn)
+                        if matches!(txn.ops, TransactionOps::None)
+                );
                 match stmt {
                     // Statements that are safe in a transaction. We still need to verify that we
                     // don't interleave reads and writes since we can't perform those serializably.
@@ -332,6 +397,7 @@ impl<S: Append + 'static> Coordinator<S> {
                     | Statement::ShowCreateConnection(_)
                     | Statement::ShowDatabases(_)
                     | Statement::ShowSchemas(_)
+                    | Statement::ShowStorageUsage(_)
                     | Statement::ShowIndexes(_)
                     | Statement::ShowObjects(_)
                     | Statement::ShowVariable(_)
@@ -356,7 +422,8 @@ impl<S: Append + 'static> Coordinator<S> {
                         // is always safe.
                     }
 
-                    // Statements below must by run singly (in Started).
+                    // Statements below must by run singly (in Started), or, for
+                    // DDL, in an implicit transaction made up only of DDL.
                     Statement::AlterConnection(_)
                     | Statement::AlterIndex(_)
                     | Statement::AlterSecret(_)
@@ -365,6 +432,7 @@ impl<S: Append + 'static> Coordinator<S> {
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterSystemReset(_)
                     | Statement::AlterSystemResetAll(_)
+                    | Statement::Comment(_)
                     | Statement::CreateConnection(_)
                     | Statement::CreateDatabase(_)
                     | Statement::CreateIndex(_)
@@ -380,15 +448,23 @@ impl<S: Append + 'static> Coordinator<S> {
                     | Statement::CreateView(_)
                     | Statement::CreateViews(_)
                     | Statement::CreateMaterializedView(_)
-                    | Statement::Delete(_)
                     | Statement::DropDatabase(_)
                     | Statement::DropSchema(_)
                     | Statement::DropObjects(_)
                     | Statement::DropRoles(_)
                     | Statement::DropClusters(_)
-                    | Statement::DropClusterReplicas(_)
-                    | Statement::Insert(_)
-                    | Statement::Update(_) => {
+                    | Statement::DropClusterReplicas(_) => {
+                        if !ddl_allowed {
+                            return tx.send(
+                                Err(AdapterError::OperationProhibitsTransaction(
+                                    stmt.to_string(),
+                                )),
+                                session,
+                            );
+                        }
+                    }
+
+                    Statement::Delete(_) | Statement::Insert(_) | Statement::Update(_) => {
                         return tx.send(
                             Err(AdapterError::OperationProhibitsTransaction(
                                 stmt.to_string(),
@@ -425,9 +501,34 @@ impl<S: Append + 'static> Coordinator<S> {
                     stmt,
                     self.connection_context.clone(),
                 );
+                // Purification can block on slow upstream systems for a long
+                // time, so it must respond to cancellation requests itself.
+                let mut cancel_rx = self
+                    .active_conns
+                    .get(&conn_id)
+                    .expect("connection must be active")
+                    .cancel_tx
+                    .subscribe();
+                let canceled = async move {
+                    loop {
+                        if let Canceled::Canceled = *cancel_rx.borrow() {
+                            return;
+                        }
+                        if cancel_rx.changed().await.is_err() {
+                            // The connection is gone, so no cancellation
+                            // request can arrive.
+                            future::pending::<()>().await;
+                        }
+                    }
+                };
                 let otel_ctx = OpenTelemetryContext::obtain();
                 task::spawn(|| format!("purify:{conn_id}"), async move {
-                    let result = purify_fut.await.map_err(|e| e.into());
+                    let result = tokio::select! {
+                        result = purify_fut => result.map_err(|e| e.into()),
+                        () = canceled => {
+                            return tx.send(Ok(ExecuteResponse::Canceled), session);
+                        }
+                    };
                     // It is not an error for purification to complete after `internal_cmd_rx` is dropped.
                     let result = internal_cmd_tx.send(Message::CreateSourceStatementReady(
                         CreateSourceStatementReady {
@@ -538,7 +639,24 @@ impl<S: Append + 'static> Coordinator<S> {
         self.catalog
             .drop_temporary_schema(session.conn_id())
             .expect("unable to drop temporary schema");
-        self.active_conns.remove(&session.conn_id());
+        if let Some(conn_meta) = self.active_conns.remove(&session.conn_id()) {
+            let session_metadata = conn_meta.session_metadata;
+            let duration = self.now_datetime() - session_metadata.connected_at;
+            info!(
+                conn_id = session.conn_id(),
+                user = %session_metadata.user,
+                application_name = %session_metadata.application_name,
+                client_addr = ?session_metadata.client_addr,
+                duration_ms = duration.num_milliseconds(),
+                "session ended"
+            );
+            let update =
+                self.catalog
+                    .state()
+                    .pack_session_update(session.conn_id(), &session_metadata, -1);
+            self.send_builtin_table_updates(vec![update], BuiltinTableUpdateSource::Background)
+                .await;
+        }
         self.cancel_pending_peeks(session.conn_id()).await;
     }
 }
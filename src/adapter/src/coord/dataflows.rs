@@ -39,7 +39,7 @@ use mz_storage::types::sinks::{
 use crate::catalog::{CatalogItem, CatalogState, MaterializedView, View};
 use crate::coord::ddl::CatalogTxn;
 use crate::coord::id_bundle::CollectionIdBundle;
-use crate::coord::{Coordinator, DEFAULT_LOGICAL_COMPACTION_WINDOW_MS};
+use crate::coord::Coordinator;
 use crate::session::{Session, SERVER_MAJOR_VERSION, SERVER_MINOR_VERSION};
 use crate::AdapterError;
 
@@ -110,7 +110,7 @@ impl<S: Append + 'static> Coordinator<S> {
         self.initialize_compute_read_policies(
             output_ids,
             instance,
-            DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+            self.default_logical_compaction_window_ms(),
         )
         .await;
     }
@@ -193,6 +193,12 @@ impl<S: Append + 'static> Coordinator<S> {
         let _: () = async {
             self.send_builtin_table_updates(builtin_table_updates, BuiltinTableUpdateSource::DDL)
                 .await;
+            // The group commit triggered above applied the updates to the system tables, so
+            // reads at the current read timestamp observe the effects of the DDL.
+            if let Some(session) = session {
+                let timestamp = self.get_local_read_ts();
+                self.record_session_write(session.conn_id(), timestamp);
+            }
 
             if !sources_to_drop.is_empty() {
                 self.drop_sources(sources_to_drop).await;
@@ -371,12 +377,17 @@ impl<S: Append + 'static> Coordinator<S> {
 
         // The AsOf is used to determine at what time to snapshot reading from the persist collection.  This is
         // primarily relevant when we do _not_ want to include the snapshot in the sink.  Choosing now will mean
-        // that only things going forward are exported.
-        let timeline = self
-            .get_timeline(sink.from)
-            .unwrap_or(Timeline::EpochMilliseconds);
-        let now = self.ensure_timeline_state(timeline).await.oracle.read_ts();
-        let frontier = Antichain::from_elem(now);
+        // that only things going forward are exported, unless the user explicitly requested another time.
+        let timestamp = match sink.as_of {
+            Some(as_of) => as_of,
+            None => {
+                let timeline = self
+                    .get_timeline(sink.from)
+                    .unwrap_or(Timeline::EpochMilliseconds);
+                self.ensure_timeline_state(timeline).await.oracle.read_ts()
+            }
+        };
+        let frontier = Antichain::from_elem(timestamp);
         let as_of = SinkAsOf {
             frontier,
             strict: !sink.with_snapshot,
@@ -576,6 +587,8 @@ impl<S: Append + 'static> Coordinator<S> {
                     }
                 }
                 Op::AlterSource { .. }
+                | Op::AlterIndexOptions { .. }
+                | Op::Comment { .. }
                 | Op::DropTimeline(_)
                 | Op::RenameItem { .. }
                 | Op::UpdateComputeInstanceStatus { .. }
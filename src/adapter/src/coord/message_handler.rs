@@ -10,19 +10,22 @@
 //! Logic for processing [`Coordinator`] messages. The [`Coordinator`] receives
 //! messages from various sources (ex: controller, clients, background tasks, etc).
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use chrono::DurationRound;
+use timely::PartialOrder;
 use tracing::{event, warn, Level};
 
 use mz_controller::{ComputeInstanceEvent, ControllerResponse};
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_persist_client::ShardId;
+use mz_repr::GlobalId;
 use mz_sql::ast::Statement;
 use mz_sql::plan::{Plan, SendDiffsPlan};
 use mz_stash::Append;
 
-use crate::catalog::{self};
+use crate::catalog::{self, CatalogItem};
 use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::{BuiltinTableUpdateSource, Deferred};
 
@@ -30,6 +33,7 @@ use crate::coord::{
     Coordinator, CreateSourceStatementReady, Message, PendingTxn, ReplicaMetadata, SendDiffs,
     SinkConnectionReady,
 };
+use crate::error::AdapterError;
 
 impl<S: Append + 'static> Coordinator<S> {
     pub(crate) async fn handle_message(&mut self, msg: Message) {
@@ -47,13 +51,28 @@ impl<S: Append + 'static> Coordinator<S> {
             Message::WriteLockGrant(write_lock_guard) => {
                 self.message_write_lock_grant(write_lock_guard).await;
             }
+            Message::WriteLockTimeout { id } => self.message_write_lock_timeout(id),
             Message::SendDiffs(diffs) => self.message_send_diffs(diffs),
             Message::GroupCommitInitiate => {
+                self.group_commit_triggered = false;
                 self.try_group_commit().await;
             }
-            Message::GroupCommitApply(timestamp, responses, write_lock_guard) => {
-                self.group_commit_apply(timestamp, responses, false, write_lock_guard)
-                    .await;
+            Message::GroupCommitApply(
+                timestamp,
+                responses,
+                write_lock_guard,
+                group_commit,
+                indeterminate_tables,
+            ) => {
+                self.group_commit_apply(
+                    timestamp,
+                    responses,
+                    false,
+                    write_lock_guard,
+                    group_commit,
+                    indeterminate_tables,
+                )
+                .await;
             }
             Message::ComputeInstanceStatus(status) => {
                 self.message_compute_instance_status(status).await
@@ -70,12 +89,15 @@ impl<S: Append + 'static> Coordinator<S> {
             Message::StorageUsageFetch => {
                 self.storage_usage_fetch().await;
             }
-            Message::StorageUsageUpdate(sizes) => {
-                self.storage_usage_update(sizes).await;
+            Message::StorageUsageUpdate(sizes, row_counts) => {
+                self.storage_usage_update(sizes, row_counts).await;
             }
             Message::Consolidate(collections) => {
                 self.consolidate(&collections).await;
             }
+            Message::SinkStatisticsRefresh => {
+                self.sink_statistics_refresh().await;
+            }
         }
     }
 
@@ -90,11 +112,37 @@ impl<S: Append + 'static> Coordinator<S> {
     async fn storage_usage_fetch(&self) {
         let internal_cmd_tx = self.internal_cmd_tx.clone();
         let client = self.storage_usage_client.clone();
+
+        // Count the updates of each storage collection as of its read capability, which persist
+        // can serve without waiting for the collection to advance further.
+        let mut row_count_requests = Vec::new();
+        for entry in self.catalog.entries() {
+            let id = entry.id();
+            if let Ok(collection) = self.controller.storage().collection(id) {
+                let as_of = collection.implied_capability.clone();
+                if PartialOrder::less_than(&as_of, &collection.write_frontier) {
+                    let stats = self.controller.storage().snapshot_stats(id, as_of);
+                    row_count_requests.push((id, stats));
+                }
+            }
+        }
+
         task::spawn(|| "storage_usage_fetch", async move {
             let shard_sizes = client.shard_sizes().await;
+            let mut row_counts = BTreeMap::new();
+            for (id, stats) in row_count_requests {
+                match stats.await {
+                    Ok(Ok(stats)) => {
+                        row_counts.insert(id, u64::cast_from(stats.num_updates));
+                    }
+                    // The collection was dropped, or could no longer be read at the requested
+                    // time, so it is left out of this snapshot.
+                    Ok(Err(_)) | Err(_) => {}
+                }
+            }
             // It is not an error for shard sizes to become ready after `internal_cmd_rx`
             // is dropped.
-            let result = internal_cmd_tx.send(Message::StorageUsageUpdate(shard_sizes));
+            let result = internal_cmd_tx.send(Message::StorageUsageUpdate(shard_sizes, row_counts));
             if let Err(e) = result {
                 warn!("internal_cmd_rx dropped before we could send: {:?}", e);
             }
@@ -102,14 +150,32 @@ impl<S: Append + 'static> Coordinator<S> {
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn storage_usage_update(&mut self, shard_sizes: HashMap<Option<ShardId>, u64>) {
-        let object_id = None;
+    async fn storage_usage_update(
+        &mut self,
+        shard_sizes: HashMap<Option<ShardId>, u64>,
+        row_counts: BTreeMap<GlobalId, u64>,
+    ) {
+        // Attribute each shard to the catalog object whose storage collection
+        // owns it. A source's remap shard is counted towards the source itself.
+        let mut shard_owners = HashMap::new();
+        for entry in self.catalog.entries() {
+            if let Ok(collection) = self.controller.storage().collection(entry.id()) {
+                let metadata = &collection.collection_metadata;
+                shard_owners.insert(metadata.data_shard, entry.id());
+                shard_owners.insert(metadata.remap_shard, entry.id());
+            }
+        }
 
+        let mut object_sizes = BTreeMap::new();
+        let mut unattributed_storage = 0;
         let mut unk_storage = 0;
-        let mut known_storage = 0;
         for (key, val) in shard_sizes {
-            match key {
-                Some(_) => known_storage += val,
+            match key.map(|shard_id| shard_owners.get(&shard_id)) {
+                Some(Some(id)) => *object_sizes.entry(*id).or_insert(0) += val,
+                // The shard is not (or no longer) owned by a catalog object,
+                // e.g. because its collection was dropped but its data has not
+                // yet been garbage collected.
+                Some(None) => unattributed_storage += val,
                 None => unk_storage += val,
             }
         }
@@ -117,19 +183,114 @@ impl<S: Append + 'static> Coordinator<S> {
         if unk_storage > 0 {
             tracing::debug!("Found {} bytes of orphaned storage", unk_storage);
         }
-        if let Err(err) = self
-            .catalog_transact(
-                None,
-                vec![catalog::Op::UpdateStorageUsage {
-                    object_id,
-                    size_bytes: known_storage,
-                }],
-                |_| Ok(()),
-            )
-            .await
-        {
+
+        let collection_timestamp = self.now();
+        let mut ops: Vec<_> = object_sizes
+            .into_iter()
+            .map(|(id, size_bytes)| catalog::Op::UpdateStorageUsage {
+                object_id: Some(id.to_string()),
+                size_bytes,
+                collection_timestamp,
+            })
+            .collect();
+        ops.push(catalog::Op::UpdateStorageUsage {
+            object_id: None,
+            size_bytes: unattributed_storage,
+            collection_timestamp,
+        });
+        if let Err(err) = self.catalog_transact(None, ops, |_| Ok(())).await {
             tracing::warn!("Failed to update storage metrics: {:?}", err);
         }
+
+        // Replace the contents of `mz_storage_row_counts`, skipping objects that were dropped
+        // while the counts were being fetched.
+        let row_counts: BTreeMap<_, _> = row_counts
+            .into_iter()
+            .filter(|(id, _)| self.catalog.try_get_entry(id).is_some())
+            .collect();
+        let mut updates = Vec::new();
+        for (id, row_count) in &self.storage_row_counts {
+            updates.push(
+                self.catalog
+                    .state()
+                    .pack_storage_row_count_update(*id, *row_count, -1),
+            );
+        }
+        for (id, row_count) in &row_counts {
+            updates.push(
+                self.catalog
+                    .state()
+                    .pack_storage_row_count_update(*id, *row_count, 1),
+            );
+        }
+        self.storage_row_counts = row_counts;
+        self.send_builtin_table_updates(updates, BuiltinTableUpdateSource::Background)
+            .await;
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn sink_statistics_refresh(&mut self) {
+        // Collect the latest statistics for every sink that the storage
+        // controller knows about. The lag is measured against the write
+        // frontier of the collection the sink reads from, so that a sink that
+        // stops making progress shows a growing lag.
+        let mut sink_statistics = BTreeMap::new();
+        for entry in self.catalog.entries() {
+            let sink = match entry.item() {
+                CatalogItem::Sink(sink) => sink,
+                _ => continue,
+            };
+            let export = match self.controller.storage().export(entry.id()) {
+                Ok(export) => export,
+                Err(_) => continue,
+            };
+            let write_frontier = export.write_frontier.as_option().copied();
+            let upstream_frontier = self
+                .controller
+                .storage()
+                .collection(sink.from)
+                .ok()
+                .and_then(|collection| collection.write_frontier.as_option().copied());
+            let lag = match (write_frontier, upstream_frontier) {
+                (Some(write_frontier), Some(upstream_frontier)) => {
+                    Some(upstream_frontier.saturating_sub(write_frontier))
+                }
+                _ => None,
+            };
+            sink_statistics.insert(entry.id(), (export.statistics, write_frontier, lag));
+        }
+
+        // Retract the rows of sinks whose statistics changed or that were
+        // dropped, and insert the rows of sinks whose statistics changed or
+        // that are new.
+        let mut updates = Vec::new();
+        for (id, (statistics, write_frontier, lag)) in &self.sink_statistics {
+            if sink_statistics.get(id) != Some(&(*statistics, *write_frontier, *lag)) {
+                updates.push(self.catalog.state().pack_sink_statistics_update(
+                    *id,
+                    statistics,
+                    *write_frontier,
+                    *lag,
+                    -1,
+                ));
+            }
+        }
+        for (id, (statistics, write_frontier, lag)) in &sink_statistics {
+            if self.sink_statistics.get(id) != Some(&(*statistics, *write_frontier, *lag)) {
+                updates.push(self.catalog.state().pack_sink_statistics_update(
+                    *id,
+                    statistics,
+                    *write_frontier,
+                    *lag,
+                    1,
+                ));
+            }
+        }
+        self.sink_statistics = sink_statistics;
+        if !updates.is_empty() {
+            self.send_builtin_table_updates(updates, BuiltinTableUpdateSource::Background)
+                .await;
+        }
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
@@ -256,6 +417,7 @@ impl<S: Append + 'static> Coordinator<S> {
             tx,
             id,
             oid,
+            read_holds,
             result,
         }: SinkConnectionReady,
     ) {
@@ -300,14 +462,22 @@ impl<S: Append + 'static> Coordinator<S> {
                 tx.send(Err(e), session);
             }
         }
+        // The sink, if it was created, now holds back compaction of the
+        // sinked collection on its own.
+        if let Some(read_holds) = read_holds {
+            self.release_read_hold(&read_holds).await;
+        }
     }
 
     async fn message_write_lock_grant(
         &mut self,
         write_lock_guard: tokio::sync::OwnedMutexGuard<()>,
     ) {
-        // It's possible to have more incoming write lock grants
-        // than pending writes because of cancellations.
+        self.write_lock_waiting = false;
+        self.prune_write_lock_wait_group();
+        // The wait group may be empty if the operations in it were canceled,
+        // timed out, or abandoned by their clients while the lock was being
+        // acquired.
         if let Some(ready) = self.write_lock_wait_group.pop_front() {
             match ready {
                 Deferred::Plan(mut ready) => {
@@ -318,11 +488,28 @@ impl<S: Append + 'static> Coordinator<S> {
                     self.sequence_plan(ready.tx, ready.session, ready.plan, depends_on)
                         .await;
                 }
-                Deferred::GroupCommit => self.group_commit_initiate().await,
+                Deferred::GroupCommit => self.group_commit_initiate(Some(write_lock_guard)).await,
             }
         }
         // N.B. if no deferred plans, write lock is released by drop
         // here.
+
+        // Wait for the lock on behalf of the next deferred operation, if any.
+        self.wait_for_write_lock();
+    }
+
+    fn message_write_lock_timeout(&mut self, id: u64) {
+        // The plan may have already been granted the write lock or canceled, in
+        // which case a later deferral of the same session has a different id.
+        if let Some(idx) = self
+            .write_lock_wait_group
+            .iter()
+            .position(|deferred| matches!(deferred, Deferred::Plan(plan) if plan.id == id))
+        {
+            if let Some(Deferred::Plan(plan)) = self.write_lock_wait_group.remove(idx) {
+                plan.tx.send(Err(AdapterError::LockTimeout), plan.session);
+            }
+        }
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(id, kind))]
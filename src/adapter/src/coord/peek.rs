@@ -14,11 +14,13 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
+use std::time::Duration;
 use std::{collections::HashMap, num::NonZeroUsize};
 
 use futures::TryFutureExt;
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
+use tracing::warn;
 use uuid::Uuid;
 
 use mz_compute_client::command::{DataflowDescription, ReplicaId};
@@ -34,6 +36,7 @@ use mz_repr::{Diff, GlobalId, RelationType, Row};
 use mz_stash::Append;
 
 use crate::client::ConnectionId;
+use crate::coord::Message;
 use crate::explain_new::Displayable;
 use crate::util::send_immediate_rows;
 use crate::AdapterError;
@@ -52,6 +55,8 @@ pub enum PeekResponseUnary {
     Rows(Vec<Row>),
     Error(String),
     Canceled,
+    /// The peek did not complete within the session's `statement_timeout`.
+    TimedOut,
 }
 
 #[derive(Debug)]
@@ -63,7 +68,7 @@ pub struct PeekDataflowPlan<T = mz_repr::Timestamp> {
     thinned_arity: usize,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum FastPathPlan<T = mz_repr::Timestamp> {
     /// The view evaluates to a constant result that can be returned.
     ///
@@ -592,6 +597,49 @@ impl<S: Append + 'static> crate::coord::Coordinator<S> {
         })
     }
 
+    /// Arranges for the peek answering `response` to be canceled if it does not
+    /// complete within `timeout`.
+    ///
+    /// Responses that are not backed by a peek are returned unchanged.
+    pub(crate) fn enforce_statement_timeout(
+        &self,
+        response: crate::ExecuteResponse,
+        conn_id: ConnectionId,
+        timeout: Duration,
+    ) -> crate::ExecuteResponse {
+        match response {
+            crate::ExecuteResponse::SendingRows { future, span } => {
+                let internal_cmd_tx = self.internal_cmd_tx.clone();
+                let future = async move {
+                    match tokio::time::timeout(timeout, future).await {
+                        Ok(resp) => resp,
+                        Err(_) => {
+                            // We timed out, so remove the pending peek. This is
+                            // best-effort and doesn't guarantee we won't
+                            // receive a response.
+                            // It is not an error for this timeout to occur after `internal_cmd_rx` has been dropped.
+                            let result =
+                                internal_cmd_tx.send(Message::RemovePendingPeeks { conn_id });
+                            if let Err(e) = result {
+                                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                            }
+                            PeekResponseUnary::TimedOut
+                        }
+                    }
+                };
+                crate::ExecuteResponse::SendingRows {
+                    future: Box::pin(future),
+                    span,
+                }
+            }
+            crate::ExecuteResponse::CopyTo { format, resp } => crate::ExecuteResponse::CopyTo {
+                format,
+                resp: Box::new(self.enforce_statement_timeout(*resp, conn_id, timeout)),
+            },
+            response => response,
+        }
+    }
+
     /// Cancel and remove all pending peeks that were initiated by the client with `conn_id`.
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn cancel_pending_peeks(&mut self, conn_id: u32) -> Vec<PendingPeek> {
@@ -15,6 +15,7 @@
 //! remain "readable" at a specific time, as long as the hold is held.
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 
 use timely::progress::frontier::MutableAntichain;
 use timely::progress::{Antichain, Timestamp as TimelyTimestamp};
@@ -26,6 +27,7 @@ use mz_storage::controller::ReadPolicy;
 
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::timeline::TimelineState;
+use crate::util::duration_to_timestamp_millis;
 
 /// Information about the read capability requirements of a collection.
 ///
@@ -62,7 +64,7 @@ impl<T: timely::progress::Timestamp> ReadCapability<T> {
 }
 
 /// Relevant information for acquiring or releasing a bundle of read holds.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub(crate) struct ReadHolds<T> {
     pub(crate) time: T,
     pub(crate) id_bundle: CollectionIdBundle,
@@ -79,6 +81,32 @@ impl<T> ReadHolds<T> {
 }
 
 impl<S: Append + 'static> crate::coord::Coordinator<S> {
+    /// Returns the logical compaction window, in milliseconds, to use for new
+    /// collections, or `None` if logical compaction is disabled.
+    pub(crate) fn default_logical_compaction_window_ms(&self) -> Option<u64> {
+        let window = self
+            .catalog
+            .system_config()
+            .default_logical_compaction_window();
+        // A window of zero is parsed as the maximum duration, whose
+        // milliseconds do not fit in a `u64`.
+        u64::try_from(window.as_millis()).ok()
+    }
+
+    /// Returns the logical compaction window, in milliseconds, to use for a
+    /// source or materialized view with the given `LOGICAL COMPACTION WINDOW`
+    /// option, falling back to the default window if the option was not
+    /// specified.
+    pub(crate) fn logical_compaction_window_ms(
+        &self,
+        window: Option<Option<Duration>>,
+    ) -> Option<u64> {
+        match window {
+            Some(window) => window.map(duration_to_timestamp_millis),
+            None => self.default_logical_compaction_window_ms(),
+        }
+    }
+
     /// Initialize the storage read policies.
     ///
     /// This should be called only after a storage collection is created, and
@@ -248,6 +276,23 @@ impl<S: Append + 'static> crate::coord::Coordinator<S> {
             .unwrap();
     }
 
+    pub(crate) async fn update_storage_base_read_policy(
+        &mut self,
+        id: GlobalId,
+        base_policy: ReadPolicy<mz_repr::Timestamp>,
+    ) {
+        let capability = self
+            .read_capability
+            .get_mut(&id)
+            .expect("coord out of sync");
+        capability.base_policy = base_policy;
+        self.controller
+            .storage_mut()
+            .set_read_policy(vec![(id, capability.policy())])
+            .await
+            .unwrap();
+    }
+
     /// Drop read policy for `id`.
     ///
     /// Returns true if `id` had a read policy and false otherwise
@@ -27,15 +27,16 @@ use mz_compute_client::explain::{
     DataflowGraphFormatter, Explanation, JsonViewFormatter, TimestampExplanation, TimestampSource,
 };
 use mz_controller::{ConcreteComputeInstanceReplicaConfig, ConcreteComputeInstanceReplicaLogging};
+use mz_expr::visit::Visit;
 use mz_expr::{
-    permutation_for_arrangement, CollectionPlan, MirRelationExpr, MirScalarExpr,
+    permutation_for_arrangement, CollectionPlan, Id, MirRelationExpr, MirScalarExpr,
     OptimizedMirRelationExpr, RowSetFinishing,
 };
 use mz_ore::ssh_key::SshKeyset;
 use mz_ore::task;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{Numeric, NumericMaxScale};
-use mz_repr::explain_new::{Explain, Explainee};
+use mz_repr::explain_new::{Explain, ExplainConfig, ExplainFormat, Explainee};
 use mz_repr::{Datum, Diff, GlobalId, RelationDesc, Row, RowArena, ScalarType, Timestamp};
 use mz_sql::ast::{ExplainStageNew, ExplainStageOld, IndexOptionName, ObjectType};
 use mz_sql::catalog::{CatalogComputeInstance, CatalogError, CatalogItemType, CatalogTypeDetails};
@@ -43,11 +44,11 @@ use mz_sql::names::QualifiedObjectName;
 
 use mz_sql::plan::{
     AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterSecretPlan,
-    AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan,
-    ComputeInstanceReplicaConfig, CreateComputeInstancePlan, CreateComputeInstanceReplicaPlan,
-    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, CreateViewsPlan,
+    AlterSourceItem, AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
+    AlterSystemSetPlan, CommentPlan, ComputeInstanceReplicaConfig, CreateComputeInstancePlan,
+    CreateComputeInstanceReplicaPlan, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, CreateViewsPlan,
     DropComputeInstanceReplicaPlan, DropComputeInstancesPlan, DropDatabasePlan, DropItemsPlan,
     DropRolesPlan, DropSchemaPlan, ExecutePlan, ExplainPlan, ExplainPlanNew, ExplainPlanOld,
     FetchPlan, HirRelationExpr, IndexOption, InsertPlan, MaterializedView, MutationKind,
@@ -71,9 +72,9 @@ use crate::catalog::{
 use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::{BuiltinTableUpdateSource, Deferred, DeferredPlan, PendingWriteTxn};
 use crate::coord::dataflows::{prep_relation_expr, prep_scalar_expr, ExprPrepStyle};
+use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::{
     peek, read_policy, Coordinator, Message, PendingTxn, SendDiffs, SinkConnectionReady, TxnReads,
-    DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
 };
 use crate::error::AdapterError;
 use crate::explain_new::{ExplainContext, Explainable, UsedIndexes};
@@ -85,6 +86,10 @@ use crate::tail::PendingTail;
 use crate::util::{duration_to_timestamp_millis, send_immediate_rows, ClientTransmitter};
 use crate::{guard_write_critical_section, sink_connection, PeekResponseUnary};
 
+/// The timeout for peeks that hold the write lock, when the session does not set a
+/// `statement_timeout`.
+const WRITE_LOCK_PEEK_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl<S: Append + 'static> Coordinator<S> {
     #[tracing::instrument(level = "debug", skip_all)]
     pub(crate) async fn sequence_plan(
@@ -233,6 +238,12 @@ impl<S: Append + 'static> Coordinator<S> {
                 self.sequence_end_transaction(tx, session, action).await;
             }
             Plan::Peek(plan) => {
+                // A read in a write transaction takes the write lock and holds it
+                // until the transaction ends, so that no other write can commit
+                // between the read and the transaction's own writes.
+                if session.transaction_writes().is_some() {
+                    guard_write_critical_section!(self, tx, session, Plan::Peek(plan));
+                }
                 tx.send(self.sequence_peek(&mut session, plan).await, session);
             }
             Plan::Tail(plan) => {
@@ -279,10 +290,17 @@ impl<S: Append + 'static> Coordinator<S> {
                 );
             }
             Plan::AlterIndexSetOptions(plan) => {
-                tx.send(self.sequence_alter_index_set_options(plan).await, session);
+                tx.send(
+                    self.sequence_alter_index_set_options(&session, plan).await,
+                    session,
+                );
             }
             Plan::AlterIndexResetOptions(plan) => {
-                tx.send(self.sequence_alter_index_reset_options(plan).await, session);
+                tx.send(
+                    self.sequence_alter_index_reset_options(&session, plan)
+                        .await,
+                    session,
+                );
             }
             Plan::AlterSecret(plan) => {
                 tx.send(self.sequence_alter_secret(&session, plan).await, session);
@@ -308,6 +326,9 @@ impl<S: Append + 'static> Coordinator<S> {
                     session,
                 );
             }
+            Plan::Comment(plan) => {
+                tx.send(self.sequence_comment(&session, plan).await, session);
+            }
             Plan::DiscardTemp => {
                 self.drop_temp_items(&session).await;
                 tx.send(Ok(ExecuteResponse::DiscardedTemp), session);
@@ -428,6 +449,7 @@ impl<S: Append + 'static> Coordinator<S> {
             timeline: plan.timeline,
             depends_on,
             host_config,
+            logical_compaction_window: plan.logical_compaction_window,
         };
         ops.push(catalog::Op::CreateItem {
             id: source_id,
@@ -468,6 +490,8 @@ impl<S: Append + 'static> Coordinator<S> {
                     None
                 };
 
+                let compaction_window_ms =
+                    self.logical_compaction_window_ms(source.logical_compaction_window);
                 self.controller
                     .storage_mut()
                     .create_collections(vec![(
@@ -483,11 +507,8 @@ impl<S: Append + 'static> Coordinator<S> {
                     .await
                     .unwrap();
 
-                self.initialize_storage_read_policies(
-                    vec![source_id],
-                    DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
-                )
-                .await;
+                self.initialize_storage_read_policies(vec![source_id], compaction_window_ms)
+                    .await;
                 Ok(ExecuteResponse::CreatedSource { existed: false })
             }
             Err(AdapterError::Catalog(catalog::Error {
@@ -770,7 +791,7 @@ impl<S: Append + 'static> Coordinator<S> {
             self.initialize_compute_read_policies(
                 arranged_introspection_source_ids,
                 instance.id,
-                DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+                self.default_logical_compaction_window_ms(),
             )
             .await;
         }
@@ -778,7 +799,7 @@ impl<S: Append + 'static> Coordinator<S> {
         if !persisted_introspection_source_ids.is_empty() {
             self.initialize_storage_read_policies(
                 persisted_introspection_source_ids,
-                DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+                self.default_logical_compaction_window_ms(),
             )
             .await;
         }
@@ -889,7 +910,7 @@ impl<S: Append + 'static> Coordinator<S> {
         if instance.logging.is_some() {
             self.initialize_storage_read_policies(
                 persisted_source_ids,
-                DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+                self.default_logical_compaction_window_ms(),
             )
             .await;
         }
@@ -968,7 +989,7 @@ impl<S: Append + 'static> Coordinator<S> {
 
                 self.initialize_storage_read_policies(
                     vec![table_id],
-                    DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+                    self.default_logical_compaction_window_ms(),
                 )
                 .await;
                 Ok(ExecuteResponse::CreatedTable { existed: false })
@@ -1039,6 +1060,7 @@ impl<S: Append + 'static> Coordinator<S> {
             name,
             sink,
             with_snapshot,
+            when,
             if_not_exists,
         } = plan;
 
@@ -1058,6 +1080,45 @@ impl<S: Append + 'static> Coordinator<S> {
             }
         };
 
+        // If the sink was requested to start at a particular timestamp, hold
+        // back compaction of the sinked collection to that timestamp until the
+        // sink has been created, which might take a while. Collections that
+        // are not in storage cannot be sinked, which is reported below.
+        let read_holds = if when == QueryWhen::Immediately
+            || self.controller.storage().collection(sink.from).is_err()
+        {
+            None
+        } else {
+            let compute_instance = match self
+                .catalog
+                .resolve_compute_instance(session.vars().cluster())
+            {
+                Ok(compute_instance) => compute_instance.id,
+                Err(e) => {
+                    tx.send(Err(e.into()), session);
+                    return;
+                }
+            };
+            let id_bundle = CollectionIdBundle {
+                storage_ids: BTreeSet::from([sink.from]),
+                compute_ids: BTreeMap::new(),
+            };
+            let timestamp =
+                match self.determine_timestamp(&session, &id_bundle, &when, compute_instance) {
+                    Ok(timestamp) => timestamp,
+                    Err(e) => {
+                        tx.send(Err(e), session);
+                        return;
+                    }
+                };
+            let read_holds = read_policy::ReadHolds {
+                time: timestamp,
+                id_bundle,
+            };
+            self.acquire_read_holds(&read_holds).await;
+            Some(read_holds)
+        };
+
         // Knowing that we're only handling kafka sinks here helps us simplify.
         let StorageSinkConnectionBuilder::Kafka(connection_builder) =
             sink.connection_builder.clone();
@@ -1077,6 +1138,7 @@ impl<S: Append + 'static> Coordinator<S> {
             )),
             envelope: sink.envelope,
             with_snapshot,
+            as_of: read_holds.as_ref().map(|read_holds| read_holds.time),
             depends_on,
         };
 
@@ -1106,6 +1168,11 @@ impl<S: Append + 'static> Coordinator<S> {
             })
             .await;
 
+        if result.is_err() {
+            if let Some(read_holds) = &read_holds {
+                self.release_read_hold(read_holds).await;
+            }
+        }
         match result {
             Ok(()) => {}
             Err(AdapterError::Catalog(catalog::Error {
@@ -1136,6 +1203,7 @@ impl<S: Append + 'static> Coordinator<S> {
                         tx,
                         id,
                         oid,
+                        read_holds,
                         result: sink_connection::build(connection_builder, connection_context)
                             .await,
                     }));
@@ -1247,6 +1315,7 @@ impl<S: Append + 'static> Coordinator<S> {
                     expr: view_expr,
                     column_names,
                     compute_instance,
+                    logical_compaction_window,
                 },
             replace,
             if_not_exists,
@@ -1302,6 +1371,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 desc: desc.clone(),
                 depends_on,
                 compute_instance,
+                logical_compaction_window,
             }),
         });
 
@@ -1335,7 +1405,7 @@ impl<S: Append + 'static> Coordinator<S> {
 
                 self.initialize_storage_read_policies(
                     vec![id],
-                    DEFAULT_LOGICAL_COMPACTION_WINDOW_MS,
+                    self.logical_compaction_window_ms(logical_compaction_window),
                 )
                 .await;
 
@@ -1375,6 +1445,7 @@ impl<S: Append + 'static> Coordinator<S> {
             conn_id: None,
             depends_on,
             compute_instance,
+            options: options.clone(),
         };
         let oid = self.catalog.allocate_oid().await?;
         let op = catalog::Op::CreateItem {
@@ -1686,6 +1757,7 @@ impl<S: Append + 'static> Coordinator<S> {
     ) -> Result<ExecuteResponse, AdapterError> {
         use mz_sql::ast::{SetVariableValue, Value};
 
+        let prev_database = session.vars().database().to_string();
         let vars = session.vars_mut();
         let (name, local) = (plan.name, plan.local);
         match plan.value {
@@ -1695,7 +1767,11 @@ impl<S: Append + 'static> Coordinator<S> {
             SetVariableValue::Default => vars.reset(&name, local)?,
         }
 
-        Ok(ExecuteResponse::SetVariable { name, tag: "SET" })
+        Ok(ExecuteResponse::SetVariable {
+            name,
+            tag: "SET",
+            unknown_database: self.unknown_session_database(session, &prev_database),
+        })
     }
 
     fn sequence_reset_variable(
@@ -1703,13 +1779,29 @@ impl<S: Append + 'static> Coordinator<S> {
         session: &mut Session,
         plan: ResetVariablePlan,
     ) -> Result<ExecuteResponse, AdapterError> {
+        let prev_database = session.vars().database().to_string();
         session.vars_mut().reset(&plan.name, false)?;
         Ok(ExecuteResponse::SetVariable {
             name: plan.name,
             tag: "RESET",
+            unknown_database: self.unknown_session_database(session, &prev_database),
         })
     }
 
+    /// Returns the name of the session's database if it was changed from
+    /// `prev_database` to a database that does not exist.
+    ///
+    /// Unlike PostgreSQL, we allow changing the database mid-session, so we
+    /// warn about unknown databases here just as we do at session startup.
+    fn unknown_session_database(&self, session: &Session, prev_database: &str) -> Option<String> {
+        let database = session.vars().database();
+        if database != prev_database && self.catalog.resolve_database(database).is_err() {
+            Some(database.to_string())
+        } else {
+            None
+        }
+    }
+
     pub(crate) async fn sequence_end_transaction(
         &mut self,
         tx: ClientTransmitter<ExecuteResponse>,
@@ -1735,6 +1827,12 @@ impl<S: Append + 'static> Coordinator<S> {
             Ok((Some(TransactionOps::Writes(writes)), _)) if writes.is_empty() => {
                 (response, action)
             }
+            Ok((Some(TransactionOps::Writes(_)), _)) if self.pending_writes_exhausted() => (
+                Err(AdapterError::TooManyPendingWrites {
+                    limit: self.catalog.system_config().max_pending_write_rows(),
+                }),
+                EndTransactionAction::Rollback,
+            ),
             Ok((Some(TransactionOps::Writes(writes)), write_lock_guard)) => {
                 self.submit_write(PendingWriteTxn::User {
                     writes,
@@ -1792,8 +1890,22 @@ impl<S: Append + 'static> Coordinator<S> {
                         })?;
                     }
 
-                    // `rows` can be empty if, say, a DELETE's WHERE clause had 0 results.
-                    writes.retain(|WriteOp { rows, .. }| !rows.is_empty());
+                    // Consolidate the transaction's writes to each table, so that rows that the
+                    // transaction both inserted and deleted are neither held while the write is
+                    // pending nor sent to storage. Writes whose rows consolidate away are dropped,
+                    // as are writes whose `rows` are empty because, say, a DELETE's WHERE clause
+                    // had 0 results.
+                    let mut rows_by_table: BTreeMap<GlobalId, Vec<(Row, Diff)>> = BTreeMap::new();
+                    for WriteOp { id, rows } in writes.drain(..) {
+                        rows_by_table.entry(id).or_default().extend(rows);
+                    }
+                    *writes = rows_by_table
+                        .into_iter()
+                        .filter_map(|(id, mut rows)| {
+                            differential_dataflow::consolidation::consolidate(&mut rows);
+                            (!rows.is_empty()).then(|| WriteOp { id, rows })
+                        })
+                        .collect();
                 }
                 return Ok((Some(ops), write_lock_guard));
             }
@@ -1895,6 +2007,7 @@ impl<S: Append + 'static> Coordinator<S> {
 
         let timeline = self.validate_timeline(source_ids.clone())?;
         let conn_id = session.conn_id();
+        let txn_writes = session.transaction_writes().map(|writes| writes.to_vec());
         // Queries are independent of the logical timestamp iff there are no referenced
         // sources or indexes and there is no reference to `mz_logical_timestamp()`.
         let timestamp_independent = source_ids.is_empty() && !source.contains_temporal();
@@ -1903,8 +2016,9 @@ impl<S: Append + 'static> Coordinator<S> {
         // query, we don't care about any possible transaction timestamp. We do
         // not do any optimization of the so-called single-statement transactions
         // (TransactionStatus::Started) because multiple statements can actually be
-        // executed there in the extended protocol.
-        let timestamp = if when == QueryWhen::Immediately {
+        // executed there in the extended protocol. Reads in a write transaction are
+        // not part of a read transaction, and each get their own timestamp.
+        let timestamp = if when == QueryWhen::Immediately && txn_writes.is_none() {
             // If all previous statements were timestamp-independent and the current one is
             // not, clear the transaction ops so it can get a new timestamp and timedomain.
             if let Some(read_txn) = self.txn_reads.get(&conn_id) {
@@ -1987,6 +2101,19 @@ impl<S: Append + 'static> Coordinator<S> {
 
             timestamp
         } else {
+            // Reads in a write transaction hold the write lock, and must observe
+            // every write committed before it was acquired.
+            let when = match when {
+                QueryWhen::Immediately if txn_writes.is_some() => {
+                    QueryWhen::AtLeastTimestamp(MirScalarExpr::literal_ok(
+                        Datum::from(Numeric::from(self.get_local_read_ts())),
+                        ScalarType::Numeric {
+                            max_scale: Some(NumericMaxScale::ZERO),
+                        },
+                    ))
+                }
+                when => when,
+            };
             // TODO(guswynn): acquire_read_holds for linearized reads
             let id_bundle = self
                 .index_oracle(compute_instance)
@@ -2031,6 +2158,9 @@ impl<S: Append + 'static> Coordinator<S> {
                 },
             )?;
         }
+        if let Some(writes) = &txn_writes {
+            self.overlay_transaction_writes(&mut dataflow, &source_ids, writes)?;
+        }
         dataflow.export_index(
             index_id,
             IndexDesc {
@@ -2079,13 +2209,71 @@ impl<S: Append + 'static> Coordinator<S> {
             )
             .await?;
 
-        match copy_to {
-            None => Ok(resp),
-            Some(format) => Ok(ExecuteResponse::CopyTo {
+        let resp = match copy_to {
+            None => resp,
+            Some(format) => ExecuteResponse::CopyTo {
                 format,
                 resp: Box::new(resp),
-            }),
+            },
+        };
+        // A timeout of zero is parsed as the maximum duration, which disables it. A peek
+        // that holds the write lock blocks every write to tables until it completes, so it
+        // is bounded even when the session does not set a timeout.
+        let mut timeout = *session.vars().statement_timeout();
+        if session.has_write_lock() && timeout == Duration::from_secs(u64::MAX) {
+            timeout = WRITE_LOCK_PEEK_TIMEOUT;
         }
+        Ok(self.enforce_statement_timeout(resp, session.conn_id(), timeout))
+    }
+
+    /// Overlays the uncommitted `writes` of a transaction onto the tables that `dataflow`
+    /// reads, so that reads in the transaction observe its writes.
+    ///
+    /// Fails if the dataflow depends on a written table only through an index or a
+    /// materialized view, which cannot reflect the writes.
+    fn overlay_transaction_writes(
+        &self,
+        dataflow: &mut DataflowDesc,
+        source_ids: &BTreeSet<GlobalId>,
+        writes: &[WriteOp],
+    ) -> Result<(), AdapterError> {
+        let mut overlaid = BTreeSet::new();
+        for BuildDesc { plan, .. } in &mut dataflow.objects_to_build {
+            plan.0.visit_mut_post(&mut |e| {
+                let (id, typ) = match e {
+                    MirRelationExpr::Get {
+                        id: Id::Global(id),
+                        typ,
+                    } if writes.iter().any(|write| write.id == *id) => (*id, typ.clone()),
+                    _ => return,
+                };
+                overlaid.insert(id);
+                let rows: Vec<_> = writes
+                    .iter()
+                    .filter(|write| write.id == id)
+                    .flat_map(|write| write.rows.iter().cloned())
+                    .collect();
+                if !rows.is_empty() {
+                    let writes = MirRelationExpr::Constant {
+                        rows: Ok(rows),
+                        typ,
+                    };
+                    *e = e.take_dangerous().union(writes);
+                }
+            })?;
+        }
+
+        let mut tables = BTreeSet::new();
+        for id in source_ids {
+            self.catalog.tables_used_by(*id, &mut tables);
+        }
+        if writes
+            .iter()
+            .any(|write| tables.contains(&write.id) && !overlaid.contains(&write.id))
+        {
+            return Err(AdapterError::WriteOnlyTransaction);
+        }
+        Ok(())
     }
 
     async fn sequence_tail(
@@ -2172,9 +2360,10 @@ impl<S: Append + 'static> Coordinator<S> {
             global_id: *sink_id,
         });
         let arity = sink_desc.from_desc.arity();
+        let as_of = sink_desc.as_of.frontier.clone();
         let (tx, rx) = mpsc::unbounded_channel();
         self.pending_tails
-            .insert(*sink_id, PendingTail::new(tx, emit_progress, arity));
+            .insert(*sink_id, PendingTail::new(tx, emit_progress, as_of, arity));
         self.ship_dataflow(dataflow, compute_instance).await;
 
         let resp = ExecuteResponse::Tailing { rx };
@@ -2203,13 +2392,8 @@ impl<S: Append + 'static> Coordinator<S> {
         session: &Session,
         plan: ExplainPlanNew,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let compute_instance = self
-            .catalog
-            .resolve_compute_instance(session.vars().cluster())?
-            .id;
-
         let ExplainPlanNew {
-            mut raw_plan,
+            raw_plan,
             row_set_finishing,
             stage,
             format,
@@ -2217,156 +2401,213 @@ impl<S: Append + 'static> Coordinator<S> {
             explainee,
         } = plan;
 
-        let decorrelate = |raw_plan: HirRelationExpr| -> Result<MirRelationExpr, AdapterError> {
-            let decorrelated_plan = raw_plan.optimize_and_lower(&OptimizerConfig {
-                qgm_optimizations: session.vars().qgm_optimizations(),
-            })?;
-            Ok(decorrelated_plan)
+        let stages = match stage {
+            ExplainStageNew::RawPlan => vec![ExplainedStage::RawPlan],
+            ExplainStageNew::QueryGraph => vec![ExplainedStage::QueryGraph],
+            ExplainStageNew::OptimizedQueryGraph => vec![ExplainedStage::OptimizedQueryGraph],
+            ExplainStageNew::DecorrelatedPlan => vec![ExplainedStage::DecorrelatedPlan],
+            ExplainStageNew::OptimizedPlan => vec![ExplainedStage::OptimizedPlan],
+            ExplainStageNew::PhysicalPlan => vec![ExplainedStage::PhysicalPlan],
+            // Explain the plan at each stage of the optimization pipeline.
+            ExplainStageNew::Trace => vec![
+                ExplainedStage::RawPlan,
+                ExplainedStage::DecorrelatedPlan,
+                ExplainedStage::OptimizedPlan,
+                ExplainedStage::PhysicalPlan,
+            ],
         };
+        let explanations = self.explain_stages(
+            session,
+            &stages,
+            raw_plan,
+            row_set_finishing,
+            &format,
+            &config,
+            &explainee,
+        )?;
 
-        let optimize =
-            |coord: &mut Self,
-             decorrelated_plan: MirRelationExpr|
-             -> Result<DataflowDescription<OptimizedMirRelationExpr>, AdapterError> {
-                let optimized_plan = coord.view_optimizer.optimize(decorrelated_plan)?;
-                let mut dataflow = DataflowDesc::new(format!("explanation"));
-                coord
-                    .dataflow_builder(compute_instance)
-                    .import_view_into_dataflow(
-                        // TODO: If explaining a view, pipe the actual id of the view.
-                        &GlobalId::Explain,
-                        &optimized_plan,
-                        &mut dataflow,
-                    )?;
-                mz_transform::optimize_dataflow(
-                    &mut dataflow,
-                    &coord.index_oracle(compute_instance),
-                )?;
-                Ok(dataflow)
+        // A trace has one (Stage, Plan) row per stage, and a single stage has
+        // one (Plan) row.
+        let rows = explanations
+            .into_iter()
+            .map(|(explained_stage, explanation_string)| match stage {
+                ExplainStageNew::Trace => Row::pack_slice(&[
+                    Datum::from(explained_stage.name()),
+                    Datum::from(&*explanation_string),
+                ]),
+                _ => Row::pack_slice(&[Datum::from(&*explanation_string)]),
+            })
+            .collect();
+        Ok(send_immediate_rows(rows))
+    }
+
+    /// Runs `raw_plan` through the optimization pipeline, up to the last of
+    /// `stages`, and explains the plan as it looks at each of `stages`.
+    ///
+    /// Each step of the pipeline runs at most once, however many of `stages`
+    /// follow it.
+    fn explain_stages(
+        &mut self,
+        session: &Session,
+        stages: &[ExplainedStage],
+        raw_plan: HirRelationExpr,
+        row_set_finishing: Option<RowSetFinishing>,
+        format: &ExplainFormat,
+        config: &ExplainConfig,
+        explainee: &Explainee,
+    ) -> Result<Vec<(ExplainedStage, String)>, AdapterError> {
+        let compute_instance = self
+            .catalog
+            .resolve_compute_instance(session.vars().cluster())?
+            .id;
+
+        // When explaining an existing object, build the explanation dataflow
+        // under the object's own id so that the plan is labeled with its name.
+        let explainee_id = match explainee {
+            Explainee::Dataflow(id) => *id,
+            Explainee::Query => GlobalId::Explain,
+        };
+
+        let last_stage = match stages.iter().max() {
+            Some(last_stage) => *last_stage,
+            None => return Ok(vec![]),
+        };
+        let mut explanations = Vec::with_capacity(stages.len());
+
+        if stages.contains(&ExplainedStage::RawPlan) {
+            // construct explanation context
+            let catalog = self.catalog.for_session(session);
+            let context = ExplainContext {
+                config,
+                humanizer: &catalog,
+                used_indexes: UsedIndexes::new(Default::default()),
+                finishing: row_set_finishing.clone(),
+                fast_path_plan: Default::default(),
             };
+            // explain plan
+            let explanation_string =
+                Explainable::new(&mut raw_plan.clone()).explain(format, config, &context)?;
+            explanations.push((ExplainedStage::RawPlan, explanation_string));
+        }
 
-        let explanation_string = match stage {
-            ExplainStageNew::RawPlan => {
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(Default::default()),
-                    finishing: row_set_finishing,
-                    fast_path_plan: Default::default(),
-                };
-                // explain plan
-                Explainable::new(&mut raw_plan).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::QueryGraph => {
-                // run partial pipeline
-                let mut model = mz_sql::query_model::Model::try_from(raw_plan)?;
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(Default::default()),
-                    finishing: row_set_finishing,
-                    fast_path_plan: Default::default(),
-                };
-                // explain plan
-                Explainable::new(&mut model).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::OptimizedQueryGraph => {
-                // run partial pipeline
-                let mut model = mz_sql::query_model::Model::try_from(raw_plan)?;
-                model.optimize();
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(Default::default()),
-                    finishing: row_set_finishing,
-                    fast_path_plan: Default::default(),
-                };
-                // explain plan
-                Explainable::new(&mut model).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::DecorrelatedPlan => {
-                // run partial pipeline
-                let mut decorrelated_plan = decorrelate(raw_plan)?;
-                self.validate_timeline(decorrelated_plan.depends_on())?;
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(Default::default()),
-                    finishing: row_set_finishing,
-                    fast_path_plan: Default::default(),
-                };
-                // explain plan
-                Explainable::new(&mut decorrelated_plan).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::OptimizedPlan => {
-                // run partial pipeline
-                let decorrelated_plan = decorrelate(raw_plan)?;
-                self.validate_timeline(decorrelated_plan.depends_on())?;
-                let mut dataflow = optimize(self, decorrelated_plan)?;
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let used_indexes: Vec<GlobalId> = dataflow.index_imports.keys().cloned().collect();
-                let fast_path_plan = match explainee {
-                    Explainee::Query => {
-                        peek::create_fast_path_plan(&mut dataflow, GlobalId::Explain)?
-                    }
-                    _ => None,
-                };
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(used_indexes),
-                    finishing: row_set_finishing,
-                    fast_path_plan,
-                };
-                // explain plan
-                Explainable::new(&mut dataflow).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::PhysicalPlan => {
-                // run partial pipeline
-                let decorrelated_plan = decorrelate(raw_plan)?;
-                self.validate_timeline(decorrelated_plan.depends_on())?;
-                let mut dataflow = optimize(self, decorrelated_plan)?;
-                let used_indexes: Vec<GlobalId> = dataflow.index_imports.keys().cloned().collect();
-                let fast_path_plan = match explainee {
-                    Explainee::Query => {
-                        peek::create_fast_path_plan(&mut dataflow, GlobalId::Explain)?
-                    }
-                    _ => None,
-                };
-                let mut dataflow_plan =
-                    mz_compute_client::plan::Plan::<mz_repr::Timestamp>::finalize_dataflow(
-                        dataflow,
-                    )
-                    .expect("Dataflow planning failed; unrecoverable error");
-                // construct explanation context
-                let catalog = self.catalog.for_session(session);
-                let context = ExplainContext {
-                    config: &config,
-                    humanizer: &catalog,
-                    used_indexes: UsedIndexes::new(used_indexes),
-                    finishing: row_set_finishing,
-                    fast_path_plan,
-                };
-                // explain plan
-                Explainable::new(&mut dataflow_plan).explain(&format, &config, &context)?
-            }
-            ExplainStageNew::Trace => {
-                let feature = "ExplainStageNew::Trace";
-                Err(AdapterError::Unsupported(feature))?
-            }
+        if stages.contains(&ExplainedStage::QueryGraph) {
+            // run partial pipeline
+            let mut model = mz_sql::query_model::Model::try_from(raw_plan.clone())?;
+            // construct explanation context
+            let catalog = self.catalog.for_session(session);
+            let context = ExplainContext {
+                config,
+                humanizer: &catalog,
+                used_indexes: UsedIndexes::new(Default::default()),
+                finishing: row_set_finishing.clone(),
+                fast_path_plan: Default::default(),
+            };
+            // explain plan
+            let explanation_string =
+                Explainable::new(&mut model).explain(format, config, &context)?;
+            explanations.push((ExplainedStage::QueryGraph, explanation_string));
+        }
+
+        if stages.contains(&ExplainedStage::OptimizedQueryGraph) {
+            // run partial pipeline
+            let mut model = mz_sql::query_model::Model::try_from(raw_plan.clone())?;
+            model.optimize();
+            // construct explanation context
+            let catalog = self.catalog.for_session(session);
+            let context = ExplainContext {
+                config,
+                humanizer: &catalog,
+                used_indexes: UsedIndexes::new(Default::default()),
+                finishing: row_set_finishing.clone(),
+                fast_path_plan: Default::default(),
+            };
+            // explain plan
+            let explanation_string =
+                Explainable::new(&mut model).explain(format, config, &context)?;
+            explanations.push((ExplainedStage::OptimizedQueryGraph, explanation_string));
+        }
+
+        if last_stage < ExplainedStage::DecorrelatedPlan {
+            return Ok(explanations);
+        }
+
+        // run partial pipeline
+        let decorrelated_plan = raw_plan.optimize_and_lower(&OptimizerConfig {
+            qgm_optimizations: session.vars().qgm_optimizations(),
+        })?;
+        self.validate_timeline(decorrelated_plan.depends_on())?;
+
+        if stages.contains(&ExplainedStage::DecorrelatedPlan) {
+            // construct explanation context
+            let catalog = self.catalog.for_session(session);
+            let context = ExplainContext {
+                config,
+                humanizer: &catalog,
+                used_indexes: UsedIndexes::new(Default::default()),
+                finishing: row_set_finishing.clone(),
+                fast_path_plan: Default::default(),
+            };
+            // explain plan
+            let explanation_string = Explainable::new(&mut decorrelated_plan.clone())
+                .explain(format, config, &context)?;
+            explanations.push((ExplainedStage::DecorrelatedPlan, explanation_string));
+        }
+
+        if last_stage < ExplainedStage::OptimizedPlan {
+            return Ok(explanations);
+        }
+
+        // run partial pipeline
+        let optimized_plan = self.view_optimizer.optimize(decorrelated_plan)?;
+        let mut dataflow = DataflowDesc::new(format!("explanation"));
+        self.dataflow_builder(compute_instance)
+            .import_view_into_dataflow(&explainee_id, &optimized_plan, &mut dataflow)?;
+        mz_transform::optimize_dataflow(&mut dataflow, &self.index_oracle(compute_instance))?;
+        let used_indexes: Vec<GlobalId> = dataflow.index_imports.keys().cloned().collect();
+        let fast_path_plan = match explainee {
+            Explainee::Query => peek::create_fast_path_plan(&mut dataflow, GlobalId::Explain)?,
+            _ => None,
         };
 
-        let rows = vec![Row::pack_slice(&[Datum::from(&*explanation_string)])];
-        Ok(send_immediate_rows(rows))
+        if stages.contains(&ExplainedStage::OptimizedPlan) {
+            // construct explanation context
+            let catalog = self.catalog.for_session(session);
+            let context = ExplainContext {
+                config,
+                humanizer: &catalog,
+                used_indexes: UsedIndexes::new(used_indexes.clone()),
+                finishing: row_set_finishing.clone(),
+                fast_path_plan: fast_path_plan.clone(),
+            };
+            // explain plan
+            let explanation_string =
+                Explainable::new(&mut dataflow.clone()).explain(format, config, &context)?;
+            explanations.push((ExplainedStage::OptimizedPlan, explanation_string));
+        }
+
+        if last_stage < ExplainedStage::PhysicalPlan {
+            return Ok(explanations);
+        }
+
+        // run partial pipeline
+        let mut dataflow_plan =
+            mz_compute_client::plan::Plan::<mz_repr::Timestamp>::finalize_dataflow(dataflow)
+                .expect("Dataflow planning failed; unrecoverable error");
+        // construct explanation context
+        let catalog = self.catalog.for_session(session);
+        let context = ExplainContext {
+            config,
+            humanizer: &catalog,
+            used_indexes: UsedIndexes::new(used_indexes),
+            finishing: row_set_finishing,
+            fast_path_plan,
+        };
+        // explain plan
+        let explanation_string =
+            Explainable::new(&mut dataflow_plan).explain(format, config, &context)?;
+        explanations.push((ExplainedStage::PhysicalPlan, explanation_string));
+
+        Ok(explanations)
     }
 
     fn sequence_explain_old(
@@ -2841,13 +3082,20 @@ impl<S: Append + 'static> Coordinator<S> {
     // read. This works by doing a Peek then queuing a SendDiffs. No writes
     // or read-then-writes can occur between the Peek and SendDiff otherwise a
     // serializability violation could occur.
+    //
+    // Blind writes, like `INSERT ... VALUES ... RETURNING`, read nothing that a
+    // concurrent write could change, so they skip the write critical section.
+    // Like other inserts, their writes still wait for the write lock in group
+    // commit.
     async fn sequence_read_then_write(
         &mut self,
         tx: ClientTransmitter<ExecuteResponse>,
         mut session: Session,
         plan: ReadThenWritePlan,
     ) {
-        guard_write_critical_section!(self, tx, session, Plan::ReadThenWrite(plan));
+        if !plan.is_blind_write() {
+            guard_write_critical_section!(self, tx, session, Plan::ReadThenWrite(plan));
+        }
 
         let ReadThenWritePlan {
             id,
@@ -2950,8 +3198,6 @@ impl<S: Append + 'static> Coordinator<S> {
             }
         };
 
-        let timeout_dur = *session.vars().statement_timeout();
-
         let internal_cmd_tx = self.internal_cmd_tx.clone();
         task::spawn(|| format!("sequence_read_then_write:{id}"), async move {
             let arena = RowArena::new();
@@ -2960,76 +3206,61 @@ impl<S: Append + 'static> Coordinator<S> {
                     future: batch,
                     span: _,
                 } => {
-                    // TODO: This timeout should be removed once #11782 lands;
-                    // we should instead periodically ensure clusters are
-                    // healthy and actively cancel any work waiting on unhealthy
-                    // clusters.
-                    match tokio::time::timeout(timeout_dur, batch).await {
-                        Ok(res) => match res {
-                            PeekResponseUnary::Rows(rows) => {
-                                |rows: Vec<Row>| -> Result<Vec<(Row, Diff)>, AdapterError> {
-                                    // Use 2x row len incase there's some assignments.
-                                    let mut diffs = Vec::with_capacity(rows.len() * 2);
-                                    let mut datum_vec = mz_repr::DatumVec::new();
-                                    for row in rows {
-                                        if !assignments.is_empty() {
-                                            assert!(
-                                                matches!(kind, MutationKind::Update),
-                                                "only updates support assignments"
-                                            );
-                                            let mut datums = datum_vec.borrow_with(&row);
-                                            let mut updates = vec![];
-                                            for (idx, expr) in &assignments {
-                                                let updated = match expr.eval(&datums, &arena) {
-                                                    Ok(updated) => updated,
-                                                    Err(e) => {
-                                                        return Err(AdapterError::Unstructured(
-                                                            anyhow!(e),
-                                                        ))
-                                                    }
-                                                };
-                                                desc.constraints_met(*idx, &updated)?;
-                                                updates.push((*idx, updated));
-                                            }
-                                            for (idx, new_value) in updates {
-                                                datums[idx] = new_value;
-                                            }
-                                            let updated = Row::pack_slice(&datums);
-                                            diffs.push((updated, 1));
+                    // TODO: The timeout applied by `sequence_peek`, which is
+                    // bounded while the write lock is held, should be removed
+                    // once #11782 lands; we should instead periodically ensure
+                    // clusters are healthy and actively cancel any work waiting
+                    // on unhealthy clusters.
+                    match batch.await {
+                        PeekResponseUnary::Rows(rows) => {
+                            |rows: Vec<Row>| -> Result<Vec<(Row, Diff)>, AdapterError> {
+                                // Use 2x row len incase there's some assignments.
+                                let mut diffs = Vec::with_capacity(rows.len() * 2);
+                                let mut datum_vec = mz_repr::DatumVec::new();
+                                for row in rows {
+                                    if !assignments.is_empty() {
+                                        assert!(
+                                            matches!(kind, MutationKind::Update),
+                                            "only updates support assignments"
+                                        );
+                                        let mut datums = datum_vec.borrow_with(&row);
+                                        let mut updates = vec![];
+                                        for (idx, expr) in &assignments {
+                                            let updated = match expr.eval(&datums, &arena) {
+                                                Ok(updated) => updated,
+                                                Err(e) => {
+                                                    return Err(AdapterError::Unstructured(
+                                                        anyhow!(e),
+                                                    ))
+                                                }
+                                            };
+                                            desc.constraints_met(*idx, &updated)?;
+                                            updates.push((*idx, updated));
                                         }
-                                        match kind {
-                                            // Updates and deletes always remove the
-                                            // current row. Updates will also add an
-                                            // updated value.
-                                            MutationKind::Update | MutationKind::Delete => {
-                                                diffs.push((row, -1))
-                                            }
-                                            MutationKind::Insert => diffs.push((row, 1)),
+                                        for (idx, new_value) in updates {
+                                            datums[idx] = new_value;
                                         }
+                                        let updated = Row::pack_slice(&datums);
+                                        diffs.push((updated, 1));
                                     }
-                                    Ok(diffs)
-                                }(rows)
-                            }
-                            PeekResponseUnary::Canceled => {
-                                Err(AdapterError::Unstructured(anyhow!("execution canceled")))
-                            }
-                            PeekResponseUnary::Error(e) => {
-                                Err(AdapterError::Unstructured(anyhow!(e)))
-                            }
-                        },
-                        Err(_) => {
-                            // We timed out, so remove the pending peek. This is
-                            // best-effort and doesn't guarantee we won't
-                            // receive a response.
-                            // It is not an error for this timeout to occur after `internal_cmd_rx` has been dropped.
-                            let result = internal_cmd_tx.send(Message::RemovePendingPeeks {
-                                conn_id: session.conn_id(),
-                            });
-                            if let Err(e) = result {
-                                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
-                            }
-                            Err(AdapterError::StatementTimeout)
+                                    match kind {
+                                        // Updates and deletes always remove the
+                                        // current row. Updates will also add an
+                                        // updated value.
+                                        MutationKind::Update | MutationKind::Delete => {
+                                            diffs.push((row, -1))
+                                        }
+                                        MutationKind::Insert => diffs.push((row, 1)),
+                                    }
+                                }
+                                Ok(diffs)
+                            }(rows)
                         }
+                        PeekResponseUnary::Canceled => {
+                            Err(AdapterError::Unstructured(anyhow!("execution canceled")))
+                        }
+                        PeekResponseUnary::Error(e) => Err(AdapterError::Unstructured(anyhow!(e))),
+                        PeekResponseUnary::TimedOut => Err(AdapterError::StatementTimeout),
                     }
                 }
                 _ => Err(AdapterError::Unstructured(anyhow!("expected SendingRows"))),
@@ -3111,31 +3342,48 @@ impl<S: Append + 'static> Coordinator<S> {
 
     async fn sequence_alter_index_set_options(
         &mut self,
+        session: &Session,
         plan: AlterIndexSetOptionsPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterIndexOptions {
+            id: plan.id,
+            set: plan.options.clone(),
+            reset: vec![],
+        };
+        self.catalog_transact(Some(session), vec![op], |_| Ok(()))
+            .await?;
         self.set_index_options(plan.id, plan.options).await?;
         Ok(ExecuteResponse::AlteredObject(ObjectType::Index))
     }
 
     async fn sequence_alter_index_reset_options(
         &mut self,
+        session: &Session,
         plan: AlterIndexResetOptionsPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         let mut options = Vec::with_capacity(plan.options.len());
-        for o in plan.options {
+        for o in &plan.options {
             options.push(match o {
                 IndexOptionName::LogicalCompactionWindow => IndexOption::LogicalCompactionWindow(
-                    DEFAULT_LOGICAL_COMPACTION_WINDOW_MS.map(Duration::from_millis),
+                    self.default_logical_compaction_window_ms()
+                        .map(Duration::from_millis),
                 ),
             });
         }
 
+        let op = catalog::Op::AlterIndexOptions {
+            id: plan.id,
+            set: vec![],
+            reset: plan.options.into_iter().collect(),
+        };
+        self.catalog_transact(Some(session), vec![op], |_| Ok(()))
+            .await?;
         self.set_index_options(plan.id, options).await?;
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Index))
     }
 
-    async fn set_index_options(
+    pub(crate) async fn set_index_options(
         &mut self,
         id: GlobalId,
         options: Vec<IndexOption>,
@@ -3180,9 +3428,24 @@ impl<S: Append + 'static> Coordinator<S> {
     async fn sequence_alter_source(
         &mut self,
         session: &Session,
-        AlterSourcePlan { id, size, remote }: AlterSourcePlan,
+        AlterSourcePlan {
+            id,
+            size,
+            remote,
+            logical_compaction_window,
+        }: AlterSourcePlan,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let op = catalog::Op::AlterSource { id, size, remote };
+        let host_config_changed = !matches!(
+            (&size, &remote),
+            (AlterSourceItem::Unchanged, AlterSourceItem::Unchanged)
+        );
+        let window_changed = !matches!(logical_compaction_window, AlterSourceItem::Unchanged);
+        let op = catalog::Op::AlterSource {
+            id,
+            size,
+            remote,
+            logical_compaction_window,
+        };
         self.catalog_transact(Some(session), vec![op], |_| Ok(()))
             .await?;
 
@@ -3191,11 +3454,24 @@ impl<S: Append + 'static> Coordinator<S> {
         let updated_source = entry.source().ok_or_else(|| {
             CatalogError::UnexpectedType(entry.name().to_string(), CatalogItemType::Source)
         })?;
+        let host_config = updated_source.host_config.clone();
+        let compaction_window_ms =
+            self.logical_compaction_window_ms(updated_source.logical_compaction_window);
 
-        self.controller
-            .storage_mut()
-            .alter_collections(vec![(id, updated_source.host_config.clone())])
-            .await?;
+        if host_config_changed {
+            self.controller
+                .storage_mut()
+                .alter_collections(vec![(id, host_config)])
+                .await?;
+        }
+
+        if window_changed {
+            let policy = match compaction_window_ms {
+                Some(time) => ReadPolicy::lag_writes_by(time),
+                None => ReadPolicy::ValidFrom(Antichain::from_elem(Timestamp::minimum())),
+            };
+            self.update_storage_base_read_policy(id, policy).await;
+        }
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Source))
     }
@@ -3300,6 +3576,25 @@ impl<S: Append + 'static> Coordinator<S> {
         Ok(ExecuteResponse::AlteredSystemConfiguraion)
     }
 
+    async fn sequence_comment(
+        &mut self,
+        session: &Session,
+        CommentPlan {
+            object_id,
+            column_position,
+            comment,
+        }: CommentPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::Comment {
+            object_id,
+            column_position,
+            comment,
+        };
+        self.catalog_transact(Some(session), vec![op], |_| Ok(()))
+            .await?;
+        Ok(ExecuteResponse::Comment)
+    }
+
     fn is_user_allowed_to_alter_system(&self, session: &Session) -> Result<(), AdapterError> {
         if session.user() == SYSTEM_USER {
             Ok(())
@@ -3336,3 +3631,31 @@ impl<S: Append + 'static> Coordinator<S> {
         Ok(GlobalId::Transient(id))
     }
 }
+
+/// A stage of the optimization pipeline whose plan `EXPLAIN` can show.
+///
+/// Unlike [`ExplainStageNew`], this does not include `OPTIMIZER TRACE`, which
+/// shows several stages. Stages are ordered as they occur in the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ExplainedStage {
+    RawPlan,
+    QueryGraph,
+    OptimizedQueryGraph,
+    DecorrelatedPlan,
+    OptimizedPlan,
+    PhysicalPlan,
+}
+
+impl ExplainedStage {
+    /// Returns the name of the stage as it is spelled in `EXPLAIN`.
+    fn name(&self) -> &'static str {
+        match self {
+            ExplainedStage::RawPlan => "RAW PLAN",
+            ExplainedStage::QueryGraph => "QUERY GRAPH",
+            ExplainedStage::OptimizedQueryGraph => "OPTIMIZED QUERY GRAPH",
+            ExplainedStage::DecorrelatedPlan => "DECORRELATED PLAN",
+            ExplainedStage::OptimizedPlan => "OPTIMIZED PLAN",
+            ExplainedStage::PhysicalPlan => "PHYSICAL PLAN",
+        }
+    }
+}
@@ -29,8 +29,13 @@ impl<S: Append + 'static> Coordinator<S> {
         params: &mz_sql::plan::Params,
     ) -> Result<mz_sql::plan::Plan, AdapterError> {
         let pcx = session.pcx();
-        let plan =
-            mz_sql::plan::plan(Some(&pcx), &self.catalog.for_session(session), stmt, params)?;
+        let (plan, notices) = mz_sql::plan::plan_with_notices(
+            Some(&pcx),
+            &self.catalog.for_session(session),
+            stmt,
+            params,
+        )?;
+        session.add_notices(notices);
         Ok(plan)
     }
 
@@ -10,12 +10,14 @@
 //! A mechanism to ensure that a sequence of writes and reads proceed correctly through timestamps.
 
 use std::collections::{HashMap, HashSet};
-use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use futures::future::LocalBoxFuture;
 use once_cell::sync::Lazy;
 use timely::progress::Timestamp as TimelyTimestamp;
+use tokio_postgres::{Client, Row};
 
 use mz_compute_client::controller::ComputeInstanceId;
 use mz_expr::CollectionPlan;
@@ -25,7 +27,7 @@ use mz_sql::names::{ResolvedDatabaseSpecifier, SchemaSpecifier};
 use mz_stash::Append;
 use mz_storage::types::sources::Timeline;
 
-use crate::catalog::CatalogItem;
+use crate::catalog::{self, Catalog, CatalogItem};
 use crate::client::ConnectionId;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::read_policy::ReadHolds;
@@ -48,10 +50,54 @@ pub struct WriteTimestamp<T = mz_repr::Timestamp> {
 /// providing read (and sometimes write) timestamps, and a set of read holds which
 /// guarantee that those read timestamps are valid.
 pub(crate) struct TimelineState<T> {
-    pub(crate) oracle: DurableTimestampOracle<T>,
+    pub(crate) oracle: Box<dyn TimestampOracle<T>>,
     pub(crate) read_holds: ReadHolds<T>,
 }
 
+/// A type that provides write and read timestamps for a timeline, such that reads observe exactly
+/// their preceding writes.
+///
+/// Specifically, all read timestamps will be greater or equal to all previously reported completed
+/// write timestamps, and strictly less than all subsequently emitted write timestamps.
+///
+/// These guarantees hold for the timestamps of a single oracle. Oracles that keep their state in
+/// memory or in the catalog's stash do not coordinate with each other, so two coordinators that
+/// run at the same time must not share a timeline through them. See [`PostgresTimestampOracle`]
+/// for an oracle that does coordinate through a shared store.
+pub trait TimestampOracle<T> {
+    /// Acquire a new timestamp for writing.
+    ///
+    /// This timestamp will be strictly greater than all prior values of `self.read_ts()` and
+    /// `self.write_ts()`.
+    fn write_ts(&mut self) -> LocalBoxFuture<'_, WriteTimestamp<T>>;
+
+    /// Peek the current write timestamp.
+    fn peek_write_ts(&self) -> T;
+
+    /// Acquire a new timestamp for reading.
+    ///
+    /// This timestamp will be greater or equal to all prior values of `self.apply_write(write_ts)`,
+    /// and strictly less than all subsequent values of `self.write_ts()`.
+    fn read_ts(&mut self) -> T;
+
+    /// Mark a write at `write_ts` completed.
+    ///
+    /// All subsequent values of `self.read_ts()` will be greater or equal to `write_ts`.
+    fn apply_write(&mut self, write_ts: T) -> LocalBoxFuture<'_, ()>;
+}
+
+/// A durable store for the timestamps of a timeline.
+///
+/// A [`DurableTimestampOracle`] records an upper bound of the timestamps that it hands out in the
+/// store. Oracles that start from the store, like the oracle of a restarted coordinator, never
+/// hand out timestamps below that bound. The store only makes timestamps survive restarts; it does
+/// not let oracles that run at the same time share a timeline, unlike a
+/// [`PostgresTimestampOracle`].
+pub trait TimestampPersistence<T> {
+    /// Durably records `timestamp` as the upper bound of the timestamps handed out.
+    fn persist(&self, timestamp: T) -> LocalBoxFuture<'_, Result<(), catalog::Error>>;
+}
+
 /// A timeline can perform reads and writes. Reads happen at the read timestamp
 /// and writes happen at the write timestamp. After the write has completed, but before a response
 /// is sent, the read timestamp must be updated to a value greater than or equal to `self.write_ts`.
@@ -60,16 +106,14 @@ struct TimestampOracleState<T> {
     write_ts: T,
 }
 
-/// A type that provides write and read timestamps, reads observe exactly their preceding writes..
-///
-/// Specifically, all read timestamps will be greater or equal to all previously reported completed
-/// write timestamps, and strictly less than all subsequently emitted write timestamps.
-struct TimestampOracle<T> {
+/// Provides write and read timestamps with the properties of a [`TimestampOracle`], keeping its
+/// state only in memory.
+struct InMemoryTimestampOracle<T> {
     state: TimestampOracleState<T>,
     next: Box<dyn Fn() -> T>,
 }
 
-impl<T: CoordTimestamp> TimestampOracle<T> {
+impl<T: CoordTimestamp> InMemoryTimestampOracle<T> {
     /// Create a new timeline, starting at the indicated time. `next` generates
     /// new timestamps when invoked. The timestamps have no requirements, and can
     /// retreat from previous invocations.
@@ -143,72 +187,81 @@ pub static TIMESTAMP_PERSIST_INTERVAL: Lazy<mz_repr::Timestamp> = Lazy::new(|| {
         .expect("15 seconds can fit into `Timestamp`")
 });
 
-/// A type that wraps a [`TimestampOracle`] and provides durable timestamps. This allows us to
-/// recover a timestamp that is larger than all previous timestamps on restart. The protocol
-/// is based on timestamp recovery from Percolator <https://research.google/pubs/pub36726/>. We
-/// "pre-allocate" a group of timestamps at once, and only durably store the largest of those
-/// timestamps. All timestamps within that interval can be served directly from memory, without
+/// A [`TimestampOracle`] that wraps an [`InMemoryTimestampOracle`] and provides durable
+/// timestamps. This allows us to recover a timestamp that is larger than all previous timestamps
+/// on restart. The protocol is based on timestamp recovery from Percolator
+/// <https://research.google/pubs/pub36726/>. We "pre-allocate" a group of timestamps at once, and
+/// only durably store the largest of those timestamps. All timestamps within that interval can be served directly from memory, without
 /// going to disk. On restart, we re-initialize the current timestamp to a value one larger
 /// than the persisted timestamp.
 ///
-/// See [`TimestampOracle`] for more details on the properties of the timestamps.
+/// The timestamps are persisted through a [`TimestampPersistence`].
 pub struct DurableTimestampOracle<T> {
-    timestamp_oracle: TimestampOracle<T>,
+    timestamp_oracle: InMemoryTimestampOracle<T>,
     durable_timestamp: T,
     persist_interval: T,
+    persistence: Box<dyn TimestampPersistence<T>>,
 }
 
 impl<T: CoordTimestamp> DurableTimestampOracle<T> {
     /// Create a new durable timeline, starting at the indicated time. Timestamps will be
-    /// allocated in groups of size `persist_interval`. Also returns the new timestamp that
-    /// needs to be persisted to disk.
+    /// allocated in groups of size `persist_interval`, and the end of each group is persisted
+    /// with `persistence`.
     ///
-    /// See [`TimestampOracle::new`] for more details.
-    pub(crate) async fn new<F, Fut>(
+    /// See [`InMemoryTimestampOracle::new`] for more details.
+    pub(crate) async fn new<F>(
         initially: T,
         next: F,
         persist_interval: T,
-        persist_fn: impl FnOnce(T) -> Fut,
+        persistence: Box<dyn TimestampPersistence<T>>,
     ) -> Self
     where
         F: Fn() -> T + 'static,
-        Fut: Future<Output = Result<(), crate::catalog::Error>>,
     {
         let mut oracle = Self {
-            timestamp_oracle: TimestampOracle::new(initially.clone(), next),
+            timestamp_oracle: InMemoryTimestampOracle::new(initially.clone(), next),
             durable_timestamp: initially.clone(),
             persist_interval,
+            persistence,
         };
-        oracle
-            .maybe_allocate_new_timestamps(&initially, persist_fn)
-            .await;
+        oracle.maybe_allocate_new_timestamps(&initially).await;
         oracle
     }
 
-    /// Acquire a new timestamp for writing. Optionally returns a timestamp that needs to be
-    /// persisted to disk.
+    /// Checks to see if we can serve the timestamp from memory, or if we need to durably store
+    /// a new timestamp.
     ///
-    /// See [`TimestampOracle::write_ts`] for more details.
-    async fn write_ts<Fut>(&mut self, persist_fn: impl FnOnce(T) -> Fut) -> WriteTimestamp<T>
-    where
-        Fut: Future<Output = Result<(), crate::catalog::Error>>,
-    {
-        let ts = self.timestamp_oracle.write_ts();
-        self.maybe_allocate_new_timestamps(&ts.timestamp, persist_fn)
-            .await;
-        ts
+    /// If `ts` is less than the persisted timestamp then we can serve `ts` from memory,
+    /// otherwise we need to durably store some timestamp greater than `ts`.
+    async fn maybe_allocate_new_timestamps(&mut self, ts: &T) {
+        if self.durable_timestamp.less_equal(ts)
+            // Since the timestamp is at its max value, we know that no other Coord can
+            // allocate a higher value.
+            && self.durable_timestamp.less_than(&T::maximum())
+        {
+            self.durable_timestamp = ts.step_forward_by(&self.persist_interval);
+            self.persistence
+                .persist(self.durable_timestamp.clone())
+                .await
+                .expect("can't persist timestamp");
+        }
+    }
+}
+
+impl<T: CoordTimestamp> TimestampOracle<T> for DurableTimestampOracle<T> {
+    fn write_ts(&mut self) -> LocalBoxFuture<'_, WriteTimestamp<T>> {
+        Box::pin(async move {
+            let ts = self.timestamp_oracle.write_ts();
+            self.maybe_allocate_new_timestamps(&ts.timestamp).await;
+            ts
+        })
     }
 
-    /// Peek current write timestamp.
     fn peek_write_ts(&self) -> T {
         self.timestamp_oracle.peek_write_ts()
     }
 
-    /// Acquire a new timestamp for reading. Optionally returns a timestamp that needs to be
-    /// persisted to disk.
-    ///
-    /// See [`TimestampOracle::read_ts`] for more details.
-    pub fn read_ts(&mut self) -> T {
+    fn read_ts(&mut self) -> T {
         let ts = self.timestamp_oracle.read_ts();
         assert!(
             ts.less_than(&self.durable_timestamp),
@@ -217,40 +270,194 @@ impl<T: CoordTimestamp> DurableTimestampOracle<T> {
         ts
     }
 
-    /// Mark a write at `write_ts` completed.
+    fn apply_write(&mut self, lower_bound: T) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.timestamp_oracle.apply_write(lower_bound.clone());
+            self.maybe_allocate_new_timestamps(&lower_bound).await;
+        })
+    }
+}
+
+/// The table in which [`PostgresTimestampOracle`]s store the timestamps of their timelines.
+const TIMESTAMP_ORACLE_SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS timestamp_oracle (
+    timeline text NOT NULL PRIMARY KEY,
+    read_ts bigint NOT NULL,
+    write_ts bigint NOT NULL
+)";
+
+/// Connects to the PostgreSQL database at `url` that [`PostgresTimestampOracle`]s share, and
+/// creates the table in which they store their timestamps if it does not exist yet.
+pub(crate) async fn connect_timestamp_oracle(url: &str) -> Result<Arc<Client>, anyhow::Error> {
+    let config: tokio_postgres::Config = url.parse()?;
+    let tls = mz_postgres_util::make_tls(&config)?;
+    let (client, connection) = config.connect(tls).await?;
+    mz_ore::task::spawn(
+        || "tokio-postgres timestamp oracle connection",
+        async move {
+            if let Err(e) = connection.await {
+                tracing::error!("postgres timestamp oracle connection error: {}", e);
+            }
+        },
+    );
+    client.batch_execute(TIMESTAMP_ORACLE_SCHEMA).await?;
+    Ok(Arc::new(client))
+}
+
+/// A [`TimestampOracle`] that stores the timestamps of a timeline in a PostgreSQL database, which
+/// lets coordinators that run at the same time share the timeline.
+///
+/// The database allocates every write timestamp, so write timestamps are strictly increasing
+/// across all the oracles of a timeline, and strictly greater than all of their read timestamps.
+/// Completed writes are recorded in the database as well. Because `read_ts` is synchronous, reads
+/// are served from the read timestamp that this oracle last saw in the database: they observe the
+/// writes completed through this oracle right away, and the writes completed through other
+/// oracles once this oracle next acquires a write timestamp or completes a write.
+pub struct PostgresTimestampOracle {
+    client: Arc<Client>,
+    timeline: String,
+    state: TimestampOracleState<Timestamp>,
+    next: Box<dyn Fn() -> Timestamp>,
+}
+
+impl PostgresTimestampOracle {
+    /// Create a new oracle for `timeline`, whose timestamps start at `initially` unless the
+    /// database already holds larger ones. `next` generates lower bounds for new write
+    /// timestamps.
     ///
-    /// See [`TimestampOracle::apply_write`] for more details.
-    pub async fn apply_write<Fut>(&mut self, lower_bound: T, persist_fn: impl FnOnce(T) -> Fut)
+    /// See [`InMemoryTimestampOracle::new`] for more details.
+    pub(crate) async fn new<F>(
+        client: Arc<Client>,
+        timeline: &Timeline,
+        initially: Timestamp,
+        next: F,
+    ) -> Self
     where
-        Fut: Future<Output = Result<(), crate::catalog::Error>>,
+        F: Fn() -> Timestamp + 'static,
     {
-        self.timestamp_oracle.apply_write(lower_bound.clone());
-        self.maybe_allocate_new_timestamps(&lower_bound, persist_fn)
-            .await;
+        let timeline = timeline.to_string();
+        let row = client
+            .query_one(
+                "INSERT INTO timestamp_oracle (timeline, read_ts, write_ts) VALUES ($1, $2, $2)
+                ON CONFLICT (timeline) DO UPDATE SET
+                    read_ts = GREATEST(timestamp_oracle.read_ts, excluded.read_ts),
+                    write_ts = GREATEST(timestamp_oracle.write_ts, excluded.write_ts)
+                RETURNING read_ts, write_ts",
+                &[&timeline, &to_sql_timestamp(initially)],
+            )
+            .await
+            .expect("can't initialize timestamp oracle");
+        let mut oracle = Self {
+            client,
+            timeline,
+            state: TimestampOracleState {
+                read_ts: initially,
+                write_ts: initially,
+            },
+            next: Box::new(next),
+        };
+        oracle.update_state(&row);
+        oracle
     }
 
-    /// Checks to see if we can serve the timestamp from memory, or if we need to durably store
-    /// a new timestamp.
-    ///
-    /// If `ts` is less than the persisted timestamp then we can serve `ts` from memory,
-    /// otherwise we need to durably store some timestamp greater than `ts`.
-    async fn maybe_allocate_new_timestamps<Fut>(
-        &mut self,
-        ts: &T,
-        persist_fn: impl FnOnce(T) -> Fut,
-    ) where
-        Fut: Future<Output = Result<(), crate::catalog::Error>>,
-    {
-        if self.durable_timestamp.less_equal(ts)
-            // Since the timestamp is at its max value, we know that no other Coord can
-            // allocate a higher value.
-            && self.durable_timestamp.less_than(&T::maximum())
-        {
-            self.durable_timestamp = ts.step_forward_by(&self.persist_interval);
-            persist_fn(self.durable_timestamp.clone())
+    /// Advances the state of the oracle to the timestamps of `row`, as read from the database.
+    fn update_state(&mut self, row: &Row) {
+        let read_ts = from_sql_timestamp(row.get("read_ts"));
+        let write_ts = from_sql_timestamp(row.get("write_ts"));
+        self.state.read_ts = std::cmp::max(self.state.read_ts, read_ts);
+        self.state.write_ts = std::cmp::max(self.state.write_ts, write_ts);
+        assert!(self.state.read_ts <= self.state.write_ts);
+    }
+}
+
+impl TimestampOracle<Timestamp> for PostgresTimestampOracle {
+    fn write_ts(&mut self) -> LocalBoxFuture<'_, WriteTimestamp<Timestamp>> {
+        Box::pin(async move {
+            // The stored write timestamp is never less than the stored read timestamp, so the new
+            // write timestamp is strictly greater than the read timestamps of all oracles.
+            let row = self
+                .client
+                .query_one(
+                    "UPDATE timestamp_oracle SET write_ts = GREATEST(write_ts + 1, $2)
+                    WHERE timeline = $1
+                    RETURNING read_ts, write_ts",
+                    &[&self.timeline, &to_sql_timestamp((self.next)())],
+                )
                 .await
-                .expect("can't persist timestamp");
-        }
+                .expect("can't allocate write timestamp");
+            self.update_state(&row);
+            let timestamp = from_sql_timestamp(row.get("write_ts"));
+            WriteTimestamp {
+                timestamp,
+                advance_to: timestamp.step_forward(),
+            }
+        })
+    }
+
+    fn peek_write_ts(&self) -> Timestamp {
+        self.state.write_ts
+    }
+
+    fn read_ts(&mut self) -> Timestamp {
+        self.state.read_ts
+    }
+
+    fn apply_write(&mut self, write_ts: Timestamp) -> LocalBoxFuture<'_, ()> {
+        Box::pin(async move {
+            let row = self
+                .client
+                .query_one(
+                    "UPDATE timestamp_oracle SET
+                        read_ts = GREATEST(read_ts, $2),
+                        write_ts = GREATEST(write_ts, $2)
+                    WHERE timeline = $1
+                    RETURNING read_ts, write_ts",
+                    &[&self.timeline, &to_sql_timestamp(write_ts)],
+                )
+                .await
+                .expect("can't apply write");
+            self.update_state(&row);
+        })
+    }
+}
+
+fn to_sql_timestamp(ts: Timestamp) -> i64 {
+    i64::try_from(ts).expect("timestamp fits in a bigint")
+}
+
+fn from_sql_timestamp(ts: i64) -> Timestamp {
+    Timestamp::try_from(ts).expect("stored timestamps are not negative")
+}
+
+/// Creates the oracle of `timeline`, starting at `initially` and generating new write timestamps
+/// with `next`.
+///
+/// The oracle shares the timeline through `shared_client` if there is one, and otherwise makes its
+/// timestamps durable in the catalog's stash.
+pub(crate) async fn new_timestamp_oracle<S, F>(
+    catalog: &Catalog<S>,
+    shared_client: Option<&Arc<Client>>,
+    timeline: &Timeline,
+    initially: Timestamp,
+    next: F,
+) -> Box<dyn TimestampOracle<Timestamp>>
+where
+    S: Append + 'static,
+    F: Fn() -> Timestamp + 'static,
+{
+    match shared_client {
+        Some(client) => Box::new(
+            PostgresTimestampOracle::new(Arc::clone(client), timeline, initially, next).await,
+        ),
+        None => Box::new(
+            DurableTimestampOracle::new(
+                initially,
+                next,
+                *TIMESTAMP_PERSIST_INTERVAL,
+                Box::new(catalog.timestamp_persistence(timeline)),
+            )
+            .await,
+        ),
     }
 }
 
@@ -266,29 +473,27 @@ impl<S: Append + 'static> Coordinator<S> {
     pub(crate) fn get_timestamp_oracle_mut(
         &mut self,
         timeline: &Timeline,
-    ) -> &mut DurableTimestampOracle<Timestamp> {
-        &mut self
-            .global_timelines
+    ) -> &mut dyn TimestampOracle<Timestamp> {
+        self.global_timelines
             .get_mut(timeline)
             .expect("all timelines have a timestamp oracle")
             .oracle
+            .as_mut()
     }
 
     /// Returns a reference to the timestamp oracle used for reads and writes
     /// from/to a local input.
-    fn get_local_timestamp_oracle(&self) -> &DurableTimestampOracle<Timestamp> {
-        &self
-            .global_timelines
+    fn get_local_timestamp_oracle(&self) -> &dyn TimestampOracle<Timestamp> {
+        self.global_timelines
             .get(&Timeline::EpochMilliseconds)
             .expect("no realtime timeline")
             .oracle
+            .as_ref()
     }
 
     /// Returns a mutable reference to the timestamp oracle used for reads and writes
     /// from/to a local input.
-    pub(crate) fn get_local_timestamp_oracle_mut(
-        &mut self,
-    ) -> &mut DurableTimestampOracle<Timestamp> {
+    pub(crate) fn get_local_timestamp_oracle_mut(&mut self) -> &mut dyn TimestampOracle<Timestamp> {
         self.get_timestamp_oracle_mut(&Timeline::EpochMilliseconds)
     }
 
@@ -303,15 +508,7 @@ impl<S: Append + 'static> Coordinator<S> {
     /// Writes following reads must ensure that they are assigned a strictly larger
     /// timestamp to ensure they are not visible to any real-time earlier reads.
     pub(crate) async fn get_local_write_ts(&mut self) -> WriteTimestamp {
-        self.global_timelines
-            .get_mut(&Timeline::EpochMilliseconds)
-            .expect("no realtime timeline")
-            .oracle
-            .write_ts(|ts| {
-                self.catalog
-                    .persist_timestamp(&Timeline::EpochMilliseconds, ts)
-            })
-            .await
+        self.get_local_timestamp_oracle_mut().write_ts().await
     }
 
     /// Peek the current timestamp used for operations on local inputs. Used to determine how much
@@ -323,14 +520,8 @@ impl<S: Append + 'static> Coordinator<S> {
     /// Peek the current timestamp used for operations on local inputs. Used to determine how much
     /// to block group commits by.
     pub(crate) async fn apply_local_write(&mut self, timestamp: Timestamp) {
-        self.global_timelines
-            .get_mut(&Timeline::EpochMilliseconds)
-            .expect("no realtime timeline")
-            .oracle
-            .apply_write(timestamp, |ts| {
-                self.catalog
-                    .persist_timestamp(&Timeline::EpochMilliseconds, ts)
-            })
+        self.get_local_timestamp_oracle_mut()
+            .apply_write(timestamp)
             .await;
     }
 
@@ -340,16 +531,18 @@ impl<S: Append + 'static> Coordinator<S> {
         timeline: Timeline,
     ) -> &mut TimelineState<Timestamp> {
         if !self.global_timelines.contains_key(&timeline) {
+            let oracle = new_timestamp_oracle(
+                &self.catalog,
+                self.timestamp_oracle_client.as_ref(),
+                &timeline,
+                Timestamp::minimum(),
+                Timestamp::minimum,
+            )
+            .await;
             self.global_timelines.insert(
                 timeline.clone(),
                 TimelineState {
-                    oracle: DurableTimestampOracle::new(
-                        Timestamp::minimum(),
-                        Timestamp::minimum,
-                        *TIMESTAMP_PERSIST_INTERVAL,
-                        |ts| self.catalog.persist_timestamp(&timeline, ts),
-                    )
-                    .await,
+                    oracle,
                     read_holds: ReadHolds::new(Timestamp::minimum()),
                 },
             );
@@ -632,3 +825,140 @@ impl<S: Append + 'static> Coordinator<S> {
         Ok(id_bundle)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use futures::future::{self, LocalBoxFuture};
+
+    use mz_ore::now::ManualClock;
+    use mz_repr::Timestamp;
+    use mz_storage::types::sources::Timeline;
+
+    use super::{
+        connect_timestamp_oracle, DurableTimestampOracle, InMemoryTimestampOracle,
+        PostgresTimestampOracle, TimestampOracle, TimestampPersistence,
+    };
+    use crate::catalog;
+
+    /// A [`TimestampPersistence`] that records the timestamps that an oracle persists.
+    #[derive(Clone, Default)]
+    struct RecordingPersistence(Rc<RefCell<Vec<Timestamp>>>);
+
+    impl RecordingPersistence {
+        fn persisted(&self) -> Vec<Timestamp> {
+            self.0.borrow().clone()
+        }
+    }
+
+    impl TimestampPersistence<Timestamp> for RecordingPersistence {
+        fn persist(&self, timestamp: Timestamp) -> LocalBoxFuture<'_, Result<(), catalog::Error>> {
+            self.0.borrow_mut().push(timestamp);
+            Box::pin(future::ready(Ok(())))
+        }
+    }
+
+    #[test]
+    fn test_timestamp_oracle() {
+        let clock = ManualClock::new(10);
+        let now = clock.now_fn();
+        let mut oracle = InMemoryTimestampOracle::<Timestamp>::new(0, move || now());
+
+        // Write timestamps follow the clock when it advances.
+        let ts = oracle.write_ts();
+        assert_eq!((ts.timestamp, ts.advance_to), (10, 11));
+
+        // Write timestamps always increase, even if the clock stalls or
+        // moves backwards.
+        assert_eq!(oracle.write_ts().timestamp, 11);
+        clock.set(5);
+        assert_eq!(oracle.write_ts().timestamp, 12);
+        clock.set(20);
+        assert_eq!(oracle.write_ts().timestamp, 20);
+
+        // Reads only observe completed writes.
+        assert_eq!(oracle.read_ts(), 0);
+        oracle.apply_write(12);
+        assert_eq!(oracle.read_ts(), 12);
+        assert_eq!(oracle.peek_write_ts(), 20);
+        assert_eq!(oracle.write_ts().timestamp, 21);
+    }
+
+    #[tokio::test]
+    async fn test_durable_timestamp_oracle() {
+        let clock = ManualClock::new(0);
+        let now = clock.now_fn();
+        let persistence = RecordingPersistence::default();
+        let mut oracle =
+            DurableTimestampOracle::new(0, move || now(), 10, Box::new(persistence.clone())).await;
+        assert_eq!(persistence.persisted(), vec![10]);
+
+        // Timestamps below the durable timestamp are served from memory.
+        clock.set(5);
+        oracle.write_ts().await;
+        assert_eq!(persistence.persisted(), vec![10]);
+
+        // Reaching the durable timestamp allocates another interval.
+        clock.set(10);
+        oracle.write_ts().await;
+        assert_eq!(persistence.persisted(), vec![10, 20]);
+        assert_eq!(oracle.read_ts(), 0);
+
+        oracle.apply_write(10).await;
+        assert_eq!(oracle.read_ts(), 10);
+        assert_eq!(persistence.persisted(), vec![10, 20]);
+    }
+
+    #[tokio::test]
+    async fn test_postgres_timestamp_oracle() {
+        let url = match std::env::var("POSTGRES_URL") {
+            Ok(s) => s,
+            Err(_) => {
+                println!("skipping test_postgres_timestamp_oracle because POSTGRES_URL is not set");
+                return;
+            }
+        };
+        let timeline = Timeline::User("test_postgres_timestamp_oracle".into());
+        let client = connect_timestamp_oracle(&url).await.unwrap();
+        client
+            .execute(
+                "DELETE FROM timestamp_oracle WHERE timeline = $1",
+                &[&timeline.to_string()],
+            )
+            .await
+            .unwrap();
+
+        // Two oracles that share a timeline, like two coordinators that run at
+        // the same time.
+        let clock = ManualClock::new(10);
+        let now = clock.now_fn();
+        let mut a =
+            PostgresTimestampOracle::new(Arc::clone(&client), &timeline, 0, move || now()).await;
+        let mut b = PostgresTimestampOracle::new(Arc::clone(&client), &timeline, 0, || 0).await;
+
+        // Write timestamps strictly increase across the oracles, even when the
+        // clock does not advance.
+        let ts = a.write_ts().await;
+        assert_eq!((ts.timestamp, ts.advance_to), (10, 11));
+        assert_eq!(b.write_ts().await.timestamp, 11);
+        assert_eq!(a.write_ts().await.timestamp, 12);
+        assert_eq!(b.peek_write_ts(), 11);
+
+        // Reads observe the writes completed through the oracle right away, and
+        // those completed through the other oracle once it talks to the store.
+        b.apply_write(11).await;
+        assert_eq!(b.read_ts(), 11);
+        assert_eq!(a.read_ts(), 0);
+        assert_eq!(a.write_ts().await.timestamp, 13);
+        assert_eq!(a.read_ts(), 11);
+
+        // A new oracle resumes from the stored timestamps.
+        let mut c = PostgresTimestampOracle::new(client, &timeline, 0, || 0).await;
+        assert_eq!(c.read_ts(), 11);
+        assert_eq!(c.peek_write_ts(), 13);
+        assert_eq!(c.write_ts().await.timestamp, 14);
+    }
+}
@@ -17,6 +17,7 @@ use mz_repr::explain_new::ExprHumanizer;
 use mz_repr::{RowArena, ScalarType, Timestamp};
 use mz_sql::plan::QueryWhen;
 use mz_stash::Append;
+use mz_storage::types::sources::Timeline;
 
 use crate::coord::dataflows::{prep_scalar_expr, ExprPrepStyle};
 use crate::coord::id_bundle::CollectionIdBundle;
@@ -93,6 +94,20 @@ impl<S: Append + 'static> Coordinator<S> {
         let use_timestamp_oracle = isolation_level == &vars::IsolationLevel::StrictSerializable
             && timeline.is_some()
             && when.advance_to_global_ts();
+        // Under `read_your_writes`, reads that would otherwise not consult the timestamp oracle
+        // must still observe the session's prior writes, which all happen in the real-time
+        // timeline. The read may have to wait for its inputs to catch up to the write.
+        let last_write_ts = if !use_timestamp_oracle
+            && session.vars().read_your_writes()
+            && timeline == Some(Timeline::EpochMilliseconds)
+            && when.advance_to_global_ts()
+        {
+            self.active_conns
+                .get(&session.conn_id())
+                .and_then(|conn_meta| conn_meta.last_write_ts)
+        } else {
+            None
+        };
 
         if use_timestamp_oracle {
             let timeline = timeline.expect("checked that timeline exists above");
@@ -106,6 +121,9 @@ impl<S: Append + 'static> Coordinator<S> {
                 let upper = self.largest_not_in_advance_of_upper(&id_bundle);
                 candidate.join_assign(&upper);
             }
+            if let Some(last_write_ts) = last_write_ts {
+                candidate.join_assign(&last_write_ts);
+            }
         }
 
         if use_timestamp_oracle && when == &QueryWhen::Immediately {
@@ -120,6 +138,7 @@ impl<S: Append + 'static> Coordinator<S> {
         // If the timestamp is greater or equal to some element in `since` we are
         // assured that the answer will be correct.
         if since.less_equal(&candidate) {
+            self.advance_tables_for_read(id_bundle, candidate);
             Ok(candidate)
         } else {
             let invalid_indexes =
@@ -164,11 +183,11 @@ impl<S: Append + 'static> Coordinator<S> {
                 .into_iter()
                 .chain(invalid_sources)
                 .collect::<Vec<_>>();
-            coord_bail!(
-                "Timestamp ({}) is not valid for all inputs: {:?}",
-                candidate,
-                invalid
-            );
+            Err(AdapterError::TimestampNotValid {
+                timestamp: candidate,
+                invalid,
+                least_valid: since,
+            })
         }
     }
 
@@ -12,6 +12,7 @@ use std::fmt;
 use std::num::TryFromIntError;
 
 use dec::TryFromDecimalError;
+use timely::progress::Antichain;
 use tokio::sync::oneshot;
 
 use mz_compute_client::controller::ComputeError;
@@ -19,7 +20,7 @@ use mz_expr::{EvalError, UnmaterializableFunc};
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::StrExt;
 use mz_repr::explain_new::ExplainError;
-use mz_repr::NotNullViolation;
+use mz_repr::{NotNullViolation, Timestamp};
 use mz_sql::plan::PlanError;
 use mz_sql::query_model::QGMError;
 use mz_storage::controller::StorageError;
@@ -90,6 +91,8 @@ pub enum AdapterError {
     InvalidTableMutationSelection,
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// Waiting for the write lock timed out.
+    LockTimeout,
     /// Target cluster has no replicas to service query.
     NoClusterReplicasAvailable(String),
     /// The named operation cannot be run in a transaction.
@@ -132,6 +135,23 @@ pub enum AdapterError {
     SqlCatalog(mz_sql::catalog::CatalogError),
     /// The transaction is in single-tail mode.
     TailOnlyTransaction,
+    /// The maximum number of connections has been reached.
+    TooManyConnections {
+        /// Whether only the connections reserved for superusers remain.
+        reserved: bool,
+    },
+    /// Too many writes to tables are waiting to be committed.
+    TooManyPendingWrites {
+        /// The maximum number of pending rows.
+        limit: u32,
+    },
+    /// The requested timestamp is not valid for all inputs, because some of
+    /// them have already been compacted past it.
+    TimestampNotValid {
+        timestamp: Timestamp,
+        invalid: Vec<Antichain<Timestamp>>,
+        least_valid: Antichain<Timestamp>,
+    },
     /// An error occurred in the MIR stage of the optimizer.
     Transform(TransformError),
     /// A user tried to perform an action that they were unauthorized to do.
@@ -167,8 +187,6 @@ pub enum AdapterError {
     },
     /// The transaction is in write-only mode.
     WriteOnlyTransaction,
-    /// The transaction only supports single table writes
-    MultiTableWriteTransaction,
     /// An error occurred in the storage layer
     Storage(mz_storage::controller::StorageError),
     /// An error occurred in the compute layer
@@ -255,6 +273,9 @@ impl AdapterError {
             AdapterError::NoClusterReplicasAvailable(_) => {
                 Some("You can create cluster replicas using CREATE CLUSTER REPLICA".into())
             }
+            AdapterError::TooManyPendingWrites { .. } => {
+                Some("Retry the transaction once pending writes have been committed.".into())
+            }
             AdapterError::UntargetedLogRead { .. } => Some(
                 "Use `SET cluster_replica = <replica-name>` to target a specific replica in the \
                  active cluster. Note that subsequent `SELECT` queries will only be answered by \
@@ -263,6 +284,9 @@ impl AdapterError {
                     .into(),
             ),
             AdapterError::PlanError(e) => e.hint(),
+            AdapterError::TimestampNotValid { least_valid, .. } => least_valid
+                .as_option()
+                .map(|ts| format!("The earliest timestamp valid for all inputs is {ts}.")),
             _ => None,
         }
     }
@@ -333,6 +357,7 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::LockTimeout => f.write_str("canceling statement due to lock timeout"),
             AdapterError::NoClusterReplicasAvailable(cluster) => {
                 write!(
                     f,
@@ -385,6 +410,22 @@ impl fmt::Display for AdapterError {
             AdapterError::TailOnlyTransaction => {
                 f.write_str("TAIL in transactions must be the only read statement")
             }
+            AdapterError::TooManyConnections { reserved: false } => {
+                f.write_str("too many connections")
+            }
+            AdapterError::TooManyConnections { reserved: true } => {
+                f.write_str("remaining connection slots are reserved for superusers")
+            }
+            AdapterError::TooManyPendingWrites { limit } => write!(
+                f,
+                "too many pending writes: more than {limit} rows are waiting to be committed"
+            ),
+            AdapterError::TimestampNotValid {
+                timestamp, invalid, ..
+            } => write!(
+                f,
+                "Timestamp ({timestamp}) is not valid for all inputs: {invalid:?}"
+            ),
             AdapterError::Transform(e) => e.fmt(f),
             AdapterError::UncallableFunction { func, context } => {
                 write!(f, "cannot call {} in {}", func, context)
@@ -420,9 +461,6 @@ impl fmt::Display for AdapterError {
             AdapterError::UntargetedLogRead { .. } => {
                 f.write_str("log source reads must target a replica")
             }
-            AdapterError::MultiTableWriteTransaction => {
-                f.write_str("write transactions only support writes to a single table")
-            }
             AdapterError::Storage(e) => e.fmt(f),
             AdapterError::Compute(e) => e.fmt(f),
         }
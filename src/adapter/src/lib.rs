@@ -36,6 +36,7 @@ mod command;
 mod coord;
 mod error;
 mod explain_new;
+mod metrics;
 mod sink_connection;
 mod tail;
 mod util;
@@ -0,0 +1,83 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics that the coordinator reports.
+
+use mz_ore::metric;
+use mz_ore::metrics::{Counter, IntCounter, MetricsRegistry};
+
+/// Metrics reported by the coordinator.
+#[derive(Clone)]
+pub(crate) struct Metrics {
+    pub(crate) group_commits: IntCounter,
+    pub(crate) group_commit_transactions: IntCounter,
+    pub(crate) group_commit_updates: IntCounter,
+    pub(crate) group_commit_now_wait_seconds: Counter,
+    pub(crate) group_commit_append_seconds: Counter,
+}
+
+impl Metrics {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            group_commits: registry.register(metric!(
+                name: "mz_group_commits_total",
+                help: "The number of group commits the coordinator has executed",
+            )),
+            group_commit_transactions: registry.register(metric!(
+                name: "mz_group_commit_transactions_total",
+                help: "The number of user transactions committed by group commits",
+            )),
+            group_commit_updates: registry.register(metric!(
+                name: "mz_group_commit_updates_total",
+                help: "The number of updates to user tables committed by group commits",
+            )),
+            group_commit_now_wait_seconds: registry.register(metric!(
+                name: "mz_group_commit_now_wait_seconds_total",
+                help: "The time group commits have waited for the system clock to catch up with the timestamp of their writes",
+            )),
+            group_commit_append_seconds: registry.register(metric!(
+                name: "mz_group_commit_append_seconds_total",
+                help: "The time group commits have spent waiting for storage to append their writes",
+            )),
+        }
+    }
+
+    /// Returns the current values of the group commit metrics.
+    pub(crate) fn group_commit_statistics(&self) -> GroupCommitStatistics {
+        GroupCommitStatistics {
+            group_commits: self.group_commits.get(),
+            transactions: self.group_commit_transactions.get(),
+            updates: self.group_commit_updates.get(),
+            now_wait_seconds: self.group_commit_now_wait_seconds.get(),
+            append_seconds: self.group_commit_append_seconds.get(),
+        }
+    }
+}
+
+/// A snapshot of the group commit metrics, as reported in
+/// `mz_group_commit_statistics`.
+#[derive(Clone, Debug)]
+pub(crate) struct GroupCommitStatistics {
+    pub(crate) group_commits: u64,
+    pub(crate) transactions: u64,
+    pub(crate) updates: u64,
+    pub(crate) now_wait_seconds: f64,
+    pub(crate) append_seconds: f64,
+}
+
+impl GroupCommitStatistics {
+    /// Reports whether `self` and `other` differ in anything other than the
+    /// number of group commits, which group commits on idle ticks advance.
+    pub(crate) fn differs_in_activity(&self, other: &GroupCommitStatistics) -> bool {
+        self.transactions != other.transactions
+            || self.updates != other.updates
+            || self.now_wait_seconds != other.now_wait_seconds
+            || self.append_seconds != other.append_seconds
+    }
+}
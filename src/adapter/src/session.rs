@@ -12,8 +12,9 @@
 #![warn(missing_docs)]
 
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::mem;
+use std::net::IpAddr;
 
 use chrono::{DateTime, Utc};
 use derivative::Derivative;
@@ -21,10 +22,11 @@ use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::OwnedMutexGuard;
 
 use mz_compute_client::controller::ComputeSinkId;
+use mz_ore::cast::CastFrom;
 use mz_pgrepr::Format;
 use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement, TransactionAccessMode};
-use mz_sql::plan::{Params, PlanContext, StatementDesc};
+use mz_sql::plan::{Params, PlanContext, PlanNotice, StatementDesc};
 use mz_sql_parser::ast::TransactionIsolationLevel;
 
 use crate::catalog::SYSTEM_USER;
@@ -52,8 +54,10 @@ pub struct Session<T = mz_repr::Timestamp> {
     transaction: TransactionStatus<T>,
     pcx: Option<PlanContext>,
     user: String,
+    client_addr: Option<IpAddr>,
     vars: SessionVars,
     drop_sinks: Vec<ComputeSinkId>,
+    notices: Vec<PlanNotice>,
 }
 
 impl<T: CoordTimestamp> Session<T> {
@@ -79,8 +83,10 @@ impl<T: CoordTimestamp> Session<T> {
             prepared_statements: HashMap::new(),
             portals: HashMap::new(),
             user,
+            client_addr: None,
             vars: SessionVars::default(),
             drop_sinks: vec![],
+            notices: vec![],
         }
     }
 
@@ -106,7 +112,12 @@ impl<T: CoordTimestamp> Session<T> {
         match self.transaction {
             TransactionStatus::Default | TransactionStatus::Started(_) => {
                 self.transaction = TransactionStatus::InTransaction(Transaction {
-                    pcx: PlanContext::new(wall_time, self.vars.qgm_optimizations()),
+                    pcx: PlanContext::new(
+                        wall_time,
+                        self.vars.qgm_optimizations(),
+                        usize::cast_from(self.vars.max_in_list_size()),
+                        usize::cast_from(self.vars.max_query_size()),
+                    ),
                     ops: TransactionOps::None,
                     write_lock_guard: None,
                     access,
@@ -131,7 +142,12 @@ impl<T: CoordTimestamp> Session<T> {
     pub fn start_transaction_implicit(mut self, wall_time: DateTime<Utc>, stmts: usize) -> Self {
         if let TransactionStatus::Default = self.transaction {
             let txn = Transaction {
-                pcx: PlanContext::new(wall_time, self.vars.qgm_optimizations()),
+                pcx: PlanContext::new(
+                    wall_time,
+                    self.vars.qgm_optimizations(),
+                    usize::cast_from(self.vars.max_in_list_size()),
+                    usize::cast_from(self.vars.max_query_size()),
+                ),
                 ops: TransactionOps::None,
                 write_lock_guard: None,
                 access: None,
@@ -210,18 +226,16 @@ impl<T: CoordTimestamp> Session<T> {
                             // We should have already checked the access above, but make sure we don't miss
                             // it anyway.
                             assert!(!matches!(access, Some(TransactionAccessMode::ReadOnly)));
+                            // The writes may target different tables. Group commit appends all
+                            // of them at the same timestamp, but storage makes the appends to
+                            // each table durable separately, so a failure can leave the
+                            // transaction partially applied.
                             txn_writes.append(&mut add_writes);
-
-                            if txn_writes
-                                .iter()
-                                .map(|op| op.id)
-                                .collect::<HashSet<_>>()
-                                .len()
-                                > 1
-                            {
-                                return Err(AdapterError::MultiTableWriteTransaction);
-                            }
                         }
+                        // Reads in a write transaction observe the transaction's own writes,
+                        // which `sequence_peek` overlays onto their inputs. They do not change
+                        // the transaction into a read transaction.
+                        TransactionOps::Peeks(_) => {}
                         _ => {
                             return Err(AdapterError::WriteOnlyTransaction);
                         }
@@ -241,6 +255,17 @@ impl<T: CoordTimestamp> Session<T> {
         self.drop_sinks.push(id)
     }
 
+    /// Adds notices that will be reported to the client.
+    pub fn add_notices(&mut self, notices: impl IntoIterator<Item = PlanNotice>) {
+        self.notices.extend(notices)
+    }
+
+    /// Removes and returns the notices that have not yet been reported to the
+    /// client.
+    pub fn drain_notices(&mut self) -> Vec<PlanNotice> {
+        mem::take(&mut self.notices)
+    }
+
     /// Sets the transaction ops to `TransactionOps::None`. Must only be used after
     /// verifying that no transaction anomalies will occur if cleared.
     pub fn clear_transaction_ops(&mut self) {
@@ -269,6 +294,18 @@ impl<T: CoordTimestamp> Session<T> {
         }
     }
 
+    /// Returns the transaction's uncommitted writes, if the active transaction is
+    /// a write transaction.
+    pub fn transaction_writes(&self) -> Option<&[WriteOp]> {
+        match self.transaction.inner() {
+            Some(Transaction {
+                ops: TransactionOps::Writes(writes),
+                ..
+            }) => Some(writes),
+            _ => None,
+        }
+    }
+
     /// Registers the prepared statement under `name`.
     pub fn set_prepared_statement(&mut self, name: String, statement: PreparedStatement) {
         self.prepared_statements.insert(name, statement);
@@ -416,6 +453,16 @@ impl<T: CoordTimestamp> Session<T> {
         &self.user
     }
 
+    /// Returns the address of the client that owns this session, if known.
+    pub fn client_addr(&self) -> Option<IpAddr> {
+        self.client_addr
+    }
+
+    /// Sets the address of the client that owns this session.
+    pub fn set_client_addr(&mut self, client_addr: IpAddr) {
+        self.client_addr = Some(client_addr);
+    }
+
     /// Returns a reference to the variables in this session.
     pub fn vars(&self) -> &SessionVars {
         &self.vars
@@ -533,6 +580,98 @@ impl InProgressRows {
 /// A channel of batched rows.
 pub type RowBatchStream = UnboundedReceiver<PeekResponseUnary>;
 
+/// Verifies that the statements of a multi-statement query can run in the same
+/// implicit transaction.
+///
+/// DDL is not transactional: it takes effect as soon as it runs and is not
+/// undone if a later statement in the query fails. A query that contains DDL
+/// must therefore be made up only of DDL, so that reads and writes never run
+/// alongside DDL that they cannot roll back. Queries that mix DDL with other
+/// statements are rejected before any of their statements run.
+pub fn verify_implicit_transaction(stmts: &[Statement<Raw>]) -> Result<(), AdapterError> {
+    if stmts.len() <= 1 || stmts.iter().all(is_ddl) {
+        return Ok(());
+    }
+    match stmts.iter().find(|stmt| is_ddl(stmt)) {
+        Some(stmt) => Err(AdapterError::OperationProhibitsTransaction(
+            stmt.to_string(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Reports whether `stmt` is a DDL statement.
+fn is_ddl(stmt: &Statement<Raw>) -> bool {
+    match stmt {
+        Statement::AlterConnection(_)
+        | Statement::AlterIndex(_)
+        | Statement::AlterSecret(_)
+        | Statement::AlterSource(_)
+        | Statement::AlterObjectRename(_)
+        | Statement::AlterSystemSet(_)
+        | Statement::AlterSystemReset(_)
+        | Statement::AlterSystemResetAll(_)
+        | Statement::Comment(_)
+        | Statement::CreateConnection(_)
+        | Statement::CreateDatabase(_)
+        | Statement::CreateIndex(_)
+        | Statement::CreateRole(_)
+        | Statement::CreateCluster(_)
+        | Statement::CreateClusterReplica(_)
+        | Statement::CreateSchema(_)
+        | Statement::CreateSecret(_)
+        | Statement::CreateSink(_)
+        | Statement::CreateSource(_)
+        | Statement::CreateTable(_)
+        | Statement::CreateType(_)
+        | Statement::CreateView(_)
+        | Statement::CreateViews(_)
+        | Statement::CreateMaterializedView(_)
+        | Statement::DropDatabase(_)
+        | Statement::DropSchema(_)
+        | Statement::DropObjects(_)
+        | Statement::DropRoles(_)
+        | Statement::DropClusters(_)
+        | Statement::DropClusterReplicas(_) => true,
+
+        Statement::Close(_)
+        | Statement::Commit(_)
+        | Statement::Copy(_)
+        | Statement::Deallocate(_)
+        | Statement::Declare(_)
+        | Statement::Delete(_)
+        | Statement::Discard(_)
+        | Statement::Execute(_)
+        | Statement::Explain(_)
+        | Statement::Fetch(_)
+        | Statement::Insert(_)
+        | Statement::Prepare(_)
+        | Statement::Raise(_)
+        | Statement::ResetVariable(_)
+        | Statement::Rollback(_)
+        | Statement::Select(_)
+        | Statement::SetTransaction(_)
+        | Statement::SetVariable(_)
+        | Statement::ShowColumns(_)
+        | Statement::ShowCreateConnection(_)
+        | Statement::ShowCreateIndex(_)
+        | Statement::ShowCreateMaterializedView(_)
+        | Statement::ShowCreateSink(_)
+        | Statement::ShowCreateSource(_)
+        | Statement::ShowCreateTable(_)
+        | Statement::ShowCreateView(_)
+        | Statement::ShowDatabases(_)
+        | Statement::ShowIndexes(_)
+        | Statement::ShowObjects(_)
+        | Statement::ShowSchemas(_)
+        | Statement::ShowStorageUsage(_)
+        | Statement::ShowVariable(_)
+        | Statement::StartTransaction(_)
+        | Statement::Tail(_)
+        | Statement::Update(_) => false,
+    }
+}
+
 /// The transaction status of a session.
 ///
 /// PostgreSQL's transaction states are in backend/access/transam/xact.c.
@@ -650,9 +789,9 @@ impl<T> Transaction<T> {
 
 /// The type of operation being performed by the transaction.
 ///
-/// This is needed because we currently do not allow mixing reads and writes in
-/// a transaction. Use this to record what we have done, and what may need to
-/// happen at commit.
+/// This is needed because we currently do not allow reads to be followed by
+/// writes in a transaction. Use this to record what we have done, and what may
+/// need to happen at commit.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TransactionOps<T> {
     /// The transaction has been initiated, but no statement has yet been executed
@@ -663,7 +802,7 @@ pub enum TransactionOps<T> {
     /// This transaction has done a TAIL and must do nothing else.
     Tail,
     /// This transaction has had a write (`INSERT`, `UPDATE`, `DELETE`) and must only do
-    /// other writes.
+    /// other writes, or reads that observe its writes.
     Writes(Vec<WriteOp>),
 }
 
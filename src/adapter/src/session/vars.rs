@@ -108,12 +108,38 @@ const INTERVAL_STYLE: ServerVar<str> = ServerVar {
     description: "Sets the display format for interval values (PostgreSQL).",
 };
 
+const LOCK_TIMEOUT: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("lock_timeout"),
+    // Zero, which disables the timeout, is represented as the maximum duration.
+    value: &Duration::from_secs(u64::MAX),
+    description: "Sets the maximum allowed duration of any wait for a lock (PostgreSQL).",
+};
+
+const MAX_IN_LIST_SIZE: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_in_list_size"),
+    value: &10_000,
+    description: "The maximum number of elements in a single IN list (Materialize).",
+};
+
+const MAX_QUERY_SIZE: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_query_size"),
+    value: &100_000,
+    description: "The maximum number of expressions in a single query (Materialize).",
+};
+
 const QGM_OPTIMIZATIONS: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("qgm_optimizations_experimental"),
     value: &false,
     description: "Enables optimizations based on a Query Graph Model (QGM) query representation.",
 };
 
+const READ_YOUR_WRITES: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("read_your_writes"),
+    value: &false,
+    description:
+        "Guarantees that serializable reads observe the session's prior writes (Materialize).",
+};
+
 static DEFAULT_SEARCH_PATH: Lazy<[String; 1]> = Lazy::new(|| [DEFAULT_SCHEMA.to_owned()]);
 static SEARCH_PATH: Lazy<ServerVar<[String]>> = Lazy::new(|| ServerVar {
     name: UncasedStr::new("search_path"),
@@ -122,11 +148,19 @@ static SEARCH_PATH: Lazy<ServerVar<[String]>> = Lazy::new(|| ServerVar {
         "Sets the schema search order for names that are not schema-qualified (PostgreSQL).",
 });
 
+const IDLE_IN_TRANSACTION_SESSION_TIMEOUT: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("idle_in_transaction_session_timeout"),
+    // Zero, which disables the timeout, is represented as the maximum duration.
+    value: &Duration::from_secs(u64::MAX),
+    description:
+        "Sets the maximum allowed idle time between queries when in a transaction (PostgreSQL).",
+};
+
 const STATEMENT_TIMEOUT: ServerVar<Duration> = ServerVar {
     name: UncasedStr::new("statement_timeout"),
-    value: &Duration::from_secs(10),
-    description:
-        "Sets the maximum allowed duration of INSERT...SELECT, UPDATE, and DELETE operations.",
+    // Zero, which disables the timeout, is represented as the maximum duration.
+    value: &Duration::from_secs(u64::MAX),
+    description: "Sets the maximum allowed duration of any statement (PostgreSQL).",
 };
 
 const SERVER_VERSION: ServerVar<str> = ServerVar {
@@ -241,6 +275,18 @@ const MAX_ROLES: ServerVar<u32> = ServerVar {
     description: "The maximum number of roles in the region (Materialize).",
 };
 
+const MAX_CONNECTIONS: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_connections"),
+    value: &1000,
+    description: "The maximum number of concurrent connections (PostgreSQL).",
+};
+
+const SUPERUSER_RESERVED_CONNECTIONS: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("superuser_reserved_connections"),
+    value: &3,
+    description: "The number of connections that are reserved for superusers (PostgreSQL).",
+};
+
 // Cloud environmentd is configured with 4 GiB of RAM, so 1 GiB is a good heuristic for a single
 // query.
 // TODO(jkosh44) Eventually we want to be able to return arbitrary sized results.
@@ -251,6 +297,41 @@ const MAX_RESULT_SIZE: ServerVar<u32> = ServerVar {
     description: "The maximum size in bytes for a single query's result (Materialize).",
 };
 
+const GROUP_COMMIT_INTERVAL: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("group_commit_interval"),
+    // Zero, which commits writes as soon as possible, is represented as the maximum duration.
+    value: &Duration::from_secs(u64::MAX),
+    description: "How long to accumulate writes to tables before committing them together; 0 commits writes as soon as possible (Materialize).",
+};
+
+const MAX_GROUP_COMMIT_ROWS: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_group_commit_rows"),
+    value: &1_000_000,
+    description:
+        "The maximum number of rows written to tables in a single group commit (Materialize).",
+};
+
+const MAX_PENDING_WRITE_ROWS: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("max_pending_write_rows"),
+    value: &10_000_000,
+    description: "The maximum number of rows written to tables that may wait for a group commit before new writes are rejected (Materialize).",
+};
+
+// The default matches the default timestamp interval, at which tables used to be advanced.
+const TABLE_ADVANCEMENT_INTERVAL: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("table_advancement_interval"),
+    value: &Duration::from_secs(1),
+    description:
+        "How often all tables are advanced, including tables that are not written to (Materialize).",
+};
+
+// The default is set to a second to track the default timestamp frequency for sources.
+const DEFAULT_LOGICAL_COMPACTION_WINDOW: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("default_logical_compaction_window"),
+    value: &Duration::from_secs(1),
+    description: "The default logical compaction window for new collections; 0 disables logical compaction (Materialize).",
+};
+
 /// Session variables.
 ///
 /// Materialize roughly follows the PostgreSQL configuration model, which works
@@ -290,9 +371,14 @@ pub struct SessionVars {
     date_style: ServerVar<str>,
     extra_float_digits: SessionVar<i32>,
     failpoints: ServerVar<str>,
+    idle_in_transaction_session_timeout: SessionVar<Duration>,
     integer_datetimes: ServerVar<bool>,
     interval_style: ServerVar<str>,
+    lock_timeout: SessionVar<Duration>,
+    max_in_list_size: SessionVar<u32>,
+    max_query_size: SessionVar<u32>,
     qgm_optimizations: SessionVar<bool>,
+    read_your_writes: SessionVar<bool>,
     search_path: SessionVar<[String]>,
     server_version: ServerVar<str>,
     server_version_num: ServerVar<i32>,
@@ -315,9 +401,16 @@ impl Default for SessionVars {
             date_style: DATE_STYLE,
             extra_float_digits: SessionVar::new(&EXTRA_FLOAT_DIGITS),
             failpoints: FAILPOINTS,
+            idle_in_transaction_session_timeout: SessionVar::new(
+                &IDLE_IN_TRANSACTION_SESSION_TIMEOUT,
+            ),
             integer_datetimes: INTEGER_DATETIMES,
             interval_style: INTERVAL_STYLE,
+            lock_timeout: SessionVar::new(&LOCK_TIMEOUT),
+            max_in_list_size: SessionVar::new(&MAX_IN_LIST_SIZE),
+            max_query_size: SessionVar::new(&MAX_QUERY_SIZE),
             qgm_optimizations: SessionVar::new(&QGM_OPTIMIZATIONS),
+            read_your_writes: SessionVar::new(&READ_YOUR_WRITES),
             search_path: SessionVar::new(&SEARCH_PATH),
             server_version: SERVER_VERSION,
             server_version_num: SERVER_VERSION_NUM,
@@ -344,9 +437,14 @@ impl SessionVars {
             &self.date_style,
             &self.extra_float_digits,
             &self.failpoints,
+            &self.idle_in_transaction_session_timeout,
             &self.integer_datetimes,
             &self.interval_style,
+            &self.lock_timeout,
+            &self.max_in_list_size,
+            &self.max_query_size,
             &self.qgm_optimizations,
+            &self.read_your_writes,
             &self.search_path,
             &self.server_version,
             &self.server_version_num,
@@ -403,12 +501,22 @@ impl SessionVars {
             Ok(&self.extra_float_digits)
         } else if name == FAILPOINTS.name {
             Ok(&self.failpoints)
+        } else if name == IDLE_IN_TRANSACTION_SESSION_TIMEOUT.name {
+            Ok(&self.idle_in_transaction_session_timeout)
         } else if name == INTEGER_DATETIMES.name {
             Ok(&self.integer_datetimes)
         } else if name == INTERVAL_STYLE.name {
             Ok(&self.interval_style)
+        } else if name == LOCK_TIMEOUT.name {
+            Ok(&self.lock_timeout)
+        } else if name == MAX_IN_LIST_SIZE.name {
+            Ok(&self.max_in_list_size)
+        } else if name == MAX_QUERY_SIZE.name {
+            Ok(&self.max_query_size)
         } else if name == QGM_OPTIMIZATIONS.name {
             Ok(&self.qgm_optimizations)
+        } else if name == READ_YOUR_WRITES.name {
+            Ok(&self.read_your_writes)
         } else if name == SEARCH_PATH.name {
             Ok(&self.search_path)
         } else if name == SERVER_VERSION.name {
@@ -508,6 +616,8 @@ impl SessionVars {
                 })?;
             }
             Ok(())
+        } else if name == IDLE_IN_TRANSACTION_SESSION_TIMEOUT.name {
+            self.idle_in_transaction_session_timeout.set(value, local)
         } else if name == INTEGER_DATETIMES.name {
             Err(AdapterError::ReadOnlyParameter(&INTEGER_DATETIMES))
         } else if name == INTERVAL_STYLE.name {
@@ -517,8 +627,16 @@ impl SessionVars {
             } else {
                 Ok(())
             }
+        } else if name == LOCK_TIMEOUT.name {
+            self.lock_timeout.set(value, local)
+        } else if name == MAX_IN_LIST_SIZE.name {
+            self.max_in_list_size.set(value, local)
+        } else if name == MAX_QUERY_SIZE.name {
+            self.max_query_size.set(value, local)
         } else if name == QGM_OPTIMIZATIONS.name {
             self.qgm_optimizations.set(value, local)
+        } else if name == READ_YOUR_WRITES.name {
+            self.read_your_writes.set(value, local)
         } else if name == SEARCH_PATH.name {
             self.search_path.set(value, local)
         } else if name == SERVER_VERSION.name {
@@ -587,12 +705,24 @@ impl SessionVars {
             self.database.reset(local);
         } else if name == EXTRA_FLOAT_DIGITS.name {
             self.extra_float_digits.reset(local);
+        } else if name == IDLE_IN_TRANSACTION_SESSION_TIMEOUT.name {
+            self.idle_in_transaction_session_timeout.reset(local);
+        } else if name == LOCK_TIMEOUT.name {
+            self.lock_timeout.reset(local);
+        } else if name == MAX_IN_LIST_SIZE.name {
+            self.max_in_list_size.reset(local);
+        } else if name == MAX_QUERY_SIZE.name {
+            self.max_query_size.reset(local);
         } else if name == QGM_OPTIMIZATIONS.name {
             self.qgm_optimizations.reset(local);
+        } else if name == READ_YOUR_WRITES.name {
+            self.read_your_writes.reset(local);
         } else if name == SEARCH_PATH.name {
             self.search_path.reset(local);
         } else if name == SQL_SAFE_UPDATES.name {
             self.sql_safe_updates.reset(local);
+        } else if name == STATEMENT_TIMEOUT.name {
+            self.statement_timeout.reset(local);
         } else if name == TIMEZONE.name {
             self.timezone.reset(local);
         } else if name == CLIENT_ENCODING.name
@@ -627,17 +757,22 @@ impl SessionVars {
             date_style: _,
             extra_float_digits,
             failpoints: _,
+            idle_in_transaction_session_timeout,
             integer_datetimes: _,
             interval_style: _,
+            lock_timeout,
+            max_in_list_size,
+            max_query_size,
             qgm_optimizations,
+            read_your_writes,
             search_path,
             server_version: _,
             server_version_num: _,
             sql_safe_updates,
             standard_conforming_strings: _,
-            statement_timeout: _,
+            statement_timeout,
             timezone,
-            transaction_isolation: _,
+            transaction_isolation,
         } = self;
         application_name.end_transaction(action);
         client_min_messages.end_transaction(action);
@@ -645,10 +780,17 @@ impl SessionVars {
         cluster_replica.end_transaction(action);
         database.end_transaction(action);
         extra_float_digits.end_transaction(action);
+        idle_in_transaction_session_timeout.end_transaction(action);
+        lock_timeout.end_transaction(action);
+        max_in_list_size.end_transaction(action);
+        max_query_size.end_transaction(action);
         qgm_optimizations.end_transaction(action);
+        read_your_writes.end_transaction(action);
         search_path.end_transaction(action);
         sql_safe_updates.end_transaction(action);
+        statement_timeout.end_transaction(action);
         timezone.end_transaction(action);
+        transaction_isolation.end_transaction(action);
     }
 
     /// Returns the value of the `application_name` configuration parameter.
@@ -691,6 +833,12 @@ impl SessionVars {
         *self.extra_float_digits.value()
     }
 
+    /// Returns the value of the `idle_in_transaction_session_timeout`
+    /// configuration parameter.
+    pub fn idle_in_transaction_session_timeout(&self) -> &Duration {
+        self.idle_in_transaction_session_timeout.value()
+    }
+
     /// Returns the value of the `integer_datetimes` configuration parameter.
     pub fn integer_datetimes(&self) -> bool {
         *self.integer_datetimes.value
@@ -701,11 +849,31 @@ impl SessionVars {
         self.interval_style.value
     }
 
+    /// Returns the value of the `lock_timeout` configuration parameter.
+    pub fn lock_timeout(&self) -> &Duration {
+        self.lock_timeout.value()
+    }
+
+    /// Returns the value of the `max_in_list_size` configuration parameter.
+    pub fn max_in_list_size(&self) -> u32 {
+        *self.max_in_list_size.value()
+    }
+
+    /// Returns the value of the `max_query_size` configuration parameter.
+    pub fn max_query_size(&self) -> u32 {
+        *self.max_query_size.value()
+    }
+
     /// Returns the value of the `qgm_optimizations` configuration parameter.
     pub fn qgm_optimizations(&self) -> bool {
         *self.qgm_optimizations.value()
     }
 
+    /// Returns the value of the `read_your_writes` configuration parameter.
+    pub fn read_your_writes(&self) -> bool {
+        *self.read_your_writes.value()
+    }
+
     /// Returns the value of the `search_path` configuration parameter.
     pub fn search_path(&self) -> Vec<&str> {
         self.search_path
@@ -769,7 +937,14 @@ pub struct SystemVars {
     max_objects_per_schema: SystemVar<u32>,
     max_secrets: SystemVar<u32>,
     max_roles: SystemVar<u32>,
+    max_connections: SystemVar<u32>,
+    superuser_reserved_connections: SystemVar<u32>,
     max_result_size: SystemVar<u32>,
+    default_logical_compaction_window: SystemVar<Duration>,
+    group_commit_interval: SystemVar<Duration>,
+    max_group_commit_rows: SystemVar<u32>,
+    max_pending_write_rows: SystemVar<u32>,
+    table_advancement_interval: SystemVar<Duration>,
 }
 
 impl Default for SystemVars {
@@ -786,7 +961,14 @@ impl Default for SystemVars {
             max_objects_per_schema: SystemVar::new(&MAX_OBJECTS_PER_SCHEMA),
             max_secrets: SystemVar::new(&MAX_SECRETS),
             max_roles: SystemVar::new(&MAX_ROLES),
+            max_connections: SystemVar::new(&MAX_CONNECTIONS),
+            superuser_reserved_connections: SystemVar::new(&SUPERUSER_RESERVED_CONNECTIONS),
             max_result_size: SystemVar::new(&MAX_RESULT_SIZE),
+            default_logical_compaction_window: SystemVar::new(&DEFAULT_LOGICAL_COMPACTION_WINDOW),
+            group_commit_interval: SystemVar::new(&GROUP_COMMIT_INTERVAL),
+            max_group_commit_rows: SystemVar::new(&MAX_GROUP_COMMIT_ROWS),
+            max_pending_write_rows: SystemVar::new(&MAX_PENDING_WRITE_ROWS),
+            table_advancement_interval: SystemVar::new(&TABLE_ADVANCEMENT_INTERVAL),
         }
     }
 }
@@ -807,7 +989,14 @@ impl SystemVars {
             &self.max_objects_per_schema,
             &self.max_secrets,
             &self.max_roles,
+            &self.max_connections,
+            &self.superuser_reserved_connections,
             &self.max_result_size,
+            &self.default_logical_compaction_window,
+            &self.group_commit_interval,
+            &self.max_group_commit_rows,
+            &self.max_pending_write_rows,
+            &self.table_advancement_interval,
         ]
         .into_iter()
     }
@@ -845,8 +1034,22 @@ impl SystemVars {
             Ok(&self.max_secrets)
         } else if name == MAX_ROLES.name {
             Ok(&self.max_roles)
+        } else if name == MAX_CONNECTIONS.name {
+            Ok(&self.max_connections)
+        } else if name == SUPERUSER_RESERVED_CONNECTIONS.name {
+            Ok(&self.superuser_reserved_connections)
         } else if name == MAX_RESULT_SIZE.name {
             Ok(&self.max_result_size)
+        } else if name == DEFAULT_LOGICAL_COMPACTION_WINDOW.name {
+            Ok(&self.default_logical_compaction_window)
+        } else if name == GROUP_COMMIT_INTERVAL.name {
+            Ok(&self.group_commit_interval)
+        } else if name == MAX_GROUP_COMMIT_ROWS.name {
+            Ok(&self.max_group_commit_rows)
+        } else if name == MAX_PENDING_WRITE_ROWS.name {
+            Ok(&self.max_pending_write_rows)
+        } else if name == TABLE_ADVANCEMENT_INTERVAL.name {
+            Ok(&self.table_advancement_interval)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -882,8 +1085,22 @@ impl SystemVars {
             self.max_secrets.set(value)
         } else if name == MAX_ROLES.name {
             self.max_roles.set(value)
+        } else if name == MAX_CONNECTIONS.name {
+            self.max_connections.set(value)
+        } else if name == SUPERUSER_RESERVED_CONNECTIONS.name {
+            self.superuser_reserved_connections.set(value)
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.set(value)
+        } else if name == DEFAULT_LOGICAL_COMPACTION_WINDOW.name {
+            self.default_logical_compaction_window.set(value)
+        } else if name == GROUP_COMMIT_INTERVAL.name {
+            self.group_commit_interval.set(value)
+        } else if name == MAX_GROUP_COMMIT_ROWS.name {
+            self.max_group_commit_rows.set(value)
+        } else if name == MAX_PENDING_WRITE_ROWS.name {
+            self.max_pending_write_rows.set(value)
+        } else if name == TABLE_ADVANCEMENT_INTERVAL.name {
+            self.table_advancement_interval.set(value)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -917,8 +1134,22 @@ impl SystemVars {
             self.max_secrets.reset()
         } else if name == MAX_ROLES.name {
             self.max_roles.reset()
+        } else if name == MAX_CONNECTIONS.name {
+            self.max_connections.reset()
+        } else if name == SUPERUSER_RESERVED_CONNECTIONS.name {
+            self.superuser_reserved_connections.reset()
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.reset()
+        } else if name == DEFAULT_LOGICAL_COMPACTION_WINDOW.name {
+            self.default_logical_compaction_window.reset()
+        } else if name == GROUP_COMMIT_INTERVAL.name {
+            self.group_commit_interval.reset()
+        } else if name == MAX_GROUP_COMMIT_ROWS.name {
+            self.max_group_commit_rows.reset()
+        } else if name == MAX_PENDING_WRITE_ROWS.name {
+            self.max_pending_write_rows.reset()
+        } else if name == TABLE_ADVANCEMENT_INTERVAL.name {
+            self.table_advancement_interval.reset()
         } else {
             return Err(AdapterError::UnknownParameter(name.into()));
         }
@@ -980,10 +1211,45 @@ impl SystemVars {
         *self.max_roles.value()
     }
 
+    /// Returns the value of the `max_connections` configuration parameter.
+    pub fn max_connections(&self) -> u32 {
+        *self.max_connections.value()
+    }
+
+    /// Returns the value of the `superuser_reserved_connections` configuration parameter.
+    pub fn superuser_reserved_connections(&self) -> u32 {
+        *self.superuser_reserved_connections.value()
+    }
+
     /// Returns the value of the `max_result_size` configuration parameter.
     pub fn max_result_size(&self) -> u32 {
         *self.max_result_size.value()
     }
+
+    /// Returns the value of the `default_logical_compaction_window` configuration parameter.
+    pub fn default_logical_compaction_window(&self) -> Duration {
+        *self.default_logical_compaction_window.value()
+    }
+
+    /// Returns the value of the `group_commit_interval` configuration parameter.
+    pub fn group_commit_interval(&self) -> Duration {
+        *self.group_commit_interval.value()
+    }
+
+    /// Returns the value of the `max_group_commit_rows` configuration parameter.
+    pub fn max_group_commit_rows(&self) -> u32 {
+        *self.max_group_commit_rows.value()
+    }
+
+    /// Returns the value of the `max_pending_write_rows` configuration parameter.
+    pub fn max_pending_write_rows(&self) -> u32 {
+        *self.max_pending_write_rows.value()
+    }
+
+    /// Returns the value of the `table_advancement_interval` configuration parameter.
+    pub fn table_advancement_interval(&self) -> Duration {
+        *self.table_advancement_interval.value()
+    }
 }
 
 /// A `Var` represents a configuration parameter of an arbitrary type.
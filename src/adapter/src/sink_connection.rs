@@ -14,6 +14,7 @@ use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, ResourceSpecifier, Top
 
 use mz_kafka_util::client::{create_new_client_config, MzClientContext};
 use mz_ore::collections::CollectionExt;
+use mz_ore::retry::Retry;
 use mz_storage::types::connections::{ConnectionContext, PopulateClientConfig};
 use mz_storage::types::sinks::{
     KafkaConsistencyConfig, KafkaSinkConnection, KafkaSinkConnectionBuilder,
@@ -32,12 +33,18 @@ pub async fn build(
     }
 }
 
+/// Creates the named topic, unless it already exists.
+///
+/// If `compact` is set, a newly created topic uses log compaction rather than
+/// time- or size-based deletion, and so retains the latest message for each
+/// key indefinitely.
 async fn ensure_kafka_topic(
     client: &AdminClient<MzClientContext>,
     topic: &str,
     mut partition_count: i32,
     mut replication_factor: i32,
     retention: KafkaSinkConnectionRetention,
+    compact: bool,
 ) -> Result<(), AdapterError> {
     // if either partition count or replication factor should be defaulted to the broker's config
     // (signaled by a value of -1), explicitly poll the broker to discover the defaults.
@@ -135,6 +142,9 @@ async fn ensure_kafka_topic(
     if let Some(ref retention_bytes) = retention_bytes_str {
         kafka_topic = kafka_topic.set("retention.bytes", retention_bytes);
     }
+    if compact {
+        kafka_topic = kafka_topic.set("cleanup.policy", "compact");
+    }
 
     mz_kafka_util::admin::ensure_topic(
         client,
@@ -159,22 +169,21 @@ async fn publish_kafka_schemas(
     value_schema: &str,
     value_schema_type: mz_ccsr::SchemaType,
 ) -> Result<(Option<i32>, i32), AdapterError> {
-    let value_schema_id = ccsr
-        .publish_schema(
-            &format!("{}-value", topic),
-            value_schema,
-            value_schema_type,
-            &[],
-        )
-        .await
-        .context("unable to publish value schema to registry in kafka sink")?;
+    let value_schema_id = publish_schema(
+        ccsr,
+        &format!("{}-value", topic),
+        value_schema,
+        value_schema_type,
+    )
+    .await
+    .context("unable to publish value schema to registry in kafka sink")?;
 
     let key_schema_id = if let Some(key_schema) = key_schema {
         let key_schema_type = key_schema_type.ok_or_else(|| {
             AdapterError::Unstructured(anyhow!("expected schema type for key schema"))
         })?;
         Some(
-            ccsr.publish_schema(&format!("{}-key", topic), key_schema, key_schema_type, &[])
+            publish_schema(ccsr, &format!("{}-key", topic), key_schema, key_schema_type)
                 .await
                 .context("unable to publish key schema to registry in kafka sink")?,
         )
@@ -185,6 +194,36 @@ async fn publish_kafka_schemas(
     Ok((key_schema_id, value_schema_id))
 }
 
+/// Publishes a schema under the given subject, retrying for a while if the
+/// schema registry is unreachable or reports an internal error.
+///
+/// Registering a schema is idempotent, so retrying a request that may have
+/// succeeded is safe. Errors that indicate a problem with the schema itself,
+/// like an incompatibility with the subject's earlier versions, are returned
+/// immediately.
+async fn publish_schema(
+    ccsr: &mz_ccsr::Client,
+    subject: &str,
+    schema: &str,
+    schema_type: mz_ccsr::SchemaType,
+) -> Result<i32, mz_ccsr::PublishError> {
+    Retry::default()
+        .max_duration(Duration::from_secs(30))
+        .retry_async(|_| async {
+            match ccsr.publish_schema(subject, schema, schema_type, &[]).await {
+                Ok(id) => Ok(Ok(id)),
+                Err(e @ mz_ccsr::PublishError::Transport(_)) => Err(e),
+                Err(e @ mz_ccsr::PublishError::Server { code, .. })
+                    if matches!(code, 500..=599 | 50000..=59999) =>
+                {
+                    Err(e)
+                }
+                Err(e) => Ok(Err(e)),
+            }
+        })
+        .await?
+}
+
 async fn build_kafka(
     builder: KafkaSinkConnectionBuilder,
     connection_context: ConnectionContext,
@@ -205,6 +244,7 @@ async fn build_kafka(
         builder.partition_count,
         builder.replication_factor,
         builder.retention,
+        false,
     )
     .await
     .context("error registering kafka topic for sink")?;
@@ -239,12 +279,18 @@ async fn build_kafka(
 
     let consistency = match builder.consistency_config {
         KafkaConsistencyConfig::Progress { topic } => {
+            // On restart, each sink scans the progress topic for its latest
+            // progress record to determine which updates it has already
+            // committed. Compaction keeps that record around for as long as
+            // the sink exists, while bounding the size of the topic and thus
+            // the time spent scanning it.
             ensure_kafka_topic(
                 &client,
                 &topic,
                 1,
                 builder.replication_factor,
                 KafkaSinkConnectionRetention::default(),
+                true,
             )
             .await
             .context("error registering kafka consistency topic for sink")?;
@@ -9,11 +9,13 @@
 
 //! Implementations around supporting the TAIL protocol with the dataflow layer
 
+use timely::progress::Antichain;
+use timely::PartialOrder;
 use tokio::sync::mpsc;
 
 use mz_compute_client::response::{TailBatch, TailResponse};
 use mz_repr::adt::numeric;
-use mz_repr::{Datum, Row};
+use mz_repr::{Datum, Row, Timestamp};
 
 use crate::coord::peek::PeekResponseUnary;
 
@@ -25,6 +27,8 @@ pub(crate) struct PendingTail {
     channel: mpsc::UnboundedSender<PeekResponseUnary>,
     /// Whether progress information should be emitted
     emit_progress: bool,
+    /// The frontier at which the tail begins
+    as_of: Antichain<Timestamp>,
     /// Number of columns in the output
     arity: usize,
 }
@@ -33,15 +37,18 @@ impl PendingTail {
     /// Create a new [PendingTail].
     /// * The `channel` receives batches of finalized PeekResponses.
     /// * If `emit_progress` is true, the finalized rows are either data or progress updates
+    /// * `as_of` is the frontier at which the tail begins.
     /// * `arity` is the arity of the sink relation.
     pub(crate) fn new(
         channel: mpsc::UnboundedSender<PeekResponseUnary>,
         emit_progress: bool,
+        as_of: Antichain<Timestamp>,
         arity: usize,
     ) -> Self {
         Self {
             channel,
             emit_progress,
+            as_of,
             arity,
         }
     }
@@ -91,7 +98,13 @@ impl PendingTail {
                     // receiver has gone away. E.g. form a DROP SINK command?
                 }
 
-                if self.emit_progress && !upper.is_empty() {
+                // Progress up to the initial frontier says nothing, as no
+                // updates are ever emitted before it. Suppressing it ensures
+                // the first progress message marks the end of the snapshot.
+                if self.emit_progress
+                    && !upper.is_empty()
+                    && !PartialOrder::less_equal(&upper, &self.as_of)
+                {
                     assert_eq!(
                         upper.len(),
                         1,
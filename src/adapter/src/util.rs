@@ -62,6 +62,12 @@ impl<T> ClientTransmitter<T> {
         }
     }
 
+    /// Reports whether the client has gone away, e.g. because it was dropped
+    /// while it waited for the response.
+    pub fn is_closed(&self) -> bool {
+        self.tx.as_ref().map_or(true, |tx| tx.is_closed())
+    }
+
     pub fn take(mut self) -> oneshot::Sender<Response<T>> {
         self.tx.take().unwrap()
     }
@@ -92,6 +98,11 @@ impl<T> CompletedClientTransmitter<T> {
         }
     }
 
+    /// Replaces the response that will be transmitted to the client.
+    pub fn set_response(&mut self, response: Result<T, AdapterError>) {
+        self.response = response;
+    }
+
     /// Transmits `result` to the client, returning ownership of the session
     /// `session` as well.
     pub fn send(mut self) {
@@ -26,7 +26,7 @@ use std::fmt::{self, Display};
 use std::fs::File;
 use std::io::{self, Cursor, Read, Seek, SeekFrom};
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use flate2::read::MultiGzDecoder;
 
 use crate::error::{DecodeError, Error as AvroError};
@@ -110,7 +110,7 @@ impl Display for TsUnit {
     }
 }
 
-fn build_ts_value(value: i64, unit: TsUnit) -> Result<Value, AvroError> {
+pub(crate) fn build_ts_value(value: i64, unit: TsUnit) -> Result<Value, AvroError> {
     let units_per_second = match unit {
         TsUnit::Millis => 1_000,
         TsUnit::Micros => 1_000_000,
@@ -129,6 +129,21 @@ fn build_ts_value(value: i64, unit: TsUnit) -> Result<Value, AvroError> {
     ))
 }
 
+pub(crate) fn build_time_value(value: i64, unit: TsUnit) -> Result<NaiveTime, AvroError> {
+    let units_per_second = match unit {
+        TsUnit::Millis => 1_000,
+        TsUnit::Micros => 1_000_000,
+    };
+    let nanos_per_unit = 1_000_000_000 / units_per_second;
+    u32::try_from(value / units_per_second)
+        .ok()
+        .and_then(|seconds| {
+            let nanos = u32::try_from((value % units_per_second) * nanos_per_unit).ok()?;
+            NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos)
+        })
+        .ok_or(AvroError::Decode(DecodeError::BadTime { unit, value }))
+}
+
 /// A convenience trait for types that are both readable and skippable.
 ///
 /// A blanket implementation is provided for all types that implement both
@@ -410,6 +425,12 @@ impl<'a, R: AvroRead> AvroRecordAccess<R> for ResolvedRecordAccess<'a, R> {
 
 pub trait AvroArrayAccess {
     fn decode_next<D: AvroDecode>(&mut self, d: D) -> Result<Option<D::Out>, AvroError>;
+
+    /// Skips over the remaining elements of the array.
+    fn skip_rest(&mut self) -> Result<(), AvroError> {
+        while self.decode_next(TrivialDecoder)?.is_some() {}
+        Ok(())
+    }
 }
 
 pub trait AvroMapAccess {
@@ -417,6 +438,28 @@ pub trait AvroMapAccess {
     fn next_entry<'b>(
         &'b mut self,
     ) -> Result<Option<(String, AvroFieldAccess<'b, Self::R>)>, AvroError>;
+
+    /// Skips over the remaining entries of the map.
+    fn skip_rest(&mut self) -> Result<(), AvroError> {
+        while let Some((_n, entry)) = self.next_entry()? {
+            entry.decode_field(TrivialDecoder)?
+        }
+        Ok(())
+    }
+}
+
+/// Reads the header of a block of array items or map entries, returning the
+/// number of items in the block and, if the writer recorded it, the size of
+/// the block in bytes.
+///
+/// From the spec: If a block's count is negative, its absolute value is used,
+/// and the count is followed immediately by a long block size indicating the
+/// number of bytes in the block.
+fn read_block_header<R: AvroRead>(r: &mut R) -> Result<(usize, Option<usize>), AvroError> {
+    match zag_i64(r)? {
+        len if len >= 0 => Ok((len as usize, None)),
+        neglen => Ok((neglen.unsigned_abs() as usize, Some(decode_len(r)?))),
+    }
 }
 
 pub struct SimpleMapAccess<'a, R: AvroRead> {
@@ -444,16 +487,11 @@ impl<'a, R: AvroRead> AvroMapAccess for SimpleMapAccess<'a, R> {
             return Ok(None);
         }
         if self.remaining == 0 {
-            // TODO -- we can use len_in_bytes to quickly skip non-demanded arrays
-            let (len, _len_in_bytes) = match zag_i64(self.r)? {
-                len if len > 0 => (len as usize, None),
-                neglen if neglen < 0 => (neglen.unsigned_abs() as usize, Some(decode_len(self.r)?)),
-                0 => {
-                    self.done = true;
-                    return Ok(None);
-                }
-                _ => unreachable!(),
-            };
+            let (len, _len_in_bytes) = read_block_header(self.r)?;
+            if len == 0 {
+                self.done = true;
+                return Ok(None);
+            }
             self.remaining = len;
         }
         assert!(self.remaining > 0);
@@ -473,6 +511,25 @@ impl<'a, R: AvroRead> AvroMapAccess for SimpleMapAccess<'a, R> {
         };
         Ok(Some((key, a)))
     }
+
+    fn skip_rest(&mut self) -> Result<(), AvroError> {
+        loop {
+            while self.remaining > 0 {
+                let (_key, entry) = self.next_entry()?.expect("block has remaining entries");
+                entry.decode_field(TrivialDecoder)?;
+            }
+            if self.done {
+                return Ok(());
+            }
+            // Skip whole blocks without decoding their entries if the writer
+            // told us how large they are.
+            match read_block_header(self.r)? {
+                (0, _) => self.done = true,
+                (_, Some(len_in_bytes)) => self.r.skip(len_in_bytes)?,
+                (len, None) => self.remaining = len,
+            }
+        }
+    }
 }
 
 struct SimpleArrayAccess<'a, R: AvroRead> {
@@ -523,16 +580,11 @@ impl<'a, R: AvroRead> AvroArrayAccess for SimpleArrayAccess<'a, R> {
             return Ok(None);
         }
         if self.remaining == 0 {
-            // TODO -- we can use len_in_bytes to quickly skip non-demanded arrays
-            let (len, _len_in_bytes) = match zag_i64(self.r)? {
-                len if len > 0 => (len as usize, None),
-                neglen if neglen < 0 => (neglen.unsigned_abs() as usize, Some(decode_len(self.r)?)),
-                0 => {
-                    self.done = true;
-                    return Ok(None);
-                }
-                _ => unreachable!(),
-            };
+            let (len, _len_in_bytes) = read_block_header(self.r)?;
+            if len == 0 {
+                self.done = true;
+                return Ok(None);
+            }
             self.remaining = len;
         }
         assert!(self.remaining > 0);
@@ -542,6 +594,24 @@ impl<'a, R: AvroRead> AvroArrayAccess for SimpleArrayAccess<'a, R> {
         };
         des.deserialize(self.r, d).map(Some)
     }
+
+    fn skip_rest(&mut self) -> Result<(), AvroError> {
+        loop {
+            while self.remaining > 0 {
+                self.decode_next(TrivialDecoder)?;
+            }
+            if self.done {
+                return Ok(());
+            }
+            // Skip whole blocks without decoding their items if the writer
+            // told us how large they are.
+            match read_block_header(self.r)? {
+                (0, _) => self.done = true,
+                (_, Some(len_in_bytes)) => self.r.skip(len_in_bytes)?,
+                (len, None) => self.remaining = len,
+            }
+        }
+    }
 }
 
 #[macro_export]
@@ -998,15 +1068,11 @@ pub mod public_decoders {
             self.maybe_skip(r)
         }
         fn array<A: AvroArrayAccess>(self, a: &mut A) -> Result<(), AvroError> {
-            while a.decode_next(TrivialDecoder)?.is_some() {}
-            Ok(())
+            a.skip_rest()
         }
 
         fn map<M: AvroMapAccess>(self, m: &mut M) -> Result<(), AvroError> {
-            while let Some((_n, entry)) = m.next_entry()? {
-                entry.decode_field(TrivialDecoder)?
-            }
-            Ok(())
+            m.skip_rest()
         }
     }
     pub struct ValueDecoder;
@@ -1202,6 +1268,7 @@ pub fn give_value<D: AvroDecode>(d: D, v: &Value) -> Result<D::Out, AvroError> {
         Value::Double(val) => d.scalar(Scalar::Double(*val)),
         Value::Date(val) => d.scalar(Scalar::Date(*val)),
         Value::Timestamp(val) => d.scalar(Scalar::Timestamp(*val)),
+        Value::Time(val) => d.scalar(Scalar::Time(*val)),
         // The &[u8] parameter here (and elsewhere in this function) is arbitrary, but we have to put in something in order for the function
         // to type-check
         Value::Decimal(val) => d.decimal::<&[u8]>(val.precision, val.scale, V(&val.unscaled)),
@@ -1305,6 +1372,16 @@ impl<'a> AvroDeserializer for GeneralDeserializer<'a> {
                 };
                 d.scalar(scalar)
             }
+            SchemaPiece::TimeMilli => {
+                let total_millis = zag_i32(r)?;
+                let time = build_time_value(total_millis.into(), TsUnit::Millis)?;
+                d.scalar(Scalar::Time(time))
+            }
+            SchemaPiece::TimeMicro => {
+                let total_micros = zag_i64(r)?;
+                let time = build_time_value(total_micros, TsUnit::Micros)?;
+                d.scalar(Scalar::Time(time))
+            }
             SchemaPiece::Decimal {
                 precision,
                 scale,
@@ -1496,6 +1573,16 @@ impl<'a> AvroDeserializer for GeneralDeserializer<'a> {
                     },
                 }
             }
+            SchemaPiece::ResolveEnumString { symbols } => {
+                let index = decode_int_nonneg(r)? as usize;
+                match symbols.get(index) {
+                    None => Err(AvroError::Decode(DecodeError::BadEnumIndex {
+                        index,
+                        len: symbols.len(),
+                    })),
+                    Some(symbol) => d.string::<&[u8]>(ValueOrReader::Value(symbol)),
+                }
+            }
             SchemaPiece::ResolveIntTsMilli => {
                 let total_millis = zag_i32(r)?;
                 let scalar = match build_ts_value(total_millis as i64, TsUnit::Millis)? {
@@ -21,6 +21,8 @@
 // The original source code is subject to the terms of the MIT license, a copy
 // of which can be found in the LICENSE file at the root of this repository.
 
+use chrono::Timelike;
+
 use crate::schema::{Schema, SchemaNode, SchemaPiece};
 use crate::types::AvroMap;
 use crate::types::{DecimalValue, Value};
@@ -100,6 +102,20 @@ pub fn encode_ref(value: &Value, schema: SchemaNode, buffer: &mut Vec<u8>) {
             };
             encode_long(ts, buffer)
         }
+        Value::Time(t) => {
+            let seconds = i64::from(t.num_seconds_from_midnight());
+            let nanos = i64::from(t.nanosecond());
+            match schema.inner {
+                SchemaPiece::TimeMilli => encode_int(
+                    (seconds * 1_000 + nanos / 1_000_000)
+                        .try_into()
+                        .expect("Milliseconds since midnight fit in i32"),
+                    buffer,
+                ),
+                SchemaPiece::TimeMicro => encode_long(seconds * 1_000_000 + nanos / 1_000, buffer),
+                other => panic!("Invalid schema for time: {:?}", other),
+            }
+        }
         Value::Double(x) => buffer.extend_from_slice(&x.to_le_bytes()),
         Value::Decimal(DecimalValue { unscaled, .. }) => match schema.name {
             None => encode_bytes(unscaled, buffer),
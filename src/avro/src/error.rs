@@ -44,6 +44,10 @@ pub enum DecodeError {
         seconds: i64,
         fraction: u32,
     },
+    BadTime {
+        unit: TsUnit,
+        value: i64,
+    },
     BadBoolean(u8),
     BadDate(i32),
     // The distinction between "bad" and "missing",
@@ -113,6 +117,7 @@ impl DecodeError {
                 seconds,
                 fraction,
             } => write!(f, "Invalid {} timestamp {}.{}", unit, seconds, fraction),
+            DecodeError::BadTime { unit, value } => write!(f, "Invalid {} time {}", unit, value),
             DecodeError::BadBoolean(byte) => write!(f, "Invalid boolean: {:x}", byte),
             DecodeError::BadDate(since_epoch) => {
                 write!(f, "Invalid num days since epoch: {}", since_epoch)
@@ -896,6 +896,21 @@ impl<'a> SchemaResolver<'a> {
                 };
                 SchemaPieceOrNamed::Named(idx)
             }
+            // Enums are not promotable to strings by the Avro specification,
+            // but every enum symbol is a valid string, so there is no harm
+            // in allowing it. This lets readers that don't care about the set
+            // of symbols, or that predate the writer's use of an enum,
+            // decode the symbol's name.
+            (
+                SchemaPieceRefOrNamed::Named(w_index),
+                SchemaPieceRefOrNamed::Piece(SchemaPiece::String),
+            ) if matches!(writer.root.lookup(w_index).piece, SchemaPiece::Enum { .. }) => {
+                let symbols = match &writer.root.lookup(w_index).piece {
+                    SchemaPiece::Enum { symbols, .. } => symbols.clone(),
+                    _ => unreachable!(),
+                };
+                SchemaPieceOrNamed::Piece(SchemaPiece::ResolveEnumString { symbols })
+            }
             (ws, rs) => {
                 return Err(SchemaResolutionError::new(format!(
                     "Schemas don't match: {:?}, {:?} for field `{}`",
@@ -42,11 +42,12 @@ use serde_json::{self, Map, Value};
 use tracing::{debug, warn};
 use types::{DecimalValue, Value as AvroValue};
 
+use crate::decode::{build_time_value, build_ts_value};
 use crate::error::Error as AvroError;
 use crate::reader::SchemaResolver;
 use crate::types;
 use crate::types::AvroMap;
-use crate::util::MapHelper;
+use crate::util::{MapHelper, TsUnit};
 
 pub fn resolve_schemas(
     writer_schema: &Schema,
@@ -193,6 +194,14 @@ pub enum SchemaPiece {
     ///
     /// <https://avro.apache.org/docs/current/spec.html#Timestamp+%28microsecond+precision%29>
     TimestampMicro,
+    /// An `Int` Avro schema with a semantic type being milliseconds since midnight.
+    ///
+    /// <https://avro.apache.org/docs/current/spec.html#Time+%28millisecond+precision%29>
+    TimeMilli,
+    /// An `Int64` Avro schema with a semantic type being microseconds since midnight.
+    ///
+    /// <https://avro.apache.org/docs/current/spec.html#Time+%28microsecond+precision%29>
+    TimeMicro,
     /// A `bytes` or `fixed` Avro schema with a logical type of `decimal` and
     /// the specified precision and scale.
     ///
@@ -315,18 +324,29 @@ pub enum SchemaPiece {
         /// The value to decode if the writer writes some value not expected by the reader.
         default: Option<(usize, String)>,
     },
+    /// An enum in the writer, read as a `string` in the reader.
+    ResolveEnumString {
+        /// Symbols in order of the writer schema.
+        symbols: Vec<String>,
+    },
 }
 
 impl SchemaPiece {
     /// Returns whether the schema node is "underlyingly" an Int (but possibly a logicalType typedef)
     pub fn is_underlying_int(&self) -> bool {
-        matches!(self, SchemaPiece::Int | SchemaPiece::Date)
+        matches!(
+            self,
+            SchemaPiece::Int | SchemaPiece::Date | SchemaPiece::TimeMilli
+        )
     }
     /// Returns whether the schema node is "underlyingly" an Int64 (but possibly a logicalType typedef)
     pub fn is_underlying_long(&self) -> bool {
         matches!(
             self,
-            SchemaPiece::Long | SchemaPiece::TimestampMilli | SchemaPiece::TimestampMicro
+            SchemaPiece::Long
+                | SchemaPiece::TimestampMilli
+                | SchemaPiece::TimestampMicro
+                | SchemaPiece::TimeMicro
         )
     }
 }
@@ -449,9 +469,10 @@ impl<'a> From<&'a SchemaPiece> for SchemaKind {
             SchemaPiece::Long => SchemaKind::Long,
             SchemaPiece::Float => SchemaKind::Float,
             SchemaPiece::Double => SchemaKind::Double,
-            SchemaPiece::Date => SchemaKind::Int,
+            SchemaPiece::Date | SchemaPiece::TimeMilli => SchemaKind::Int,
             SchemaPiece::TimestampMilli
             | SchemaPiece::TimestampMicro
+            | SchemaPiece::TimeMicro
             | SchemaPiece::ResolveIntTsMilli
             | SchemaPiece::ResolveDateTimestamp
             | SchemaPiece::ResolveIntTsMicro => SchemaKind::Long,
@@ -481,6 +502,7 @@ impl<'a> From<&'a SchemaPiece> for SchemaKind {
             SchemaPiece::Fixed { .. } => SchemaKind::Fixed,
             SchemaPiece::ResolveRecord { .. } => SchemaKind::Record,
             SchemaPiece::ResolveEnum { .. } => SchemaKind::Enum,
+            SchemaPiece::ResolveEnumString { .. } => SchemaKind::String,
             SchemaPiece::Json => SchemaKind::String,
             SchemaPiece::Uuid => SchemaKind::String,
         }
@@ -1167,14 +1189,19 @@ impl SchemaParser {
     /// Parse a [`serde_json::Value`] representing an Avro Int type
     ///
     /// If the complex type has a `connect.name` tag (as [emitted by
-    /// Debezium][1]) that matches a `Date` tag, we specify that the correct
-    /// schema to use is `Date`.
+    /// Debezium][1]) that matches a `Date` or `Time` tag, we specify that the
+    /// correct schema to use is `Date` or `TimeMilli`, respectively.
     ///
     /// [1]: https://debezium.io/docs/connectors/mysql/#temporal-values
     fn parse_int(complex: &Map<String, Value>) -> Result<SchemaPiece, AvroError> {
         const AVRO_DATE: &str = "date";
+        const AVRO_MILLI_TIME: &str = "time-millis";
         const DEBEZIUM_DATE: &str = "io.debezium.time.Date";
         const KAFKA_DATE: &str = "org.apache.kafka.connect.data.Date";
+        const CONNECT_MILLI_TIME: &[&str] = &[
+            "io.debezium.time.Time",
+            "org.apache.kafka.connect.data.Time",
+        ];
         if let Some(name) = complex.get("connect.name") {
             if name == DEBEZIUM_DATE || name == KAFKA_DATE {
                 if name == KAFKA_DATE {
@@ -1182,6 +1209,11 @@ impl SchemaParser {
                 }
                 return Ok(SchemaPiece::Date);
             }
+            if let serde_json::Value::String(name) = name {
+                if CONNECT_MILLI_TIME.contains(&&**name) {
+                    return Ok(SchemaPiece::TimeMilli);
+                }
+            }
         }
         // Put this after the custom semantic types so that the debezium
         // warning is emitted, since the logicalType tag shows up in the
@@ -1190,6 +1222,9 @@ impl SchemaParser {
             if name == AVRO_DATE {
                 return Ok(SchemaPiece::Date);
             }
+            if name == AVRO_MILLI_TIME {
+                return Ok(SchemaPiece::TimeMilli);
+            }
         }
         if !complex.is_empty() {
             debug!("parsing complex type as regular int: {:?}", complex);
@@ -1207,12 +1242,18 @@ impl SchemaParser {
     fn parse_long(complex: &Map<String, Value>) -> Result<SchemaPiece, AvroError> {
         const AVRO_MILLI_TS: &str = "timestamp-millis";
         const AVRO_MICRO_TS: &str = "timestamp-micros";
+        // Materialize has no notion of time zones in Avro timestamps, so
+        // local timestamps decode identically to their UTC counterparts.
+        const AVRO_LOCAL_MILLI_TS: &str = "local-timestamp-millis";
+        const AVRO_LOCAL_MICRO_TS: &str = "local-timestamp-micros";
+        const AVRO_MICRO_TIME: &str = "time-micros";
 
         const CONNECT_MILLI_TS: &[&str] = &[
             "io.debezium.time.Timestamp",
             "org.apache.kafka.connect.data.Timestamp",
         ];
         const CONNECT_MICRO_TS: &str = "io.debezium.time.MicroTimestamp";
+        const CONNECT_MICRO_TIME: &str = "io.debezium.time.MicroTime";
 
         if let Some(serde_json::Value::String(name)) = complex.get("connect.name") {
             if CONNECT_MILLI_TS.contains(&&**name) {
@@ -1221,14 +1262,20 @@ impl SchemaParser {
             if name == CONNECT_MICRO_TS {
                 return Ok(SchemaPiece::TimestampMicro);
             }
+            if name == CONNECT_MICRO_TIME {
+                return Ok(SchemaPiece::TimeMicro);
+            }
         }
         if let Some(name) = complex.get("logicalType") {
-            if name == AVRO_MILLI_TS {
+            if name == AVRO_MILLI_TS || name == AVRO_LOCAL_MILLI_TS {
                 return Ok(SchemaPiece::TimestampMilli);
             }
-            if name == AVRO_MICRO_TS {
+            if name == AVRO_MICRO_TS || name == AVRO_LOCAL_MICRO_TS {
                 return Ok(SchemaPiece::TimestampMicro);
             }
+            if name == AVRO_MICRO_TIME {
+                return Ok(SchemaPiece::TimeMicro);
+            }
         }
         if !complex.is_empty() {
             debug!("parsing complex type as regular long: {:?}", complex);
@@ -1479,6 +1526,8 @@ impl<'a> SchemaSubtreeDeepCloner<'a> {
             SchemaPiece::Date => SchemaPiece::Date,
             SchemaPiece::TimestampMilli => SchemaPiece::TimestampMilli,
             SchemaPiece::TimestampMicro => SchemaPiece::TimestampMicro,
+            SchemaPiece::TimeMilli => SchemaPiece::TimeMilli,
+            SchemaPiece::TimeMicro => SchemaPiece::TimeMicro,
             SchemaPiece::Json => SchemaPiece::Json,
             SchemaPiece::Decimal {
                 scale,
@@ -1610,6 +1659,9 @@ impl<'a> SchemaSubtreeDeepCloner<'a> {
                 symbols: symbols.clone(),
                 default: default.clone(),
             },
+            SchemaPiece::ResolveEnumString { symbols } => SchemaPiece::ResolveEnumString {
+                symbols: symbols.clone(),
+            },
         }
     }
     fn clone_piece_or_named(&mut self, piece: SchemaPieceRefOrNamed) -> SchemaPieceOrNamed {
@@ -1694,6 +1746,40 @@ impl<'a> SchemaNode<'a> {
                         .ok_or_else(|| ParseSchemaError(format!("{} is not a 64-bit float", n)))?;
                     AvroValue::Double(f)
                 }
+                SchemaPiece::Date => {
+                    let days = n
+                        .as_i64()
+                        .and_then(|i| i32::try_from(i).ok())
+                        .ok_or_else(|| {
+                            ParseSchemaError(format!("{} is not a 32-bit integer", n))
+                        })?;
+                    let date = chrono::NaiveDate::from_ymd(1970, 1, 1)
+                        .checked_add_signed(chrono::Duration::days(days.into()))
+                        .ok_or_else(|| ParseSchemaError(format!("Invalid date {}", n)))?;
+                    AvroValue::Date(date)
+                }
+                SchemaPiece::TimestampMilli | SchemaPiece::TimestampMicro => {
+                    let i = n.as_i64().ok_or_else(|| {
+                        ParseSchemaError(format!("{} is not a 64-bit integer", n))
+                    })?;
+                    let unit = match piece {
+                        SchemaPiece::TimestampMilli => TsUnit::Millis,
+                        _ => TsUnit::Micros,
+                    };
+                    build_ts_value(i, unit).map_err(|e| ParseSchemaError(e.to_string()))?
+                }
+                SchemaPiece::TimeMilli | SchemaPiece::TimeMicro => {
+                    let i = n.as_i64().ok_or_else(|| {
+                        ParseSchemaError(format!("{} is not a 64-bit integer", n))
+                    })?;
+                    let unit = match piece {
+                        SchemaPiece::TimeMilli => TsUnit::Millis,
+                        _ => TsUnit::Micros,
+                    };
+                    let time =
+                        build_time_value(i, unit).map_err(|e| ParseSchemaError(e.to_string()))?;
+                    AvroValue::Time(time)
+                }
                 _ => {
                     return Err(ParseSchemaError(format!(
                         "Unexpected number in default: {}",
@@ -1731,7 +1817,7 @@ impl<'a> SchemaNode<'a> {
                     _ => unreachable!(),
                 }
             }
-            (String(s), SchemaPiece::Bytes) => AvroValue::Bytes(s.clone().into_bytes()),
+            (String(s), SchemaPiece::Bytes) => AvroValue::Bytes(json_string_to_bytes(s)?),
             (
                 String(s),
                 SchemaPiece::Decimal {
@@ -1740,9 +1826,19 @@ impl<'a> SchemaNode<'a> {
             ) => AvroValue::Decimal(DecimalValue {
                 precision: *precision,
                 scale: *scale,
-                unscaled: s.clone().into_bytes(),
+                unscaled: json_string_to_bytes(s)?,
             }),
             (String(s), SchemaPiece::String) => AvroValue::String(s.clone()),
+            (String(s), SchemaPiece::Json) => {
+                let j = serde_json::from_str(s)
+                    .map_err(|e| ParseSchemaError(format!("Invalid JSON {}: {}", s, e)))?;
+                AvroValue::Json(j)
+            }
+            (String(s), SchemaPiece::Uuid) => {
+                let u = uuid::Uuid::parse_str(s)
+                    .map_err(|e| ParseSchemaError(format!("Invalid UUID {}: {}", s, e)))?;
+                AvroValue::Uuid(u)
+            }
             (Object(map), SchemaPiece::Record { fields, .. }) => {
                 let field_values = fields
                     .iter()
@@ -1781,8 +1877,15 @@ impl<'a> SchemaNode<'a> {
                     .collect::<Result<HashMap<_, _>, ParseSchemaError>>()?;
                 AvroValue::Map(AvroMap(map))
             }
-            (String(s), SchemaPiece::Fixed { size }) if s.len() == *size => {
-                AvroValue::Fixed(*size, s.clone().into_bytes())
+            (String(s), SchemaPiece::Fixed { size }) => {
+                let bytes = json_string_to_bytes(s)?;
+                if bytes.len() != *size {
+                    return Err(ParseSchemaError(format!(
+                        "Json default value {} does not match schema",
+                        json
+                    )));
+                }
+                AvroValue::Fixed(*size, bytes)
             }
             _ => {
                 return Err(ParseSchemaError(format!(
@@ -1795,6 +1898,21 @@ impl<'a> SchemaNode<'a> {
     }
 }
 
+/// Converts the JSON representation of a `bytes` or `fixed` value, a string
+/// in which each code point between 0 and 255 stands for one byte, into bytes.
+fn json_string_to_bytes(s: &str) -> Result<Vec<u8>, ParseSchemaError> {
+    s.chars()
+        .map(|c| {
+            u8::try_from(c).map_err(|_| {
+                ParseSchemaError(format!(
+                    "Json default value {:?} contains a character that is not a byte: {:?}",
+                    s, c
+                ))
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone)]
 struct SchemaSerContext<'a> {
     node: SchemaNodeOrNamed<'a>,
@@ -1853,6 +1971,18 @@ impl<'a> Serialize for SchemaSerContext<'a> {
                     }
                     map.end()
                 }
+                SchemaPiece::TimeMilli => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("type", "int")?;
+                    map.serialize_entry("logicalType", "time-millis")?;
+                    map.end()
+                }
+                SchemaPiece::TimeMicro => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("type", "long")?;
+                    map.serialize_entry("logicalType", "time-micros")?;
+                    map.end()
+                }
                 SchemaPiece::Decimal {
                     precision,
                     scale,
@@ -1921,7 +2051,8 @@ impl<'a> Serialize for SchemaSerContext<'a> {
                 | SchemaPiece::ResolveRecord { .. }
                 | SchemaPiece::ResolveIntTsMicro
                 | SchemaPiece::ResolveIntTsMilli
-                | SchemaPiece::ResolveEnum { .. } => {
+                | SchemaPiece::ResolveEnum { .. }
+                | SchemaPiece::ResolveEnumString { .. } => {
                     panic!("Attempted to serialize resolved schema")
                 }
             },
@@ -2014,6 +2145,8 @@ impl<'a> Serialize for SchemaSerContext<'a> {
                     | SchemaPiece::Date
                     | SchemaPiece::TimestampMilli
                     | SchemaPiece::TimestampMicro
+                    | SchemaPiece::TimeMilli
+                    | SchemaPiece::TimeMicro
                     | SchemaPiece::Decimal {
                         fixed_size: None, ..
                     }
@@ -2039,7 +2172,8 @@ impl<'a> Serialize for SchemaSerContext<'a> {
                     | SchemaPiece::ResolveRecord { .. }
                     | SchemaPiece::ResolveIntTsMilli
                     | SchemaPiece::ResolveIntTsMicro
-                    | SchemaPiece::ResolveEnum { .. } => {
+                    | SchemaPiece::ResolveEnum { .. }
+                    | SchemaPiece::ResolveEnumString { .. } => {
                         panic!("Attempted to serialize resolved schema")
                     }
                 }
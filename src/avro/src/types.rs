@@ -31,7 +31,7 @@ use std::fmt;
 use std::hash::BuildHasher;
 use std::u8;
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use enum_kinds::EnumKind;
 use serde_json::Value as JsonValue;
 
@@ -77,6 +77,7 @@ pub enum Scalar {
     Double(f64),
     Date(NaiveDate),
     Timestamp(NaiveDateTime),
+    Time(NaiveTime),
 }
 
 impl From<Scalar> for Value {
@@ -90,6 +91,7 @@ impl From<Scalar> for Value {
             Scalar::Double(v) => Value::Double(v),
             Scalar::Date(v) => Value::Date(v),
             Scalar::Timestamp(v) => Value::Timestamp(v),
+            Scalar::Time(v) => Value::Time(v),
         }
     }
 }
@@ -130,6 +132,8 @@ pub enum Value {
     Date(NaiveDate),
     /// A `DateTime` coming from an avro Logical `Timestamp`
     Timestamp(NaiveDateTime),
+    /// A `Time` coming from an avro Logical `Time`
+    Time(NaiveTime),
 
     // Variable-length types
     /// A `decimal` Avro value
@@ -383,6 +387,8 @@ impl Value {
             (&Value::Date(_), SchemaPiece::Date) => true,
             (&Value::Timestamp(_), SchemaPiece::TimestampMicro) => true,
             (&Value::Timestamp(_), SchemaPiece::TimestampMilli) => true,
+            (&Value::Time(_), SchemaPiece::TimeMicro) => true,
+            (&Value::Time(_), SchemaPiece::TimeMilli) => true,
             (
                 &Value::Decimal(DecimalValue {
                     precision: vp,
@@ -26,7 +26,7 @@
 use std::io::Cursor;
 use std::str::FromStr;
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use mz_avro::schema::resolve_schemas;
 use mz_avro::types::AvroMap;
 use mz_avro::{
@@ -36,6 +36,7 @@ use mz_avro::{
     Schema, ValidationError,
 };
 use once_cell::sync::Lazy;
+use uuid::Uuid;
 
 static SCHEMAS_TO_VALIDATE: Lazy<Vec<(&'static str, Value)>> = Lazy::new(|| {
     vec![
@@ -91,6 +92,14 @@ static SCHEMAS_TO_VALIDATE: Lazy<Vec<(&'static str, Value)>> = Lazy::new(|| {
             r#"{"type": "record", "name": "Test", "fields": [{"name": "f", "type": "long"}]}"#,
             Value::Record(vec![("f".to_string(), Value::Long(1))]),
         ),
+        (
+            r#"{"type": "int", "logicalType": "time-millis"}"#,
+            Value::Time(NaiveTime::from_hms_milli(13, 45, 1, 250)),
+        ),
+        (
+            r#"{"type": "long", "logicalType": "time-micros"}"#,
+            Value::Time(NaiveTime::from_hms_micro(23, 59, 59, 999_999)),
+        ),
     ]
 });
 
@@ -113,13 +122,55 @@ static DEFAULT_VALUE_EXAMPLES: Lazy<Vec<(&'static str, &'static str, Value)>> =
         (r#""null""#, "null", Value::Null),
         (r#""boolean""#, "true", Value::Boolean(true)),
         (r#""string""#, r#""foo""#, Value::String("foo".to_string())),
-        //(r#""bytes""#, r#""\u00FF\u00FF""#, Value::Bytes(vec![0xff, 0xff])),
+        (
+            r#""bytes""#,
+            r#""\u00FF\u00FF""#,
+            Value::Bytes(vec![0xff, 0xff]),
+        ),
         (r#""int""#, "5", Value::Int(5)),
         (r#""long""#, "5", Value::Long(5)),
         (r#""float""#, "1.1", Value::Float(1.1)),
         (r#""double""#, "1.1", Value::Double(1.1)),
-        //(r#"{"type": "fixed", "name": "F", "size": 2}"#, r#""\u00FF\u00FF""#, Value::Bytes(vec![0xff, 0xff])),
-        //(r#"{"type": "enum", "name": "F", "symbols": ["FOO", "BAR"]}"#, r#""FOO""#, Value::Enum(0, "FOO".to_string())),
+        (
+            r#"{"type": "fixed", "name": "F", "size": 2}"#,
+            r#""\u00FF\u00FF""#,
+            Value::Fixed(2, vec![0xff, 0xff]),
+        ),
+        (
+            r#"{"type": "enum", "name": "F", "symbols": ["FOO", "BAR"]}"#,
+            r#""FOO""#,
+            Value::Enum(0, "FOO".to_string()),
+        ),
+        (
+            r#"{"type": "int", "logicalType": "date"}"#,
+            "1",
+            Value::Date(NaiveDate::from_ymd(1970, 1, 2)),
+        ),
+        (
+            r#"{"type": "long", "logicalType": "timestamp-millis"}"#,
+            "1000",
+            Value::Timestamp(NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 1)),
+        ),
+        (
+            r#"{"type": "long", "logicalType": "timestamp-micros"}"#,
+            "1000",
+            Value::Timestamp(NaiveDate::from_ymd(1970, 1, 1).and_hms_milli(0, 0, 0, 1)),
+        ),
+        (
+            r#"{"type": "int", "logicalType": "time-millis"}"#,
+            "1000",
+            Value::Time(NaiveTime::from_hms(0, 0, 1)),
+        ),
+        (
+            r#"{"type": "long", "logicalType": "time-micros"}"#,
+            "1000",
+            Value::Time(NaiveTime::from_hms_milli(0, 0, 0, 1)),
+        ),
+        (
+            r#"{"type": "string", "logicalType": "uuid"}"#,
+            r#""6f63a7ec-0d60-4ca1-8b54-0c0c06e0e4a1""#,
+            Value::Uuid(Uuid::parse_str("6f63a7ec-0d60-4ca1-8b54-0c0c06e0e4a1").unwrap()),
+        ),
         (
             r#"{"type": "array", "items": "int"}"#,
             "[1, 2, 3]",
@@ -138,7 +189,16 @@ static DEFAULT_VALUE_EXAMPLES: Lazy<Vec<(&'static str, &'static str, Value)>> =
                 .collect(),
             )),
         ),
-        //(r#"["int", "null"]"#, "5", Value::Union(Box::new(Value::Int(5)))),
+        (
+            r#"["int", "null"]"#,
+            "5",
+            Value::Union {
+                index: 0,
+                inner: Box::new(Value::Int(5)),
+                n_variants: 2,
+                null_variant: Some(1),
+            },
+        ),
         (
             r#"{"type": "record", "name": "F", "fields": [{"name": "A", "type": "int"}]}"#,
             r#"{"A": 5}"#,
@@ -270,6 +330,47 @@ fn test_unknown_symbol() {
     assert!(decoded.is_err());
 }
 
+#[test]
+fn test_enum_to_string() {
+    let writer_schema = Schema::from_str(
+        r#"{
+            "type": "record",
+            "name": "Test",
+            "fields": [
+                {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["ACTIVE", "INACTIVE"]}}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let reader_schema = Schema::from_str(
+        r#"{
+            "type": "record",
+            "name": "Test",
+            "fields": [
+                {"name": "status", "type": "string"}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let original_value = Value::Record(vec![(
+        "status".to_string(),
+        Value::Enum(1, "INACTIVE".to_string()),
+    )]);
+    let encoded = to_avro_datum(&writer_schema, original_value).unwrap();
+    let resolved_schema = resolve_schemas(&writer_schema, &reader_schema).unwrap();
+    let decoded = from_avro_datum(&resolved_schema, &mut Cursor::new(encoded)).unwrap();
+    assert_eq!(
+        decoded,
+        Value::Record(vec![(
+            "status".to_string(),
+            Value::String("INACTIVE".to_string())
+        )])
+    );
+
+    // The reverse is not allowed, as not every string is a valid symbol.
+    assert!(resolve_schemas(&reader_schema, &writer_schema).is_err());
+}
+
 #[test]
 fn test_default_value() {
     for (field_type, default_json, default_datum) in DEFAULT_VALUE_EXAMPLES.iter() {
@@ -519,6 +620,56 @@ fn test_projection() {
     assert_eq!(datum_to_read, datum_read);
 }
 
+#[test]
+fn test_projection_skips_sized_blocks() {
+    let writer_schema = Schema::from_str(
+        r#"
+        {
+            "type": "record",
+            "name": "Test",
+            "fields": [
+                {"name": "a", "type": {"type": "array", "items": "long"}},
+                {"name": "m", "type": {"type": "map", "values": "long"}},
+                {"name": "b", "type": "long"}
+            ]
+        }
+    "#,
+    )
+    .unwrap();
+    let reader_schema = Schema::from_str(
+        r#"
+        {
+            "type": "record",
+            "name": "Test",
+            "fields": [
+                {"name": "b", "type": "long"}
+            ]
+        }
+    "#,
+    )
+    .unwrap();
+    let encoded = vec![
+        // A block of two array items whose size in bytes is known. The items
+        // are not valid longs, so this only decodes if they are skipped.
+        0x03, 0x04, 0x80, 0x80, //
+        // A block of one array item whose size is not known.
+        0x02, 0x02, //
+        // The end of the array.
+        0x00, //
+        // A block of one map entry whose size in bytes is known, followed by
+        // the end of the map.
+        0x01, 0x06, 0x02, b'k', 0x80, 0x00, //
+        // The field `b`.
+        0x06,
+    ];
+    let resolved_schema = resolve_schemas(&writer_schema, &reader_schema).unwrap();
+    let datum_read = from_avro_datum(&resolved_schema, &mut Cursor::new(encoded)).unwrap();
+    assert_eq!(
+        datum_read,
+        Value::Record(vec![("b".to_string(), Value::Long(3))])
+    );
+}
+
 #[test]
 fn test_field_order() {
     let reader_schema = Schema::from_str(
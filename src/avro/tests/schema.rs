@@ -27,7 +27,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use mz_avro::types::AvroMap;
 use mz_avro::{types::DecimalValue, types::Value, Schema};
 use once_cell::sync::Lazy;
@@ -334,12 +334,20 @@ static VALID_LOGICAL_TYPES: Lazy<Vec<(&'static str, Value)>> = Lazy::new(|| {
         // Time millis logical type
         (
             r#"{"type": "int", "logicalType": "time-millis"}"#,
-            Value::Int(0),
+            Value::Time(NaiveTime::from_hms(0, 0, 0)),
+        ),
+        (
+            r#"{"type": "int", "connect.name": "io.debezium.time.Time"}"#,
+            Value::Time(NaiveTime::from_hms(0, 0, 0)),
         ),
         // Time micros logical type
         (
             r#"{"type": "long", "logicalType": "time-micros"}"#,
-            Value::Long(0),
+            Value::Time(NaiveTime::from_hms(0, 0, 0)),
+        ),
+        (
+            r#"{"type": "long", "connect.name": "io.debezium.time.MicroTime"}"#,
+            Value::Time(NaiveTime::from_hms(0, 0, 0)),
         ),
         // Timestamp millis logical type
         (
@@ -351,6 +359,15 @@ static VALID_LOGICAL_TYPES: Lazy<Vec<(&'static str, Value)>> = Lazy::new(|| {
             r#"{"type": "long", "logicalType": "timestamp-micros"}"#,
             Value::Timestamp(NaiveDateTime::from_timestamp(0, 0)),
         ),
+        // Local timestamp logical types
+        (
+            r#"{"type": "long", "logicalType": "local-timestamp-millis"}"#,
+            Value::Timestamp(NaiveDateTime::from_timestamp(0, 0)),
+        ),
+        (
+            r#"{"type": "long", "logicalType": "local-timestamp-micros"}"#,
+            Value::Timestamp(NaiveDateTime::from_timestamp(0, 0)),
+        ),
     ]
 });
 
@@ -661,12 +661,62 @@ impl PendingPeek {
             return None;
         }
         let response = match self.collect_finished_data() {
-            Ok(rows) => PeekResponse::Rows(rows),
+            Ok(mut rows) => {
+                // Ship back only the records the finishing can possibly use.
+                if let Some(max_results) = self.max_results() {
+                    self.truncate_results(&mut rows, max_results);
+                }
+                PeekResponse::Rows(rows)
+            }
             Err(text) => PeekResponse::Error(text),
         };
         Some(response)
     }
 
+    /// When set, a bound on the number of records we need to return.
+    ///
+    /// The requirements on the records are driven by the finishing's
+    /// `order_by` field. Further limiting will happen when the results
+    /// are collected, so we don't need to have exactly this many results,
+    /// just at least those results that would have been returned.
+    fn max_results(&self) -> Option<usize> {
+        self.peek
+            .finishing
+            .limit
+            .map(|l| l.saturating_add(self.peek.finishing.offset))
+    }
+
+    /// Truncates `results` to at most `max_results` records, retaining those
+    /// that come first according to the finishing's `order_by`.
+    fn truncate_results(&self, results: &mut Vec<(Row, NonZeroUsize)>, max_results: usize) {
+        if results.len() <= max_results {
+            return;
+        }
+        if !self.peek.finishing.order_by.is_empty() {
+            // We can sort `results` and then truncate to `max_results`.
+            // This has an effect similar to a priority queue, without
+            // its interactive dequeueing properties.
+            // TODO: Had we left these as `Vec<Datum>` we would avoid
+            // the unpacking; we should consider doing that, although
+            // it will require a re-pivot of the code to branch on this
+            // inner test (as we prefer not to maintain `Vec<Datum>`
+            // in the other case).
+            let mut l_datum_vec = mz_repr::DatumVec::new();
+            let mut r_datum_vec = mz_repr::DatumVec::new();
+            results.sort_by(|left, right| {
+                let left_datums = l_datum_vec.borrow_with(&left.0);
+                let right_datums = r_datum_vec.borrow_with(&right.0);
+                mz_expr::compare_columns(
+                    &self.peek.finishing.order_by,
+                    &left_datums,
+                    &right_datums,
+                    || left.0.cmp(&right.0),
+                )
+            });
+        }
+        results.truncate(max_results);
+    }
+
     /// Collects data for a known-complete peek.
     fn collect_finished_data(&mut self) -> Result<Vec<(Row, NonZeroUsize)>, String> {
         // Check if there exist any errors and, if so, return whatever one we
@@ -697,16 +747,10 @@ impl PendingPeek {
         // Accumulated `Vec<(row, count)>` results that we are likely to return.
         let mut results = Vec::new();
 
-        // When set, a bound on the number of records we need to return.
-        // The requirements on the records are driven by the finishing's
-        // `order_by` field. Further limiting will happen when the results
-        // are collected, so we don't need to have exactly this many results,
-        // just at least those results that would have been returned.
-        let max_results = self
-            .peek
-            .finishing
-            .limit
-            .map(|l| l + self.peek.finishing.offset);
+        // The number of copies of records accumulated in `results`.
+        let mut total_copies: usize = 0;
+
+        let max_results = self.max_results();
 
         use differential_dataflow::trace::Cursor;
         use mz_ore::result::ResultExt;
@@ -714,8 +758,6 @@ impl PendingPeek {
 
         let mut row_builder = Row::default();
         let mut datum_vec = DatumVec::new();
-        let mut l_datum_vec = DatumVec::new();
-        let mut r_datum_vec = DatumVec::new();
 
         // We have to sort the literal constraints because cursor.seek_key can seek only forward.
         self.peek
@@ -797,40 +839,23 @@ impl PendingPeek {
                     };
                     // if copies > 0 ... otherwise skip
                     if let Some(copies) = NonZeroUsize::new(copies) {
+                        total_copies = total_copies.saturating_add(copies.get());
                         results.push((result, copies));
                     }
 
-                    // If we hold many more than `max_results` records, we can thin down
-                    // `results` using `self.finishing.ordering`.
                     if let Some(max_results) = max_results {
-                        // We use a threshold twice what we intend, to amortize the work
-                        // across all of the insertions. We could tighten this, but it
-                        // works for the moment.
-                        if results.len() >= 2 * max_results {
-                            if self.peek.finishing.order_by.is_empty() {
-                                results.truncate(max_results);
+                        if self.peek.finishing.order_by.is_empty() {
+                            // Without an ordering any records will do, so we can stop
+                            // scanning as soon as we hold enough copies of them.
+                            if total_copies >= max_results {
                                 return Ok(results);
-                            } else {
-                                // We can sort `results` and then truncate to `max_results`.
-                                // This has an effect similar to a priority queue, without
-                                // its interactive dequeueing properties.
-                                // TODO: Had we left these as `Vec<Datum>` we would avoid
-                                // the unpacking; we should consider doing that, although
-                                // it will require a re-pivot of the code to branch on this
-                                // inner test (as we prefer not to maintain `Vec<Datum>`
-                                // in the other case).
-                                results.sort_by(|left, right| {
-                                    let left_datums = l_datum_vec.borrow_with(&left.0);
-                                    let right_datums = r_datum_vec.borrow_with(&right.0);
-                                    mz_expr::compare_columns(
-                                        &self.peek.finishing.order_by,
-                                        &left_datums,
-                                        &right_datums,
-                                        || left.0.cmp(&right.0),
-                                    )
-                                });
-                                results.truncate(max_results);
                             }
+                        } else if results.len() >= max_results.saturating_mul(2) {
+                            // If we hold many more than `max_results` records, we can
+                            // thin down `results` using `self.finishing.ordering`. We
+                            // use a threshold twice what we intend, to amortize the
+                            // work across all of the insertions.
+                            self.truncate_results(&mut results, max_results);
                         }
                     }
                 }
@@ -87,6 +87,9 @@ pub fn construct<A: Allocate>(
                         let time_ms = (((time.as_millis() as Timestamp / interval_ms) + 1)
                             * interval_ms) as Timestamp;
 
+                        // Arrangement sizes are logged in records only. Differential's
+                        // events do not report the heap size of batches, so there is no
+                        // byte count to log, and no basis for a memory budget.
                         match datum {
                             DifferentialEvent::Batch(event) => {
                                 arrangement_batches_session
@@ -142,6 +142,11 @@ mod top_k;
 /// This method imports sources from provided assets, and then builds the remaining
 /// dataflow using "compute-local" assets like shared arrangements, and producing
 /// both arrangements and sinks.
+///
+/// Every worker of the replica builds every dataflow, so a dataflow always runs
+/// with the full parallelism of the replica. Timely requires all workers to
+/// construct the same dataflows, which rules out confining a dataflow to a
+/// subset of the workers.
 pub fn build_compute_dataflow<A: Allocate>(
     timely_worker: &mut TimelyWorker<A>,
     compute_state: &mut ComputeState,
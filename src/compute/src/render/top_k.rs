@@ -104,6 +104,21 @@ where
             G: Scope,
             G::Timestamp: Lattice,
         {
+            // Without a limit, the result is the input less the first `offset` records of
+            // each group. Those records can be determined by the hierarchical plan below,
+            // whereas a single `offset` stage would need to revisit entire groups on changes.
+            if limit.is_none() && offset > 0 {
+                let skipped = build_topk(
+                    collection.clone(),
+                    group_key,
+                    order_key,
+                    0,
+                    Some(offset),
+                    arity,
+                );
+                return collection.concat(&skipped.negate());
+            }
+
             let mut datum_vec = mz_repr::DatumVec::new();
             let mut collection = collection.map({
                 move |row| {
@@ -321,6 +321,14 @@ pub struct Args {
     /// The PostgreSQL URL for the adapter stash.
     #[clap(long, env = "ADAPTER_STASH_URL", value_name = "POSTGRES_URL")]
     adapter_stash_url: String,
+    /// The PostgreSQL URL of a store through which the timestamp oracles of
+    /// this coordinator share timelines with other coordinators that run at
+    /// the same time.
+    ///
+    /// If not specified, timestamps are only made durable in the adapter stash,
+    /// and no other coordinator may use the same timelines while this one runs.
+    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
+    timestamp_oracle_url: Option<String>,
 
     // === Cloud options. ===
     /// Prefix for an external ID to be supplied to all AWS AssumeRole operations.
@@ -669,6 +677,7 @@ max log level: {max_log_level}",
         frontegg,
         cors_allowed_origin,
         adapter_stash_url: args.adapter_stash_url,
+        timestamp_oracle_url: args.timestamp_oracle_url,
         controller,
         secrets_controller,
         unsafe_mode: args.unsafe_mode,
@@ -105,6 +105,7 @@ impl Server {
                 routing::get(catalog::handle_internal_catalog),
             )
             .route("/api/sql", routing::post(sql::handle_sql))
+            .route("/api/tail", routing::get(sql::handle_tail))
             .route("/memory", routing::get(memory::handle_memory))
             .route(
                 "/hierarchical-memory",
@@ -159,7 +160,10 @@ impl Server {
         let router = self.router.lock().expect("lock poisoned").clone();
         let svc = router.layer(Extension(conn_protocol));
         let http = hyper::server::conn::Http::new();
-        http.serve_connection(conn, svc).err_into().await
+        http.serve_connection(conn, svc)
+            .with_upgrades()
+            .err_into()
+            .await
     }
 
     // Handler functions are attached by various submodules. They all have a
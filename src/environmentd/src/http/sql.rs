@@ -7,10 +7,26 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use axum::body::Body;
 use axum::response::IntoResponse;
 use axum::Json;
-use http::StatusCode;
-use serde::Deserialize;
+use futures::{SinkExt, StreamExt};
+use http::header::{
+    CONNECTION, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use http::{HeaderMap, Request, StatusCode};
+use hyper::upgrade::Upgraded;
+use serde::{Deserialize, Serialize};
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::warn;
+
+use mz_adapter::session::EndTransactionAction;
+use mz_adapter::{AdapterError, PeekResponseUnary, SessionClient};
+use mz_ore::task;
+use mz_repr::DatumVec;
 
 use crate::http::AuthedClient;
 
@@ -28,3 +44,160 @@ pub async fn handle_sql(
         Err(e) => Err((StatusCode::BAD_REQUEST, e.to_string())),
     }
 }
+
+/// A message sent to the client of a `TAIL` over a WebSocket.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum TailMessage {
+    /// The names of the columns in each row. Always the first message.
+    Columns { col_names: Vec<String> },
+    /// A row produced by the `TAIL`.
+    Row { row: Vec<serde_json::Value> },
+    /// The `TAIL` failed. Always the last message.
+    Err { error: String },
+}
+
+/// Upgrades the connection to a WebSocket and runs a `TAIL` over it.
+///
+/// The client sends a single text message containing a JSON object of the
+/// same shape as the body of a request to `/api/sql`, whose `sql` must be a
+/// single `TAIL` statement. The server then sends one text message per
+/// [`TailMessage`] until either side closes the connection.
+pub async fn handle_tail(
+    AuthedClient(client): AuthedClient,
+    mut req: Request<Body>,
+) -> impl IntoResponse {
+    let key = match websocket_key(req.headers()) {
+        Some(key) => key,
+        None => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "expected a WebSocket upgrade request",
+            ))
+        }
+    };
+    let on_upgrade = hyper::upgrade::on(&mut req);
+    task::spawn(|| "http_tail", async move {
+        match on_upgrade.await {
+            Ok(upgraded) => {
+                let ws = WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await;
+                run_tail(client, ws).await;
+            }
+            Err(e) => warn!("WebSocket upgrade failed: {}", e),
+        }
+    });
+    Ok((
+        StatusCode::SWITCHING_PROTOCOLS,
+        [
+            (CONNECTION, "upgrade".to_string()),
+            (UPGRADE, "websocket".to_string()),
+            (SEC_WEBSOCKET_ACCEPT, derive_accept_key(key.as_bytes())),
+        ],
+    ))
+}
+
+/// Returns the `Sec-WebSocket-Key` of a valid WebSocket upgrade request.
+fn websocket_key(headers: &HeaderMap) -> Option<String> {
+    let header_contains = |name, value: &str| {
+        headers.get_all(name).iter().any(|v| {
+            v.to_str()
+                .map(|v| v.split(',').any(|v| v.trim().eq_ignore_ascii_case(value)))
+                .unwrap_or(false)
+        })
+    };
+    if !header_contains(CONNECTION, "upgrade")
+        || !header_contains(UPGRADE, "websocket")
+        || !header_contains(SEC_WEBSOCKET_VERSION, "13")
+    {
+        return None;
+    }
+    headers
+        .get(SEC_WEBSOCKET_KEY)
+        .and_then(|key| key.to_str().ok())
+        .map(|key| key.to_string())
+}
+
+async fn run_tail(mut client: SessionClient, mut ws: WebSocketStream<Upgraded>) {
+    let sql = loop {
+        match ws.next().await {
+            Some(Ok(Message::Text(text))) => match serde_json::from_str::<SqlRequest>(&text) {
+                Ok(SqlRequest { sql }) => break sql,
+                Err(e) => {
+                    send_message(
+                        &mut ws,
+                        TailMessage::Err {
+                            error: e.to_string(),
+                        },
+                    )
+                    .await;
+                    return;
+                }
+            },
+            // Pings and pongs are answered by the WebSocket library.
+            Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+            _ => return,
+        }
+    };
+
+    let (col_names, mut rx) = match client.simple_tail(&sql).await {
+        Ok(tail) => tail,
+        Err(e) => {
+            send_message(
+                &mut ws,
+                TailMessage::Err {
+                    error: e.to_string(),
+                },
+            )
+            .await;
+            return;
+        }
+    };
+
+    if send_message(&mut ws, TailMessage::Columns { col_names }).await {
+        let mut datum_vec = DatumVec::new();
+        'tail: loop {
+            tokio::select! {
+                msg = ws.next() => match msg {
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => {}
+                    // The client closed the connection or sent something
+                    // unexpected, so stop the `TAIL`.
+                    _ => break,
+                },
+                batch = rx.recv() => {
+                    let error = match batch {
+                        Some(PeekResponseUnary::Rows(rows)) => {
+                            for row in rows {
+                                let datums = datum_vec.borrow_with(&row);
+                                let row = datums.iter().map(From::from).collect();
+                                if !send_message(&mut ws, TailMessage::Row { row }).await {
+                                    break 'tail;
+                                }
+                            }
+                            continue;
+                        }
+                        Some(PeekResponseUnary::Error(e)) => e,
+                        Some(PeekResponseUnary::Canceled) => {
+                            "statement canceled due to user request".into()
+                        }
+                        Some(PeekResponseUnary::TimedOut) => {
+                            AdapterError::StatementTimeout.to_string()
+                        }
+                        None => break,
+                    };
+                    send_message(&mut ws, TailMessage::Err { error }).await;
+                    break;
+                }
+            }
+        }
+    }
+
+    // Ending the transaction drops the `TAIL`.
+    let _ = client.end_transaction(EndTransactionAction::Commit).await;
+    let _ = ws.close(None).await;
+}
+
+/// Sends `msg` to the client, returning whether the send succeeded.
+async fn send_message(ws: &mut WebSocketStream<Upgraded>, msg: TailMessage) -> bool {
+    let msg = serde_json::to_string(&msg).expect("serialization cannot fail");
+    ws.send(Message::Text(msg)).await.is_ok()
+}
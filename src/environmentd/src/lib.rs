@@ -89,6 +89,9 @@ pub struct Config {
     // === Adapter options. ===
     /// The PostgreSQL URL for the adapter stash.
     pub adapter_stash_url: String,
+    /// The PostgreSQL URL of the store through which timestamp oracles share
+    /// timelines with the oracles of other coordinators, if any.
+    pub timestamp_oracle_url: Option<String>,
 
     // === Cloud options. ===
     /// Availability zones in which storage and compute resources may be
@@ -111,6 +114,9 @@ pub struct Config {
 
     // === Testing options. ===
     /// A now generation function for mocking time.
+    ///
+    /// It is the clock of `now()` and of the timestamps of writes to tables, and thus of temporal
+    /// filters. Tests can control it with a [`ManualClock`](mz_ore::now::ManualClock).
     pub now: NowFn,
 }
 
@@ -266,6 +272,7 @@ pub async fn serve(config: Config) -> Result<Server, anyhow::Error> {
         availability_zones: config.availability_zones,
         connection_context: config.connection_context,
         storage_usage_client,
+        timestamp_oracle_url: config.timestamp_oracle_url,
     })
     .await?;
 
@@ -85,7 +85,8 @@ impl ConnectionHandler for mz_pgwire::Server {
         // Using fully-qualified syntax means we won't accidentally call
         // ourselves (i.e., silently infinitely recurse) if the name or type of
         // `mz_pgwire::Server::handle_connection` changes.
-        mz_pgwire::Server::handle_connection(self, conn).await
+        let client_addr = conn.peer_addr().ok().map(|addr| addr.ip());
+        mz_pgwire::Server::handle_connection(self, conn, client_addr).await
     }
 }
 
@@ -0,0 +1,114 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Integration tests that enable failpoints.
+//!
+//! Failpoints are global to the process, so these tests live in their own test
+//! binary, where they cannot affect the servers started by other tests.
+
+use std::error::Error;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use fail::FailScenario;
+
+use mz_adapter::catalog::SYSTEM_USER;
+use mz_ore::assert_contains;
+
+use crate::util::PostgresErrorExt;
+
+pub mod util;
+
+// Test how group commit handles table appends whose outcome storage cannot
+// determine.
+#[test]
+fn test_indeterminate_table_appends() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+    let scenario = FailScenario::setup();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(SYSTEM_USER)
+        .connect(postgres::NoTls)?;
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("SET statement_timeout = '10s'")?;
+    client.batch_execute("CREATE TABLE t1 (a int)")?;
+    client.batch_execute("CREATE TABLE t2 (a int)")?;
+    let t1: String = client
+        .query_one("SELECT id FROM mz_tables WHERE name = 't1'", &[])?
+        .get(0);
+    let enable_failpoint = format!("SET failpoints = 'table_append_indeterminate=return({t1})'");
+    let disable_failpoint = "SET failpoints = 'table_append_indeterminate=off'";
+
+    // An append whose outcome is indeterminate is retried until it succeeds.
+    client.batch_execute(&enable_failpoint)?;
+    let mut writer = server.connect(postgres::NoTls)?;
+    let insert = thread::spawn(move || {
+        let start = Instant::now();
+        writer.batch_execute("INSERT INTO t1 VALUES (1)")?;
+        Ok::<_, postgres::Error>(start.elapsed())
+    });
+    thread::sleep(Duration::from_secs(2));
+    client.batch_execute(disable_failpoint)?;
+    let elapsed = insert.join().unwrap()?;
+    assert!(elapsed >= Duration::from_secs(2), "elapsed: {elapsed:?}");
+
+    // If the outcome is still indeterminate after retrying, the transactions
+    // that wrote to the table get an error. The other transactions of the same
+    // group commit commit as usual.
+    mz_client.batch_execute("ALTER SYSTEM SET group_commit_interval TO '1s'")?;
+    client.batch_execute(&enable_failpoint)?;
+    let mut writer = server.connect(postgres::NoTls)?;
+    let insert = thread::spawn(move || writer.batch_execute("INSERT INTO t1 VALUES (2)"));
+    let mut other_writer = server.connect(postgres::NoTls)?;
+    other_writer.batch_execute("INSERT INTO t2 VALUES (1)")?;
+    let err = insert.join().unwrap().unwrap_db_error();
+    assert_contains!(
+        err.message(),
+        format!("could not determine whether appends succeeded for: {t1}")
+    );
+    client.batch_execute(disable_failpoint)?;
+
+    // The table is advanced again, so that reads of it do not hang. The
+    // simulated append never happened.
+    let count: i64 = client.query_one("SELECT count(*) FROM t1", &[])?.get(0);
+    assert_eq!(count, 1);
+    let count: i64 = client.query_one("SELECT count(*) FROM t2", &[])?.get(0);
+    assert_eq!(count, 1);
+
+    // A transaction that wrote to several tables gets an error for all of them,
+    // even if only one append is indeterminate, since the appends to the other
+    // tables might have been applied.
+    let t2: String = client
+        .query_one("SELECT id FROM mz_tables WHERE name = 't2'", &[])?
+        .get(0);
+    client.batch_execute(&format!(
+        "SET failpoints = 'table_append_indeterminate=return({t2})'"
+    ))?;
+    let mut writer = server.connect(postgres::NoTls)?;
+    let err = writer
+        .batch_execute("BEGIN; INSERT INTO t1 VALUES (3); INSERT INTO t2 VALUES (2); COMMIT")
+        .unwrap_db_error();
+    assert_contains!(
+        err.message(),
+        format!("could not determine whether appends succeeded for: {t1}, {t2}")
+    );
+    client.batch_execute(disable_failpoint)?;
+
+    // The append to `t1` was applied, the simulated one to `t2` was not.
+    let count: i64 = client.query_one("SELECT count(*) FROM t1", &[])?.get(0);
+    assert_eq!(count, 2);
+    let count: i64 = client.query_one("SELECT count(*) FROM t2", &[])?.get(0);
+    assert_eq!(count, 1);
+
+    scenario.teardown();
+    Ok(())
+}
@@ -11,6 +11,8 @@
 
 use bytes::Buf;
 use std::error::Error;
+use std::io::{Read, Write};
+use std::net::TcpListener;
 use std::thread;
 use std::time::Duration;
 
@@ -18,6 +20,7 @@ use mz_ore::retry::Retry;
 use reqwest::{blocking::Client, StatusCode, Url};
 use serde_json::json;
 use tokio_postgres::types::{FromSql, Type};
+use tungstenite::{Message, WebSocket};
 
 use crate::util::KAFKA_ADDRS;
 
@@ -64,6 +67,8 @@ fn test_persistence() -> Result<(), Box<dyn Error>> {
             "CREATE VIEW mat (a, a_data, c, c_data) AS SELECT 'a', data, 'c' AS c, data FROM src",
         )?;
         client.batch_execute("CREATE DEFAULT INDEX ON mat")?;
+        client
+            .batch_execute("ALTER INDEX mat_primary_idx SET (LOGICAL COMPACTION WINDOW = '1s')")?;
         client.batch_execute("CREATE DATABASE d")?;
         client.batch_execute("CREATE SCHEMA d.s")?;
         client.batch_execute("CREATE VIEW d.s.v AS SELECT 1")?;
@@ -95,6 +100,14 @@ fn test_persistence() -> Result<(), Box<dyn Error>> {
             ("c_data".into(), 4),
         ],
     );
+    // Test that options set by `ALTER INDEX` survive a restart.
+    let create_sql: String = client
+        .query_one("SHOW CREATE INDEX mat_primary_idx", &[])?
+        .get(1);
+    assert!(
+        create_sql.ends_with("WITH (LOGICAL COMPACTION WINDOW = '1000ms')"),
+        "{create_sql}"
+    );
     assert_eq!(
         client
             .query("SHOW VIEWS FROM d.s", &[])?
@@ -141,25 +154,31 @@ fn test_http_sql() -> Result<(), Box<dyn Error>> {
         TestCase {
             query: "select 1+2 as col",
             status: StatusCode::OK,
-            body: r#"{"results":[{"rows":[[3]],"col_names":["col"]}]}"#,
+            body: r#"{"results":[{"rows":[[3]],"col_names":["col"],"desc":{"columns":[{"name":"col","type_oid":23,"type_len":4,"type_mod":-1,"nullable":false}]}}]}"#,
         },
         // Multiple queries are ok.
         TestCase {
             query: "select 1; select 2",
             status: StatusCode::OK,
-            body: r#"{"results":[{"rows":[[1]],"col_names":["?column?"]},{"rows":[[2]],"col_names":["?column?"]}]}"#,
+            body: r#"{"results":[{"rows":[[1]],"col_names":["?column?"],"desc":{"columns":[{"name":"?column?","type_oid":23,"type_len":4,"type_mod":-1,"nullable":false}]}},{"rows":[[2]],"col_names":["?column?"],"desc":{"columns":[{"name":"?column?","type_oid":23,"type_len":4,"type_mod":-1,"nullable":false}]}}]}"#,
         },
         // Arrays + lists work
         TestCase {
             query: "select array[1], list[2]",
             status: StatusCode::OK,
-            body: r#"{"results":[{"rows":[[[1],[2]]],"col_names":["array","list"]}]}"#,
+            body: r#"{"results":[{"rows":[[[1],[2]]],"col_names":["array","list"],"desc":{"columns":[{"name":"array","type_oid":1007,"type_len":-1,"type_mod":-1,"nullable":false},{"name":"list","type_oid":16384,"type_len":-1,"type_mod":-1,"nullable":false}]}}]}"#,
+        },
+        // Column types and nullability are described.
+        TestCase {
+            query: "select 'a'::varchar(3) as a, null::text as b",
+            status: StatusCode::OK,
+            body: r#"{"results":[{"rows":[["a",null]],"col_names":["a","b"],"desc":{"columns":[{"name":"a","type_oid":1043,"type_len":-1,"type_mod":7,"nullable":false},{"name":"b","type_oid":25,"type_len":-1,"type_mod":-1,"nullable":true}]}}]}"#,
         },
         // Succeeding and failing queries can mix and match.
         TestCase {
             query: "select 1; select * from noexist;",
             status: StatusCode::OK,
-            body: r#"{"results":[{"rows":[[1]],"col_names":["?column?"]},{"error":"unknown catalog item 'noexist'"}]}"#,
+            body: r#"{"results":[{"rows":[[1]],"col_names":["?column?"],"desc":{"columns":[{"name":"?column?","type_oid":23,"type_len":4,"type_mod":-1,"nullable":false}]}},{"error":"unknown catalog item 'noexist'"}]}"#,
         },
         // CREATEs should work when provided alone.
         TestCase {
@@ -173,11 +192,17 @@ fn test_http_sql() -> Result<(), Box<dyn Error>> {
             status: StatusCode::OK,
             body: r#"{"results":[{"ok":"CREATE VIEW","partial_err":{"severity":"notice","message":"view already exists, skipping"}}]}"#,
         },
-        // Multiple CREATEs do not work.
+        // Multiple CREATEs work.
         TestCase {
             query: "create view v1 as select 1; create view v2 as select 1",
             status: StatusCode::OK,
-            body: r#"{"results":[{"error":"CREATE VIEW v1 AS SELECT 1 cannot be run inside a transaction block"},{"error":"CREATE VIEW v2 AS SELECT 1 cannot be run inside a transaction block"}]}"#,
+            body: r#"{"results":[{"ok":"CREATE VIEW"},{"ok":"CREATE VIEW"}]}"#,
+        },
+        // Mixing CREATEs with other statements fails the request.
+        TestCase {
+            query: "create view v3 as select 1; select 1",
+            status: StatusCode::BAD_REQUEST,
+            body: r#"CREATE VIEW v3 AS SELECT 1 cannot be run inside a transaction block"#,
         },
         // Syntax errors fail the request.
         TestCase {
@@ -199,6 +224,55 @@ fn test_http_sql() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// Test the /api/tail WebSocket endpoint of the HTTP server.
+#[test]
+fn test_http_tail() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+    let server = util::start_server(util::Config::default())?;
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("INSERT INTO t VALUES (1)")?;
+    let url = format!("ws://{}/api/tail", server.inner.http_local_addr());
+
+    fn read_json<S: Read + Write>(
+        ws: &mut WebSocket<S>,
+    ) -> Result<serde_json::Value, Box<dyn Error>> {
+        match ws.read_message()? {
+            Message::Text(text) => Ok(serde_json::from_str(&text)?),
+            msg => Err(format!("unexpected message: {:?}", msg).into()),
+        }
+    }
+
+    // A TAIL sends its column names, then its snapshot, then later changes.
+    let (mut ws, _) = tungstenite::connect(url.as_str())?;
+    ws.write_message(Message::Text(json!({"sql": "TAIL t"}).to_string()))?;
+    assert_eq!(
+        read_json(&mut ws)?,
+        json!({"col_names": ["mz_timestamp", "mz_diff", "a"]})
+    );
+    // Skip the `mz_timestamp` column, whose value is not predictable.
+    let msg = read_json(&mut ws)?;
+    assert_eq!(&msg["row"].as_array().unwrap()[1..], &[json!(1), json!(1)]);
+    client.batch_execute("INSERT INTO t VALUES (2)")?;
+    let msg = read_json(&mut ws)?;
+    assert_eq!(&msg["row"].as_array().unwrap()[1..], &[json!(1), json!(2)]);
+    ws.close(None)?;
+
+    // Statements other than TAIL are rejected.
+    let (mut ws, _) = tungstenite::connect(url.as_str())?;
+    ws.write_message(Message::Text(json!({"sql": "SELECT 1"}).to_string()))?;
+    assert_eq!(
+        read_json(&mut ws)?,
+        json!({"error": "expected a single TAIL statement"})
+    );
+
+    // Plain HTTP requests are rejected.
+    let res = Client::new().get(url.replace("ws://", "http://")).send()?;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
 // Test that the server properly handles cancellation requests.
 #[test]
 fn test_cancel_long_running_query() -> Result<(), Box<dyn Error>> {
@@ -229,6 +303,50 @@ fn test_cancel_long_running_query() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// Test that cancellation requests interrupt the purification of a `CREATE
+// SOURCE` statement that is waiting on an unresponsive upstream.
+#[test]
+fn test_cancel_create_source_purification() -> Result<(), Box<dyn Error>> {
+    let config = util::Config::default().unsafe_mode();
+    let server = util::start_server(config)?;
+
+    // Accept connections but never respond on them.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    thread::spawn(move || {
+        let mut streams = vec![];
+        for stream in listener.incoming() {
+            streams.push(stream);
+        }
+    });
+
+    let mut client = server.connect(postgres::NoTls)?;
+    let cancel_token = client.cancel_token();
+
+    client.batch_execute(&format!(
+        "CREATE CONNECTION pg FOR POSTGRES HOST '127.0.0.1', PORT {port}, USER postgres, DATABASE postgres"
+    ))?;
+
+    thread::spawn(move || {
+        // Abort the statement after 2s.
+        thread::sleep(Duration::from_secs(2));
+        let _ = cancel_token.cancel_query(postgres::NoTls);
+    });
+
+    match client.simple_query("CREATE SOURCE s FROM POSTGRES CONNECTION pg PUBLICATION 'p'") {
+        Err(e) if e.code() == Some(&postgres::error::SqlState::QUERY_CANCELED) => {}
+        Err(e) => panic!("expected error SqlState::QUERY_CANCELED, but got {:?}", e),
+        Ok(_) => panic!("expected error SqlState::QUERY_CANCELED, but statement succeeded"),
+    }
+
+    let count: i64 = client
+        .query_one("SELECT count(*) FROM mz_sources WHERE name = 's'", &[])?
+        .get(0);
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
 // Test that dataflow uninstalls cancelled peeks.
 #[test]
 fn test_cancel_dataflow_removal() -> Result<(), Box<dyn Error>> {
@@ -37,7 +37,7 @@ use tracing::info;
 
 use mz_adapter::catalog::SYSTEM_USER;
 use mz_ore::assert_contains;
-use mz_ore::now::{EpochMillis, NowFn, NOW_ZERO, SYSTEM_TIME};
+use mz_ore::now::{EpochMillis, ManualClock, NowFn, NOW_ZERO, SYSTEM_TIME};
 use mz_ore::retry::Retry;
 use mz_ore::task::{self, AbortOnDropHandle, JoinHandleExt};
 
@@ -1060,6 +1060,100 @@ write frontier:[         <TIMESTAMP>]\n";
     Ok(())
 }
 
+// Test that writes to system tables that nobody waits for, like the updates to
+// `mz_sessions` when sessions start and end, wait for the system clock to
+// catch up with the timeline instead of advancing it.
+#[test]
+fn test_background_writes_wait_for_now() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+    let timestamp = Arc::new(Mutex::new(1_000_000));
+    let now = {
+        let timestamp = Arc::clone(&timestamp);
+        NowFn::from(move || *timestamp.lock().unwrap())
+    };
+    let config = util::Config::default().with_now(now);
+    let server = util::start_server(config)?;
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+
+    let timestamp_re = Regex::new(r"timestamp:\s+(\d+)").unwrap();
+    let mut read_ts = || -> Result<u64, Box<dyn Error>> {
+        let row = client.query_one("EXPLAIN TIMESTAMP FOR SELECT * FROM t", &[])?;
+        let explain: String = row.get(0);
+        let captures = timestamp_re.captures(&explain).unwrap();
+        Ok(captures[1].parse()?)
+    };
+
+    // With the system clock stopped, starting and ending sessions does not
+    // advance the timeline.
+    let before = read_ts()?;
+    for _ in 0..20 {
+        let session = server.connect(postgres::NoTls)?;
+        drop(session);
+    }
+    let after = read_ts()?;
+    assert!(after - before < 5, "before: {before}, after: {after}");
+
+    // Once the system clock advances, the writes are committed.
+    *timestamp.lock().unwrap() += 10_000;
+    Retry::default().retry(|_| {
+        let ts = read_ts()?;
+        if ts > after {
+            Ok(())
+        } else {
+            Err(format!("timeline has not advanced past {after}").into())
+        }
+    })
+}
+
+// Test that a manually controlled clock drives `now()`, the timestamps of
+// writes, and with them temporal filters, so that all of them can be tested
+// deterministically.
+#[test]
+fn test_manual_clock() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+    let clock = ManualClock::new(1_000_000);
+    let config = util::Config::default().with_now(clock.now_fn());
+    let server = util::start_server(config)?;
+    let mut client = server.connect(postgres::NoTls)?;
+
+    // Group commit waits for the clock to catch up with the timestamps of
+    // earlier writes, so the clock is moved ahead before each round of writes.
+    clock.set(2_000_000);
+    client.batch_execute(
+        "CREATE TABLE events (content text, insert_ts numeric, delete_ts numeric)",
+    )?;
+    client.batch_execute(
+        "CREATE MATERIALIZED VIEW valid_events AS
+        SELECT content FROM events
+        WHERE mz_logical_timestamp() >= insert_ts AND mz_logical_timestamp() < delete_ts",
+    )?;
+    let now: DateTime<Utc> = client.query_one("SELECT now()", &[])?.get(0);
+    assert_eq!(now.timestamp_millis(), 2_000_000);
+    client.batch_execute("INSERT INTO events VALUES ('a', 2000000, 2010000)")?;
+    let valid_events: Vec<String> = client
+        .query("SELECT content FROM valid_events", &[])?
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(valid_events, vec!["a"]);
+
+    // Once the clock passes the end of the validity of 'a', reads at the
+    // timestamp of the next write no longer observe it.
+    clock.set(2_020_000);
+    let now: DateTime<Utc> = client.query_one("SELECT now()", &[])?.get(0);
+    assert_eq!(now.timestamp_millis(), 2_020_000);
+    client.batch_execute("INSERT INTO events VALUES ('b', 2020000, 2030000)")?;
+    let valid_events: Vec<String> = client
+        .query("SELECT content FROM valid_events", &[])?
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(valid_events, vec!["b"]);
+
+    Ok(())
+}
+
 // Test that a query that causes a compute instance to panic will resolve
 // the panic and allow the compute instance to restart (instead of crash loop
 // forever) when a client is terminated (disconnects from the server) instead
@@ -1512,6 +1606,562 @@ fn test_alter_system_invalid_param() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// Test that connections beyond `max_connections` are rejected, except that the
+// system user may use the connections reserved for superusers.
+#[test]
+fn test_connection_limits() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(SYSTEM_USER)
+        .connect(postgres::NoTls)?;
+    mz_client.batch_execute("ALTER SYSTEM SET max_connections TO 3")?;
+    mz_client.batch_execute("ALTER SYSTEM SET superuser_reserved_connections TO 1")?;
+
+    // One regular connection fits alongside `mz_client`, but the last
+    // connection is reserved.
+    let _client = server.connect(postgres::NoTls)?;
+    let err = server.connect(postgres::NoTls).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("remaining connection slots are reserved for superusers"));
+
+    // The system user may use the reserved connection, but not more.
+    let _mz_client2 = server
+        .pg_config_internal()
+        .user(SYSTEM_USER)
+        .connect(postgres::NoTls)?;
+    let err = server
+        .pg_config_internal()
+        .user(SYSTEM_USER)
+        .connect(postgres::NoTls)
+        .unwrap_err();
+    assert!(err.to_string().contains("too many connections"));
+
+    mz_client.batch_execute("ALTER SYSTEM RESET max_connections")?;
+    server.connect(postgres::NoTls)?;
+
+    Ok(())
+}
+
+// Test that active sessions are listed in `mz_sessions`.
+#[test]
+fn test_mz_sessions() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client = server.connect(postgres::NoTls)?;
+    let app_client = server
+        .pg_config()
+        .application_name("sessions-test")
+        .connect(postgres::NoTls)?;
+
+    let query = "SELECT \"user\", client_addr FROM mz_sessions
+        WHERE application_name = 'sessions-test'";
+    Retry::default()
+        .retry(|_| {
+            let rows = client.query(query, &[]).unwrap();
+            match rows.as_slice() {
+                [row] => Ok((row.get::<_, String>(0), row.get::<_, Option<String>>(1))),
+                _ => Err(format!("expected one session, got {}", rows.len())),
+            }
+        })
+        .map(|(user, client_addr)| {
+            assert_eq!(user, "materialize");
+            assert_eq!(client_addr.as_deref(), Some("127.0.0.1"));
+        })?;
+
+    // The session is removed once its connection closes.
+    drop(app_client);
+    Retry::default().retry(|_| {
+        let rows = client.query(query, &[]).unwrap();
+        if rows.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("expected no sessions, got {}", rows.len()))
+        }
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn test_mz_group_commit_statistics() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+    let mut client = server.connect(postgres::NoTls)?;
+
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("INSERT INTO t VALUES (1), (2), (3)")?;
+
+    // The statistics reflect the `INSERT` once a group commit after it has
+    // written them.
+    Retry::default().retry(|_| {
+        let row = client
+            .query_one(
+                "SELECT transactions >= 1 AND updates >= 3 FROM mz_group_commit_statistics",
+                &[],
+            )
+            .unwrap();
+        if row.get::<_, bool>(0) {
+            Ok(())
+        } else {
+            Err("group commit statistics do not reflect the insert")
+        }
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn test_mz_group_commits() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+    let mut client = server.connect(postgres::NoTls)?;
+
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("INSERT INTO t VALUES (1), (2), (3)")?;
+
+    // The group commit of the `INSERT` is written to the tables by a later
+    // group commit.
+    Retry::default().retry(|_| {
+        let count: i64 = client
+            .query_one(
+                "SELECT count(*)
+                 FROM mz_group_commits gc
+                 JOIN mz_group_commit_table_updates tu ON gc.id = tu.group_commit_id
+                 JOIN mz_tables t ON tu.table_id = t.id
+                 WHERE t.name = 't' AND gc.transactions = 1 AND tu.updates = 3",
+                &[],
+            )
+            .unwrap()
+            .get(0);
+        if count == 1 {
+            Ok(())
+        } else {
+            Err("mz_group_commits does not reflect the insert")
+        }
+    })?;
+
+    Ok(())
+}
+
+// Test that `group_commit_interval` batches concurrent writes into one group
+// commit, and that `max_group_commit_rows` splits them up again.
+#[test]
+fn test_group_commit_parameters() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(SYSTEM_USER)
+        .connect(postgres::NoTls)?;
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("CREATE TABLE u (a int)")?;
+
+    // Runs `sql` from three sessions at once.
+    let insert_concurrently = |sql: &'static str| -> Result<(), Box<dyn Error>> {
+        let mut clients = Vec::new();
+        for _ in 0..3 {
+            clients.push(server.connect(postgres::NoTls)?);
+        }
+        let handles: Vec<_> = clients
+            .into_iter()
+            .map(|mut client| thread::spawn(move || client.batch_execute(sql).unwrap()))
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        Ok(())
+    };
+
+    // Returns the largest number of transactions and the total number of
+    // updates that group commits wrote to `table`, once all `updates` are
+    // reflected in `mz_group_commits`.
+    let mut group_commits = |table: &str, updates: i64| {
+        Retry::default().retry(|_| {
+            let row = client
+                .query_one(
+                    "SELECT max(gc.transactions)::int8, sum(tu.updates)::int8
+                     FROM mz_group_commits gc
+                     JOIN mz_group_commit_table_updates tu ON gc.id = tu.group_commit_id
+                     JOIN mz_tables t ON tu.table_id = t.id
+                     WHERE t.name = $1",
+                    &[&table],
+                )
+                .unwrap();
+            let max_transactions: Option<i64> = row.get(0);
+            let total_updates: Option<i64> = row.get(1);
+            match (max_transactions, total_updates) {
+                (Some(max_transactions), Some(total)) if total == updates => Ok(max_transactions),
+                _ => Err(format!(
+                    "mz_group_commits does not reflect the writes to {table}"
+                )),
+            }
+        })
+    };
+
+    // Writes submitted within the interval are committed together.
+    mz_client.batch_execute("ALTER SYSTEM SET group_commit_interval TO '2s'")?;
+    insert_concurrently("INSERT INTO t VALUES (1)")?;
+    assert!(group_commits("t", 3)? > 1);
+
+    // No group commit writes more than `max_group_commit_rows` rows, unless a
+    // single transaction is larger than that.
+    mz_client.batch_execute("ALTER SYSTEM SET max_group_commit_rows TO 2")?;
+    insert_concurrently("INSERT INTO u VALUES (1), (2)")?;
+    assert_eq!(group_commits("u", 6)?, 1);
+
+    Ok(())
+}
+
+// Test that writes that are larger than a single append chunk are committed
+// in full.
+#[test]
+fn test_large_insert() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+    let mut client = server.connect(postgres::NoTls)?;
+
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("INSERT INTO t SELECT generate_series(1, 25000)")?;
+    let row = client.query_one("SELECT count(*), sum(a) FROM t", &[])?;
+    assert_eq!(row.get::<_, i64>(0), 25_000);
+    assert_eq!(row.get::<_, i64>(1), 312_512_500);
+
+    Ok(())
+}
+
+// Test that sessions that are idle in a transaction for longer than
+// `idle_in_transaction_session_timeout` are terminated.
+#[test]
+fn test_idle_in_transaction_session_timeout() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("SET idle_in_transaction_session_timeout = '1s'")?;
+
+    // Sessions that are idle outside of a transaction are not terminated.
+    thread::sleep(Duration::from_secs(2));
+    client.batch_execute("BEGIN; SELECT * FROM t")?;
+
+    thread::sleep(Duration::from_secs(2));
+    assert!(client.batch_execute("SELECT * FROM t").is_err());
+    assert!(client.is_closed());
+
+    // The terminated session's transaction no longer blocks other sessions.
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("INSERT INTO t VALUES (1)")?;
+
+    // The timeout is disabled by default.
+    client.batch_execute("BEGIN; SELECT * FROM t")?;
+    thread::sleep(Duration::from_secs(2));
+    client.batch_execute("SELECT * FROM t")?;
+    client.batch_execute("COMMIT")?;
+
+    Ok(())
+}
+
+// Test that writes that wait for the write lock for longer than `lock_timeout`
+// fail.
+#[test]
+fn test_lock_timeout() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client_a = server.connect(postgres::NoTls)?;
+    let mut client_b = server.connect(postgres::NoTls)?;
+    client_a.batch_execute("CREATE TABLE t (a int)")?;
+    client_a.batch_execute("CREATE TABLE u (a int)")?;
+    client_a.batch_execute("INSERT INTO t VALUES (1)")?;
+
+    // Hold the write lock with a slow `UPDATE`.
+    let slow_update = thread::spawn(move || {
+        client_a
+            .batch_execute("UPDATE t SET a = a + 1 WHERE mz_internal.mz_sleep(a * 3) IS NULL")
+            .unwrap();
+    });
+    thread::sleep(Duration::from_secs(1));
+
+    client_b.batch_execute("SET lock_timeout = '100ms'")?;
+    let err = client_b
+        .batch_execute("UPDATE t SET a = a + 10")
+        .unwrap_db_error();
+    assert_eq!(err.message(), "canceling statement due to lock timeout");
+
+    // Blind writes do not wait for the write lock before they execute, so they
+    // are not subject to `lock_timeout`.
+    let a: i32 = client_b
+        .query_one("INSERT INTO u VALUES (5) RETURNING a", &[])?
+        .get(0);
+    assert_eq!(a, 5);
+
+    // Once the lock is released, writes succeed again.
+    slow_update.join().unwrap();
+    client_b.batch_execute("UPDATE t SET a = a + 10")?;
+    let a: i32 = client_b.query_one("SELECT a FROM t", &[])?.get(0);
+    assert_eq!(a, 12);
+
+    Ok(())
+}
+
+// Test that operations waiting for the write lock are granted it in the order in
+// which they started waiting.
+#[test]
+fn test_write_lock_fairness() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("INSERT INTO t VALUES (1)")?;
+
+    // Hold the write lock with a slow `UPDATE`.
+    let mut slow_client = server.connect(postgres::NoTls)?;
+    let mut handles = vec![thread::spawn(move || {
+        slow_client
+            .batch_execute("UPDATE t SET a = a + 1 WHERE mz_internal.mz_sleep(a * 2) IS NULL")
+            .unwrap();
+    })];
+    thread::sleep(Duration::from_millis(500));
+
+    // Queue up updates behind it, each of which appends a digit to `a`.
+    for digit in 3..6 {
+        let mut client = server.connect(postgres::NoTls)?;
+        handles.push(thread::spawn(move || {
+            client
+                .batch_execute(&format!("UPDATE t SET a = a * 10 + {digit}"))
+                .unwrap();
+        }));
+        thread::sleep(Duration::from_millis(200));
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let a: i32 = client.query_one("SELECT a FROM t", &[])?.get(0);
+    assert_eq!(a, 2345);
+
+    Ok(())
+}
+
+// Test that the lock timeout of an earlier deferral of a session does not
+// cancel a later deferral of the same session.
+#[test]
+fn test_lock_timeout_per_deferral() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("CREATE TABLE slow (a int)")?;
+    client.batch_execute("INSERT INTO t VALUES (1)")?;
+    client.batch_execute("INSERT INTO slow VALUES (1)")?;
+    client.batch_execute("SET lock_timeout = '1500ms'")?;
+
+    // Holds the write lock for a second.
+    let hold_write_lock = || -> Result<_, Box<dyn Error>> {
+        let mut slow_client = server.connect(postgres::NoTls)?;
+        let handle = thread::spawn(move || {
+            slow_client
+                .batch_execute("UPDATE slow SET a = a WHERE mz_internal.mz_sleep(a) IS NULL")
+                .unwrap();
+        });
+        thread::sleep(Duration::from_millis(200));
+        Ok(handle)
+    };
+
+    // The first deferral is granted the lock before its timeout expires.
+    let holder = hold_write_lock()?;
+    client.batch_execute("UPDATE t SET a = a + 1")?;
+    holder.join().unwrap();
+
+    // The second deferral is still waiting when the timeout of the first one
+    // would have expired, but is granted the lock before its own timeout does.
+    let holder = hold_write_lock()?;
+    client.batch_execute("UPDATE t SET a = a + 1")?;
+    holder.join().unwrap();
+
+    let a: i32 = client.query_one("SELECT a FROM t", &[])?.get(0);
+    assert_eq!(a, 3);
+
+    Ok(())
+}
+
+// Test that a read in a write transaction holds the write lock until the
+// transaction ends, so that no other write can commit in between.
+#[test]
+fn test_write_transaction_read_holds_write_lock() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client_a = server.connect(postgres::NoTls)?;
+    let mut client_b = server.connect(postgres::NoTls)?;
+    let mut client_c = server.connect(postgres::NoTls)?;
+    client_a.batch_execute("CREATE TABLE t (a int)")?;
+    client_a.batch_execute("INSERT INTO t VALUES (1)")?;
+
+    client_a.batch_execute("BEGIN")?;
+    client_a.batch_execute("INSERT INTO t VALUES (2)")?;
+    let count: i64 = client_a.query_one("SELECT count(*) FROM t", &[])?.get(0);
+    assert_eq!(count, 2);
+
+    // Writes that read wait for the write lock.
+    client_b.batch_execute("SET lock_timeout = '100ms'")?;
+    let err = client_b
+        .batch_execute("UPDATE t SET a = a + 10")
+        .unwrap_db_error();
+    assert_eq!(err.message(), "canceling statement due to lock timeout");
+
+    // Blind writes are not committed until the transaction ends.
+    let blind_insert = thread::spawn(move || {
+        client_b.batch_execute("INSERT INTO t VALUES (3)").unwrap();
+    });
+    thread::sleep(Duration::from_secs(1));
+    let count: i64 = client_c.query_one("SELECT count(*) FROM t", &[])?.get(0);
+    assert_eq!(count, 1);
+
+    client_a.batch_execute("COMMIT")?;
+    blind_insert.join().unwrap();
+    let count: i64 = client_c.query_one("SELECT count(*) FROM t", &[])?.get(0);
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+// Test that a group commit that waited for the write lock commits once the
+// holder releases the lock without writing.
+#[test]
+fn test_deferred_group_commit() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client_a = server.connect(postgres::NoTls)?;
+    let mut client_b = server.connect(postgres::NoTls)?;
+    let mut client_c = server.connect(postgres::NoTls)?;
+    client_a.batch_execute("CREATE TABLE t (a int)")?;
+
+    // Hold the write lock with a read in a write transaction.
+    client_a.batch_execute("BEGIN")?;
+    client_a.batch_execute("INSERT INTO t VALUES (1)")?;
+    client_a.batch_execute("SELECT * FROM t")?;
+
+    // The group commit of a blind write waits for the lock.
+    client_b.batch_execute("SET statement_timeout = '10s'")?;
+    let blind_insert = thread::spawn(move || client_b.batch_execute("INSERT INTO t VALUES (2)"));
+    thread::sleep(Duration::from_secs(1));
+
+    // Releasing the lock without writing grants it to the group commit.
+    client_a.batch_execute("ROLLBACK")?;
+    blind_insert.join().unwrap()?;
+    let count: i64 = client_c.query_one("SELECT count(*) FROM t", &[])?.get(0);
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+// Test that the writes of a transaction that writes to multiple tables are
+// observed either all at once or not at all.
+#[test]
+fn test_multi_table_write_transaction() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client_write = server.connect(postgres::NoTls)?;
+    let mut client_read = server.connect(postgres::NoTls)?;
+    client_write.batch_execute("CREATE TABLE foo (a int)")?;
+    client_write.batch_execute("CREATE TABLE bar (a int)")?;
+
+    let writer = thread::spawn(move || {
+        for i in 0..50 {
+            client_write
+                .batch_execute(&format!(
+                    "BEGIN; INSERT INTO foo VALUES ({i}); INSERT INTO bar VALUES ({i}); COMMIT;"
+                ))
+                .unwrap();
+        }
+    });
+    while !writer.is_finished() {
+        let row = client_read.query_one(
+            "SELECT (SELECT count(*) FROM foo), (SELECT count(*) FROM bar)",
+            &[],
+        )?;
+        let foo: i64 = row.get(0);
+        let bar: i64 = row.get(1);
+        assert_eq!(foo, bar);
+    }
+    writer.join().unwrap();
+
+    let row = client_read.query_one(
+        "SELECT (SELECT count(*) FROM foo), (SELECT count(*) FROM bar)",
+        &[],
+    )?;
+    assert_eq!(row.get::<_, i64>(0), 50);
+    assert_eq!(row.get::<_, i64>(1), 50);
+
+    Ok(())
+}
+
+// Test that serializable reads observe the session's prior writes and DDL
+// when `read_your_writes` is set.
+#[test]
+fn test_read_your_writes() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let config = util::Config::default();
+    let server = util::start_server(config)?;
+
+    let mut client = server.connect(postgres::NoTls)?;
+    client.batch_execute("SET transaction_isolation = 'serializable'")?;
+    client.batch_execute("SET read_your_writes = true")?;
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("CREATE MATERIALIZED VIEW v AS SELECT count(*) AS c FROM t")?;
+    for i in 1..=20 {
+        client.batch_execute(&format!("INSERT INTO t VALUES ({i})"))?;
+        let count: i64 = client.query_one("SELECT c FROM v", &[])?.get(0);
+        assert_eq!(count, i);
+    }
+
+    client.batch_execute("CREATE TABLE u (a int)")?;
+    let count: i64 = client
+        .query_one("SELECT count(*) FROM mz_tables WHERE name = 'u'", &[])?
+        .get(0);
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 /// Group commit will block writes until the current time has advanced. This can make
 /// performing inserts while using deterministic time difficult. This is a helper
 /// method to perform writes and advance the current time.
@@ -1720,3 +2370,40 @@ fn test_load_generator() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+// Test that a logical compaction window can be set on indexes, sources, and
+// materialized views outside of unsafe mode, but compaction cannot be disabled.
+#[test]
+fn test_logical_compaction_window() -> Result<(), Box<dyn Error>> {
+    mz_ore::test::init_logging();
+
+    let server = util::start_server(util::Config::default())?;
+    let mut client = server.connect(postgres::NoTls)?;
+
+    client.batch_execute("CREATE TABLE t (a int)")?;
+    client.batch_execute("CREATE INDEX i ON t (a) WITH (LOGICAL COMPACTION WINDOW = '1m')")?;
+    client.batch_execute(
+        "CREATE MATERIALIZED VIEW mv WITH (LOGICAL COMPACTION WINDOW = '1m') AS SELECT * FROM t",
+    )?;
+    client.batch_execute(
+        "CREATE SOURCE s FROM LOAD GENERATOR COUNTER WITH (LOGICAL COMPACTION WINDOW = '1m')",
+    )?;
+    client.batch_execute("ALTER SOURCE s SET (LOGICAL COMPACTION WINDOW = '2m')")?;
+    client.batch_execute("ALTER INDEX i SET (LOGICAL COMPACTION WINDOW = '2m')")?;
+
+    for statement in [
+        "CREATE INDEX i2 ON t (a) WITH (LOGICAL COMPACTION WINDOW = 0)",
+        "CREATE MATERIALIZED VIEW mv2 WITH (LOGICAL COMPACTION WINDOW = 0) AS SELECT * FROM t",
+        "ALTER SOURCE s SET (LOGICAL COMPACTION WINDOW = 0)",
+        "ALTER INDEX i SET (LOGICAL COMPACTION WINDOW = 0)",
+    ] {
+        let err = client.batch_execute(statement).unwrap_db_error();
+        assert_eq!(
+            err.message(),
+            "LOGICAL COMPACTION WINDOW = 0 is unsupported",
+            "{statement}"
+        );
+    }
+
+    Ok(())
+}
@@ -170,6 +170,7 @@ pub fn start_server(config: Config) -> Result<Server, anyhow::Error> {
     let persist_clients = Arc::new(Mutex::new(persist_clients));
     let inner = runtime.block_on(mz_environmentd::serve(mz_environmentd::Config {
         adapter_stash_url,
+        timestamp_oracle_url: None,
         controller: ControllerConfig {
             build_info: &mz_environmentd::BUILD_INFO,
             orchestrator: Arc::clone(&orchestrator) as Arc<dyn Orchestrator>,
@@ -139,10 +139,12 @@ impl fmt::Display for SourceInstanceId {
 
 /// Unique identifier for each part of a whole source.
 ///     Kafka -> partition
+///     KafkaTopic -> topic and partition, for sources that read several topics
 ///     None -> sources that have no notion of partitioning (e.g file sources)
 #[derive(Arbitrary, Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum PartitionId {
     Kafka(i32),
+    KafkaTopic { topic: String, partition: i32 },
     None,
 }
 
@@ -150,6 +152,7 @@ impl fmt::Display for PartitionId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             PartitionId::Kafka(id) => write!(f, "{}", id),
+            PartitionId::KafkaTopic { topic, partition } => write!(f, "{}:{}", topic, partition),
             PartitionId::None => write!(f, "none"),
         }
     }
@@ -170,6 +173,15 @@ impl FromStr for PartitionId {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "none" => Ok(PartitionId::None),
+            // Kafka topic names cannot contain colons, so the last one
+            // separates the topic from the partition.
+            s if s.contains(':') => {
+                let (topic, partition) = s.rsplit_once(':').unwrap();
+                Ok(PartitionId::KafkaTopic {
+                    topic: topic.to_string(),
+                    partition: partition.parse()?,
+                })
+            }
             s => {
                 let val: i32 = s.parse()?;
                 Ok(PartitionId::Kafka(val))
@@ -184,6 +196,12 @@ impl RustType<ProtoPartitionId> for PartitionId {
         ProtoPartitionId {
             kind: Some(match self {
                 PartitionId::Kafka(x) => Kafka(*x),
+                PartitionId::KafkaTopic { topic, partition } => {
+                    KafkaTopic(ProtoKafkaTopicPartition {
+                        topic: topic.clone(),
+                        partition: *partition,
+                    })
+                }
                 PartitionId::None => None(()),
             }),
         }
@@ -193,6 +211,10 @@ impl RustType<ProtoPartitionId> for PartitionId {
         use proto_partition_id::Kind::*;
         match proto.kind {
             Option::Some(Kafka(x)) => Ok(PartitionId::Kafka(x)),
+            Option::Some(KafkaTopic(x)) => Ok(PartitionId::KafkaTopic {
+                topic: x.topic,
+                partition: x.partition,
+            }),
             Option::Some(None(_)) => Ok(PartitionId::None),
             Option::None => Err(TryFromProtoError::missing_field("ProtoPartitionId::kind")),
         }
@@ -1681,6 +1681,15 @@ pub mod plan {
                 }
             }
 
+            // The plan is only evaluated from here on, so replace disjunctions
+            // of literal equalities with lookups in pre-built lists.
+            for (_position, predicate) in mfp.predicates.iter_mut() {
+                predicate.rewrite_in_lists();
+            }
+            for expr in mfp.expressions.iter_mut() {
+                expr.rewrite_in_lists();
+            }
+
             Ok(Self {
                 mfp: SafeMfpPlan { mfp },
                 lower_bounds,
@@ -379,9 +379,23 @@ impl MirRelationExpr {
                 }
                 result
             }
-            // Iterating and cloning types inside the flat_map() avoids allocating Vec<>,
-            // as clones are directly added to column_types Vec<>.
-            Join { .. } => input_types.flat_map(|cols| cols.to_owned()).collect(),
+            Join { equivalences, .. } => {
+                // Iterating and cloning types inside the flat_map() avoids allocating Vec<>,
+                // as clones are directly added to column_types Vec<>.
+                let mut result: Vec<_> = input_types.flat_map(|cols| cols.to_owned()).collect();
+                // Null values only match other null values, so if any expression in an
+                // equivalence class is non-nullable, all columns in the class are as well.
+                for equivalence in equivalences {
+                    if equivalence.iter().any(|e| !e.typ(&result).nullable) {
+                        for expr in equivalence {
+                            if let MirScalarExpr::Column(c) = expr {
+                                result[*c].nullable = false;
+                            }
+                        }
+                    }
+                }
+                result
+            }
             Reduce {
                 group_key,
                 aggregates,
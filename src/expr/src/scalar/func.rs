@@ -41,7 +41,9 @@ use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
 use mz_repr::adt::regex::any_regex;
 use mz_repr::chrono::any_naive_datetime;
-use mz_repr::{strconv, ColumnName, ColumnType, Datum, DatumType, Row, RowArena, ScalarType};
+use mz_repr::{
+    arb_datum, strconv, ColumnName, ColumnType, Datum, DatumType, Row, RowArena, ScalarType,
+};
 
 use crate::scalar::func::format::DateTimeFormat;
 use crate::scalar::{
@@ -3703,6 +3705,7 @@ derive_unary!(
     IsNull,
     IsTrue,
     IsFalse,
+    IsInList,
     BitNotInt16,
     BitNotInt32,
     BitNotInt64,
@@ -3980,7 +3983,10 @@ impl UnaryFunc {
     /// Returns true if the function could introduce an error on non-error input.
     pub fn could_error(&self) -> bool {
         match self {
-            UnaryFunc::IsNull(_) | UnaryFunc::CastVarCharToString(_) | UnaryFunc::Not(_) => false,
+            UnaryFunc::IsNull(_)
+            | UnaryFunc::IsInList(_)
+            | UnaryFunc::CastVarCharToString(_)
+            | UnaryFunc::Not(_) => false,
             _ => true,
         }
     }
@@ -4003,6 +4009,13 @@ impl Arbitrary for UnaryFunc {
             IsNull::arbitrary().prop_map_into(),
             IsTrue::arbitrary().prop_map_into(),
             IsFalse::arbitrary().prop_map_into(),
+            prop::collection::vec(arb_datum(), 0..5).prop_map(|datums| {
+                let values = datums
+                    .iter()
+                    .map(|d| Row::pack_slice(&[d.into()]))
+                    .collect();
+                UnaryFunc::IsInList(IsInList::new(values))
+            }),
             BitNotInt16::arbitrary().prop_map_into(),
             BitNotInt32::arbitrary().prop_map_into(),
             BitNotInt64::arbitrary().prop_map_into(),
@@ -4313,6 +4326,10 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::IsNull(_) => IsNull(()),
             UnaryFunc::IsTrue(_) => IsTrue(()),
             UnaryFunc::IsFalse(_) => IsFalse(()),
+            UnaryFunc::IsInList(func) => IsInList(ProtoIsInList {
+                values: func.values.into_proto(),
+                contains_null: func.contains_null,
+            }),
             UnaryFunc::BitNotInt16(_) => BitNotInt16(()),
             UnaryFunc::BitNotInt32(_) => BitNotInt32(()),
             UnaryFunc::BitNotInt64(_) => BitNotInt64(()),
@@ -4620,6 +4637,11 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 IsNull(()) => Ok(impls::IsNull.into()),
                 IsTrue(()) => Ok(impls::IsTrue.into()),
                 IsFalse(()) => Ok(impls::IsFalse.into()),
+                IsInList(list) => Ok(impls::IsInList {
+                    values: list.values.into_rust()?,
+                    contains_null: list.contains_null,
+                }
+                .into()),
                 BitNotInt16(()) => Ok(impls::BitNotInt16.into()),
                 BitNotInt32(()) => Ok(impls::BitNotInt32.into()),
                 BitNotInt64(()) => Ok(impls::BitNotInt64.into()),
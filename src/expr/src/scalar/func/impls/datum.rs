@@ -7,9 +7,16 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use mz_repr::{Datum, DatumList};
+use std::fmt;
 
-use crate::EvalError;
+use serde::{Deserialize, Serialize};
+
+use mz_lowertest::MzReflect;
+use mz_ore::str::separated;
+use mz_repr::{ColumnType, Datum, DatumList, Row, RowArena, ScalarType};
+
+use crate::scalar::func::LazyUnaryFunc;
+use crate::{EvalError, MirScalarExpr};
 
 sqlfunc!(
     #[sqlname = "isnull"]
@@ -52,3 +59,86 @@ sqlfunc!(
         i32::try_from(sz).or(Err(EvalError::Int32OutOfRange))
     }
 );
+
+/// Tests whether its input is equal to any of a list of literal values.
+///
+/// This is equivalent to `a = v1 OR a = v2 OR ...`, but the input is looked
+/// up in the values with a binary search instead of being compared against
+/// each value in turn.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct IsInList {
+    /// The non-null values in the list, each packed into its own row, sorted
+    /// by datum and deduplicated.
+    #[mzreflect(ignore)]
+    pub values: Vec<Row>,
+    /// Whether the list contained a null value.
+    pub contains_null: bool,
+}
+
+impl IsInList {
+    /// Constructs an `IsInList` from a list of single-datum rows.
+    pub fn new(values: Vec<Row>) -> IsInList {
+        let contains_null = values.iter().any(|v| v.unpack_first().is_null());
+        let mut values: Vec<_> = values
+            .into_iter()
+            .filter(|v| !v.unpack_first().is_null())
+            .collect();
+        values.sort_by(|a, b| a.unpack_first().cmp(&b.unpack_first()));
+        values.dedup();
+        IsInList {
+            values,
+            contains_null,
+        }
+    }
+}
+
+impl LazyUnaryFunc for IsInList {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        if self
+            .values
+            .binary_search_by(|v| v.unpack_first().cmp(&a))
+            .is_ok()
+        {
+            Ok(Datum::True)
+        } else if self.contains_null {
+            // Like `a = NULL`, a miss against a list containing a null is
+            // unknown rather than false.
+            Ok(Datum::Null)
+        } else {
+            Ok(Datum::False)
+        }
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Bool.nullable(input_type.nullable || self.contains_null)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        self.contains_null
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for IsInList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let null = self.contains_null.then(|| Datum::Null);
+        let values = self.values.iter().map(|v| v.unpack_first()).chain(null);
+        write!(f, "in_list[{}]", separated(", ", values))
+    }
+}
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use itertools::Itertools;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 use std::mem;
 
@@ -367,6 +367,68 @@ impl MirScalarExpr {
         None
     }
 
+    /// Rewrites disjunctions of two or more equalities between the same
+    /// expression and literals, like `a = 1 OR a = 2 OR a = 3`, into a single
+    /// lookup of the expression in a pre-built list of the literals.
+    ///
+    /// The lookup is cheaper to evaluate than the disjunction, but it is
+    /// opaque to the optimizer, which e.g. recognizes literal equalities when
+    /// selecting indexes. This should therefore only be applied to
+    /// expressions that are about to be rendered.
+    pub fn rewrite_in_lists(&mut self) {
+        #[allow(deprecated)]
+        self.visit_mut_post_nolimit(&mut |e| {
+            if let MirScalarExpr::CallVariadic {
+                func: VariadicFunc::Or,
+                exprs,
+            } = e
+            {
+                // Collect the literals that each expression is compared to.
+                let mut lists = BTreeMap::<_, Vec<_>>::new();
+                for expr in exprs.iter() {
+                    if let Some(lhs) = expr.any_expr_eq_literal() {
+                        if let Some(datum) = expr.expr_eq_literal(&lhs) {
+                            let literal = Row::pack_slice(&[datum]);
+                            lists.entry(lhs).or_default().push(literal);
+                        }
+                    }
+                }
+                lists.retain(|_, literals| literals.len() > 1);
+                if lists.is_empty() {
+                    return;
+                }
+                // Replace the first equality of each list with the lookup,
+                // and drop the remaining equalities.
+                let mut rewritten = Vec::new();
+                for expr in mem::take(exprs) {
+                    let lhs = match expr.any_expr_eq_literal() {
+                        Some(lhs) if expr.expr_eq_literal(&lhs).is_some() => lhs,
+                        _ => {
+                            rewritten.push(expr);
+                            continue;
+                        }
+                    };
+                    match lists.get_mut(&lhs) {
+                        Some(literals) if literals.is_empty() => (),
+                        Some(literals) => {
+                            let func = func::IsInList::new(mem::take(literals));
+                            rewritten.push(lhs.call_unary(UnaryFunc::IsInList(func)));
+                        }
+                        None => rewritten.push(expr),
+                    }
+                }
+                *e = if rewritten.len() == 1 {
+                    rewritten.pop().unwrap()
+                } else {
+                    MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::Or,
+                        exprs: rewritten,
+                    }
+                };
+            }
+        });
+    }
+
     /// Rewrites column indices with their value in `permutation`.
     ///
     /// This method is applicable even when `permutation` is not a
@@ -977,6 +1039,23 @@ impl MirScalarExpr {
                                 }
                                 _ => unreachable!(),
                             }
+                        } else if is_if_on(then, cond) {
+                            // Within `then`, `cond` is known to be true, so a nested
+                            // conditional on the same expression takes its `then` branch.
+                            let inner_then = match &mut **then {
+                                MirScalarExpr::If { then, .. } => then.take(),
+                                _ => unreachable!(),
+                            };
+                            **then = inner_then;
+                        } else if is_if_on(els, cond) {
+                            // Within `els`, `cond` is known to be false or null, so a
+                            // nested conditional on the same expression takes its `els`
+                            // branch.
+                            let inner_els = match &mut **els {
+                                MirScalarExpr::If { els, .. } => els.take(),
+                                _ => unreachable!(),
+                            };
+                            **els = inner_els;
                         } else if then == els {
                             *e = then.take();
                         } else if then.is_literal_ok() && els.is_literal_ok() {
@@ -1029,6 +1108,11 @@ impl MirScalarExpr {
             );
         }
 
+        /// Whether `expr` is a conditional on exactly `cond`.
+        fn is_if_on(expr: &MirScalarExpr, cond: &MirScalarExpr) -> bool {
+            matches!(expr, MirScalarExpr::If { cond: inner, .. } if **inner == *cond)
+        }
+
         /* #region `reduce_list_create_list_index_literal` and helper functions */
 
         fn list_create_type(list_create: &MirScalarExpr) -> ScalarType {
@@ -2382,6 +2466,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rewrite_in_lists() {
+        let col = MirScalarExpr::Column;
+        let lit = |i| MirScalarExpr::literal_ok(Datum::Int64(i), ScalarType::Int64);
+        let null = || MirScalarExpr::literal_null(ScalarType::Int64);
+        let eq = |a: MirScalarExpr, b| a.call_binary(b, BinaryFunc::Eq);
+        let or = |exprs| MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs,
+        };
+        let in_list = |a: MirScalarExpr, values: &[i64]| {
+            let values = values
+                .iter()
+                .map(|i| Row::pack_slice(&[Datum::Int64(*i)]))
+                .collect();
+            a.call_unary(UnaryFunc::IsInList(func::IsInList::new(values)))
+        };
+
+        struct TestCase {
+            input: MirScalarExpr,
+            output: MirScalarExpr,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                input: or(vec![
+                    eq(col(0), lit(3)),
+                    eq(col(1), lit(1)),
+                    eq(lit(1), col(0)),
+                ]),
+                output: or(vec![in_list(col(0), &[1, 3]), eq(col(1), lit(1))]),
+            },
+            TestCase {
+                input: or(vec![
+                    eq(col(0), lit(2)),
+                    eq(col(0), lit(1)),
+                    eq(col(0), lit(2)),
+                ]),
+                output: in_list(col(0), &[1, 2]),
+            },
+            TestCase {
+                input: or(vec![eq(col(0), lit(1)), eq(col(0), null())]),
+                output: col(0).call_unary(UnaryFunc::IsInList(func::IsInList::new(vec![
+                    Row::pack_slice(&[Datum::Int64(1)]),
+                    Row::pack_slice(&[Datum::Null]),
+                ]))),
+            },
+            TestCase {
+                input: or(vec![eq(col(0), lit(1)), eq(col(1), lit(1))]),
+                output: or(vec![eq(col(0), lit(1)), eq(col(1), lit(1))]),
+            },
+        ];
+
+        let datums = [
+            Datum::Int64(1),
+            Datum::Int64(2),
+            Datum::Int64(3),
+            Datum::Null,
+        ];
+        for tc in test_cases {
+            let mut actual = tc.input.clone();
+            actual.rewrite_in_lists();
+            assert!(
+                actual == tc.output,
+                "input: {}\nactual: {}\nexpected: {}",
+                tc.input,
+                actual,
+                tc.output
+            );
+            // The rewritten expression must evaluate identically.
+            for a in datums {
+                for b in datums {
+                    let arena = RowArena::new();
+                    assert_eq!(
+                        tc.input.eval(&[a, b], &arena),
+                        actual.eval(&[a, b], &arena),
+                        "input: {}, datums: ({}, {})",
+                        tc.input,
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn mir_scalar_expr_protobuf_roundtrip(expect in any::<MirScalarExpr>()) {
@@ -21,7 +21,10 @@ pub use self::encode::{
     encode_datums_as_avro, encode_debezium_transaction_unchecked, get_debezium_transaction_schema,
     AvroEncoder, AvroSchemaGenerator,
 };
-pub use self::schema::{parse_schema, schema_to_relationdesc, ConfluentAvroResolver};
+pub use self::schema::{
+    parse_schema, projected_schema_to_relationdesc, record_field_indexes, schema_to_relationdesc,
+    ConfluentAvroResolver,
+};
 
 fn is_null(schema: &SchemaPieceOrNamed) -> bool {
     matches!(schema, SchemaPieceOrNamed::Piece(SchemaPiece::Null))
@@ -72,6 +75,142 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn projected_record() -> anyhow::Result<()> {
+        let schema = r#"{
+            "type": "record",
+            "name": "test",
+            "fields": [
+                { "name": "f1", "type": "int" },
+                { "name": "f2", "type": "string" },
+                { "name": "f3", "type": ["int", "string"] }
+            ]
+        }"#;
+        let schema = parse_schema(schema)?;
+
+        let projection = record_field_indexes(&schema, &["f3".into(), "f1".into()])?;
+        assert_eq!(projection, vec![0, 2]);
+
+        // The essential union in `f3` still expands to one column per variant.
+        let desc = projected_schema_to_relationdesc(schema, &projection)?;
+        let expected_desc = RelationDesc::empty()
+            .with_column("f1", ScalarType::Int32.nullable(false))
+            .with_column("f31", ScalarType::Int32.nullable(true))
+            .with_column("f32", ScalarType::String.nullable(true));
+
+        assert_eq!(desc, expected_desc);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_record() -> anyhow::Result<()> {
+        let schema = r#"{
+            "type": "record",
+            "name": "test",
+            "fields": [
+                { "name": "f1", "type": "int" },
+                {
+                    "name": "f2",
+                    "type": {
+                        "type": "record",
+                        "name": "inner",
+                        "fields": [
+                            { "name": "a", "type": "string" },
+                            { "name": "b", "type": ["null", "long"] }
+                        ]
+                    }
+                }
+            ]
+        }"#;
+
+        let desc = schema_to_relationdesc(parse_schema(schema)?)?;
+        let expected_desc = RelationDesc::empty()
+            .with_column("f1", ScalarType::Int32.nullable(false))
+            .with_column(
+                "f2",
+                ScalarType::Record {
+                    fields: vec![
+                        ("a".into(), ScalarType::String.nullable(false)),
+                        ("b".into(), ScalarType::Int64.nullable(true)),
+                    ],
+                    custom_id: None,
+                }
+                .nullable(false),
+            );
+
+        assert_eq!(desc, expected_desc);
+        Ok(())
+    }
+
+    #[test]
+    fn array_of_essential_union() -> anyhow::Result<()> {
+        let schema = r#"{
+            "type": "record",
+            "name": "test",
+            "fields": [
+                {
+                    "name": "f1",
+                    "type": {
+                        "type": "array",
+                        "items": [
+                            "null",
+                            "long",
+                            { "type": "record", "name": "inner", "fields": [] }
+                        ]
+                    }
+                }
+            ]
+        }"#;
+
+        let desc = schema_to_relationdesc(parse_schema(schema)?)?;
+        let expected_desc = RelationDesc::empty().with_column(
+            "f1",
+            ScalarType::List {
+                element_type: Box::new(ScalarType::Record {
+                    fields: vec![
+                        ("long".into(), ScalarType::Int64.nullable(true)),
+                        (
+                            "inner".into(),
+                            ScalarType::Record {
+                                fields: vec![],
+                                custom_id: None,
+                            }
+                            .nullable(true),
+                        ),
+                    ],
+                    custom_id: None,
+                }),
+                custom_id: None,
+            }
+            .nullable(false),
+        );
+
+        assert_eq!(desc, expected_desc);
+        Ok(())
+    }
+
+    #[test]
+    fn recursive_record() {
+        // Records that refer to themselves, whether directly or through an
+        // array or map, cannot be represented as a SQL type.
+        for field_type in [
+            r#"["test", "null"]"#,
+            r#"{"type": "array", "items": "test"}"#,
+            r#"{"type": "map", "values": "test"}"#,
+        ] {
+            let schema = format!(
+                r#"{{
+                    "type": "record",
+                    "name": "test",
+                    "fields": [{{ "name": "f", "type": {} }}]
+                }}"#,
+                field_type
+            );
+            let err = schema_to_relationdesc(parse_schema(&schema).unwrap()).unwrap_err();
+            assert_eq!(err.to_string(), "Recursive types are not supported: .test");
+        }
+    }
+
     #[test]
     /// Test that primitive Avro Schema types are allow Datums to be correctly
     /// serialized into Avro Values.
@@ -20,8 +20,8 @@ use uuid::Uuid;
 use mz_avro::error::{DecodeError, Error as AvroError};
 use mz_avro::{
     define_unexpected, give_value, AvroArrayAccess, AvroDecode, AvroDeserializer, AvroMapAccess,
-    AvroRead, AvroRecordAccess, GeneralDeserializer, StatefulAvroDecodable, ValueDecoder,
-    ValueOrReader,
+    AvroRead, AvroRecordAccess, GeneralDeserializer, StatefulAvroDecodable, TrivialDecoder,
+    ValueDecoder, ValueOrReader,
 };
 use mz_ore::result::ResultExt;
 use mz_repr::adt::jsonb::JsonbPacker;
@@ -35,6 +35,9 @@ use crate::avro::ConfluentAvroResolver;
 pub struct Decoder {
     csr_avro: ConfluentAvroResolver,
     debug_name: String,
+    /// The indexes of the top-level record fields to decode, in ascending
+    /// order, or `None` to decode every field.
+    projection: Option<Vec<usize>>,
     buf1: Vec<u8>,
     row_buf: Row,
 }
@@ -52,7 +55,7 @@ mod tests {
 "name": "test",
 "fields": [{"name": "f1", "type": "int"}, {"name": "f2", "type": "int"}]
 }"#;
-        let mut decoder = Decoder::new(&schema, None, "Test".to_string(), false).unwrap();
+        let mut decoder = Decoder::new(&schema, None, "Test".to_string(), false, None).unwrap();
         // This is not a valid Avro blob for the given schema
         let mut bad_bytes: &[u8] = &[0];
         assert!(decoder.decode(&mut bad_bytes).await.is_err());
@@ -64,6 +67,28 @@ mod tests {
             Row::pack([Datum::Int32(0), Datum::Int32(0)])
         );
     }
+
+    #[tokio::test]
+    async fn test_projection() {
+        let schema = r#"{
+"type": "record",
+"name": "test",
+"fields": [
+    {"name": "f1", "type": "int"},
+    {"name": "f2", "type": {"type": "array", "items": "string"}},
+    {"name": "f3", "type": "string"}
+]
+}"#;
+        let mut decoder =
+            Decoder::new(&schema, None, "Test".to_string(), false, Some(vec![0, 2])).unwrap();
+        // f1 = 1, f2 = ["a", "b"], f3 = "c"
+        let mut bytes: &[u8] = &[2, 4, 2, b'a', 2, b'b', 0, 2, b'c'];
+        assert_eq!(
+            decoder.decode(&mut bytes).await.unwrap(),
+            Row::pack([Datum::Int32(1), Datum::String("c")])
+        );
+        assert!(bytes.is_empty());
+    }
 }
 
 impl Decoder {
@@ -72,18 +97,30 @@ impl Decoder {
     /// The provided schema is called the "reader schema", which is the schema
     /// that we are expecting to use to decode records. The records may indicate
     /// that they are encoded with a different schema; as long as those.
+    ///
+    /// If a `projection` is provided, only the top-level record fields at
+    /// those indexes of the reader schema are decoded into each row; the
+    /// other fields are skipped without being decoded.
     pub fn new(
         reader_schema: &str,
         ccsr_client: Option<mz_ccsr::Client>,
         debug_name: String,
         confluent_wire_format: bool,
+        projection: Option<Vec<usize>>,
     ) -> anyhow::Result<Decoder> {
         let csr_avro =
             ConfluentAvroResolver::new(reader_schema, ccsr_client, confluent_wire_format)?;
 
+        let projection = projection.map(|mut projection| {
+            projection.sort_unstable();
+            projection.dedup();
+            projection
+        });
+
         Ok(Decoder {
             csr_avro,
             debug_name,
+            projection,
             buf1: vec![],
             row_buf: Row::default(),
         })
@@ -98,15 +135,29 @@ impl Decoder {
         let mut packer = self.row_buf.packer();
         let (bytes2, resolved_schema, csr_schema_id) = self.csr_avro.resolve(bytes).await?;
         *bytes = bytes2;
-        let dec = AvroFlatDecoder {
-            packer: &mut packer,
-            buf: &mut self.buf1,
-            is_top: true,
-        };
         let dsr = GeneralDeserializer {
             schema: resolved_schema.top_node(),
         };
-        dsr.deserialize(bytes, dec).with_context(|| {
+        let result = match &self.projection {
+            None => {
+                let dec = AvroFlatDecoder {
+                    packer: &mut packer,
+                    buf: &mut self.buf1,
+                    is_top: true,
+                    is_element: false,
+                };
+                dsr.deserialize(bytes, dec)
+            }
+            Some(projection) => {
+                let dec = ProjectedRecordDecoder {
+                    packer: &mut packer,
+                    buf: &mut self.buf1,
+                    projection,
+                };
+                dsr.deserialize(bytes, dec)
+            }
+        };
+        result.with_context(|| {
             format!(
                 "unable to decode row {}",
                 match csr_schema_id {
@@ -124,6 +175,67 @@ impl Decoder {
     }
 }
 
+/// Decodes the top-level record of a message, packing only the fields at the
+/// indexes in `projection` and skipping all other fields.
+struct ProjectedRecordDecoder<'a, 'row> {
+    packer: &'a mut RowPacker<'row>,
+    buf: &'a mut Vec<u8>,
+    /// The indexes of the fields to pack, in ascending order.
+    projection: &'a [usize],
+}
+
+impl<'a, 'row> AvroDecode for ProjectedRecordDecoder<'a, 'row> {
+    type Out = ();
+    fn record<R: AvroRead, A: AvroRecordAccess<R>>(
+        self,
+        a: &mut A,
+    ) -> Result<Self::Out, AvroError> {
+        // As in `AvroFlatDecoder::record`, fields that arrive in the order we
+        // pack them are decoded directly into the row, and the others are
+        // stashed as values and packed at the end.
+        let mut next = 0;
+        let mut stash = vec![];
+        while let Some((_name, idx, f)) = a.next_field()? {
+            if self.projection.get(next) == Some(&idx) {
+                next += 1;
+                f.decode_field(AvroFlatDecoder {
+                    packer: self.packer,
+                    buf: self.buf,
+                    is_top: false,
+                    is_element: false,
+                })?;
+            } else if self.projection.binary_search(&idx).is_ok() {
+                let val = f.decode_field(ValueDecoder)?;
+                stash.push((idx, val));
+            } else {
+                f.decode_field(TrivialDecoder)?;
+            }
+        }
+        stash.sort_by_key(|(idx, _val)| *idx);
+        for (idx, val) in stash {
+            assert!(self.projection.get(next) == Some(&idx));
+            next += 1;
+            let dec = AvroFlatDecoder {
+                packer: self.packer,
+                buf: self.buf,
+                is_top: false,
+                is_element: false,
+            };
+            give_value(dec, &val)?;
+        }
+        if next != self.projection.len() {
+            return Err(AvroError::Decode(DecodeError::Custom(format!(
+                "projected field {} is missing from the record",
+                self.projection[next]
+            ))));
+        }
+        Ok(())
+    }
+    define_unexpected! {
+        union_branch, array, map, enum_variant, scalar, decimal, bytes, string, json, uuid, fixed
+    }
+}
+
 pub struct AvroStringDecoder<'a> {
     pub buf: &'a mut Vec<u8>,
 }
@@ -174,6 +286,7 @@ impl<'a, 'row> AvroDecode for OptionalRecordDecoder<'a, 'row> {
                 packer: self.packer,
                 buf: self.buf,
                 is_top: false,
+                is_element: false,
             };
             deserializer.deserialize(reader, d)?;
             Ok(true)
@@ -201,6 +314,7 @@ impl AvroDecode for RowDecoder {
             packer: &mut packer,
             buf: &mut buf_borrow,
             is_top: true,
+            is_element: false,
         };
         inner.record(a)?;
         Ok(RowWrapper(row_borrow.clone()))
@@ -230,6 +344,11 @@ pub struct AvroFlatDecoder<'a, 'row> {
     pub packer: &'a mut RowPacker<'row>,
     pub buf: &'a mut Vec<u8>,
     pub is_top: bool,
+    /// Whether this decoder produces an array element or a map value, rather
+    /// than a column or a record field. An essential union in this position
+    /// is packed as a record with one field per non-null variant, rather
+    /// than flattened into one datum per variant.
+    pub is_element: bool,
 }
 
 impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
@@ -259,6 +378,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                         packer: rp,
                         buf: &mut str_buf,
                         is_top: false,
+                        is_element: false,
                     })?;
                 } else {
                     let val = f.decode_field(ValueDecoder)?;
@@ -273,6 +393,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: rp,
                     buf: &mut str_buf,
                     is_top: false,
+                    is_element: false,
                 };
                 give_value(dec, &val)?;
             }
@@ -295,6 +416,25 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
         deserializer: D,
         reader: &'b mut R,
     ) -> Result<Self::Out, AvroError> {
+        let n_non_null_variants = n_variants - usize::from(null_variant.is_some());
+        if self.is_element && n_non_null_variants > 1 {
+            if null_variant == Some(idx) {
+                self.packer.push(Datum::Null);
+            } else {
+                let mut str_buf = std::mem::take(self.buf);
+                self.packer.push_list_with(|rp| {
+                    let dec = AvroFlatDecoder {
+                        packer: rp,
+                        buf: &mut str_buf,
+                        is_top: false,
+                        is_element: false,
+                    };
+                    dec.union_branch(idx, n_variants, null_variant, deserializer, reader)
+                })?;
+                *self.buf = str_buf;
+            }
+            return Ok(());
+        }
         if null_variant == Some(idx) {
             for _ in 0..n_variants - 1 {
                 self.packer.push(Datum::Null)
@@ -306,6 +446,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: self.packer,
                     buf: self.buf,
                     is_top: false,
+                    is_element: false,
                 };
                 if null_variant != Some(i) {
                     if i == idx {
@@ -345,6 +486,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
             }
             mz_avro::types::Scalar::Date(val) => self.packer.push(Datum::Date(val)),
             mz_avro::types::Scalar::Timestamp(val) => self.packer.push(Datum::Timestamp(val)),
+            mz_avro::types::Scalar::Time(val) => self.packer.push(Datum::Time(val)),
         }
         Ok(())
     }
@@ -486,6 +628,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: rp,
                     buf: &mut str_buf,
                     is_top: false,
+                    is_element: true,
                 };
                 if a.decode_next(next)?.is_none() {
                     break;
@@ -512,6 +655,7 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                             packer,
                             buf: &mut vec![],
                             is_top: false,
+                            is_element: true,
                         },
                         &val,
                     )?;
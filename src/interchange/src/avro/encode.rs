@@ -11,7 +11,6 @@ use std::collections::HashMap;
 use std::fmt;
 
 use byteorder::{NetworkEndian, WriteBytesExt};
-use chrono::Timelike;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use serde_json::json;
@@ -320,11 +319,7 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                     })
                 }
                 ScalarType::Date => Value::Date(datum.unwrap_date()),
-                ScalarType::Time => Value::Long({
-                    let time = datum.unwrap_time();
-                    (time.num_seconds_from_midnight() * 1_000_000) as i64
-                        + (time.nanosecond() as i64) / 1_000
-                }),
+                ScalarType::Time => Value::Time(datum.unwrap_time()),
                 ScalarType::Timestamp => Value::Timestamp(datum.unwrap_timestamp()),
                 ScalarType::TimestampTz => Value::Timestamp(datum.unwrap_timestamptz().naive_utc()),
                 // This feature isn't actually supported by the Avro Java
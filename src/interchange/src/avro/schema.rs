@@ -31,9 +31,12 @@
 //! of the field. For example, if an Essential Union in a field named `"Foo"` has schema `[int, bool]`, it will expand to the columns `"Foo1": bool, "Foo2": int`. There is an implicit constraint upheld be the source pipeline that only one such column will be non-`null` at a time
 //!
 //! When an Essential Union appears _elsewhere_ than as one of the fields of a record,
-//! there is nothing we can do, because we expect to be able to turn it into exactly one
-//! SQL type, not a series of them. Thus, in these cases, we just bail. For example, it's
-//! not possible to ingest an array or map whose element type is an Essential Union.
+//! i.e. as the element type of an array or the value type of a map, we must turn it into
+//! exactly one SQL type, not a series of them. In these cases, we use a record type with
+//! one nullable field per non-null variant, named after the variant's type (or, for named
+//! types, after the variant's name). For example, an array whose items have schema
+//! `["null", "int", "string"]` becomes a list of records with fields `"int": int` and
+//! `"string": text`. The `null` variant is represented by a `null` element.
 
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
@@ -46,7 +49,9 @@ use mz_ore::collections::CollectionExt;
 use tracing::warn;
 
 use mz_avro::error::Error as AvroError;
-use mz_avro::schema::{resolve_schemas, Schema, SchemaNode, SchemaPiece, SchemaPieceOrNamed};
+use mz_avro::schema::{
+    resolve_schemas, Schema, SchemaKind, SchemaNode, SchemaPiece, SchemaPieceOrNamed,
+};
 use mz_ore::cast::CastFrom;
 use mz_ore::retry::Retry;
 use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
@@ -66,17 +71,65 @@ pub fn schema_to_relationdesc(schema: Schema) -> Result<RelationDesc, anyhow::Er
     // we're in RelationDesc land
     Ok(RelationDesc::from_names_and_types(validate_schema_1(
         schema.top_node(),
+        None,
     )?))
 }
 
+/// Like [`schema_to_relationdesc`], but only converts the fields of the
+/// top-level record at the indexes in `projection`.
+///
+/// This is the relation that a [`Decoder`](crate::avro::Decoder) produces
+/// when it is given the same projection.
+pub fn projected_schema_to_relationdesc(
+    schema: Schema,
+    projection: &[usize],
+) -> Result<RelationDesc, anyhow::Error> {
+    Ok(RelationDesc::from_names_and_types(validate_schema_1(
+        schema.top_node(),
+        Some(projection),
+    )?))
+}
+
+/// Returns the indexes of the named fields of the top-level record of an
+/// Avro schema, in ascending order.
+pub fn record_field_indexes(schema: &Schema, names: &[String]) -> anyhow::Result<Vec<usize>> {
+    let lookup = match schema.top_node().inner {
+        SchemaPiece::Record { lookup, .. } => lookup,
+        _ => bail!("schema is not a record"),
+    };
+    let mut indexes = names
+        .iter()
+        .map(|name| {
+            lookup
+                .get(name)
+                .copied()
+                .ok_or_else(|| anyhow!("record has no field named {}", name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    indexes.sort_unstable();
+    Ok(indexes)
+}
+
 /// Convert an Avro schema to a series of columns and names, flattening the top-level record,
 /// if the top node is indeed a record.
-fn validate_schema_1(schema: SchemaNode) -> anyhow::Result<Vec<(ColumnName, ColumnType)>> {
+///
+/// If a projection is provided, only the record fields at those indexes are
+/// converted. It is an error to provide a projection for a schema that is not
+/// a record.
+fn validate_schema_1(
+    schema: SchemaNode,
+    projection: Option<&[usize]>,
+) -> anyhow::Result<Vec<(ColumnName, ColumnType)>> {
     let mut columns = vec![];
     let mut seen_avro_nodes = Default::default();
     match schema.inner {
         SchemaPiece::Record { fields, .. } => {
-            for f in fields {
+            for (i, f) in fields.iter().enumerate() {
+                if let Some(projection) = projection {
+                    if !projection.contains(&i) {
+                        continue;
+                    }
+                }
                 columns.extend(get_named_columns(
                     &mut seen_avro_nodes,
                     schema.step(&f.schema),
@@ -84,6 +137,9 @@ fn validate_schema_1(schema: SchemaNode) -> anyhow::Result<Vec<(ColumnName, Colu
                 )?);
             }
         }
+        _ if projection.is_some() => {
+            bail!("cannot project fields of a schema that is not a record")
+        }
         _ => {
             columns.extend(get_named_columns(&mut seen_avro_nodes, schema, None)?);
         }
@@ -189,19 +245,36 @@ fn validate_schema_2(
     schema: SchemaNode,
 ) -> anyhow::Result<ScalarType> {
     Ok(match schema.inner {
-        SchemaPiece::Union(_) => {
+        SchemaPiece::Union(us) => {
             let columns = get_union_columns(seen_avro_nodes, schema, None)?;
-            if columns.len() != 1 {
-                bail!("Union of more than one non-null type not valid here");
-            }
-            let (_column_name, column_type) = columns.into_element();
             // It's okay to lose the nullability information here, as it's not relevant to
             // any higher layer. This will either be included in an array or map type,
             // where all values are nullable. It can't be included as a top-level column
             // or as a record type, where nullability is actually tracked, because in
             // those cases we will have already gone through the `Union` code path in
             // `get_named_columns`.
-            column_type.scalar_type
+            if columns.len() == 1 {
+                let (_column_name, column_type) = columns.into_element();
+                column_type.scalar_type
+            } else {
+                // An Essential Union becomes a record with one field per
+                // non-null variant. Anonymous variants are unique by type,
+                // so name their fields after their type.
+                let variants = us.variants().iter().filter(|v| !is_null(v));
+                let fields = variants
+                    .zip(columns)
+                    .map(|(v, (name, typ))| match v {
+                        SchemaPieceOrNamed::Piece(piece) => {
+                            (SchemaKind::from(piece).name().into(), typ)
+                        }
+                        SchemaPieceOrNamed::Named(_) => (name, typ),
+                    })
+                    .collect();
+                ScalarType::Record {
+                    fields,
+                    custom_id: None,
+                }
+            }
         }
         SchemaPiece::Null => bail!("null outside of union types is not supported"),
         SchemaPiece::Boolean => ScalarType::Bool,
@@ -212,6 +285,8 @@ fn validate_schema_2(
         SchemaPiece::Date => ScalarType::Date,
         SchemaPiece::TimestampMilli => ScalarType::Timestamp,
         SchemaPiece::TimestampMicro => ScalarType::Timestamp,
+        SchemaPiece::TimeMilli => ScalarType::Time,
+        SchemaPiece::TimeMicro => ScalarType::Time,
         SchemaPiece::Decimal {
             precision, scale, ..
         } => {
@@ -281,10 +356,29 @@ fn validate_schema_2(
             }
             ret
         }
-        SchemaPiece::Map(inner) => ScalarType::Map {
-            value_type: Box::new(validate_schema_2(seen_avro_nodes, schema.step(inner))?),
-            custom_id: None,
-        },
+        SchemaPiece::Map(inner) => {
+            let named_idx = match inner.as_ref() {
+                SchemaPieceOrNamed::Named(idx) => Some(*idx),
+                _ => None,
+            };
+            if let Some(named_idx) = named_idx {
+                if !seen_avro_nodes.insert(named_idx) {
+                    bail!(
+                        "Recursive types are not supported: {}",
+                        inner.get_human_name(schema.root)
+                    );
+                }
+            }
+            let next_node = schema.step(inner);
+            let ret = ScalarType::Map {
+                value_type: Box::new(validate_schema_2(seen_avro_nodes, next_node)?),
+                custom_id: None,
+            };
+            if let Some(named_idx) = named_idx {
+                seen_avro_nodes.remove(&named_idx);
+            }
+            ret
+        }
 
         _ => bail!("Unsupported type in schema: {:?}", schema.inner),
     })
@@ -366,10 +460,15 @@ impl fmt::Debug for ConfluentAvroResolver {
     }
 }
 
+/// How long to retry fetching a schema from the schema registry. Fetches block
+/// the timely worker that decodes the message, so they must give up quickly.
+const SCHEMA_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Debug)]
 struct SchemaCache {
     cache: HashMap<i32, Result<Schema, AvroError>>,
     ccsr_client: mz_ccsr::Client,
+    fetch_timeout: Duration,
 }
 
 impl SchemaCache {
@@ -377,6 +476,7 @@ impl SchemaCache {
         Ok(SchemaCache {
             cache: HashMap::new(),
             ccsr_client,
+            fetch_timeout: SCHEMA_FETCH_TIMEOUT,
         })
     }
 
@@ -394,7 +494,7 @@ impl SchemaCache {
                 // next retry.
                 let ccsr_client = &self.ccsr_client;
                 let response = Retry::default()
-                    .max_duration(Duration::from_secs(30))
+                    .max_duration(self.fetch_timeout)
                     .retry_async(|state| async move {
                         let res = ccsr_client.get_schema_by_id(id).await;
                         match res {
@@ -426,3 +526,91 @@ impl SchemaCache {
         entry.as_ref().map_err(|e| anyhow::Error::new(e.clone()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use mz_avro::Schema;
+
+    use super::SchemaCache;
+
+    /// Starts a fake schema registry that fails the first `failures` requests
+    /// with an internal server error and then serves an `int` schema. Returns
+    /// a client for the registry and the number of requests it has received.
+    fn fake_schema_registry(failures: usize) -> (mz_ccsr::Client, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        thread::spawn({
+            let requests = Arc::clone(&requests);
+            move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    // Read the request headers. The requests have no body.
+                    let mut buf = vec![0; 4096];
+                    let mut len = 0;
+                    while !buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+                        match stream.read(&mut buf[len..]).unwrap() {
+                            0 => break,
+                            n => len += n,
+                        }
+                    }
+                    let (status, body) = if requests.fetch_add(1, Ordering::SeqCst) < failures {
+                        (
+                            "500 Internal Server Error",
+                            r#"{"error_code": 50001, "message": "unavailable"}"#,
+                        )
+                    } else {
+                        ("200 OK", r#"{"schema": "\"int\""}"#)
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            }
+        });
+        let client = mz_ccsr::ClientConfig::new(url.parse().unwrap())
+            .build()
+            .unwrap();
+        (client, requests)
+    }
+
+    #[tokio::test]
+    async fn test_schema_fetch_retries_transient_failures() {
+        let reader_schema: Schema = r#""int""#.parse().unwrap();
+        let (client, requests) = fake_schema_registry(2);
+        let mut cache = SchemaCache::new(client).unwrap();
+        assert!(cache.get(1, &reader_schema).await.is_ok());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // The schema is cached once it has been fetched.
+        assert!(cache.get(1, &reader_schema).await.is_ok());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_schema_fetch_gives_up() {
+        let reader_schema: Schema = r#""int""#.parse().unwrap();
+        let (client, requests) = fake_schema_registry(usize::MAX);
+        let mut cache = SchemaCache::new(client).unwrap();
+        cache.fetch_timeout = Duration::from_secs(1);
+        let err = cache.get(1, &reader_schema).await.unwrap_err();
+        assert!(err.to_string().contains("unavailable"), "{:#}", err);
+        assert!(requests.load(Ordering::SeqCst) > 1);
+
+        // Failures to fetch a schema are not cached.
+        let before = requests.load(Ordering::SeqCst);
+        assert!(cache.get(1, &reader_schema).await.is_err());
+        assert!(requests.load(Ordering::SeqCst) > before);
+    }
+}
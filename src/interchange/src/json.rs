@@ -15,6 +15,7 @@ use serde_json::{json, Map};
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
+use mz_repr::strconv;
 use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, ScalarType};
 
 use crate::encode::{column_names_and_types, Encode, TypedDatum};
@@ -133,8 +134,14 @@ impl ToJson for TypedDatum<'_> {
                     json!(datum.unwrap_uint32())
                 }
                 ScalarType::UInt64 => json!(datum.unwrap_uint64()),
-                ScalarType::Float32 => json!(datum.unwrap_float32()),
-                ScalarType::Float64 => json!(datum.unwrap_float64()),
+                ScalarType::Float32 => match datum.unwrap_float32() {
+                    f if f.is_finite() => json!(f),
+                    f => non_finite_float_json(f.into()),
+                },
+                ScalarType::Float64 => match datum.unwrap_float64() {
+                    f if f.is_finite() => json!(f),
+                    f => non_finite_float_json(f),
+                },
                 ScalarType::Numeric { .. } => {
                     json!(datum.unwrap_numeric().0.to_standard_notation_string())
                 }
@@ -224,6 +231,14 @@ impl ToJson for TypedDatum<'_> {
     }
 }
 
+/// Encodes a NaN or infinite float, which JSON numbers cannot represent, as a
+/// string spelled the same way as PostgreSQL's `to_jsonb`.
+fn non_finite_float_json(f: f64) -> serde_json::value::Value {
+    let mut s = String::new();
+    strconv::format_float64(&mut s, f);
+    serde_json::value::Value::String(s)
+}
+
 fn build_row_schema_field<F: FnMut() -> String>(
     namer: &mut F,
     names_seen: &mut HashSet<String>,
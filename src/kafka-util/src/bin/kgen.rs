@@ -147,6 +147,7 @@ impl<'a> RandomAvroGenerator<'a> {
             }
             SchemaPiece::Json => unreachable!(),
             SchemaPiece::Uuid => unreachable!(),
+            SchemaPiece::TimeMilli | SchemaPiece::TimeMicro => unreachable!(),
             SchemaPiece::Array(inner) => {
                 let len = self.array_lens.get_mut(&p).unwrap()(rng);
                 let next = node.step(&**inner);
@@ -197,7 +198,8 @@ impl<'a> RandomAvroGenerator<'a> {
             | SchemaPiece::ResolveUnionUnion { .. }
             | SchemaPiece::ResolveUnionConcrete { .. }
             | SchemaPiece::ResolveRecord { .. }
-            | SchemaPiece::ResolveEnum { .. } => {
+            | SchemaPiece::ResolveEnum { .. }
+            | SchemaPiece::ResolveEnumString { .. } => {
                 unreachable!("We never resolve schemas, so seeing this is impossible")
             }
             SchemaPiece::Record { fields, .. } => {
@@ -358,6 +360,7 @@ impl<'a> RandomAvroGenerator<'a> {
             }
             SchemaPiece::Json => unimplemented!(),
             SchemaPiece::Uuid => unimplemented!(),
+            SchemaPiece::TimeMilli | SchemaPiece::TimeMicro => unimplemented!(),
             SchemaPiece::Array(inner) => {
                 let fn_ = field_name.unwrap();
                 let len_dist_json = annotations.get(&format!("{}.len", fn_)).unwrap();
@@ -410,7 +413,8 @@ impl<'a> RandomAvroGenerator<'a> {
             | SchemaPiece::ResolveUnionUnion { .. }
             | SchemaPiece::ResolveUnionConcrete { .. }
             | SchemaPiece::ResolveRecord { .. }
-            | SchemaPiece::ResolveEnum { .. } => unreachable!(),
+            | SchemaPiece::ResolveEnum { .. }
+            | SchemaPiece::ResolveEnumString { .. } => unreachable!(),
         };
     }
     pub fn new(schema: &'a Schema, annotations: &serde_json::Value) -> Self {
@@ -11,6 +11,7 @@
 
 use std::fmt;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -86,11 +87,50 @@ pub static SYSTEM_TIME: Lazy<NowFn> = Lazy::new(|| NowFn::from(system_time));
 /// For use in tests.
 pub static NOW_ZERO: Lazy<NowFn> = Lazy::new(|| NowFn::from(now_zero));
 
+/// A clock whose time changes only when it is explicitly set or advanced.
+///
+/// For use in tests that need deterministic control over the passage of time.
+/// Clones of a `ManualClock` share the same time, so a test can hand a
+/// [`NowFn`] obtained via [`ManualClock::now_fn`] to the system under test and
+/// keep a clone around to move time forward.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock(Arc<AtomicU64>);
+
+impl ManualClock {
+    /// Creates a new clock that reads `millis`.
+    pub fn new(millis: EpochMillis) -> ManualClock {
+        ManualClock(Arc::new(AtomicU64::new(millis)))
+    }
+
+    /// Returns the current time of the clock.
+    pub fn now(&self) -> EpochMillis {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Sets the time of the clock to `millis`.
+    ///
+    /// The time may be set backwards, to simulate clock skew.
+    pub fn set(&self, millis: EpochMillis) {
+        self.0.store(millis, Ordering::SeqCst);
+    }
+
+    /// Advances the time of the clock by `millis`.
+    pub fn advance(&self, millis: EpochMillis) {
+        self.0.fetch_add(millis, Ordering::SeqCst);
+    }
+
+    /// Returns a [`NowFn`] that reads the time of the clock.
+    pub fn now_fn(&self) -> NowFn {
+        let clock = self.clone();
+        NowFn::from(move || clock.now())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
 
-    use super::to_datetime;
+    use super::{to_datetime, ManualClock};
 
     #[test]
     fn test_to_datetime() {
@@ -112,4 +152,15 @@ mod tests {
             assert_eq!(millis, converted_datetime.timestamp_millis() as u64)
         }
     }
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(1_000);
+        let now = clock.now_fn();
+        assert_eq!(now(), 1_000);
+        clock.advance(500);
+        assert_eq!(now(), 1_500);
+        clock.clone().set(200);
+        assert_eq!(now(), 200);
+    }
 }
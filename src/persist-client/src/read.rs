@@ -509,6 +509,19 @@ where
         Ok(leased_parts)
     }
 
+    /// Returns statistics about the contents of this shard as of `as_of`,
+    /// without fetching any of its data.
+    ///
+    /// Like [Self::snapshot], this may "block" (in an async-friendly way) if
+    /// `as_of` is greater or equal to the current `upper` of the shard, and
+    /// returns a `Since` error if `as_of` cannot be served.
+    #[instrument(level = "trace", skip_all, fields(shard = %self.machine.shard_id()))]
+    pub async fn snapshot_stats(&mut self, as_of: Antichain<T>) -> Result<SnapshotStats, Since<T>> {
+        let batches = self.machine.snapshot(&as_of).await?;
+        let num_updates = batches.iter().map(|b| b.len).sum();
+        Ok(SnapshotStats { num_updates })
+    }
+
     /// Generates a [Self::snapshot], and fetches all of the batches
     /// it contains.
     pub async fn snapshot_and_fetch(
@@ -745,6 +758,17 @@ where
     }
 }
 
+/// Statistics about the contents of a shard, as returned by
+/// [ReadHandle::snapshot_stats].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotStats {
+    /// The number of updates stored in the batches that make up the snapshot.
+    ///
+    /// This counts updates as they are stored, so it can exceed the number of
+    /// updates in the consolidated snapshot until the shard is compacted.
+    pub num_updates: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::new_test_client;
@@ -778,6 +802,32 @@ mod tests {
         drop(subscribe);
     }
 
+    #[tokio::test]
+    async fn snapshot_stats() {
+        let data = vec![
+            (("0".to_owned(), "zero".to_owned()), 0, 1),
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+        ];
+
+        let (mut write, mut read) = new_test_client()
+            .await
+            .expect_open::<String, String, u64, i64>(crate::ShardId::new())
+            .await;
+
+        write.expect_compare_and_append(&data[0..1], 0, 1).await;
+        write.expect_compare_and_append(&data[1..2], 1, 2).await;
+        write.expect_compare_and_append(&data[2..3], 2, 3).await;
+
+        for (as_of, num_updates) in [(0, 1), (1, 2), (2, 3)] {
+            let stats = read
+                .snapshot_stats(timely::progress::Antichain::from_elem(as_of))
+                .await
+                .unwrap();
+            assert_eq!(stats, super::SnapshotStats { num_updates });
+        }
+    }
+
     // Verifies the semantics of `SeqNo` leases + checks dropping `LeasedBatchPart` semantics.
     #[tokio::test]
     async fn seqno_leases() {
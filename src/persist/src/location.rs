@@ -126,6 +126,15 @@ impl std::fmt::Display for Indeterminate {
 
 impl std::error::Error for Indeterminate {}
 
+impl Indeterminate {
+    /// Return a new Indeterminate wrapping the given error.
+    ///
+    /// Exposed for simulating indeterminate results in tests.
+    pub fn new(inner: anyhow::Error) -> Self {
+        Indeterminate { inner }
+    }
+}
+
 /// An impl of PartialEq purely for convenience in tests and debug assertions.
 #[cfg(any(test, debug_assertions))]
 impl PartialEq for Indeterminate {
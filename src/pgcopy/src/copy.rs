@@ -95,6 +95,47 @@ pub fn encode_copy_row_text(
     Ok(())
 }
 
+pub fn encode_copy_row_csv(
+    row: Row,
+    typ: &RelationType,
+    out: &mut Vec<u8>,
+) -> Result<(), io::Error> {
+    let delim = b',';
+    let quote = b'"';
+    let mut buf = BytesMut::new();
+    for (idx, field) in mz_pgrepr::values_from_row(row, typ).into_iter().enumerate() {
+        if idx > 0 {
+            out.push(delim);
+        }
+        // Nulls are written as empty, unquoted values.
+        if let Some(field) = field {
+            buf.clear();
+            field.encode_text(&mut buf);
+            // Quote values that could otherwise be mistaken for a null, the end
+            // of a value or row, or the end of copy marker, as PostgreSQL does.
+            let needs_quotes = buf.is_empty()
+                || (typ.column_types.len() == 1 && &buf[..] == END_OF_COPY_MARKER)
+                || buf
+                    .iter()
+                    .any(|b| *b == delim || *b == quote || *b == b'\n' || *b == b'\r');
+            if needs_quotes {
+                out.push(quote);
+                for b in &buf {
+                    if *b == quote {
+                        out.push(quote);
+                    }
+                    out.push(*b);
+                }
+                out.push(quote);
+            } else {
+                out.extend(&buf);
+            }
+        }
+    }
+    out.push(b'\n');
+    Ok(())
+}
+
 pub struct CopyTextFormatParser<'a> {
     data: &'a [u8],
     position: usize,
@@ -355,7 +396,7 @@ impl<'a> RawIterator<'a> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum CopyFormatParams<'a> {
     Text(CopyTextFormatParams<'a>),
     Csv(CopyCsvFormatParams<'a>),
@@ -372,7 +413,134 @@ pub fn decode_copy_format<'a>(
     }
 }
 
+/// Incrementally decodes data received in the copy-in mode of the PostgreSQL
+/// protocol.
+///
+/// Data is buffered by [`CopyFormatDecoder::push`] until the next call to
+/// [`CopyFormatDecoder::decode_complete_rows`], which decodes every complete
+/// row in the buffer and leaves any trailing partial row for the next call.
+/// This allows large inputs to be processed in batches rather than buffered in
+/// full.
 #[derive(Debug)]
+pub struct CopyFormatDecoder<'a> {
+    column_types: Vec<mz_pgrepr::Type>,
+    params: CopyFormatParams<'a>,
+    buf: Vec<u8>,
+    /// Whether the end of copy marker has been decoded. Any data after the
+    /// marker is ignored.
+    done: bool,
+}
+
+impl<'a> CopyFormatDecoder<'a> {
+    pub fn new(column_types: Vec<mz_pgrepr::Type>, params: CopyFormatParams<'a>) -> Self {
+        CopyFormatDecoder {
+            column_types,
+            params,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Appends `data` to the buffer.
+    pub fn push(&mut self, data: &[u8]) {
+        if !self.done {
+            self.buf.extend(data);
+        }
+    }
+
+    /// Returns the number of bytes that have been pushed but not yet decoded.
+    pub fn buffered_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Decodes all complete rows in the buffer.
+    pub fn decode_complete_rows(&mut self) -> Result<Vec<Row>, io::Error> {
+        let (len, end_of_copy) = self.complete_rows_len();
+        let rows = self.decode(len)?;
+        if end_of_copy {
+            self.done = true;
+            self.buf = Vec::new();
+        }
+        Ok(rows)
+    }
+
+    /// Decodes all remaining data in the buffer, which must end with a complete
+    /// row.
+    pub fn finish(mut self) -> Result<Vec<Row>, io::Error> {
+        self.decode(self.buf.len())
+    }
+
+    fn decode(&mut self, len: usize) -> Result<Vec<Row>, io::Error> {
+        if self.done || len == 0 {
+            return Ok(Vec::new());
+        }
+        let rows = decode_copy_format(&self.buf[..len], &self.column_types, self.params.clone())?;
+        self.buf.drain(..len);
+        // The header, if any, was the first row of the data just decoded.
+        if let CopyFormatParams::Csv(params) = &mut self.params {
+            params.header = false;
+        }
+        Ok(rows)
+    }
+
+    /// Returns the length of the prefix of the buffer that contains only
+    /// complete rows, and whether that prefix contains the end of copy
+    /// marker, in which case it spans the entire buffer.
+    fn complete_rows_len(&self) -> (usize, bool) {
+        let buf = &self.buf;
+        let mut len = 0;
+        let mut i = 0;
+        match &self.params {
+            CopyFormatParams::Text(_) => {
+                while i < buf.len() {
+                    match buf[i] {
+                        b'\\' if buf.get(i + 1) == Some(&b'.') => return (buf.len(), true),
+                        // Skip the escaped byte, which may be a newline.
+                        b'\\' => i += 1,
+                        b'\n' => len = i + 1,
+                        _ => (),
+                    }
+                    i += 1;
+                }
+            }
+            CopyFormatParams::Csv(CopyCsvFormatParams {
+                delimiter,
+                quote,
+                escape,
+                ..
+            }) => {
+                let mut row_start = true;
+                let mut field_start = true;
+                let mut in_quotes = false;
+                while i < buf.len() {
+                    let b = buf[i];
+                    if in_quotes {
+                        if b == *escape && (escape != quote || buf.get(i + 1) == Some(quote)) {
+                            // Skip the escaped byte, which may be a quote.
+                            i += 1;
+                        } else if b == *quote {
+                            in_quotes = false;
+                        }
+                    } else if row_start && buf[i..].starts_with(END_OF_COPY_MARKER) {
+                        if matches!(buf.get(i + 2), Some(b'\r' | b'\n')) {
+                            return (buf.len(), true);
+                        }
+                    } else if field_start && b == *quote {
+                        in_quotes = true;
+                    } else if b == b'\n' {
+                        len = i + 1;
+                    }
+                    row_start = !in_quotes && b == b'\n';
+                    field_start = row_start || (!in_quotes && b == *delimiter);
+                    i += 1;
+                }
+            }
+        }
+        (len, false)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct CopyTextFormatParams<'a> {
     pub null: Cow<'a, str>,
     pub delimiter: Cow<'a, str>,
@@ -414,7 +582,7 @@ pub fn decode_copy_format_text(
     Ok(rows)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CopyCsvFormatParams<'a> {
     pub delimiter: u8,
     pub quote: u8,
@@ -661,4 +829,61 @@ mod tests {
             assert!(parser.is_eof());
         }
     }
+
+    #[test]
+    fn test_copy_format_decoder() {
+        struct TestCase {
+            input: &'static str,
+            params: CopyFormatParams<'static>,
+        }
+        let text = CopyFormatParams::Text(CopyTextFormatParams {
+            null: Cow::from("\\N"),
+            delimiter: Cow::from("\t"),
+        });
+        let csv = |escape, header| {
+            CopyFormatParams::Csv(CopyCsvFormatParams {
+                delimiter: b',',
+                quote: b'"',
+                escape,
+                header,
+                null: Cow::from(""),
+            })
+        };
+        let tests = vec![
+            TestCase {
+                input: "a\tb\nc\\\nd\t\\N\n\\\\\t.\n\\.\nignored\n",
+                params: text,
+            },
+            TestCase {
+                input: "x,y\na,b\n\"c\n\"\"d\",\n\"\\.\",e\n\\.\nignored\n",
+                params: csv(b'"', true),
+            },
+            TestCase {
+                input: "\"a\\\"\n\",b\n\"c\\\\\",\"\n\"\n",
+                params: csv(b'\\', false),
+            },
+        ];
+
+        let column_types = vec![mz_pgrepr::Type::Text; 2];
+        for test in tests {
+            let input = test.input.as_bytes();
+            let expected =
+                decode_copy_format(input, &column_types, test.params.clone()).expect("valid input");
+            // Feed the input to the decoder in chunks of every possible size.
+            for chunk_size in 1..=input.len() {
+                let mut decoder = CopyFormatDecoder::new(column_types.clone(), test.params.clone());
+                let mut rows = Vec::new();
+                for chunk in input.chunks(chunk_size) {
+                    decoder.push(chunk);
+                    rows.extend(decoder.decode_complete_rows().expect("valid input"));
+                }
+                rows.extend(decoder.finish().expect("valid input"));
+                assert_eq!(
+                    rows, expected,
+                    "input: {:?}, chunk size: {}",
+                    test.input, chunk_size
+                );
+            }
+        }
+    }
 }
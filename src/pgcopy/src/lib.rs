@@ -15,5 +15,10 @@
 
 mod copy;
 
-pub use copy::{decode_copy_format, encode_copy_row_binary, encode_copy_row_text};
-pub use copy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams, CopyTextFormatParser};
+pub use copy::{
+    decode_copy_format, encode_copy_row_binary, encode_copy_row_csv, encode_copy_row_text,
+};
+pub use copy::{
+    CopyCsvFormatParams, CopyFormatDecoder, CopyFormatParams, CopyTextFormatParams,
+    CopyTextFormatParser,
+};
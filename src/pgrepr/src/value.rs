@@ -186,10 +186,23 @@ impl Value {
     /// Converts a Materialize datum from this value.
     pub fn into_datum<'a>(self, buf: &'a RowArena, typ: &Type) -> Datum<'a> {
         match self {
-            Value::Array { .. } => {
-                // This situation is handled gracefully by Value::decode; if we
-                // wind up here it's a programming error.
-                unreachable!("into_datum cannot be called on Value::Array");
+            Value::Array { dims, elements } => {
+                let elem_pg_type = match typ {
+                    Type::Array(t) => &*t,
+                    _ => panic!("Value::Array should have type Type::Array. Found {:?}", typ),
+                };
+                let elements: Vec<_> = elements
+                    .into_iter()
+                    .map(|elem| match elem {
+                        Some(elem) => elem.into_datum(buf, elem_pg_type),
+                        None => Datum::Null,
+                    })
+                    .collect();
+                buf.make_datum(|packer| {
+                    packer
+                        .push_array(&dims, elements)
+                        .expect("dimensions match number of elements")
+                })
             }
             Value::Int2Vector { .. } => {
                 // This situation is handled gracefully by Value::decode; if we
@@ -468,7 +481,23 @@ impl Value {
     pub fn decode_text(ty: &Type, raw: &[u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
         let s = str::from_utf8(raw)?;
         Ok(match ty {
-            Type::Array(_) => return Err("input of array types is not implemented".into()),
+            Type::Array(elem_type) => {
+                let elements = strconv::parse_array(
+                    s,
+                    || None,
+                    |elem_text| Value::decode_text(elem_type, elem_text.as_bytes()).map(Some),
+                )?;
+                // Per PostgreSQL, empty arrays have zero dimensions.
+                let dims = if elements.is_empty() {
+                    vec![]
+                } else {
+                    vec![ArrayDimension {
+                        lower_bound: 1,
+                        length: elements.len(),
+                    }]
+                };
+                Value::Array { dims, elements }
+            }
             Type::Int2Vector { .. } => {
                 return Err("input of Int2Vector types is not implemented".into())
             }
@@ -19,6 +19,7 @@ use mz_adapter::session::TransactionStatus as AdapterTransactionStatus;
 use mz_adapter::{AdapterError, StartupMessage};
 use mz_expr::EvalError;
 use mz_repr::{ColumnName, NotNullViolation, RelationDesc};
+use mz_sql::plan::PlanNotice;
 
 // Pgwire protocol versions are represented as 32-bit integers, where the
 // high 16 bits represent the major version and the low 16 bits represent the
@@ -391,6 +392,7 @@ impl ErrorResponse {
             AdapterError::InvalidStorageHostSize { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::InvalidTableMutationSelection => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::LockTimeout => SqlState::LOCK_NOT_AVAILABLE,
             AdapterError::NoClusterReplicasAvailable(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::OperationProhibitsTransaction(_) => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterError::OperationRequiresTransaction(_) => SqlState::NO_ACTIVE_SQL_TRANSACTION,
@@ -399,7 +401,7 @@ impl ErrorResponse {
             AdapterError::QGM(_) => SqlState::INTERNAL_ERROR,
             AdapterError::ReadOnlyTransaction => SqlState::READ_ONLY_SQL_TRANSACTION,
             AdapterError::ReadOnlyParameter(_) => SqlState::CANT_CHANGE_RUNTIME_PARAM,
-            AdapterError::StatementTimeout => SqlState::IDLE_IN_TRANSACTION_SESSION_TIMEOUT,
+            AdapterError::StatementTimeout => SqlState::QUERY_CANCELED,
             AdapterError::RecursionLimit(_) => SqlState::INTERNAL_ERROR,
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
@@ -407,6 +409,9 @@ impl ErrorResponse {
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
             AdapterError::SqlCatalog(_) => SqlState::INTERNAL_ERROR,
             AdapterError::TailOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
+            AdapterError::TooManyConnections { .. } => SqlState::TOO_MANY_CONNECTIONS,
+            AdapterError::TooManyPendingWrites { .. } => SqlState::INSUFFICIENT_RESOURCES,
+            AdapterError::TimestampNotValid { .. } => SqlState::SNAPSHOT_TOO_OLD,
             AdapterError::Transform(_) => SqlState::INTERNAL_ERROR,
             AdapterError::Unauthorized(_) => SqlState::INSUFFICIENT_PRIVILEGE,
             AdapterError::UncallableFunction { .. } => SqlState::FEATURE_NOT_SUPPORTED,
@@ -424,7 +429,6 @@ impl ErrorResponse {
             // not things in Postgres. This error code is the generic "bad txn thing"
             // code, so it's probably the best choice.
             AdapterError::WriteOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
-            AdapterError::MultiTableWriteTransaction => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::Storage(_) | AdapterError::Compute(_) => SqlState::INTERNAL_ERROR,
         };
         ErrorResponse {
@@ -448,6 +452,17 @@ impl ErrorResponse {
         }
     }
 
+    pub fn from_plan_notice(notice: PlanNotice) -> ErrorResponse {
+        ErrorResponse {
+            severity: Severity::Notice,
+            code: SqlState::SUCCESSFUL_COMPLETION,
+            message: notice.to_string(),
+            detail: notice.detail(),
+            hint: notice.hint(),
+            position: None,
+        }
+    }
+
     pub fn with_position(mut self, position: usize) -> ErrorResponse {
         self.position = Some(position);
         self
@@ -13,6 +13,7 @@ use std::convert::TryFrom;
 use std::future::Future;
 use std::iter;
 use std::mem;
+use std::net::IpAddr;
 
 use byteorder::{ByteOrder, NetworkEndian};
 use futures::future::{pending, BoxFuture, FutureExt};
@@ -26,15 +27,15 @@ use tokio::time::{self, Duration, Instant};
 use tracing::{debug, warn, Instrument};
 
 use mz_adapter::session::{
-    EndTransactionAction, InProgressRows, Portal, PortalState, RowBatchStream, Session,
-    TransactionStatus,
+    verify_implicit_transaction, EndTransactionAction, InProgressRows, Portal, PortalState,
+    RowBatchStream, Session, TransactionStatus,
 };
-use mz_adapter::{ExecuteResponse, PeekResponseUnary, RowsFuture};
+use mz_adapter::{AdapterError, ExecuteResponse, PeekResponseUnary, RowsFuture};
 use mz_frontegg_auth::FronteggAuthentication;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
-use mz_pgcopy::CopyFormatParams;
+use mz_pgcopy::{CopyFormatDecoder, CopyFormatParams};
 use mz_repr::{Datum, RelationDesc, RelationType, Row, RowArena, ScalarType};
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{FetchDirection, Ident, Raw, Statement};
@@ -46,6 +47,10 @@ use crate::message::{
 };
 use crate::server::{Conn, TlsMode};
 
+/// The number of bytes of `COPY ... FROM STDIN` data to buffer before decoding
+/// them and sending the decoded rows to the adapter.
+const COPY_FROM_BATCH_BYTES: usize = 8 << 20;
+
 /// Reports whether the given stream begins with a pgwire handshake.
 ///
 /// To avoid false negatives, there must be at least eight bytes in `buf`.
@@ -83,6 +88,8 @@ pub struct RunParams<'a, A> {
     /// Whether this is an internal server that permits access to restricted
     /// system resources.
     pub internal: bool,
+    /// The address of the client, if known.
+    pub client_addr: Option<IpAddr>,
 }
 
 /// Runs a pgwire connection to completion.
@@ -104,6 +111,7 @@ pub async fn run<'a, A>(
         mut params,
         frontegg,
         internal,
+        client_addr,
     }: RunParams<'a, A>,
 ) -> Result<(), io::Error>
 where
@@ -206,6 +214,9 @@ where
 
     // Construct session.
     let mut session = Session::new(conn.id(), user);
+    if let Some(client_addr) = client_addr {
+        session.set_client_addr(client_addr);
+    }
     for (name, value) in params {
         let local = false;
         let _ = session.vars_mut().set(&name, &value, local);
@@ -289,7 +300,29 @@ where
     }
 
     async fn advance_ready(&mut self) -> Result<State, io::Error> {
-        let message = self.conn.recv().await?;
+        // Sessions that sit idle in a transaction may hold back compaction or
+        // block writes, so they can be terminated after a timeout. Terminating
+        // the session releases everything its transaction held. A timeout of
+        // zero is parsed as the maximum duration, which disables it.
+        let session = self.adapter_client.session();
+        let timeout = *session.vars().idle_in_transaction_session_timeout();
+        let message = if matches!(session.transaction(), TransactionStatus::Default)
+            || timeout == Duration::from_secs(u64::MAX)
+        {
+            self.conn.recv().await?
+        } else {
+            match time::timeout(timeout, self.conn.recv()).await {
+                Ok(message) => message?,
+                Err(_) => {
+                    return self
+                        .error(ErrorResponse::fatal(
+                            SqlState::IDLE_IN_TRANSACTION_SESSION_TIMEOUT,
+                            "terminating connection due to idle-in-transaction timeout",
+                        ))
+                        .await
+                }
+            }
+        };
 
         self.adapter_client.reset_canceled();
 
@@ -415,7 +448,9 @@ where
             }
         }
 
-        let result = match self.adapter_client.execute(EMPTY_PORTAL.to_string()).await {
+        let response = self.adapter_client.execute(EMPTY_PORTAL.to_string()).await;
+        self.send_pending_notices().await?;
+        let result = match response {
             Ok(response) => {
                 self.send_execute_response(
                     response,
@@ -460,6 +495,14 @@ where
             }
         };
 
+        // Reject queries whose statements cannot share an implicit
+        // transaction before running any of them.
+        if let Err(err) = verify_implicit_transaction(&stmts) {
+            self.error(ErrorResponse::from_adapter(Severity::Error, err))
+                .await?;
+            return self.ready().await;
+        }
+
         let num_stmts = stmts.len();
 
         // Compare with postgres' backend/tcop/postgres.c exec_simple_query.
@@ -764,7 +807,9 @@ where
                     // Postgres).
                     self.start_transaction(Some(1)).await;
 
-                    match self.adapter_client.execute(portal_name.clone()).await {
+                    let response = self.adapter_client.execute(portal_name.clone()).await;
+                    self.send_pending_notices().await?;
+                    match response {
                         Ok(response) => {
                             self.send_execute_response(
                                 response,
@@ -963,6 +1008,15 @@ where
         Ok(State::Ready)
     }
 
+    /// Sends the notices that were produced while executing the last statement.
+    async fn send_pending_notices(&mut self) -> Result<(), io::Error> {
+        let notices = self.adapter_client.session().drain_notices();
+        for notice in notices {
+            self.send(ErrorResponse::from_plan_notice(notice)).await?;
+        }
+        Ok(())
+    }
+
     /// Sends a backend message to the client, after applying a severity filter.
     ///
     /// The message is only sent if its severity is above the severity set
@@ -1216,6 +1270,7 @@ where
             ExecuteResponse::AlteredIndexLogicalCompaction
             | ExecuteResponse::AlteredObject(..)
             | ExecuteResponse::AlteredSystemConfiguraion
+            | ExecuteResponse::Comment
             | ExecuteResponse::CreatedComputeInstance { .. }
             | ExecuteResponse::CreatedComputeInstanceReplica { .. }
             | ExecuteResponse::CreatedConnection { .. }
@@ -1341,6 +1396,7 @@ where
                         Some(PeekResponseUnary::Rows(rows)) => FetchResult::Rows(Some(rows)),
                         Some(PeekResponseUnary::Error(err)) => FetchResult::Error(err),
                         Some(PeekResponseUnary::Canceled) => FetchResult::Canceled,
+                        Some(PeekResponseUnary::TimedOut) => FetchResult::TimedOut,
                     },
                 }
             };
@@ -1421,6 +1477,14 @@ where
                         ))
                         .await;
                 }
+                FetchResult::TimedOut => {
+                    return self
+                        .error(ErrorResponse::from_adapter(
+                            Severity::Error,
+                            AdapterError::StatementTimeout,
+                        ))
+                        .await;
+                }
             }
         }
 
@@ -1457,15 +1521,8 @@ where
             mz_pgrepr::Format,
         ) = match format {
             CopyFormat::Text => (mz_pgcopy::encode_copy_row_text, mz_pgrepr::Format::Text),
+            CopyFormat::Csv => (mz_pgcopy::encode_copy_row_csv, mz_pgrepr::Format::Text),
             CopyFormat::Binary => (mz_pgcopy::encode_copy_row_binary, mz_pgrepr::Format::Binary),
-            _ => {
-                return self
-                    .error(ErrorResponse::error(
-                        SqlState::FEATURE_NOT_SUPPORTED,
-                        format!("COPY TO format {:?} not supported", format),
-                    ))
-                    .await
-            }
         };
 
         let typ = row_desc.typ();
@@ -1540,6 +1597,14 @@ where
                             ))
                             .await;
                     }
+                    Some(PeekResponseUnary::TimedOut) => {
+                        return self
+                            .error(ErrorResponse::from_adapter(
+                                Severity::Error,
+                                AdapterError::StatementTimeout,
+                            ))
+                            .await;
+                    }
                     Some(PeekResponseUnary::Rows(rows)) => {
                         count += rows.len();
                         for row in rows {
@@ -1585,12 +1650,37 @@ where
         .await?;
         self.conn.flush().await?;
 
-        let mut data = Vec::new();
-        let mut next_state = State::Ready;
+        let column_types = typ
+            .column_types
+            .iter()
+            .map(|x| &x.scalar_type)
+            .map(mz_pgrepr::Type::from)
+            .collect::<Vec<mz_pgrepr::Type>>();
+        let mut decoder = CopyFormatDecoder::new(column_types, params);
+
+        // Rows are decoded and sent to the adapter in batches as the data
+        // arrives, so that large inputs need not be buffered in full. The
+        // batches are all written in the current transaction, so either all
+        // or none of them are committed. If a batch fails, the remaining data
+        // is discarded and the error is reported once the client has finished
+        // sending it.
+        let mut count = 0;
+        let mut err = None;
         loop {
             let message = self.conn.recv().await?;
             match message {
-                Some(FrontendMessage::CopyData(buf)) => data.extend(buf),
+                Some(FrontendMessage::CopyData(buf)) => {
+                    if err.is_some() {
+                        continue;
+                    }
+                    decoder.push(&buf);
+                    if decoder.buffered_len() >= COPY_FROM_BATCH_BYTES {
+                        let rows = decoder.decode_complete_rows();
+                        if let Err(e) = self.copy_rows(id, &columns, rows, &mut count).await {
+                            err = Some(e);
+                        }
+                    }
+                }
                 Some(FrontendMessage::CopyDone) => break,
                 Some(FrontendMessage::CopyFail(err)) => {
                     return self
@@ -1609,46 +1699,43 @@ where
                         ))
                         .await
                 }
-                _ => {
-                    next_state = State::Done;
-                    break;
-                }
+                _ => return Ok(State::Done),
             }
         }
 
-        let column_types = typ
-            .column_types
-            .iter()
-            .map(|x| &x.scalar_type)
-            .map(mz_pgrepr::Type::from)
-            .collect::<Vec<mz_pgrepr::Type>>();
-
-        if let State::Ready = next_state {
-            let rows = match mz_pgcopy::decode_copy_format(&data, &column_types, params) {
-                Ok(rows) => rows,
-                Err(e) => {
-                    return self
-                        .error(ErrorResponse::error(
-                            SqlState::BAD_COPY_FILE_FORMAT,
-                            format!("{}", e),
-                        ))
-                        .await
-                }
-            };
-
-            let count = rows.len();
-
-            if let Err(e) = self.adapter_client.insert_rows(id, columns, rows).await {
-                return self
-                    .error(ErrorResponse::from_adapter(Severity::Error, e))
-                    .await;
+        if err.is_none() {
+            let rows = decoder.finish();
+            if let Err(e) = self.copy_rows(id, &columns, rows, &mut count).await {
+                err = Some(e);
             }
-
-            let tag = format!("COPY {}", count);
-            self.send(BackendMessage::CommandComplete { tag }).await?;
+        }
+        if let Some(err) = err {
+            return self.error(err).await;
         }
 
-        Ok(next_state)
+        let tag = format!("COPY {}", count);
+        self.send(BackendMessage::CommandComplete { tag }).await?;
+        Ok(State::Ready)
+    }
+
+    /// Inserts a batch of rows decoded from `COPY ... FROM STDIN` data into the
+    /// table `id`, adding the number of rows to `count`.
+    async fn copy_rows(
+        &mut self,
+        id: GlobalId,
+        columns: &[usize],
+        rows: Result<Vec<Row>, io::Error>,
+        count: &mut usize,
+    ) -> Result<(), ErrorResponse> {
+        let rows = rows
+            .map_err(|e| ErrorResponse::error(SqlState::BAD_COPY_FILE_FORMAT, format!("{}", e)))?;
+        let len = rows.len();
+        self.adapter_client
+            .insert_rows(id, columns.to_vec(), rows)
+            .await
+            .map_err(|e| ErrorResponse::from_adapter(Severity::Error, e))?;
+        *count += len;
+        Ok(())
     }
 
     async fn error(&mut self, err: ErrorResponse) -> Result<State, io::Error> {
@@ -1797,5 +1884,6 @@ fn is_txn_exit_stmt(stmt: Option<&Statement<Raw>>) -> bool {
 enum FetchResult {
     Rows(Option<Vec<Row>>),
     Canceled,
+    TimedOut,
     Error(String),
 }
@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::fmt;
+use std::net::IpAddr;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -84,7 +85,11 @@ impl Server {
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    pub async fn handle_connection<A>(&self, conn: A) -> Result<(), anyhow::Error>
+    pub async fn handle_connection<A>(
+        &self,
+        conn: A,
+        client_addr: Option<IpAddr>,
+    ) -> Result<(), anyhow::Error>
     where
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + fmt::Debug + 'static,
     {
@@ -115,6 +120,7 @@ impl Server {
                         params,
                         frontegg: self.frontegg.as_ref(),
                         internal: self.internal,
+                        client_addr,
                     })
                     .await?;
                     conn.flush().await?;
@@ -740,6 +740,40 @@ impl<T: AstInfo> AstDisplay for AwsConnectionOption<T> {
 }
 impl_display_t!(AwsConnectionOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GcpConnectionOptionName {
+    Credentials,
+    Endpoint,
+}
+
+impl AstDisplay for GcpConnectionOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            GcpConnectionOptionName::Credentials => "CREDENTIALS",
+            GcpConnectionOptionName::Endpoint => "ENDPOINT",
+        })
+    }
+}
+impl_display!(GcpConnectionOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `CREATE CONNECTION...GCP`.
+pub struct GcpConnectionOption<T: AstInfo> {
+    pub name: GcpConnectionOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for GcpConnectionOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(GcpConnectionOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SshConnectionOptionName {
     Host,
@@ -787,6 +821,9 @@ pub enum CreateConnection<T: AstInfo> {
     Csr {
         with_options: Vec<CsrConnectionOption<T>>,
     },
+    Gcp {
+        with_options: Vec<GcpConnectionOption<T>>,
+    },
     Postgres {
         with_options: Vec<PostgresConnectionOption<T>>,
     },
@@ -806,6 +843,10 @@ impl<T: AstInfo> AstDisplay for CreateConnection<T> {
                 f.write_str("CONFLUENT SCHEMA REGISTRY ");
                 f.write_node(&display::comma_separated(&with_options));
             }
+            Self::Gcp { with_options } => {
+                f.write_str("GCP ");
+                f.write_node(&display::comma_separated(&with_options));
+            }
             Self::Postgres { with_options } => {
                 f.write_str("POSTGRES ");
                 f.write_node(&display::comma_separated(&with_options));
@@ -834,11 +875,14 @@ pub enum KafkaConfigOptionName {
     IsolationLevel,
     StatisticsIntervalMs,
     Topic,
+    Topics,
+    TopicPattern,
     TopicMetadataRefreshIntervalMs,
     TransactionTimeoutMs,
     StartTimestamp,
     StartOffset,
     PartitionCount,
+    PartitionStrategy,
     ReplicationFactor,
     RetentionMs,
     RetentionBytes,
@@ -856,6 +900,8 @@ impl AstDisplay for KafkaConfigOptionName {
             KafkaConfigOptionName::IsolationLevel => "ISOLATION LEVEL",
             KafkaConfigOptionName::StatisticsIntervalMs => "STATISTICS INTERVAL MS",
             KafkaConfigOptionName::Topic => "TOPIC",
+            KafkaConfigOptionName::Topics => "TOPICS",
+            KafkaConfigOptionName::TopicPattern => "TOPIC PATTERN",
             KafkaConfigOptionName::TopicMetadataRefreshIntervalMs => {
                 "TOPIC METADATA REFRESH INTERVAL MS"
             }
@@ -863,6 +909,7 @@ impl AstDisplay for KafkaConfigOptionName {
             KafkaConfigOptionName::StartOffset => "START OFFSET",
             KafkaConfigOptionName::StartTimestamp => "START TIMESTAMP",
             KafkaConfigOptionName::PartitionCount => "PARTITION COUNT",
+            KafkaConfigOptionName::PartitionStrategy => "PARTITION STRATEGY",
             KafkaConfigOptionName::ReplicationFactor => "REPLICATION FACTOR",
             KafkaConfigOptionName::RetentionBytes => "RETENTION BYTES",
             KafkaConfigOptionName::RetentionMs => "RETENTION MS",
@@ -934,6 +981,10 @@ pub struct KafkaSourceConnection<T: AstInfo> {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceConnection<T: AstInfo> {
+    File {
+        /// The path to the file on the storage host.
+        path: String,
+    },
     Kafka(KafkaSourceConnection<T>),
     Kinesis {
         /// The AWS connection.
@@ -957,6 +1008,13 @@ pub enum CreateSourceConnection<T: AstInfo> {
         /// Hex encoded string of binary serialization of `dataflow_types::PostgresSourceDetails`
         details: Option<String>,
     },
+    PubSub {
+        /// The GCP connection.
+        connection: T::ObjectName,
+        /// The fully qualified name of the subscription to pull from, e.g.
+        /// `projects/my-project/subscriptions/my-subscription`.
+        subscription: String,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
@@ -966,6 +1024,11 @@ pub enum CreateSourceConnection<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            CreateSourceConnection::File { path } => {
+                f.write_str("FILE '");
+                f.write_node(&display::escape_single_quote_string(path));
+                f.write_str("'");
+            }
             CreateSourceConnection::Kafka(KafkaSourceConnection {
                 connection,
                 topic,
@@ -1025,6 +1088,16 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                 }
                 f.write_str("'");
             }
+            CreateSourceConnection::PubSub {
+                connection,
+                subscription,
+            } => {
+                f.write_str("PUBSUB CONNECTION ");
+                f.write_node(connection);
+                f.write_str(" SUBSCRIPTION '");
+                f.write_node(&display::escape_single_quote_string(subscription));
+                f.write_str("'");
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1272,9 +1345,12 @@ impl_display!(KeyConstraint);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceOptionName {
+    Fields,
     IgnoreKeys,
+    LogicalCompactionWindow,
     Remote,
     Size,
+    Tail,
     Timeline,
     TimestampInterval,
 }
@@ -1282,9 +1358,12 @@ pub enum CreateSourceOptionName {
 impl AstDisplay for CreateSourceOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
+            CreateSourceOptionName::Fields => "FIELDS",
             CreateSourceOptionName::IgnoreKeys => "IGNORE KEYS",
+            CreateSourceOptionName::LogicalCompactionWindow => "LOGICAL COMPACTION WINDOW",
             CreateSourceOptionName::Remote => "REMOTE",
             CreateSourceOptionName::Size => "SIZE",
+            CreateSourceOptionName::Tail => "TAIL",
             CreateSourceOptionName::Timeline => "TIMELINE",
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
         })
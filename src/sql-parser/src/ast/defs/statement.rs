@@ -60,6 +60,7 @@ pub enum Statement<T: AstInfo> {
     AlterSystemReset(AlterSystemResetStatement),
     AlterSystemResetAll(AlterSystemResetAllStatement),
     AlterConnection(AlterConnectionStatement),
+    Comment(CommentStatement),
     Discard(DiscardStatement),
     DropDatabase(DropDatabaseStatement),
     DropSchema(DropSchemaStatement),
@@ -71,6 +72,7 @@ pub enum Statement<T: AstInfo> {
     ResetVariable(ResetVariableStatement),
     ShowDatabases(ShowDatabasesStatement<T>),
     ShowSchemas(ShowSchemasStatement<T>),
+    ShowStorageUsage(ShowStorageUsageStatement<T>),
     ShowObjects(ShowObjectsStatement<T>),
     ShowIndexes(ShowIndexesStatement<T>),
     ShowColumns(ShowColumnsStatement<T>),
@@ -128,6 +130,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterSystemReset(stmt) => f.write_node(stmt),
             Statement::AlterSystemResetAll(stmt) => f.write_node(stmt),
             Statement::AlterConnection(stmt) => f.write_node(stmt),
+            Statement::Comment(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropDatabase(stmt) => f.write_node(stmt),
             Statement::DropSchema(stmt) => f.write_node(stmt),
@@ -139,6 +142,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::ShowDatabases(stmt) => f.write_node(stmt),
             Statement::ShowSchemas(stmt) => f.write_node(stmt),
+            Statement::ShowStorageUsage(stmt) => f.write_node(stmt),
             Statement::ShowObjects(stmt) => f.write_node(stmt),
             Statement::ShowIndexes(stmt) => f.write_node(stmt),
             Statement::ShowColumns(stmt) => f.write_node(stmt),
@@ -573,6 +577,7 @@ pub struct CreateSinkStatement<T: AstInfo> {
     pub format: Option<Format<T>>,
     pub envelope: Option<Envelope<T>>,
     pub with_options: Vec<CreateSinkOption<T>>,
+    pub as_of: Option<AsOf<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkStatement<T> {
@@ -600,6 +605,11 @@ impl<T: AstInfo> AstDisplay for CreateSinkStatement<T> {
             f.write_node(&display::comma_separated(&self.with_options));
             f.write_str(")");
         }
+
+        if let Some(as_of) = &self.as_of {
+            f.write_str(" ");
+            f.write_node(as_of);
+        }
     }
 }
 impl_display_t!(CreateSinkStatement);
@@ -718,6 +728,7 @@ pub struct CreateMaterializedViewStatement<T: AstInfo> {
     pub name: UnresolvedObjectName,
     pub columns: Vec<Ident>,
     pub in_cluster: Option<T::ClusterName>,
+    pub with_options: Vec<MaterializedViewOption<T>>,
     pub query: Query<T>,
 }
 
@@ -748,12 +759,51 @@ impl<T: AstInfo> AstDisplay for CreateMaterializedViewStatement<T> {
             f.write_node(cluster);
         }
 
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+
         f.write_str(" AS ");
         f.write_node(&self.query);
     }
 }
 impl_display_t!(CreateMaterializedViewStatement);
 
+/// An option in a `CREATE MATERIALIZED VIEW` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MaterializedViewOptionName {
+    // The `LOGICAL COMPACTION WINDOW` option
+    LogicalCompactionWindow,
+}
+
+impl AstDisplay for MaterializedViewOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MaterializedViewOptionName::LogicalCompactionWindow => {
+                f.write_str("LOGICAL COMPACTION WINDOW");
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MaterializedViewOption<T: AstInfo> {
+    pub name: MaterializedViewOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MaterializedViewOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
 /// `CREATE TABLE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTableStatement<T: AstInfo> {
@@ -1281,6 +1331,66 @@ impl AstDisplay for AlterConnectionStatement {
 
 impl_display!(AlterConnectionStatement);
 
+/// `COMMENT ON ... IS ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommentStatement {
+    pub object: CommentObject,
+    /// The new comment, or `None` to remove the existing comment.
+    pub comment: Option<String>,
+}
+
+impl AstDisplay for CommentStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("COMMENT ON ");
+        f.write_node(&self.object);
+        f.write_str(" IS ");
+        match &self.comment {
+            Some(comment) => {
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(comment));
+                f.write_str("'");
+            }
+            None => f.write_str("NULL"),
+        }
+    }
+}
+impl_display!(CommentStatement);
+
+/// The object of a [`CommentStatement`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommentObject {
+    Object {
+        object_type: ObjectType,
+        name: UnresolvedObjectName,
+    },
+    Column {
+        relation_name: UnresolvedObjectName,
+        column_name: Ident,
+    },
+}
+
+impl AstDisplay for CommentObject {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            CommentObject::Object { object_type, name } => {
+                f.write_node(object_type);
+                f.write_str(" ");
+                f.write_node(name);
+            }
+            CommentObject::Column {
+                relation_name,
+                column_name,
+            } => {
+                f.write_str("COLUMN ");
+                f.write_node(relation_name);
+                f.write_str(".");
+                f.write_node(column_name);
+            }
+        }
+    }
+}
+impl_display!(CommentObject);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiscardStatement {
     pub target: DiscardTarget,
@@ -1574,6 +1684,28 @@ impl<T: AstInfo> AstDisplay for ShowSchemasStatement<T> {
 }
 impl_display_t!(ShowSchemasStatement);
 
+/// `SHOW STORAGE USAGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShowStorageUsageStatement<T: AstInfo> {
+    pub from: Option<T::SchemaName>,
+    pub filter: Option<ShowStatementFilter<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ShowStorageUsageStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SHOW STORAGE USAGE");
+        if let Some(from) = &self.from {
+            f.write_str(" FROM ");
+            f.write_node(from);
+        }
+        if let Some(filter) = &self.filter {
+            f.write_str(" ");
+            f.write_node(filter);
+        }
+    }
+}
+impl_display_t!(ShowStorageUsageStatement);
+
 /// `SHOW <object>S`
 ///
 /// ```sql
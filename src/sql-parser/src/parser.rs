@@ -243,6 +243,7 @@ impl<'a> Parser<'a> {
                 Token::Keyword(UPDATE) => Ok(self.parse_update()?),
                 Token::Keyword(ALTER) => Ok(self.parse_alter()?),
                 Token::Keyword(COPY) => Ok(self.parse_copy()?),
+                Token::Keyword(COMMENT) => Ok(self.parse_comment()?),
                 Token::Keyword(SET) => Ok(self.parse_set()?),
                 Token::Keyword(RESET) => Ok(self.parse_reset()?),
                 Token::Keyword(SHOW) => Ok(self.parse_show()?),
@@ -1957,12 +1958,17 @@ impl<'a> Parser<'a> {
         let name = self.parse_object_name()?;
         self.expect_keyword(FOR)?;
         let connection =
-            match self.expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, POSTGRES, SSH])? {
+            match self.expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, GCP, POSTGRES, SSH])? {
                 AWS => {
                     let with_options =
                         self.parse_comma_separated(Parser::parse_aws_connection_option)?;
                     CreateConnection::Aws { with_options }
                 }
+                GCP => {
+                    let with_options =
+                        self.parse_comma_separated(Parser::parse_gcp_connection_option)?;
+                    CreateConnection::Gcp { with_options }
+                }
                 KAFKA => {
                     let with_options =
                         self.parse_comma_separated(Parser::parse_kafka_connection_option)?;
@@ -2060,6 +2066,7 @@ impl<'a> Parser<'a> {
             START,
             STATISTICS,
             TOPIC,
+            TOPICS,
             TRANSACTION,
         ])? {
             ACKS => KafkaConfigOptionName::Acks,
@@ -2087,10 +2094,11 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(LEVEL)?;
                 KafkaConfigOptionName::IsolationLevel
             }
-            PARTITION => {
-                self.expect_keyword(COUNT)?;
-                KafkaConfigOptionName::PartitionCount
-            }
+            PARTITION => match self.expect_one_of_keywords(&[COUNT, STRATEGY])? {
+                COUNT => KafkaConfigOptionName::PartitionCount,
+                STRATEGY => KafkaConfigOptionName::PartitionStrategy,
+                _ => unreachable!(),
+            },
             REPLICATION => {
                 self.expect_keyword(FACTOR)?;
                 KafkaConfigOptionName::ReplicationFactor
@@ -2108,10 +2116,13 @@ impl<'a> Parser<'a> {
                 if self.parse_keyword(METADATA) {
                     self.expect_keywords(&[REFRESH, INTERVAL, MS])?;
                     KafkaConfigOptionName::TopicMetadataRefreshIntervalMs
+                } else if self.parse_keyword(PATTERN) {
+                    KafkaConfigOptionName::TopicPattern
                 } else {
                     KafkaConfigOptionName::Topic
                 }
             }
+            TOPICS => KafkaConfigOptionName::Topics,
             TRANSACTION => {
                 self.expect_keywords(&[TIMEOUT, MS])?;
                 KafkaConfigOptionName::TransactionTimeoutMs
@@ -2216,6 +2227,20 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_gcp_connection_option(&mut self) -> Result<GcpConnectionOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[CREDENTIALS, ENDPOINT])? {
+            CREDENTIALS => GcpConnectionOptionName::Credentials,
+            ENDPOINT => GcpConnectionOptionName::Endpoint,
+            _ => unreachable!(),
+        };
+
+        let _ = self.consume_token(&Token::Eq);
+        Ok(GcpConnectionOption {
+            name,
+            value: self.parse_opt_with_option_value(false)?,
+        })
+    }
+
     fn parse_ssh_connection_option(&mut self) -> Result<SshConnectionOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[HOST, PORT, USER])? {
             HOST => SshConnectionOptionName::Host,
@@ -2326,21 +2351,28 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name =
-            match self.expect_one_of_keywords(&[IGNORE, REMOTE, SIZE, TIMELINE, TIMESTAMP])? {
-                IGNORE => {
-                    self.expect_keyword(KEYS)?;
-                    CreateSourceOptionName::IgnoreKeys
-                }
-                REMOTE => CreateSourceOptionName::Remote,
-                SIZE => CreateSourceOptionName::Size,
-                TIMELINE => CreateSourceOptionName::Timeline,
-                TIMESTAMP => {
-                    self.expect_keyword(INTERVAL)?;
-                    CreateSourceOptionName::TimestampInterval
-                }
-                _ => unreachable!(),
-            };
+        let name = match self.expect_one_of_keywords(&[
+            FIELDS, IGNORE, LOGICAL, REMOTE, SIZE, TAIL, TIMELINE, TIMESTAMP,
+        ])? {
+            FIELDS => CreateSourceOptionName::Fields,
+            IGNORE => {
+                self.expect_keyword(KEYS)?;
+                CreateSourceOptionName::IgnoreKeys
+            }
+            LOGICAL => {
+                self.expect_keywords(&[COMPACTION, WINDOW])?;
+                CreateSourceOptionName::LogicalCompactionWindow
+            }
+            REMOTE => CreateSourceOptionName::Remote,
+            SIZE => CreateSourceOptionName::Size,
+            TAIL => CreateSourceOptionName::Tail,
+            TIMELINE => CreateSourceOptionName::Timeline,
+            TIMESTAMP => {
+                self.expect_keyword(INTERVAL)?;
+                CreateSourceOptionName::TimestampInterval
+            }
+            _ => unreachable!(),
+        };
         Ok(name)
     }
 
@@ -2381,6 +2413,7 @@ impl<'a> Parser<'a> {
         } else {
             vec![]
         };
+        let as_of = self.parse_optional_as_of()?;
 
         Ok(Statement::CreateSink(CreateSinkStatement {
             name,
@@ -2390,6 +2423,7 @@ impl<'a> Parser<'a> {
             envelope,
             if_not_exists,
             with_options,
+            as_of,
         }))
     }
 
@@ -2409,7 +2443,11 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, KINESIS, S3, POSTGRES, LOAD])? {
+        match self.expect_one_of_keywords(&[FILE, KAFKA, KINESIS, S3, POSTGRES, PUBSUB, LOAD])? {
+            FILE => {
+                let path = self.parse_literal_string()?;
+                Ok(CreateSourceConnection::File { path })
+            }
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -2462,6 +2500,16 @@ impl<'a> Parser<'a> {
                     key,
                 }))
             }
+            PUBSUB => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+                self.expect_keyword(SUBSCRIPTION)?;
+                let subscription = self.parse_literal_string()?;
+                Ok(CreateSourceConnection::PubSub {
+                    connection,
+                    subscription,
+                })
+            }
             KINESIS => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -2674,6 +2722,15 @@ impl<'a> Parser<'a> {
         let columns = self.parse_parenthesized_column_list(Optional)?;
         let in_cluster = self.parse_optional_in_cluster()?;
 
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let o = self.parse_comma_separated(Parser::parse_materialized_view_option)?;
+            self.expect_token(&Token::RParen)?;
+            o
+        } else {
+            vec![]
+        };
+
         self.expect_keyword(AS)?;
         let query = self.parse_query()?;
 
@@ -2683,11 +2740,28 @@ impl<'a> Parser<'a> {
                 name,
                 columns,
                 in_cluster,
+                with_options,
                 query,
             },
         ))
     }
 
+    fn parse_materialized_view_option_name(
+        &mut self,
+    ) -> Result<MaterializedViewOptionName, ParserError> {
+        self.expect_keywords(&[LOGICAL, COMPACTION, WINDOW])?;
+        Ok(MaterializedViewOptionName::LogicalCompactionWindow)
+    }
+
+    fn parse_materialized_view_option(
+        &mut self,
+    ) -> Result<MaterializedViewOption<Raw>, ParserError> {
+        let name = self.parse_materialized_view_option_name()?;
+        let _ = self.consume_token(&Token::Eq);
+        let value = self.parse_opt_with_option_value(false)?;
+        Ok(MaterializedViewOption { name, value })
+    }
+
     fn parse_create_index(&mut self) -> Result<Statement<Raw>, ParserError> {
         let default_index = self.parse_keyword(DEFAULT);
         self.expect_keyword(INDEX)?;
@@ -4587,6 +4661,18 @@ impl<'a> Parser<'a> {
             }));
         }
 
+        if self.parse_keywords(&[STORAGE, USAGE]) {
+            let from = if self.parse_keyword(FROM) {
+                Some(self.parse_schema_name()?)
+            } else {
+                None
+            };
+            return Ok(Statement::ShowStorageUsage(ShowStorageUsageStatement {
+                from,
+                filter: self.parse_show_statement_filter()?,
+            }));
+        }
+
         let extended = self.parse_keyword(EXTENDED);
         if extended {
             self.expect_one_of_keywords(&[
@@ -5219,6 +5305,8 @@ impl<'a> Parser<'a> {
                     TransactionIsolationLevel::RepeatableRead
                 } else if self.parse_keyword(SERIALIZABLE) {
                     TransactionIsolationLevel::Serializable
+                } else if self.parse_keywords(&[STRICT, SERIALIZABLE]) {
+                    TransactionIsolationLevel::StrictSerializable
                 } else {
                     self.expected(self.peek_pos(), "isolation level", self.peek_token())?
                 };
@@ -5583,6 +5671,68 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a `COMMENT ON` statement, assuming that the `COMMENT` token
+    /// has already been consumed.
+    fn parse_comment(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(ON)?;
+        let object = match self.expect_one_of_keywords(&[
+            TABLE,
+            VIEW,
+            MATERIALIZED,
+            SOURCE,
+            SINK,
+            INDEX,
+            TYPE,
+            SECRET,
+            CONNECTION,
+            COLUMN,
+        ])? {
+            COLUMN => {
+                let mut idents = self.parse_identifiers()?;
+                if idents.len() < 2 {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "column name must be qualified by a relation name"
+                    );
+                }
+                let column_name = idents.pop().unwrap();
+                CommentObject::Column {
+                    relation_name: UnresolvedObjectName(idents),
+                    column_name,
+                }
+            }
+            kw => {
+                let object_type = match kw {
+                    TABLE => ObjectType::Table,
+                    VIEW => ObjectType::View,
+                    MATERIALIZED => {
+                        self.expect_keyword(VIEW)?;
+                        ObjectType::MaterializedView
+                    }
+                    SOURCE => ObjectType::Source,
+                    SINK => ObjectType::Sink,
+                    INDEX => ObjectType::Index,
+                    TYPE => ObjectType::Type,
+                    SECRET => ObjectType::Secret,
+                    CONNECTION => ObjectType::Connection,
+                    _ => unreachable!(),
+                };
+                CommentObject::Object {
+                    object_type,
+                    name: self.parse_object_name()?,
+                }
+            }
+        };
+        self.expect_keyword(IS)?;
+        let comment = if self.parse_keyword(NULL) {
+            None
+        } else {
+            Some(self.parse_literal_string()?)
+        };
+        Ok(Statement::Comment(CommentStatement { object, comment }))
+    }
+
     /// Parse a `RAISE` statement, assuming that the `RAISE` token
     /// has already been consumed.
     fn parse_raise(&mut self) -> Result<Statement<Raw>, ParserError> {
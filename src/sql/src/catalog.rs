@@ -128,6 +128,10 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync {
     /// Returns true if `schema` is an internal system schema, false otherwise
     fn is_system_schema(&self, schema: &str) -> bool;
 
+    /// Returns the schemas named in the session's `search_path` that exist,
+    /// in order of precedence.
+    fn search_path(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)];
+
     /// Resolves the named role.
     fn resolve_role(&self, role_name: &str) -> Result<&dyn CatalogRole, CatalogError>;
 
@@ -656,6 +660,10 @@ impl SessionCatalog for DummyCatalog {
         false
     }
 
+    fn search_path(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)] {
+        &[]
+    }
+
     fn resolve_role(&self, _: &str) -> Result<&dyn CatalogRole, CatalogError> {
         unimplemented!();
     }
@@ -1630,7 +1630,12 @@ macro_rules! impl_def {
     // Return type can be automatically determined as a function of the
     // parameters.
     ($params:expr, $op:expr, $oid:expr) => {{
-        let pcx = crate::plan::PlanContext::new(DateTime::<Utc>::MIN_UTC, false);
+        let pcx = crate::plan::PlanContext::new(
+            DateTime::<Utc>::MIN_UTC,
+            false,
+            usize::MAX,
+            usize::MAX,
+        );
         let scx = StatementContext::new(None, &crate::catalog::DummyCatalog);
         // This lifetime is compatible with more functions.
         let qcx = QueryContext::root(&scx, QueryLifetime::OneShot(&pcx));
@@ -1803,6 +1808,12 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         "char_length" => Scalar {
             params!(String) => UnaryFunc::CharLength(func::CharLength), 1381;
         },
+        "col_description" => Scalar {
+            params!(Oid, Int32) => sql_impl_func(
+                "(SELECT description FROM pg_catalog.pg_description
+                     WHERE objoid = $1 AND objsubid = $2)"
+            ) => String, 1216;
+        },
         "concat" => Scalar {
             params!(Any...) => Operation::variadic(|ecx, cexprs| {
                 if cexprs.is_empty() {
@@ -2043,12 +2054,12 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             }), 1375;
         },
         "obj_description" => Scalar {
-            params!(Oid, String) => Operation::binary(|_ecx, _oid, _catalog| {
-                // This function is meant to return the comment on a
-                // database object, but we don't presently support comments,
-                // so stubbed out out to always return NULL.
-                Ok(HirScalarExpr::literal_null(ScalarType::String))
-            }), 1215;
+            // Object OIDs are unique across catalogs, so the catalog name is
+            // only checked for NULL.
+            params!(Oid, String) => sql_impl_func(
+                "(SELECT description FROM pg_catalog.pg_description
+                     WHERE objoid = $1 AND objsubid = 0 AND $2 IS NOT NULL)"
+            ) => String, 1215;
         },
         "pg_column_size" => Scalar {
             params!(Any) => UnaryFunc::PgColumnSize(func::PgColumnSize) => Int32, 1269;
@@ -2062,6 +2073,31 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
                 Ok(e.call_unary(UnaryFunc::MzRowSize(func::MzRowSize)))
             }) => Int32, oid::FUNC_MZ_ROW_SIZE;
         },
+        "getdatabaseencoding" => Scalar {
+            // Materialize only supports UTF8-encoded databases.
+            params!() => sql_impl_func("'UTF8'") => String, 1039;
+        },
+        // Every role is a superuser, so the privilege checks below only
+        // report whether the object exists. Like PostgreSQL, they return NULL
+        // for an OID that does not identify an object of the right kind.
+        "has_database_privilege" => Scalar {
+            params!(Oid, String) => sql_impl_func(
+                "(SELECT true FROM mz_catalog.mz_databases WHERE oid = $1 AND $2 IS NOT NULL)"
+            ) => Bool, 2255;
+        },
+        "has_schema_privilege" => Scalar {
+            params!(Oid, String) => sql_impl_func(
+                "(SELECT true FROM mz_catalog.mz_schemas WHERE oid = $1 AND $2 IS NOT NULL)"
+            ) => Bool, 2273;
+        },
+        "has_table_privilege" => Scalar {
+            params!(Oid, String) => sql_impl_func(
+                "(SELECT true FROM mz_catalog.mz_relations WHERE oid = $1 AND $2 IS NOT NULL)"
+            ) => Bool, 1927;
+        },
+        "pg_client_encoding" => Scalar {
+            params!() => sql_impl_func("'UTF8'") => String, 810;
+        },
         "pg_encoding_to_char" => Scalar {
             // Materialize only supports UT8-encoded databases. Return 'UTF8' if Postgres'
             // encoding id for UTF8 (6) is provided, otherwise return 'NULL'.
@@ -2151,9 +2187,20 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             params!(String, Oid) => Operation::binary(|_ecx, l, _r| Ok(l)), 1716;
             params!(String, Oid, Bool) => Operation::variadic(move |_ecx, mut args| Ok(args.remove(0))), 2509;
         },
+        "pg_function_is_visible" => Scalar {
+            params!(Oid) => sql_impl_func(
+                "(SELECT s.name = ANY(pg_catalog.current_schemas(true))
+                     FROM mz_catalog.mz_functions f JOIN mz_catalog.mz_schemas s ON f.schema_id = s.id
+                     WHERE f.oid = $1)"
+            ) => Bool, 2081;
+        },
         "pg_get_userbyid" => Scalar {
             params!(Oid) => sql_impl_func("'unknown (OID=' || $1 || ')'") => String, 1642;
         },
+        "pg_is_in_recovery" => Scalar {
+            // Materialize has no notion of a standby server.
+            params!() => sql_impl_func("false") => Bool, 3810;
+        },
         "pg_postmaster_start_time" => Scalar {
             params!() => UnmaterializableFunc::PgPostmasterStartTime, 2560;
         },
@@ -16,6 +16,7 @@ use anyhow::bail;
 use rdkafka::client::ClientContext;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{Offset, TopicPartitionList};
+use regex::Regex;
 use tokio::time::Duration;
 
 use mz_kafka_util::client::{create_new_client_config, MzClientContext};
@@ -24,6 +25,7 @@ use mz_secrets::SecretsReader;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{AstInfo, KafkaConfigOption, KafkaConfigOptionName};
 use mz_storage::types::connections::{KafkaConnection, StringOrSecret};
+use mz_storage::types::sources::KafkaTopicSelection;
 
 use crate::names::Aug;
 use crate::normalize::generate_extracted_config;
@@ -57,9 +59,12 @@ pub fn validate_options_for_context<T: AstInfo>(
             Topic => None,
             TopicMetadataRefreshIntervalMs => None,
             TransactionTimeoutMs => None,
+            Topics => Some(Source),
+            TopicPattern => Some(Source),
             StartTimestamp => Some(Source),
             StartOffset => Some(Source),
             PartitionCount => Some(Sink),
+            PartitionStrategy => Some(Sink),
             ReplicationFactor => Some(Sink),
             RetentionBytes => Some(Sink),
             RetentionMs => Some(Sink),
@@ -96,9 +101,12 @@ generate_extracted_config!(
     (Topic, String),
     (TopicMetadataRefreshIntervalMs, i32),
     (TransactionTimeoutMs, i32),
+    (Topics, Vec<String>),
+    (TopicPattern, String),
     (StartTimestamp, i64),
     (StartOffset, Vec<i64>),
     (PartitionCount, i32, Default(-1)),
+    (PartitionStrategy, String),
     (ReplicationFactor, i32, Default(-1)),
     (RetentionBytes, i64),
     (RetentionMs, i64)
@@ -118,6 +126,7 @@ impl TryFrom<&KafkaConfigOptionExtracted> for LibRdKafkaConfig {
             enable_idempotence,
             fetch_message_max_bytes,
             isolation_level,
+            partition_strategy,
             statistics_interval_ms,
             topic_metadata_refresh_interval_ms,
             transaction_timeout_ms,
@@ -175,6 +184,25 @@ impl TryFrom<&KafkaConfigOptionExtracted> for LibRdKafkaConfig {
             "FETCH MESSAGE MAX BYTES must be within [0, 1,000,000,000]"
         );
 
+        if let Some(strategy) = partition_strategy {
+            // Map the user-facing strategies onto librdkafka's partitioners.
+            // `murmur2_random` matches the Java client's default partitioner,
+            // so records with the same key land in the same partition no
+            // matter which client produced them.
+            let partitioner = match strategy.to_lowercase().as_str() {
+                "hash" => "murmur2_random",
+                "random" => "random",
+                _ => sql_bail!(
+                    "PARTITION STRATEGY must be one of 'hash' or 'random', got '{}'",
+                    strategy
+                ),
+            };
+            o.insert(
+                "partitioner".to_string(),
+                StringOrSecret::String(partitioner.to_string()),
+            );
+        }
+
         Ok(LibRdKafkaConfig(o))
     }
 }
@@ -208,6 +236,38 @@ impl TryFrom<&KafkaConfigOptionExtracted> for Option<KafkaStartOffsetType> {
     }
 }
 
+impl TryFrom<&KafkaConfigOptionExtracted> for KafkaTopicSelection {
+    type Error = PlanError;
+    fn try_from(
+        KafkaConfigOptionExtracted {
+            topic,
+            topics,
+            topic_pattern,
+            ..
+        }: &KafkaConfigOptionExtracted,
+    ) -> Result<KafkaTopicSelection, Self::Error> {
+        Ok(match (topic, topics, topic_pattern) {
+            (Some(topic), None, None) => KafkaTopicSelection::Topic(topic.clone()),
+            (None, Some(topics), None) => {
+                if topics.is_empty() {
+                    sql_bail!("TOPICS must name at least one topic");
+                }
+                if topics.iter().collect::<HashSet<_>>().len() != topics.len() {
+                    sql_bail!("TOPICS must not name a topic more than once");
+                }
+                KafkaTopicSelection::Topics(topics.clone())
+            }
+            (None, None, Some(pattern)) => {
+                let regex =
+                    Regex::new(pattern).map_err(|e| sql_err!("parsing TOPIC PATTERN: {e}"))?;
+                KafkaTopicSelection::Pattern(mz_repr::adt::regex::Regex(regex))
+            }
+            (None, None, None) => sql_bail!("KAFKA CONNECTION without TOPIC"),
+            _ => sql_bail!("cannot specify more than one of TOPIC, TOPICS, and TOPIC PATTERN"),
+        })
+    }
+}
+
 /// Create a new `rdkafka::ClientConfig` with the provided
 /// [`options`](https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md),
 /// and test its ability to create an `rdkafka::consumer::BaseConsumer`.
@@ -222,7 +282,7 @@ impl TryFrom<&KafkaConfigOptionExtracted> for Option<KafkaStartOffsetType> {
 ///
 /// - `librdkafka` cannot create a BaseConsumer using the provided `options`.
 pub async fn create_consumer(
-    topic: &str,
+    topic: Option<&str>,
     kafka_connection: &KafkaConnection,
     options: &BTreeMap<String, StringOrSecret>,
     librdkafka_log_level: tracing::Level,
@@ -250,7 +310,8 @@ pub async fn create_consumer(
             .map_err(|e| sql_err!("{}", e))?,
     );
     let context = Arc::clone(&consumer.context());
-    let owned_topic = String::from(topic);
+    let owned_topic = topic.map(String::from);
+    let topic = topic.unwrap_or("*");
     // Wait for a metadata request for up to one second. This greatly
     // increases the probability that we'll see a connection error if
     // e.g. the hostname was mistyped. librdkafka doesn't expose a
@@ -259,7 +320,7 @@ pub async fn create_consumer(
     task::spawn_blocking(move || format!("kafka_set_metadata:{broker}:{topic}"), {
         let consumer = Arc::clone(&consumer);
         move || {
-            let _ = consumer.fetch_metadata(Some(&owned_topic), Duration::from_secs(1));
+            let _ = consumer.fetch_metadata(owned_topic.as_deref(), Duration::from_secs(1));
         }
     })
     .await
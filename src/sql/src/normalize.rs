@@ -398,10 +398,17 @@ pub fn create_statement(
             format: _,
             envelope: _,
             if_not_exists,
+            as_of,
             ..
         }) => {
             *name = allocate_name(name)?;
             *if_not_exists = false;
+            // The `AS OF` timestamp only determines where the sink starts, and
+            // the storage controller durably records that starting point when
+            // the sink is created. Re-evaluating the expression when the
+            // catalog is reloaded would be meaningless, and could fail if the
+            // timestamp has since been compacted away.
+            *as_of = None;
         }
 
         Statement::CreateView(CreateViewStatement {
@@ -434,6 +441,7 @@ pub fn create_statement(
             name,
             columns: _,
             in_cluster: _,
+            with_options: _,
             query,
         }) => {
             *name = allocate_name(name)?;
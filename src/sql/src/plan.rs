@@ -35,7 +35,7 @@ use mz_repr::explain_new::{ExplainConfig, ExplainFormat};
 use serde::{Deserialize, Serialize};
 
 use mz_compute_client::controller::ComputeInstanceId;
-use mz_expr::{MirRelationExpr, MirScalarExpr, RowSetFinishing};
+use mz_expr::{CollectionPlan, MirRelationExpr, MirScalarExpr, RowSetFinishing};
 use mz_ore::now::{self, NOW_ZERO};
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
@@ -55,6 +55,7 @@ pub(crate) mod error;
 pub(crate) mod explain;
 pub(crate) mod expr;
 pub(crate) mod lowering;
+pub(crate) mod notice;
 pub(crate) mod optimize;
 pub(crate) mod plan_utils;
 pub(crate) mod query;
@@ -71,9 +72,12 @@ pub use self::expr::{
 pub use error::PlanError;
 pub use explain::Explanation;
 use mz_sql_parser::ast::TransactionIsolationLevel;
+pub use notice::PlanNotice;
 pub use optimize::OptimizerConfig;
 pub use query::{QueryContext, QueryLifetime};
-pub use statement::{describe, plan, plan_copy_from, StatementContext, StatementDesc};
+pub use statement::{
+    describe, plan, plan_copy_from, plan_with_notices, StatementContext, StatementDesc,
+};
 
 /// Instructions for executing a SQL query.
 #[derive(Debug)]
@@ -125,6 +129,7 @@ pub enum Plan {
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
     AlterSystemResetAll(AlterSystemResetAllPlan),
+    Comment(CommentPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
     Close(ClosePlan),
@@ -213,6 +218,9 @@ pub struct CreateSourcePlan {
     pub if_not_exists: bool,
     pub timeline: Timeline,
     pub host_config: StorageHostConfig,
+    /// The logical compaction window requested for the source, if any. `Some(None)`
+    /// disables logical compaction entirely.
+    pub logical_compaction_window: Option<Option<Duration>>,
 }
 
 /// Settings related to storage hosts
@@ -255,6 +263,7 @@ pub struct CreateSinkPlan {
     pub name: QualifiedObjectName,
     pub sink: Sink,
     pub with_snapshot: bool,
+    pub when: QueryWhen,
     pub if_not_exists: bool,
 }
 
@@ -439,6 +448,14 @@ pub struct ReadThenWritePlan {
     pub returning: Vec<mz_expr::MirScalarExpr>,
 }
 
+impl ReadThenWritePlan {
+    /// Reports whether the plan is a blind write, i.e., an insert whose rows do
+    /// not depend on the contents of any collection.
+    pub fn is_blind_write(&self) -> bool {
+        matches!(self.kind, MutationKind::Insert) && self.selection.depends_on().is_empty()
+    }
+}
+
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
 #[derive(Debug)]
 pub struct AlterNoopPlan {
@@ -458,8 +475,8 @@ pub struct AlterIndexResetOptionsPlan {
 }
 
 #[derive(Debug, Clone)]
-pub enum AlterSourceItem {
-    Set(String),
+pub enum AlterSourceItem<T = String> {
+    Set(T),
     Reset,
     Unchanged,
 }
@@ -469,6 +486,9 @@ pub struct AlterSourcePlan {
     pub id: GlobalId,
     pub size: AlterSourceItem,
     pub remote: AlterSourceItem,
+    /// The new logical compaction window. `Set(None)` disables logical
+    /// compaction entirely.
+    pub logical_compaction_window: AlterSourceItem<Option<Duration>>,
 }
 
 #[derive(Debug)]
@@ -499,6 +519,16 @@ pub struct AlterSystemResetPlan {
 #[derive(Debug)]
 pub struct AlterSystemResetAllPlan {}
 
+#[derive(Debug)]
+pub struct CommentPlan {
+    pub object_id: GlobalId,
+    /// The 1-indexed position of the commented column, if the comment is on a
+    /// column rather than on the object itself.
+    pub column_position: Option<usize>,
+    /// The new comment, or `None` to remove the existing comment.
+    pub comment: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct RotateKeysPlan {
     pub id: GlobalId,
@@ -594,6 +624,7 @@ pub struct MaterializedView {
     pub expr: mz_expr::MirRelationExpr,
     pub column_names: Vec<ColumnName>,
     pub compute_instance: ComputeInstanceId,
+    pub logical_compaction_window: Option<Option<Duration>>,
 }
 
 #[derive(Clone, Debug)]
@@ -678,13 +709,22 @@ pub enum ExecuteTimeout {
     WaitOnce,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum IndexOption {
     /// Configures the logical compaction window for an index. `None` disables
     /// logical compaction entirely.
     LogicalCompactionWindow(Option<Duration>),
 }
 
+impl IndexOption {
+    /// Returns the name of the SQL option that configures this option.
+    pub fn name(&self) -> IndexOptionName {
+        match self {
+            IndexOption::LogicalCompactionWindow(_) => IndexOptionName::LogicalCompactionWindow,
+        }
+    }
+}
+
 /// A vector of values to which parameter references should be bound.
 #[derive(Debug, Clone)]
 pub struct Params {
@@ -707,13 +747,24 @@ impl Params {
 pub struct PlanContext {
     pub wall_time: DateTime<Utc>,
     pub qgm_optimizations: bool,
+    /// The maximum number of elements permitted in an `IN` list.
+    pub max_in_list_size: usize,
+    /// The maximum number of expressions permitted in a query.
+    pub max_query_size: usize,
 }
 
 impl PlanContext {
-    pub fn new(wall_time: DateTime<Utc>, qgm_optimizations: bool) -> Self {
+    pub fn new(
+        wall_time: DateTime<Utc>,
+        qgm_optimizations: bool,
+        max_in_list_size: usize,
+        max_query_size: usize,
+    ) -> Self {
         Self {
             wall_time,
             qgm_optimizations,
+            max_in_list_size,
+            max_query_size,
         }
     }
 
@@ -724,6 +775,8 @@ impl PlanContext {
         PlanContext {
             wall_time: now::to_datetime(NOW_ZERO()),
             qgm_optimizations: false,
+            max_in_list_size: usize::MAX,
+            max_query_size: usize::MAX,
         }
     }
 }
@@ -81,6 +81,14 @@ pub enum PlanError {
     ShowCreateViewOnMaterializedView(String),
     ExplainViewOnMaterializedView(String),
     UnacceptableTimelineName(String),
+    InListTooLarge {
+        len: usize,
+        limit: usize,
+    },
+    QueryTooLarge {
+        size: usize,
+        limit: usize,
+    },
     // TODO(benesch): eventually all errors should be structured.
     Unstructured(String),
 }
@@ -114,6 +122,12 @@ impl PlanError {
             Self::UnacceptableTimelineName(_) => {
                 Some("The prefix \"mz_\" is reserved for system timelines.".into())
             }
+            Self::InListTooLarge { .. } => Some(
+                "Increase max_in_list_size, or join against a VALUES list or table instead.".into(),
+            ),
+            Self::QueryTooLarge { .. } => {
+                Some("Increase max_query_size, or simplify the query.".into())
+            }
             _ => None,
         }
     }
@@ -185,6 +199,16 @@ impl fmt::Display for PlanError {
             Self::UnacceptableTimelineName(name) => {
                 write!(f, "unacceptable timeline name {}", name.quoted(),)
             }
+            Self::InListTooLarge { len, limit } => write!(
+                f,
+                "IN list has {} elements, which exceeds the limit of {}",
+                len, limit
+            ),
+            Self::QueryTooLarge { size, limit } => write!(
+                f,
+                "query has {} expressions, which exceeds the limit of {}",
+                size, limit
+            ),
             Self::SubqueriesDisallowed { context } => {
                 write!(f, "{} does not allow subqueries", context)
             }
@@ -0,0 +1,65 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Notices produced during planning.
+
+use std::fmt;
+
+/// A notice about a planned statement that should be reported to the client.
+///
+/// Notices describe behavior that is valid but likely surprising, like parts
+/// of a statement that Materialize accepts but ignores. Unlike errors, they
+/// do not prevent the statement from executing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlanNotice {
+    /// A precision was specified for the `numeric` type.
+    NumericPrecisionIgnored { precision: i64 },
+    /// A view definition contains an `ORDER BY` clause without a `LIMIT` or
+    /// `OFFSET` clause.
+    ViewOrderByIgnored,
+}
+
+impl PlanNotice {
+    /// Reports additional details about the notice, if any are available.
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            PlanNotice::NumericPrecisionIgnored { .. } => {
+                Some("All numeric values have a precision of 39.".into())
+            }
+            PlanNotice::ViewOrderByIgnored => Some(
+                "The rows of a view are unordered. Queries against the view \
+                 do not return rows in the order specified by the view."
+                    .into(),
+            ),
+        }
+    }
+
+    /// Reports a hint for the user about how the notice could be addressed.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            PlanNotice::NumericPrecisionIgnored { .. } => None,
+            PlanNotice::ViewOrderByIgnored => {
+                Some("Specify ORDER BY in queries against the view instead.".into())
+            }
+        }
+    }
+}
+
+impl fmt::Display for PlanNotice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanNotice::NumericPrecisionIgnored { precision } => {
+                write!(f, "precision {} for type numeric is ignored", precision)
+            }
+            PlanNotice::ViewOrderByIgnored => {
+                write!(f, "ORDER BY in a view definition is ignored")
+            }
+        }
+    }
+}
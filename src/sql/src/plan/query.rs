@@ -45,7 +45,7 @@ use uuid::Uuid;
 use mz_expr::virtual_syntax::AlgExcept;
 use mz_expr::{func as expr_func, Id, LocalId, MirScalarExpr, RowSetFinishing};
 use mz_ore::collections::CollectionExt;
-use mz_ore::stack::{CheckedRecursion, RecursionGuard};
+use mz_ore::stack::{self, CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
 use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
@@ -55,6 +55,7 @@ use mz_repr::{
     ScalarType,
 };
 
+use mz_sql_parser::ast::visit::{self, Visit};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, DeleteStatement, Distinct, Expr, Function, FunctionArgs,
@@ -80,7 +81,7 @@ use crate::plan::scope::{Scope, ScopeItem};
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::typeconv::{self, CastContext};
 use crate::plan::{transform_ast, PlanContext};
-use crate::plan::{Params, QueryWhen};
+use crate::plan::{Params, PlanNotice, QueryWhen};
 
 #[derive(Debug)]
 pub struct PlannedQuery<E> {
@@ -104,6 +105,7 @@ pub fn plan_root_query(
     lifetime: QueryLifetime,
 ) -> Result<PlannedQuery<HirRelationExpr>, PlanError> {
     transform_ast::transform_query(scx, &mut query)?;
+    check_query_size(scx, &query)?;
     let mut qcx = QueryContext::root(scx, lifetime);
     let (mut expr, scope, mut finishing) = plan_query(&mut qcx, &query)?;
 
@@ -165,6 +167,33 @@ fn try_push_projection_order_by(
     }
 }
 
+/// Counts the expressions in an AST.
+struct ExprCounter(usize);
+
+impl<'ast> Visit<'ast, Aug> for ExprCounter {
+    fn visit_expr(&mut self, expr: &'ast Expr<Aug>) {
+        self.0 += 1;
+        stack::maybe_grow(|| visit::visit_expr(self, expr))
+    }
+}
+
+/// Returns an error if `query` has more expressions than the plan context
+/// permits, so that generated queries are rejected before they produce
+/// pathologically large plans.
+fn check_query_size(scx: &StatementContext, query: &Query<Aug>) -> Result<(), PlanError> {
+    if let Ok(pcx) = scx.pcx() {
+        let mut counter = ExprCounter(0);
+        counter.visit_query(query);
+        if counter.0 > pcx.max_query_size {
+            return Err(PlanError::QueryTooLarge {
+                size: counter.0,
+                limit: pcx.max_query_size,
+            });
+        }
+    }
+    Ok(())
+}
+
 pub fn plan_insert_query(
     scx: &StatementContext,
     table_name: ResolvedObjectName,
@@ -3103,12 +3132,25 @@ fn plan_in_list(
     list: &Vec<Expr<Aug>>,
     negated: &bool,
 ) -> Result<CoercibleScalarExpr, PlanError> {
+    if let Ok(pcx) = ecx.qcx.scx.pcx() {
+        if list.len() > pcx.max_in_list_size {
+            return Err(PlanError::InListTooLarge {
+                len: list.len(),
+                limit: pcx.max_in_list_size,
+            });
+        }
+    }
     let ecx = ecx.with_name("IN list");
+    // Plan the left-hand side only once, rather than once per element, so
+    // that the cost of planning does not scale with the product of the size
+    // of the left-hand side and the length of the list.
+    let lhs = plan_expr(&ecx, lhs)?;
+    let impls = func::resolve_op("=")?;
     let or = HirScalarExpr::variadic_or(
         list.into_iter()
             .map(|e| {
-                let eq = lhs.clone().equals(e.clone());
-                plan_expr(&ecx, &eq)?.type_as(&ecx, &ScalarType::Bool)
+                let args = vec![lhs.clone(), plan_expr(&ecx, e)?];
+                func::select_impl(&ecx, FuncSpec::Op("="), impls, args, vec![])
             })
             .collect::<Result<Vec<HirScalarExpr>, PlanError>>()?,
     );
@@ -4584,7 +4626,10 @@ fn scalar_type_from_catalog(
                         NUMERIC_DATUM_MAX_PRECISION,
                     );
                 }
-                Some(p) => Some(*p),
+                Some(p) => {
+                    scx.add_notice(PlanNotice::NumericPrecisionIgnored { precision: *p });
+                    Some(*p)
+                }
                 None => None,
             };
             let scale = match modifiers.next() {
@@ -29,7 +29,7 @@ use crate::names::{
 };
 use crate::plan::error::PlanError;
 use crate::plan::query;
-use crate::plan::{Params, Plan, PlanContext};
+use crate::plan::{Params, Plan, PlanContext, PlanNotice};
 use crate::{normalize, DEFAULT_SCHEMA};
 
 mod ddl;
@@ -100,6 +100,7 @@ pub fn describe(
         pcx: Some(pcx),
         catalog,
         param_types: RefCell::new(param_types),
+        notices: RefCell::new(vec![]),
     };
 
     let desc = match stmt {
@@ -112,6 +113,7 @@ pub fn describe(
         Statement::AlterSystemSet(stmt) => ddl::describe_alter_system_set(&scx, stmt)?,
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
+        Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
         Statement::CreateClusterReplica(stmt) => ddl::describe_create_cluster_replica(&scx, stmt)?,
         Statement::CreateConnection(stmt) => ddl::describe_create_connection(&scx, stmt)?,
@@ -151,6 +153,7 @@ pub fn describe(
         Statement::ShowIndexes(stmt) => show::show_indexes(&scx, stmt)?.describe()?,
         Statement::ShowObjects(stmt) => show::show_objects(&scx, stmt)?.describe()?,
         Statement::ShowSchemas(stmt) => show::show_schemas(&scx, stmt)?.describe()?,
+        Statement::ShowStorageUsage(stmt) => show::show_storage_usage(&scx, stmt)?.describe()?,
 
         // SCL statements.
         Statement::Close(stmt) => scl::describe_close(&scx, stmt)?,
@@ -203,6 +206,18 @@ pub fn plan(
     stmt: Statement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
+    let (plan, _notices) = plan_with_notices(pcx, catalog, stmt, params)?;
+    Ok(plan)
+}
+
+/// Like [`plan`], but additionally returns the notices that planning produced
+/// for the client.
+pub fn plan_with_notices(
+    pcx: Option<&PlanContext>,
+    catalog: &dyn SessionCatalog,
+    stmt: Statement<Aug>,
+    params: &Params,
+) -> Result<(Plan, Vec<PlanNotice>), PlanError> {
     let param_types = params
         .types
         .iter()
@@ -214,9 +229,10 @@ pub fn plan(
         pcx,
         catalog,
         param_types: RefCell::new(param_types),
+        notices: RefCell::new(vec![]),
     };
 
-    match stmt {
+    let plan = match stmt {
         // DDL statements.
         Statement::AlterConnection(stmt) => ddl::plan_alter_connection(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
@@ -226,6 +242,7 @@ pub fn plan(
         Statement::AlterSystemSet(stmt) => ddl::plan_alter_system_set(scx, stmt),
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
+        Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
         Statement::CreateClusterReplica(stmt) => ddl::plan_create_cluster_replica(scx, stmt),
         Statement::CreateConnection(stmt) => ddl::plan_create_connection(scx, stmt),
@@ -274,6 +291,7 @@ pub fn plan(
         Statement::ShowIndexes(stmt) => show::show_indexes(scx, stmt)?.plan(),
         Statement::ShowObjects(stmt) => show::show_objects(scx, stmt)?.plan(),
         Statement::ShowSchemas(stmt) => show::show_schemas(scx, stmt)?.plan(),
+        Statement::ShowStorageUsage(stmt) => show::show_storage_usage(scx, stmt)?.plan(),
 
         // SCL statements.
         Statement::Close(stmt) => scl::plan_close(scx, stmt),
@@ -295,7 +313,8 @@ pub fn plan(
 
         // Other statements.
         Statement::Raise(stmt) => raise::plan_raise(scx, stmt),
-    }
+    }?;
+    Ok((plan, scx.notices.take()))
 }
 
 pub fn plan_copy_from(
@@ -349,6 +368,9 @@ pub struct StatementContext<'a> {
     /// The types of the parameters in the query. This is filled in as planning
     /// occurs.
     pub param_types: RefCell<BTreeMap<usize, ScalarType>>,
+    /// The notices to report to the client. This is filled in as planning
+    /// occurs.
+    notices: RefCell<Vec<PlanNotice>>,
 }
 
 impl<'a> StatementContext<'a> {
@@ -360,6 +382,7 @@ impl<'a> StatementContext<'a> {
             pcx,
             catalog,
             param_types: Default::default(),
+            notices: Default::default(),
         }
     }
 
@@ -368,7 +391,30 @@ impl<'a> StatementContext<'a> {
     }
 
     pub fn allocate_full_name(&self, name: PartialObjectName) -> Result<FullObjectName, PlanError> {
-        let schema = name.schema.unwrap_or_else(|| DEFAULT_SCHEMA.into());
+        let schema = match name.schema {
+            Some(schema) => schema,
+            None => match self.active_search_path_schema() {
+                Some((database_spec, schema_spec)) => {
+                    let database = match database_spec {
+                        ResolvedDatabaseSpecifier::Ambient => RawDatabaseSpecifier::Ambient,
+                        ResolvedDatabaseSpecifier::Id(id) => {
+                            RawDatabaseSpecifier::Name(self.get_database(id).name().to_string())
+                        }
+                    };
+                    let schema = self
+                        .get_schema(database_spec, schema_spec)
+                        .name()
+                        .schema
+                        .clone();
+                    return Ok(FullObjectName {
+                        database,
+                        schema,
+                        item: name.item,
+                    });
+                }
+                None => DEFAULT_SCHEMA.into(),
+            },
+        };
         let database = match name.database {
             Some(name) => RawDatabaseSpecifier::Name(name),
             None if self.catalog.is_system_schema(&schema) => RawDatabaseSpecifier::Ambient,
@@ -464,8 +510,26 @@ impl<'a> StatementContext<'a> {
         }
     }
 
+    /// Resolves the schema that unqualified names refer to by default, i.e.,
+    /// the first schema in the session's `search_path` that exists.
     pub fn resolve_active_schema(&self) -> Result<&SchemaSpecifier, PlanError> {
-        Ok(self.catalog.resolve_schema(None, DEFAULT_SCHEMA)?.id())
+        match self.active_search_path_schema() {
+            Some((_, schema_spec)) => Ok(schema_spec),
+            None => Ok(self.catalog.resolve_schema(None, DEFAULT_SCHEMA)?.id()),
+        }
+    }
+
+    /// Returns the first non-temporary schema in the session's `search_path`
+    /// that exists, if any.
+    ///
+    /// Like PostgreSQL, the temporary schema is never chosen implicitly; it
+    /// must be named explicitly or requested via `CREATE TEMPORARY`.
+    fn active_search_path_schema(&self) -> Option<(&ResolvedDatabaseSpecifier, &SchemaSpecifier)> {
+        self.catalog
+            .search_path()
+            .iter()
+            .find(|(_, schema_spec)| !matches!(schema_spec, SchemaSpecifier::Temporary))
+            .map(|(database_spec, schema_spec)| (database_spec, schema_spec))
     }
 
     pub fn resolve_database(
@@ -589,6 +653,14 @@ impl<'a> StatementContext<'a> {
         Ok(data_type)
     }
 
+    /// Records a notice to report to the client.
+    pub fn add_notice(&self, notice: PlanNotice) {
+        let mut notices = self.notices.borrow_mut();
+        if !notices.contains(&notice) {
+            notices.push(notice);
+        }
+    }
+
     pub fn unsafe_mode(&self) -> bool {
         self.catalog.config().unsafe_mode
     }
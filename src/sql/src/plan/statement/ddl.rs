@@ -15,6 +15,7 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Write;
 use std::str::FromStr;
+use std::time::Duration;
 
 use aws_arn::ResourceName as AmazonResourceName;
 use globset::GlobBuilder;
@@ -23,7 +24,7 @@ use prost::Message;
 use regex::Regex;
 use tracing::warn;
 
-use mz_expr::CollectionPlan;
+use mz_expr::{CollectionPlan, RowSetFinishing};
 use mz_interchange::avro::AvroSchemaGenerator;
 use mz_kafka_util::KafkaAddrs;
 use mz_ore::collections::CollectionExt;
@@ -36,14 +37,14 @@ use mz_repr::{ColumnName, GlobalId, RelationDesc, RelationType, ScalarType};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterSourceAction, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, LoadGenerator, SetVariableValue,
-    SshConnectionOption,
+    AlterSystemResetStatement, AlterSystemSetStatement, CommentObject, CommentStatement,
+    LoadGenerator, SetVariableValue, SshConnectionOption,
 };
 use mz_storage::source::generator::as_generator;
 use mz_storage::types::connections::aws::AwsCredentials;
 use mz_storage::types::connections::{
-    Connection, CsrConnectionHttpAuth, KafkaConnection, KafkaSecurity, KafkaTlsConfig, SaslConfig,
-    StringOrSecret, TlsIdentity,
+    Connection, CsrConnectionHttpAuth, GcpConnection, KafkaConnection, KafkaSecurity,
+    KafkaTlsConfig, SaslConfig, StringOrSecret, TlsIdentity,
 };
 use mz_storage::types::sinks::{
     KafkaConsistencyConfig, KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention,
@@ -55,10 +56,11 @@ use mz_storage::types::sources::encoding::{
 };
 use mz_storage::types::sources::{
     DebeziumDedupProjection, DebeziumEnvelope, DebeziumSourceProjection,
-    DebeziumTransactionMetadata, IncludedColumnPos, KafkaSourceConnection, KeyEnvelope,
-    KinesisSourceConnection, LoadGeneratorSourceConnection, MzOffset, PostgresSourceConnection,
-    PostgresSourceDetails, ProtoPostgresSourceDetails, S3SourceConnection, SourceConnection,
-    SourceDesc, SourceEnvelope, Timeline, UnplannedSourceEnvelope, UpsertStyle,
+    DebeziumTransactionMetadata, FileSourceConnection, IncludedColumnPos, KafkaSourceConnection,
+    KafkaTopicSelection, KeyEnvelope, KinesisSourceConnection, LoadGeneratorSourceConnection,
+    MzOffset, PostgresSourceConnection, PostgresSourceDetails, ProtoPostgresSourceDetails,
+    PubSubSourceConnection, S3SourceConnection, SourceConnection, SourceDesc, SourceEnvelope,
+    Timeline, UnplannedSourceEnvelope, UpsertStyle,
 };
 
 use crate::ast::display::AstDisplay;
@@ -77,9 +79,10 @@ use crate::ast::{
     CsrConnectionOptionName, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
     DbzTxMetadataOption, DropClusterReplicasStatement, DropClustersStatement,
     DropDatabaseStatement, DropObjectsStatement, DropRolesStatement, DropSchemaStatement, Envelope,
-    Expr, Format, Ident, IfExistsBehavior, IndexOption, IndexOptionName, KafkaConfigOptionName,
-    KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, ObjectType, Op, PostgresConnectionOption,
+    Expr, Format, GcpConnectionOption, GcpConnectionOptionName, Ident, IfExistsBehavior,
+    IndexOption, IndexOptionName, KafkaConfigOptionName, KafkaConnectionOption,
+    KafkaConnectionOptionName, KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName,
+    MaterializedViewOption, MaterializedViewOptionName, ObjectType, Op, PostgresConnectionOption,
     PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica, Query, ReplicaDefinition,
     ReplicaOption, ReplicaOptionName, Select, SelectItem, SetExpr, SourceIncludeMetadata,
     SourceIncludeMetadataType, SshConnectionOptionName, Statement, SubscriptPosition,
@@ -100,14 +103,14 @@ use crate::plan::with_options::{self, OptionalInterval, TryFromValue};
 use crate::plan::{
     plan_utils, query, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan,
     AlterNoopPlan, AlterSecretPlan, AlterSourceItem, AlterSourcePlan, AlterSystemResetAllPlan,
-    AlterSystemResetPlan, AlterSystemSetPlan, ComputeInstanceIntrospectionConfig,
+    AlterSystemResetPlan, AlterSystemSetPlan, CommentPlan, ComputeInstanceIntrospectionConfig,
     ComputeInstanceReplicaConfig, CreateComputeInstancePlan, CreateComputeInstanceReplicaPlan,
     CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
     CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
     CreateTablePlan, CreateTypePlan, CreateViewPlan, CreateViewsPlan,
     DropComputeInstanceReplicaPlan, DropComputeInstancesPlan, DropDatabasePlan, DropItemsPlan,
-    DropRolesPlan, DropSchemaPlan, Index, MaterializedView, Params, Plan, RotateKeysPlan, Secret,
-    Sink, Source, StorageHostConfig, Table, Type, View,
+    DropRolesPlan, DropSchemaPlan, Index, MaterializedView, Params, Plan, PlanNotice,
+    RotateKeysPlan, Secret, Sink, Source, StorageHostConfig, Table, Type, View,
 };
 
 pub fn describe_create_database(
@@ -311,9 +314,12 @@ pub fn describe_create_source(
 
 generate_extracted_config!(
     CreateSourceOption,
+    (Fields, Vec<String>),
     (IgnoreKeys, bool),
+    (LogicalCompactionWindow, OptionalInterval),
     (Remote, String),
     (Size, String),
+    (Tail, bool),
     (Timeline, String),
     (TimestampInterval, Interval)
 );
@@ -340,7 +346,11 @@ pub fn plan_create_source(
     let legacy_with_options_original = legacy_with_options;
     let mut legacy_with_options = normalize::options(legacy_with_options_original)?;
 
-    const SAFE_WITH_OPTIONS: &'static [CreateSourceOptionName] = &[CreateSourceOptionName::Size];
+    const SAFE_WITH_OPTIONS: &'static [CreateSourceOptionName] = &[
+        CreateSourceOptionName::Fields,
+        CreateSourceOptionName::Size,
+        CreateSourceOptionName::LogicalCompactionWindow,
+    ];
 
     if !legacy_with_options.is_empty()
         || with_options
@@ -365,13 +375,41 @@ pub fn plan_create_source(
         bail_unsupported!("INCLUDE metadata with non-Kafka sources");
     }
 
-    let (external_connection, encoding) = match connection {
+    let CreateSourceOptionExtracted {
+        remote,
+        size,
+        tail,
+        timeline,
+        timestamp_interval,
+        ignore_keys,
+        logical_compaction_window,
+        fields,
+        seen: _,
+    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+
+    if tail.is_some() && !matches!(connection, CreateSourceConnection::File { .. }) {
+        sql_bail!("TAIL is only supported for FILE sources");
+    }
+
+    let (external_connection, mut encoding) = match connection {
+        CreateSourceConnection::File { path } => {
+            scx.require_unsafe_mode("CREATE SOURCE ... FROM FILE")?;
+            let encoding = get_encoding(scx, format, &envelope, connection)?;
+            if matches!(encoding, SourceDataEncoding::KeyValue { .. }) {
+                sql_bail!("file sources do not support key decoding");
+            }
+            let connection = SourceConnection::File(FileSourceConnection {
+                path: path.clone(),
+                tail: tail.unwrap_or(false),
+            });
+            (connection, encoding)
+        }
         CreateSourceConnection::Kafka(mz_sql_parser::ast::KafkaSourceConnection {
             connection: connection_inner,
             topic,
             key: _,
         }) => {
-            let (kafka_connection, topic, options, optional_start_offset, group_id_prefix) =
+            let (kafka_connection, topics, options, optional_start_offset, group_id_prefix) =
                 match &connection_inner {
                     mz_sql_parser::ast::KafkaConnection::Inline { broker } => {
                         scx.require_unsafe_mode("creating Kafka sources with inline connections")?;
@@ -384,9 +422,11 @@ pub fn plan_create_source(
                         let options = connection.clone().into();
                         (
                             connection,
-                            topic
-                                .clone()
-                                .expect("inline definitions always parse a topic"),
+                            KafkaTopicSelection::Topic(
+                                topic
+                                    .clone()
+                                    .expect("inline definitions always parse a topic"),
+                            ),
                             options,
                             None,
                             None,
@@ -426,13 +466,11 @@ pub fn plan_create_source(
                         let config_options =
                             kafka_util::LibRdKafkaConfig::try_from(&extracted_options)?.0;
 
-                        let topic = extracted_options
-                            .topic
-                            .ok_or_else(|| sql_err!("KAFKA CONNECTION without TOPIC"))?;
+                        let topics = KafkaTopicSelection::try_from(&extracted_options)?;
 
                         (
                             connection,
-                            topic,
+                            topics,
                             config_options,
                             optional_start_offset,
                             extracted_options.group_id_prefix,
@@ -455,17 +493,27 @@ pub fn plan_create_source(
                 }
             };
 
+            // Start offsets are given per partition of a single topic, which
+            // would be ambiguous for sources that read several topics.
+            if optional_start_offset.is_some() && !matches!(topics, KafkaTopicSelection::Topic(_)) {
+                sql_bail!("START OFFSET and START TIMESTAMP require a single TOPIC")
+            }
+
             let mut start_offsets = HashMap::new();
             let has_nontrivial_start_offsets = match optional_start_offset {
                 None => {
-                    start_offsets.insert(0, MzOffset::from(0));
+                    if let KafkaTopicSelection::Topic(_) = topics {
+                        start_offsets.insert(0, MzOffset::from(0));
+                    }
                     false
                 }
                 Some(KafkaStartOffsetType::StartOffset(vs)) => {
                     for (i, v) in vs.iter().enumerate() {
                         start_offsets.insert(i32::try_from(i)?, parse_offset(*v)?);
                     }
-                    true
+                    // Starting every partition at offset zero reads the whole
+                    // topic, just like not specifying a start offset.
+                    vs.iter().any(|v| *v > 0)
                 }
                 Some(KafkaStartOffsetType::StartTimestamp(_)) => {
                     unreachable!("time offsets should be converted in purification")
@@ -481,7 +529,7 @@ pub fn plan_create_source(
             let mut connection = KafkaSourceConnection {
                 connection: kafka_connection,
                 options,
-                topic,
+                topics,
                 start_offsets,
                 group_id_prefix,
                 cluster_id: scx.catalog.config().cluster_id,
@@ -523,6 +571,14 @@ pub fn plan_create_source(
                 }
             }
 
+            // Duplicate `INCLUDE KEY`s are rejected by `get_key_envelope`.
+            let mut seen_metadata = HashSet::new();
+            for item in include_metadata {
+                if item.ty != SourceIncludeMetadataType::Key && !seen_metadata.insert(&item.ty) {
+                    sql_bail!("INCLUDE {} specified more than once", item.ty);
+                }
+            }
+
             for (pos, item) in include_metadata.iter().cloned().enumerate() {
                 match item.ty {
                     SourceIncludeMetadataType::Timestamp => {
@@ -532,12 +588,7 @@ pub fn plan_create_source(
                         connection.include_partition = unwrap_name(item.alias, "partition", pos);
                     }
                     SourceIncludeMetadataType::Topic => {
-                        // TODO(bwm): This requires deeper thought, the current structure of the
-                        // code requires us to clone the topic name around all over the place
-                        // whether or not anyone ever uses it. Considering we expect the
-                        // overwhelming majority of people will *not* want topics in dataflows that
-                        // is an unnacceptable cost.
-                        bail_unsupported!("INCLUDE TOPIC");
+                        connection.include_topic = unwrap_name(item.alias, "topic", pos);
                     }
                     SourceIncludeMetadataType::Offset => {
                         connection.include_offset = unwrap_name(item.alias, "offset", pos);
@@ -585,6 +636,9 @@ pub fn plan_create_source(
                 aws_connection,
             )?;
             let encoding = get_encoding(scx, format, &envelope, &connection)?;
+            if matches!(encoding, SourceDataEncoding::KeyValue { .. }) {
+                sql_bail!("Kinesis sources do not support key decoding");
+            }
             let connection =
                 SourceConnection::Kinesis(KinesisSourceConnection { stream_name, aws });
             (connection, encoding)
@@ -691,7 +745,71 @@ pub fn plan_create_source(
             });
             (connection, generator.data_encoding())
         }
+        CreateSourceConnection::PubSub {
+            connection: gcp_connection,
+            subscription,
+        } => {
+            scx.require_unsafe_mode("CREATE SOURCE ... FROM PUBSUB")?;
+
+            match subscription.split('/').collect::<Vec<_>>()[..] {
+                ["projects", project, "subscriptions", name]
+                    if !project.is_empty() && !name.is_empty() => {}
+                _ => sql_bail!(
+                    "invalid Pub/Sub subscription {}: expected projects/<project>/subscriptions/<subscription>",
+                    subscription.quoted()
+                ),
+            }
+
+            let item = scx.get_item_by_resolved_name(&gcp_connection)?;
+            let gcp_connection = match item.connection()? {
+                Connection::Gcp(connection) => connection.clone(),
+                _ => sql_bail!("{} is not a GCP connection", item.name()),
+            };
+
+            // Pub/Sub forgets acknowledged messages, so a restarted source must
+            // resume where it left off rather than reingest from the beginning,
+            // which only the stateless envelope does.
+            if !matches!(envelope, Envelope::None) {
+                sql_bail!("Pub/Sub sources only support ENVELOPE NONE");
+            }
+
+            let encoding = get_encoding(scx, format, &envelope, &connection)?;
+            if matches!(encoding, SourceDataEncoding::KeyValue { .. }) {
+                sql_bail!("Pub/Sub sources do not support key decoding");
+            }
+            let connection = SourceConnection::PubSub(PubSubSourceConnection {
+                connection: gcp_connection,
+                subscription: subscription.clone(),
+            });
+            (connection, encoding)
+        }
     };
+
+    // Decode only the requested fields of the value. The other fields are
+    // skipped by the decoder and do not become columns of the source.
+    if let Some(fields) = fields {
+        if !matches!(envelope, Envelope::None | Envelope::Upsert) {
+            sql_bail!("FIELDS is only supported with ENVELOPE NONE and ENVELOPE UPSERT");
+        }
+        let value = match &mut encoding {
+            SourceDataEncoding::Single(value) | SourceDataEncoding::KeyValue { value, .. } => value,
+        };
+        let avro = match &mut value.inner {
+            DataEncodingInner::Avro(avro) => avro,
+            _ => sql_bail!("FIELDS is only supported for sources with FORMAT AVRO"),
+        };
+        if fields.is_empty() {
+            sql_bail!("FIELDS must name at least one field");
+        }
+        if let Some(dup) = fields.iter().duplicates().next() {
+            sql_bail!("FIELDS must not name field {} more than once", dup.quoted());
+        }
+        let schema = mz_interchange::avro::parse_schema(&avro.schema)?;
+        let projection = mz_interchange::avro::record_field_indexes(&schema, &fields)
+            .map_err(|e| sql_err!("invalid FIELDS: {}", e))?;
+        avro.projection = Some(projection);
+    }
+
     let (key_desc, value_desc) = encoding.desc()?;
 
     let mut key_envelope = get_key_envelope(include_metadata, &envelope, &encoding)?;
@@ -709,6 +827,14 @@ pub fn plan_create_source(
         mz_sql_parser::ast::Envelope::None => UnplannedSourceEnvelope::None(key_envelope),
         mz_sql_parser::ast::Envelope::Debezium(mode) => {
             scx.require_unsafe_mode("ENVELOPE DEBEZIUM")?;
+            match format {
+                CreateSourceFormat::Bare(Format::Avro(_))
+                | CreateSourceFormat::KeyValue {
+                    value: Format::Avro(_),
+                    ..
+                } => {}
+                _ => bail_unsupported!("non-Avro-encoded ENVELOPE DEBEZIUM"),
+            }
 
             //TODO check that key envelope is not set
             let (before_idx, after_idx) = typecheck_debezium(&value_desc)?;
@@ -811,15 +937,6 @@ pub fn plan_create_source(
     let metadata_desc = included_column_desc(metadata_columns.clone());
     let (envelope, mut desc) = envelope.desc(key_desc, value_desc, metadata_desc)?;
 
-    let CreateSourceOptionExtracted {
-        remote,
-        size,
-        timeline,
-        timestamp_interval,
-        ignore_keys,
-        seen: _,
-    } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
-
     if ignore_keys.unwrap_or(false) {
         desc = desc.without_keys();
     }
@@ -880,6 +997,10 @@ pub fn plan_create_source(
         None => scx.catalog.config().timestamp_interval,
     };
 
+    let logical_compaction_window = logical_compaction_window
+        .map(|lcw| plan_logical_compaction_window(scx, lcw))
+        .transpose()?;
+
     let if_not_exists = *if_not_exists;
     let name = scx.allocate_qualified_name(normalize::unresolved_object_name(name.clone())?)?;
     let create_sql = normalize::create_statement(&scx, Statement::CreateSource(stmt))?;
@@ -920,6 +1041,7 @@ pub fn plan_create_source(
         if_not_exists,
         timeline,
         host_config,
+        logical_compaction_window,
     }))
 }
 
@@ -1281,11 +1403,13 @@ fn get_encoding_inner(
                         schema: key_schema,
                         csr_connection: csr_connection.clone(),
                         confluent_wire_format,
+                        projection: None,
                     }),
                     value: DataEncodingInner::Avro(AvroEncoding {
                         schema: value_schema,
                         csr_connection,
                         confluent_wire_format,
+                        projection: None,
                     }),
                 });
             } else {
@@ -1293,6 +1417,7 @@ fn get_encoding_inner(
                     schema: value_schema,
                     csr_connection,
                     confluent_wire_format,
+                    projection: None,
                 })
             }
         }
@@ -1361,6 +1486,11 @@ fn get_encoding_inner(
         },
         Format::Regex(regex) => {
             let regex = Regex::new(&regex).map_err(|e| sql_err!("parsing regex: {e}"))?;
+            // The first capture is the entire match, which does not produce a
+            // column.
+            if regex.captures_len() <= 1 {
+                sql_bail!("FORMAT REGEX requires at least one capture group");
+            }
             DataEncodingInner::Regex(RegexEncoding {
                 regex: mz_repr::adt::regex::Regex(regex),
             })
@@ -1396,9 +1526,13 @@ fn get_key_envelope(
     envelope: &Envelope<Aug>,
     encoding: &SourceDataEncoding,
 ) -> Result<KeyEnvelope, PlanError> {
-    let key_definition = included_items
+    let mut key_definitions = included_items
         .iter()
-        .find(|i| i.ty == SourceIncludeMetadataType::Key);
+        .filter(|i| i.ty == SourceIncludeMetadataType::Key);
+    let key_definition = key_definitions.next();
+    if key_definitions.next().is_some() {
+        sql_bail!("INCLUDE KEY specified more than once");
+    }
     if matches!(envelope, Envelope::Debezium { .. }) && key_definition.is_some() {
         sql_bail!(
             "Cannot use INCLUDE KEY with ENVELOPE DEBEZIUM: Debezium values include all keys."
@@ -1484,6 +1618,7 @@ pub fn plan_view(
     } = query::plan_root_query(scx, query.clone(), QueryLifetime::Static)?;
 
     expr.bind_parameters(&params)?;
+    check_view_finishing(scx, &finishing);
     //TODO: materialize#724 - persist finishing information with the view?
     expr.finish(finishing);
     let relation_expr = expr.optimize_and_lower(&scx.into())?;
@@ -1511,6 +1646,14 @@ pub fn plan_view(
     Ok((name, view))
 }
 
+/// Reports a notice if the finishing of a view's query orders its rows
+/// without limiting them, as the order is lost once the rows are stored.
+fn check_view_finishing(scx: &StatementContext, finishing: &RowSetFinishing) {
+    if !finishing.order_by.is_empty() && finishing.limit.is_none() && finishing.offset == 0 {
+        scx.add_notice(PlanNotice::ViewOrderByIgnored);
+    }
+}
+
 pub fn plan_create_view(
     scx: &StatementContext,
     mut stmt: CreateViewStatement<Aug>,
@@ -1776,6 +1919,11 @@ pub fn describe_create_materialized_view(
     Ok(StatementDesc::new(None))
 }
 
+generate_extracted_config!(
+    MaterializedViewOption,
+    (LogicalCompactionWindow, OptionalInterval)
+);
+
 pub fn plan_create_materialized_view(
     scx: &StatementContext,
     mut stmt: CreateMaterializedViewStatement<Aug>,
@@ -1793,6 +1941,14 @@ pub fn plan_create_materialized_view(
     let create_sql =
         normalize::create_statement(scx, Statement::CreateMaterializedView(stmt.clone()))?;
 
+    let MaterializedViewOptionExtracted {
+        logical_compaction_window,
+        seen: _,
+    } = stmt.with_options.try_into()?;
+    let logical_compaction_window = logical_compaction_window
+        .map(|lcw| plan_logical_compaction_window(scx, lcw))
+        .transpose()?;
+
     let partial_name = normalize::unresolved_object_name(stmt.name)?;
     let name = scx.allocate_qualified_name(partial_name.clone())?;
 
@@ -1803,6 +1959,7 @@ pub fn plan_create_materialized_view(
     } = query::plan_root_query(scx, stmt.query, QueryLifetime::Static)?;
 
     expr.bind_parameters(params)?;
+    check_view_finishing(scx, &finishing);
     expr.finish(finishing);
     let expr = expr.optimize_and_lower(&scx.into())?;
 
@@ -1843,6 +2000,7 @@ pub fn plan_create_materialized_view(
             expr,
             column_names,
             compute_instance,
+            logical_compaction_window,
         },
         replace,
         if_not_exists,
@@ -1874,6 +2032,7 @@ pub fn plan_create_sink(
         envelope,
         if_not_exists,
         with_options,
+        as_of,
     } = stmt;
 
     let envelope = match envelope {
@@ -1975,6 +2134,7 @@ pub fn plan_create_sink(
     };
 
     let CreateSinkOptionExtracted { snapshot, seen: _ } = with_options.try_into()?;
+    let when = query::plan_as_of(scx, as_of)?;
 
     Ok(Plan::CreateSink(CreateSinkPlan {
         name,
@@ -1985,6 +2145,7 @@ pub fn plan_create_sink(
             envelope,
         },
         with_snapshot: snapshot,
+        when,
         if_not_exists,
     }))
 }
@@ -2782,9 +2943,16 @@ impl KafkaConnectionOptionExtracted {
 
 impl From<&KafkaConnectionOptionExtracted> for Option<KafkaTlsConfig> {
     fn from(k: &KafkaConnectionOptionExtracted) -> Self {
-        if k.ssl_config().iter().all(|config| k.seen.contains(config)) {
+        let has_identity = k.ssl_config().iter().all(|config| k.seen.contains(config));
+        // A certificate authority on its own requests TLS without client
+        // authentication, unless it is being used to secure SASL.
+        let server_auth_only = k
+            .seen
+            .contains(&KafkaConnectionOptionName::SslCertificateAuthority)
+            && !k.sasl_config().iter().any(|config| k.seen.contains(config));
+        if has_identity || server_auth_only {
             Some(KafkaTlsConfig {
-                identity: Some(TlsIdentity {
+                identity: has_identity.then(|| TlsIdentity {
                     key: k.ssl_key.unwrap().into(),
                     cert: k.ssl_certificate.clone().unwrap(),
                 }),
@@ -2828,11 +2996,16 @@ impl TryFrom<&KafkaConnectionOptionExtracted> for Option<KafkaSecurity> {
             None => None,
         };
 
-        if res.is_none()
+        let partially_seen = |config: HashSet<KafkaConnectionOptionName>| {
+            let seen = config.iter().filter(|c| value.seen.contains(c)).count();
+            seen > 0 && seen < config.len()
+        };
+        if (res.is_none()
             && [value.sasl_config(), value.ssl_config()]
                 .iter()
                 .flatten()
-                .any(|c| value.seen.contains(c))
+                .any(|c| value.seen.contains(c)))
+            || partially_seen(value.ssl_config())
         {
             sql_bail!("invalid CONNECTION: under-specified security configuration");
         }
@@ -3012,6 +3185,32 @@ impl TryFrom<AwsConnectionOptionExtracted> for AwsCredentials {
     }
 }
 
+generate_extracted_config!(
+    GcpConnectionOption,
+    (Credentials, with_options::Secret),
+    (Endpoint, String)
+);
+
+impl TryFrom<GcpConnectionOptionExtracted> for GcpConnection {
+    type Error = PlanError;
+
+    fn try_from(options: GcpConnectionOptionExtracted) -> Result<Self, Self::Error> {
+        let endpoint = options
+            .endpoint
+            .map(|endpoint| endpoint.parse())
+            .transpose()
+            .map_err(|e| sql_err!("parsing GCP endpoint: {e}"))?;
+        // Only an emulator accepts unauthenticated requests.
+        if options.credentials.is_none() && endpoint.is_none() {
+            sql_bail!("CREDENTIALS option is required");
+        }
+        Ok(GcpConnection {
+            credentials: options.credentials.map(|secret| secret.into()),
+            endpoint,
+        })
+    }
+}
+
 pub fn plan_create_connection(
     scx: &StatementContext,
     stmt: CreateConnectionStatement<Aug>,
@@ -3042,6 +3241,11 @@ pub fn plan_create_connection(
             let connection = AwsCredentials::try_from(c)?;
             Connection::Aws(connection)
         }
+        CreateConnection::Gcp { with_options } => {
+            let c = GcpConnectionOptionExtracted::try_from(with_options)?;
+            let connection = GcpConnection::try_from(c)?;
+            Connection::Gcp(connection)
+        }
         CreateConnection::Ssh { with_options } => {
             let c = SshConnectionOptionExtracted::try_from(with_options)?;
             let connection = mz_storage::types::connections::SshConnection::try_from(c)?;
@@ -3430,15 +3634,29 @@ pub fn describe_alter_index_options(
 
 generate_extracted_config!(IndexOption, (LogicalCompactionWindow, OptionalInterval));
 
+/// Plans the value of a `LOGICAL COMPACTION WINDOW` option of an index, source,
+/// or materialized view.
+///
+/// A window of 0 disables compaction, which lets the history of the object, and
+/// with it the memory or storage it uses, grow without bound. That is only
+/// available in unsafe mode.
+fn plan_logical_compaction_window(
+    scx: &StatementContext,
+    OptionalInterval(lcw): OptionalInterval,
+) -> Result<Option<Duration>, PlanError> {
+    match lcw {
+        Some(interval) => Ok(Some(interval.duration()?)),
+        None => {
+            scx.require_unsafe_mode("LOGICAL COMPACTION WINDOW = 0")?;
+            Ok(None)
+        }
+    }
+}
+
 fn plan_index_options(
     scx: &StatementContext,
     with_opts: Vec<IndexOption<Aug>>,
 ) -> Result<Vec<crate::plan::IndexOption>, PlanError> {
-    if !with_opts.is_empty() {
-        // Index options are not durable.
-        scx.require_unsafe_mode("INDEX OPTIONS")?;
-    }
-
     let IndexOptionExtracted {
         logical_compaction_window,
         ..
@@ -3446,10 +3664,9 @@ fn plan_index_options(
 
     let mut out = Vec::with_capacity(1);
 
-    if let Some(OptionalInterval(lcw)) = logical_compaction_window {
-        scx.require_unsafe_mode("LOGICAL COMPACTION WINDOW")?;
+    if let Some(lcw) = logical_compaction_window {
         out.push(crate::plan::IndexOption::LogicalCompactionWindow(
-            lcw.map(|interval| interval.duration()).transpose()?,
+            plan_logical_compaction_window(scx, lcw)?,
         ))
     }
 
@@ -3619,7 +3836,6 @@ pub fn plan_alter_source(
     scx: &StatementContext,
     stmt: AlterSourceStatement<Aug>,
 ) -> Result<Plan, PlanError> {
-    scx.require_unsafe_mode("ALTER SOURCE")?;
     let AlterSourceStatement {
         source_name,
         if_exists,
@@ -3646,15 +3862,19 @@ pub fn plan_alter_source(
 
     let mut size = AlterSourceItem::Unchanged;
     let mut remote = AlterSourceItem::Unchanged;
+    let mut logical_compaction_window = AlterSourceItem::Unchanged;
     match action {
         AlterSourceAction::SetOptions(options) => {
             let CreateSourceOptionExtracted {
                 seen: _,
                 remote: remote_opt,
                 size: size_opt,
+                tail: tail_opt,
                 timeline: timeline_opt,
                 timestamp_interval: timestamp_interval_opt,
                 ignore_keys: ignore_keys_opt,
+                logical_compaction_window: logical_compaction_window_opt,
+                fields: fields_opt,
             } = CreateSourceOptionExtracted::try_from(options)?;
 
             if let Some(value) = remote_opt {
@@ -3663,6 +3883,9 @@ pub fn plan_alter_source(
             if let Some(value) = size_opt {
                 size = AlterSourceItem::Set(value);
             }
+            if let Some(_) = tail_opt {
+                sql_bail!("Cannot modify the TAIL property of a SOURCE.");
+            }
             if let Some(_) = timeline_opt {
                 sql_bail!("Cannot modify the TIMELINE of a SOURCE.");
             }
@@ -3672,6 +3895,13 @@ pub fn plan_alter_source(
             if let Some(_) = ignore_keys_opt {
                 sql_bail!("Cannot modify the IGNORE KEYS property of a SOURCE.");
             }
+            if let Some(_) = fields_opt {
+                sql_bail!("Cannot modify the FIELDS of a SOURCE.");
+            }
+            if let Some(lcw) = logical_compaction_window_opt {
+                logical_compaction_window =
+                    AlterSourceItem::Set(plan_logical_compaction_window(scx, lcw)?);
+            }
         }
         AlterSourceAction::ResetOptions(reset) => {
             for name in reset {
@@ -3682,6 +3912,9 @@ pub fn plan_alter_source(
                     CreateSourceOptionName::Size => {
                         size = AlterSourceItem::Reset;
                     }
+                    CreateSourceOptionName::Tail => {
+                        sql_bail!("Cannot modify the TAIL property of a SOURCE.");
+                    }
                     CreateSourceOptionName::Timeline => {
                         sql_bail!("Cannot modify the TIMELINE of a SOURCE.");
                     }
@@ -3691,12 +3924,32 @@ pub fn plan_alter_source(
                     CreateSourceOptionName::IgnoreKeys => {
                         sql_bail!("Cannot modify the IGNORE KEYS property of a SOURCE.");
                     }
+                    CreateSourceOptionName::Fields => {
+                        sql_bail!("Cannot modify the FIELDS of a SOURCE.");
+                    }
+                    CreateSourceOptionName::LogicalCompactionWindow => {
+                        logical_compaction_window = AlterSourceItem::Reset;
+                    }
                 }
             }
         }
     };
 
-    Ok(Plan::AlterSource(AlterSourcePlan { id, size, remote }))
+    // Changing the storage host of a source is experimental. The logical
+    // compaction window can be changed like that of an index.
+    if !matches!(
+        (&size, &remote),
+        (AlterSourceItem::Unchanged, AlterSourceItem::Unchanged)
+    ) {
+        scx.require_unsafe_mode("ALTER SOURCE")?;
+    }
+
+    Ok(Plan::AlterSource(AlterSourcePlan {
+        id,
+        size,
+        remote,
+        logical_compaction_window,
+    }))
 }
 
 pub fn describe_alter_system_set(
@@ -3747,6 +4000,73 @@ pub fn plan_alter_system_reset_all(
     Ok(Plan::AlterSystemResetAll(AlterSystemResetAllPlan {}))
 }
 
+pub fn describe_comment(
+    _: &StatementContext,
+    _: CommentStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_comment(
+    scx: &StatementContext,
+    CommentStatement { object, comment }: CommentStatement,
+) -> Result<Plan, PlanError> {
+    let name = match &object {
+        CommentObject::Object { name, .. } => name,
+        CommentObject::Column { relation_name, .. } => relation_name,
+    };
+    let name = normalize::unresolved_object_name(name.clone())?;
+    let entry = scx.catalog.resolve_item(&name)?;
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    let item_type = entry.item_type();
+    if entry.id().is_system() {
+        sql_bail!(
+            "cannot comment on {} because it is required by the database system",
+            full_name
+        );
+    }
+
+    let column_position = match object {
+        CommentObject::Object { object_type, .. } => {
+            if object_type != item_type {
+                sql_bail!("{} is a {} not a {}", full_name, item_type, object_type)
+            }
+            None
+        }
+        CommentObject::Column { column_name, .. } => {
+            match item_type {
+                CatalogItemType::Table
+                | CatalogItemType::View
+                | CatalogItemType::MaterializedView
+                | CatalogItemType::Source => (),
+                _ => sql_bail!(
+                    "cannot comment on columns of {} because it is a {}",
+                    full_name,
+                    item_type
+                ),
+            }
+            let desc = entry.desc(&full_name)?;
+            let column_name = normalize::column_name(column_name);
+            match desc.get_by_name(&column_name) {
+                // Column positions are 1-indexed, as in `mz_columns`.
+                Some((i, _)) => Some(i + 1),
+                None => {
+                    return Err(PlanError::UnknownColumn {
+                        table: Some(name),
+                        column: column_name,
+                    })
+                }
+            }
+        }
+    };
+
+    Ok(Plan::Comment(CommentPlan {
+        object_id: entry.id(),
+        column_position,
+        comment,
+    }))
+}
+
 pub fn plan_alter_connection(
     scx: &StatementContext,
     stmt: AlterConnectionStatement,
@@ -26,9 +26,10 @@ use crate::ast::display::AstDisplay;
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
     CreateMaterializedViewStatement, CreateViewStatement, DeleteStatement, ExplainStageNew,
-    ExplainStageOld, ExplainStatement, ExplainStatementNew, ExplainStatementOld, Explainee, Ident,
-    InsertStatement, Query, SelectStatement, Statement, TailOption, TailOptionName, TailRelation,
-    TailStatement, UpdateStatement, ViewDefinition,
+    ExplainStageOld, ExplainStatement, ExplainStatementNew, ExplainStatementOld, Explainee, Expr,
+    Ident, InsertStatement, Query, Select, SelectItem, SelectStatement, Statement, TableFactor,
+    TableWithJoins, TailOption, TailOptionName, TailRelation, TailStatement, UpdateStatement,
+    ViewDefinition,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{self, Aug, ResolvedObjectName};
@@ -199,7 +200,11 @@ pub fn describe_explain_new(
         stage, explainee, ..
     }: ExplainStatementNew<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    Ok(StatementDesc::new(Some(RelationDesc::empty().with_column(
+    let mut relation_desc = RelationDesc::empty();
+    if let ExplainStageNew::Trace = stage {
+        relation_desc = relation_desc.with_column("Stage", ScalarType::String.nullable(false));
+    }
+    relation_desc = relation_desc.with_column(
         match stage {
             ExplainStageNew::RawPlan => "Raw Plan",
             ExplainStageNew::QueryGraph => "Query Graph",
@@ -207,23 +212,25 @@ pub fn describe_explain_new(
             ExplainStageNew::DecorrelatedPlan => "Decorrelated Plan",
             ExplainStageNew::OptimizedPlan { .. } => "Optimized Plan",
             ExplainStageNew::PhysicalPlan => "Physical Plan",
-            ExplainStageNew::Trace => "Plan", // TODO: add more columns as part of #13139
+            ExplainStageNew::Trace => "Plan",
         },
         ScalarType::String.nullable(false),
-    )))
-    .with_params(match explainee {
-        Explainee::Query(q) => {
-            describe_select(
-                scx,
-                SelectStatement {
-                    query: q,
-                    as_of: None,
-                },
-            )?
-            .param_types
-        }
-        _ => vec![],
-    }))
+    );
+    Ok(
+        StatementDesc::new(Some(relation_desc)).with_params(match explainee {
+            Explainee::Query(q) => {
+                describe_select(
+                    scx,
+                    SelectStatement {
+                        query: q,
+                        as_of: None,
+                    },
+                )?
+                .param_types
+            }
+            _ => vec![],
+        }),
+    )
 }
 
 pub fn describe_explain_old(
@@ -605,16 +612,45 @@ pub fn describe_table(
 
 pub fn describe_copy(
     scx: &StatementContext,
-    CopyStatement { relation, .. }: CopyStatement<Aug>,
+    CopyStatement {
+        relation,
+        direction,
+        ..
+    }: CopyStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
     Ok(match relation {
-        CopyRelation::Table { name, columns } => describe_table(scx, name, columns)?,
+        CopyRelation::Table { name, columns } => match direction {
+            CopyDirection::To => describe_select(scx, copy_to_select(name, columns))?,
+            CopyDirection::From => describe_table(scx, name, columns)?,
+        },
         CopyRelation::Select(stmt) => describe_select(scx, stmt)?,
         CopyRelation::Tail(stmt) => describe_tail(scx, stmt)?,
     }
     .with_is_copy())
 }
 
+/// Returns the `SELECT` statement that reads the given columns of a relation,
+/// or all of its columns if none are given, for `COPY <relation> TO`.
+fn copy_to_select(name: ResolvedObjectName, columns: Vec<Ident>) -> SelectStatement<Aug> {
+    let mut select = Select::default().from(TableWithJoins {
+        relation: TableFactor::Table { name, alias: None },
+        joins: vec![],
+    });
+    if columns.is_empty() {
+        select = select.project(SelectItem::Wildcard);
+    }
+    for column in columns {
+        select = select.project(SelectItem::Expr {
+            expr: Expr::Identifier(vec![column]),
+            alias: None,
+        });
+    }
+    SelectStatement {
+        query: Query::select(select),
+        as_of: None,
+    }
+}
+
 fn plan_copy_from(
     scx: &StatementContext,
     table_name: ResolvedObjectName,
@@ -723,10 +759,24 @@ pub fn plan_copy(
         if options.null.is_some() {
             sql_bail!("COPY TO does not support NULL option yet");
         }
+        if options.quote.is_some() {
+            sql_bail!("COPY TO does not support QUOTE option yet");
+        }
+        if options.escape.is_some() {
+            sql_bail!("COPY TO does not support ESCAPE option yet");
+        }
+        if options.header.is_some() {
+            sql_bail!("COPY TO does not support HEADER option yet");
+        }
     }
     match (&direction, &target) {
         (CopyDirection::To, CopyTarget::Stdout) => match relation {
-            CopyRelation::Table { .. } => sql_bail!("table with COPY TO unsupported"),
+            CopyRelation::Table { name, columns } => Ok(plan_select(
+                scx,
+                copy_to_select(name, columns),
+                &Params::empty(),
+                Some(format),
+            )?),
             CopyRelation::Select(stmt) => {
                 Ok(plan_select(scx, stmt, &Params::empty(), Some(format))?)
             }
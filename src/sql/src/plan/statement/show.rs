@@ -25,7 +25,7 @@ use crate::ast::{
     ObjectType, SelectStatement, ShowColumnsStatement, ShowCreateIndexStatement,
     ShowCreateSinkStatement, ShowCreateSourceStatement, ShowCreateTableStatement,
     ShowCreateViewStatement, ShowDatabasesStatement, ShowIndexesStatement, ShowObjectsStatement,
-    ShowSchemasStatement, ShowStatementFilter, Statement, Value,
+    ShowSchemasStatement, ShowStatementFilter, ShowStorageUsageStatement, Statement, Value,
 };
 use crate::catalog::{CatalogItemType, SessionCatalog};
 use crate::names::{
@@ -310,6 +310,23 @@ pub fn show_schemas<'a>(
     ShowSelect::new(scx, query, filter, None, None)
 }
 
+pub fn show_storage_usage<'a>(
+    scx: &'a StatementContext<'a>,
+    ShowStorageUsageStatement { from, filter }: ShowStorageUsageStatement<Aug>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    let schema_spec = scx.resolve_optional_schema(&from)?;
+
+    let query = format!(
+        "SELECT o.name, o.type, u.size_bytes, u.row_count
+        FROM mz_catalog.mz_recent_storage_usage u
+        JOIN mz_catalog.mz_objects o ON u.object_id = o.id
+        WHERE o.schema_id = {}",
+        schema_spec,
+    );
+
+    ShowSelect::new(scx, query, filter, None, None)
+}
+
 pub fn show_objects<'a>(
     scx: &'a StatementContext<'a>,
     ShowObjectsStatement {
@@ -557,7 +574,7 @@ fn show_all_objects<'a>(
     let schema_spec = scx.resolve_optional_schema(&from)?;
 
     let mut query = format!(
-        "SELECT o.name, mz_internal.mz_classify_object_id(o.id) AS type
+        "SELECT o.name, mz_internal.mz_classify_object_id(o.id) AS type, o.comment
         FROM mz_catalog.mz_objects o
         JOIN mz_catalog.mz_schemas s ON o.schema_id = s.id
         WHERE o.schema_id = {}",
@@ -645,29 +662,51 @@ pub fn show_columns<'a>(
     if extended {
         bail_unsupported!("SHOW EXTENDED COLUMNS");
     }
-    if full {
-        bail_unsupported!("SHOW FULL COLUMNS");
-    }
 
     let entry = scx.get_item_by_resolved_name(&table_name)?;
+    let id = match entry.item_type() {
+        CatalogItemType::Table
+        | CatalogItemType::Source
+        | CatalogItemType::View
+        | CatalogItemType::MaterializedView => entry.id(),
+        // An index has the same columns as the relation it indexes.
+        CatalogItemType::Index => {
+            let (_, on) = entry
+                .index_details()
+                .expect("index entries have index details");
+            on
+        }
+        CatalogItemType::Sink
+        | CatalogItemType::Type
+        | CatalogItemType::Func
+        | CatalogItemType::Secret
+        | CatalogItemType::Connection => sql_bail!(
+            "cannot show columns of {} because it is a {}",
+            table_name.full_name_str(),
+            entry.item_type(),
+        ),
+    };
 
     let query = format!(
         "SELECT
             mz_columns.name,
             mz_columns.nullable,
             mz_columns.type,
+            mz_comments.comment,
             mz_columns.position
          FROM mz_catalog.mz_columns AS mz_columns
+         LEFT JOIN mz_catalog.mz_comments AS mz_comments
+            ON mz_comments.id = mz_columns.id
+            AND mz_comments.column_position = mz_columns.position
          WHERE mz_columns.id = '{}'",
-        entry.id(),
+        id,
     );
-    ShowSelect::new(
-        scx,
-        query,
-        filter,
-        Some("position"),
-        Some(&["name", "nullable", "type"]),
-    )
+    let projection: &[&str] = if full {
+        &["name", "nullable", "type", "comment"]
+    } else {
+        &["name", "nullable", "type"]
+    };
+    ShowSelect::new(scx, query, filter, Some("position"), Some(projection))
 }
 
 pub fn show_clusters<'a>(
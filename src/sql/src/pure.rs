@@ -35,7 +35,7 @@ use mz_proto::RustType;
 use mz_repr::strconv;
 use mz_storage::types::connections::aws::{AwsConfig, AwsExternalIdPrefix};
 use mz_storage::types::connections::{Connection, ConnectionContext};
-use mz_storage::types::sources::PostgresSourceDetails;
+use mz_storage::types::sources::{KafkaTopicSelection, PostgresSourceDetails};
 
 use crate::ast::{
     AvroSchema, CreateSourceConnection, CreateSourceFormat, CreateSourceStatement,
@@ -102,10 +102,15 @@ pub async fn purify_create_source(
                     let config_options =
                         kafka_util::LibRdKafkaConfig::try_from(&extracted_options)?.0;
 
-                    let topic = extracted_options.topic.expect("validated topic exists");
+                    let topics = KafkaTopicSelection::try_from(&extracted_options)?;
+                    let topic = match &topics {
+                        KafkaTopicSelection::Topic(topic) => Some(topic.as_str()),
+                        KafkaTopicSelection::Topics(topics) => Some(topics[0].as_str()),
+                        KafkaTopicSelection::Pattern(_) => None,
+                    };
 
                     let consumer = kafka_util::create_consumer(
-                        &topic,
+                        topic,
                         &connection,
                         &config_options,
                         connection_context.librdkafka_log_level,
@@ -115,10 +120,14 @@ pub async fn purify_create_source(
                     .map_err(|e| anyhow!("Failed to create and connect Kafka consumer: {}", e))?;
 
                     if let Some(offset_type) = offset_type {
+                        let topic = match &topics {
+                            KafkaTopicSelection::Topic(topic) => topic,
+                            _ => bail!("START OFFSET and START TIMESTAMP require a single TOPIC"),
+                        };
                         // Translate `START TIMESTAMP` to a start offset
                         match kafka_util::lookup_start_offsets(
                             Arc::clone(&consumer),
-                            &topic,
+                            topic,
                             offset_type,
                             now,
                         )
@@ -222,6 +231,8 @@ pub async fn purify_create_source(
             };
             *details_ast = Some(hex::encode(details.into_proto().encode_to_vec()));
         }
+        CreateSourceConnection::File { .. } => (),
+        CreateSourceConnection::PubSub { .. } => (),
         CreateSourceConnection::LoadGenerator { .. } => (),
     }
 
@@ -342,6 +353,35 @@ async fn purify_source_format_single(
     Ok(())
 }
 
+/// Returns the topic whose schema registry subjects describe the schemas of
+/// the Kafka source `connection`.
+fn csr_topic(connection: &CreateSourceConnection<Aug>) -> Result<String, anyhow::Error> {
+    let (connection, topic) = match connection {
+        CreateSourceConnection::Kafka(KafkaSourceConnection {
+            connection, topic, ..
+        }) => (connection, topic),
+        _ => bail!("Confluent Schema Registry is only supported with Kafka sources"),
+    };
+    match connection {
+        KafkaConnection::Inline { .. } => Ok(topic.clone().unwrap()),
+        KafkaConnection::Reference { options, .. } => {
+            let extracted_options: KafkaConfigOptionExtracted = options
+                .clone()
+                .try_into()
+                .expect("already verified options valid provided");
+            match KafkaTopicSelection::try_from(&extracted_options)? {
+                KafkaTopicSelection::Topic(topic) => Ok(topic),
+                // All topics of a source share its schemas, so the subjects of
+                // the first one describe them all.
+                KafkaTopicSelection::Topics(topics) => Ok(topics[0].clone()),
+                KafkaTopicSelection::Pattern(_) => {
+                    bail!("Confluent Schema Registry requires TOPIC or TOPICS, not TOPIC PATTERN")
+                }
+            }
+        }
+    }
+}
+
 async fn purify_csr_connection_proto(
     catalog: &dyn SessionCatalog,
     connection: &mut CreateSourceConnection<Aug>,
@@ -349,24 +389,7 @@ async fn purify_csr_connection_proto(
     envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), anyhow::Error> {
-    let topic =
-        if let CreateSourceConnection::Kafka(KafkaSourceConnection {
-            connection, topic, ..
-        }) = connection
-        {
-            match connection {
-                KafkaConnection::Inline { .. } => topic.clone().unwrap(),
-                KafkaConnection::Reference { options, .. } => {
-                    let KafkaConfigOptionExtracted { topic, .. } = options
-                        .clone()
-                        .try_into()
-                        .expect("already verified options valid provided");
-                    topic.expect("already validated topic provided")
-                }
-            }
-        } else {
-            bail!("Confluent Schema Registry is only supported with Kafka sources")
-        };
+    let topic = csr_topic(connection)?;
 
     let CsrConnectionProtobuf {
         seed,
@@ -412,24 +435,7 @@ async fn purify_csr_connection_avro(
     envelope: &Option<Envelope<Aug>>,
     connection_context: &ConnectionContext,
 ) -> Result<(), anyhow::Error> {
-    let topic =
-        if let CreateSourceConnection::Kafka(KafkaSourceConnection {
-            connection, topic, ..
-        }) = connection
-        {
-            match connection {
-                KafkaConnection::Inline { .. } => topic.clone().unwrap(),
-                KafkaConnection::Reference { options, .. } => {
-                    let KafkaConfigOptionExtracted { topic, .. } = options
-                        .clone()
-                        .try_into()
-                        .expect("already verified options valid provided");
-                    topic.expect("already validated topic provided")
-                }
-            }
-        } else {
-            bail!("Confluent Schema Registry is only supported with Kafka sources")
-        };
+    let topic = csr_topic(connection)?;
 
     let CsrConnectionAvro {
         connection: CsrConnection { connection, .. },
@@ -276,6 +276,10 @@ impl SessionCatalog for TestCatalog {
         false
     }
 
+    fn search_path(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)] {
+        &[]
+    }
+
     fn get_item(&self, id: &GlobalId) -> &dyn CatalogItem {
         &self.tables[&self.id_to_name[id]]
     }
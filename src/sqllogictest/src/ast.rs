@@ -106,6 +106,9 @@ pub struct QueryOutput<'a> {
     pub mode: Mode,
     pub output: Output,
     pub output_str: &'a str,
+    /// Whether the expected output is a single block of text, to which all
+    /// values of the output are joined with newlines.
+    pub multiline: bool,
 }
 
 /// A single directive in a sqllogictest file.
@@ -317,6 +317,7 @@ impl<'a> Parser<'a> {
                 mode: self.mode,
                 output,
                 output_str,
+                multiline,
             }),
             location,
         })
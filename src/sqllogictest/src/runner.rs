@@ -643,6 +643,7 @@ impl Runner {
         let persist_clients = Arc::new(Mutex::new(persist_clients));
         let server_config = mz_environmentd::Config {
             adapter_stash_url,
+            timestamp_oracle_url: None,
             controller: ControllerConfig {
                 build_info: &mz_environmentd::BUILD_INFO,
                 orchestrator: Arc::clone(&orchestrator) as Arc<dyn Orchestrator>,
@@ -928,6 +929,7 @@ impl Runner {
             column_names: expected_column_names,
             output: expected_output,
             mode,
+            multiline,
             ..
         } = match output {
             Err(expected_error) => {
@@ -980,6 +982,9 @@ impl Runner {
         if let Sort::Value = sort {
             values.sort();
         }
+        if *multiline && values.len() > 1 {
+            values = vec![values.join("\n")];
+        }
 
         // check output
         match expected_output {
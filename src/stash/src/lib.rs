@@ -653,6 +653,21 @@ where
         key: &K,
         value: V,
     ) -> Result<V, StashError>
+    where
+        S: Append,
+    {
+        let (value, _inserted) = self.get_or_insert(stash, key, value).await?;
+        Ok(value)
+    }
+
+    /// Sets the given k,v pair if not already set. Returns the value of the key
+    /// and whether it was newly set.
+    pub async fn get_or_insert<S>(
+        &self,
+        stash: &mut S,
+        key: &K,
+        value: V,
+    ) -> Result<(V, bool), StashError>
     where
         S: Append,
     {
@@ -672,11 +687,11 @@ where
             },
         };
         match prev {
-            Some(prev) => Ok(prev),
+            Some(prev) => Ok((prev, false)),
             None => {
                 collection.append_to_batch(&mut batch, &key, &value, 1);
                 stash.append(&[batch]).await?;
-                Ok(value)
+                Ok((value, true))
             }
         }
     }
@@ -47,6 +47,7 @@ use tracing::debug;
 use mz_build_info::BuildInfo;
 use mz_orchestrator::NamespacedOrchestrator;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::read::SnapshotStats;
 use mz_persist_client::{PersistLocation, ShardId};
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
@@ -56,7 +57,7 @@ use mz_stash::{self, StashError, TypedCollection};
 use crate::controller::hosts::{StorageHosts, StorageHostsConfig};
 use crate::protocol::client::{
     ExportSinkCommand, IngestSourceCommand, ProtoStorageCommand, ProtoStorageResponse,
-    StorageCommand, StorageResponse, Update,
+    SinkStatistics, StorageCommand, StorageResponse, Update,
 };
 use crate::types::errors::DataflowError;
 use crate::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
@@ -238,6 +239,16 @@ pub trait StorageController: Debug + Send {
         as_of: Self::Timestamp,
     ) -> Result<Vec<(Row, Diff)>, StorageError>;
 
+    /// Returns statistics about the contents of the collection named `id` at `as_of`.
+    ///
+    /// The method returns a oneshot that can be awaited for the statistics, which does not
+    /// block the caller while persist is consulted.
+    fn snapshot_stats(
+        &self,
+        id: GlobalId,
+        as_of: Antichain<Self::Timestamp>,
+    ) -> tokio::sync::oneshot::Receiver<Result<SnapshotStats, StorageError>>;
+
     /// Assigns a read policy to specific identifiers.
     ///
     /// The policies are assigned in the order presented, and repeated identifiers should
@@ -575,6 +586,8 @@ pub enum StorageError {
     ReadBeforeSince(GlobalId),
     /// The expected upper of one or more appends was different from the actual upper of the collection
     InvalidUppers(Vec<GlobalId>),
+    /// Whether one or more appends succeeded could not be determined, even after retrying
+    IndeterminateAppends(Vec<GlobalId>),
     /// An error from the underlying client.
     ClientError(anyhow::Error),
     /// An operation failed to read or write state
@@ -591,6 +604,7 @@ impl Error for StorageError {
             Self::UpdateBeyondUpper(_) => None,
             Self::ReadBeforeSince(_) => None,
             Self::InvalidUppers(_) => None,
+            Self::IndeterminateAppends(_) => None,
             Self::ClientError(_) => None,
             Self::IOError(err) => Some(err),
             Self::DataflowError(err) => Some(err),
@@ -623,6 +637,13 @@ impl fmt::Display for StorageError {
                     id.iter().map(|id| id.to_string()).join(", ")
                 )
             }
+            Self::IndeterminateAppends(id) => {
+                write!(
+                    f,
+                    "could not determine whether appends succeeded for: {}",
+                    id.iter().map(|id| id.to_string()).join(", ")
+                )
+            }
             Self::ClientError(err) => write!(f, "underlying client error: {err}"),
             Self::IOError(err) => write!(f, "failed to read or write state: {err}"),
             Self::DataflowError(err) => write!(f, "dataflow failed to process request: {err}"),
@@ -898,18 +919,26 @@ where
             // We've added the dependency above in `exported_collections` so this guaranteed not to change at least until the sink is started up.
             let from_since = from_collection.implied_capability.clone();
 
-            let initial_as_of = MetadataExportFetcher::get_stash_collection()
-                .insert_without_overwrite(
-                    &mut self.state.stash,
-                    &id,
-                    DurableExportMetadata {
-                        initial_as_of: description.sink.as_of,
-                    },
-                )
-                .await?
-                .initial_as_of;
+            let (DurableExportMetadata { initial_as_of }, created) =
+                MetadataExportFetcher::get_stash_collection()
+                    .get_or_insert(
+                        &mut self.state.stash,
+                        &id,
+                        DurableExportMetadata {
+                            initial_as_of: description.sink.as_of,
+                        },
+                    )
+                    .await?;
 
-            let as_of = if PartialOrder::less_equal(&initial_as_of.frontier, &from_since) {
+            // A new sink whose as_of is the since reads the snapshot at the since, as requested.
+            // An existing sink that restarts at the since has already emitted everything up to
+            // it, so it must not read the snapshot again.
+            let behind_since = if created {
+                PartialOrder::less_than(&initial_as_of.frontier, &from_since)
+            } else {
+                PartialOrder::less_equal(&initial_as_of.frontier, &from_since)
+            };
+            let as_of = if behind_since {
                 SinkAsOf {
                     frontier: from_since,
                     // If we're using the since, never read the snapshot
@@ -1033,6 +1062,14 @@ where
             .unwrap()
     }
 
+    fn snapshot_stats(
+        &self,
+        id: GlobalId,
+        as_of: Antichain<Self::Timestamp>,
+    ) -> tokio::sync::oneshot::Receiver<Result<SnapshotStats, StorageError>> {
+        self.state.persist_read_handles.snapshot_stats(id, as_of)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn set_read_policy(
         &mut self,
@@ -1187,6 +1224,15 @@ where
                 self.update_write_frontiers(&updates).await?;
                 Ok(())
             }
+            Some(StorageResponse::SinkStatistics(updates)) => {
+                for (id, statistics) in updates {
+                    // Statistics may still arrive for sinks that were dropped in the meantime.
+                    if let Ok(export) = self.export_mut(id) {
+                        export.statistics = statistics;
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -1366,12 +1412,16 @@ pub struct ExportState<T> {
 
     /// Reported write frontier.
     pub write_frontier: Antichain<T>,
+
+    /// Most recently reported statistics.
+    pub statistics: SinkStatistics,
 }
 impl<T: Timestamp> ExportState<T> {
     fn new(description: ExportDescription<T>) -> Self {
         Self {
             description,
             write_frontier: Antichain::from_elem(Timestamp::minimum()),
+            statistics: SinkStatistics::default(),
         }
     }
     fn from(&self) -> GlobalId {
@@ -1389,7 +1439,7 @@ mod persist_read_handles {
     use timely::progress::{Antichain, Timestamp};
     use tokio::sync::mpsc::UnboundedSender;
 
-    use mz_persist_client::read::ReadHandle;
+    use mz_persist_client::read::{ReadHandle, SnapshotStats};
     use mz_persist_types::Codec64;
     use mz_repr::Row;
     use mz_repr::{Diff, GlobalId};
@@ -1432,6 +1482,11 @@ mod persist_read_handles {
             Antichain<T>,
             tokio::sync::oneshot::Sender<Result<Vec<(Row, Diff)>, StorageError>>,
         ),
+        SnapshotStats(
+            GlobalId,
+            Antichain<T>,
+            tokio::sync::oneshot::Sender<Result<SnapshotStats, StorageError>>,
+        ),
         Shutdown,
     }
 
@@ -1527,6 +1582,21 @@ mod persist_read_handles {
                                             };
                                             oneshot.send(result).expect("Oneshot should not fail");
                                         }
+                                        PersistWorkerCmd::SnapshotStats(id, as_of, oneshot) => {
+                                            let result = match read_handles.get_mut(&id) {
+                                                Some(Some(read_handle)) => {
+                                                    read_handle
+                                                        .snapshot_stats(as_of)
+                                                        .instrument(span.clone())
+                                                        .await
+                                                        .map_err(|_| StorageError::ReadBeforeSince(id))
+                                                },
+                                                Some(None) | None => Err(StorageError::IdentifierMissing(id)),
+                                            };
+                                            // The requester may have stopped waiting for the
+                                            // statistics, which is fine.
+                                            let _ = oneshot.send(result);
+                                        }
                                         PersistWorkerCmd::Shutdown => {
                                             shutdown = true;
                                         }
@@ -1606,6 +1676,16 @@ mod persist_read_handles {
             rx
         }
 
+        pub(crate) fn snapshot_stats(
+            &self,
+            id: GlobalId,
+            as_of: Antichain<T>,
+        ) -> tokio::sync::oneshot::Receiver<Result<SnapshotStats, StorageError>> {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            self.send(PersistWorkerCmd::SnapshotStats(id, as_of, tx));
+            rx
+        }
+
         fn send(&self, cmd: PersistWorkerCmd<T>) {
             match self.tx.send((tracing::Span::current(), cmd)) {
                 Ok(()) => (), // All good!
@@ -1620,13 +1700,17 @@ mod persist_read_handles {
 mod persist_write_handles {
 
     use std::collections::BTreeMap;
+    use std::time::Duration;
 
+    use anyhow::anyhow;
     use differential_dataflow::lattice::Lattice;
     use futures::stream::FuturesUnordered;
-    use itertools::Itertools;
+    use itertools::{Either, Itertools};
     use timely::progress::{Antichain, Timestamp};
     use tokio::sync::mpsc::UnboundedSender;
 
+    use mz_ore::retry::Retry;
+    use mz_persist::location::Indeterminate;
     use mz_persist_client::write::WriteHandle;
     use mz_persist_types::Codec64;
     use mz_repr::{Diff, GlobalId};
@@ -1637,6 +1721,56 @@ mod persist_write_handles {
     use crate::protocol::client::Update;
     use crate::types::sources::SourceData;
 
+    /// How long an append with an indeterminate result is retried before its result is reported
+    /// as indeterminate.
+    const INDETERMINATE_APPEND_RETRY_DURATION: Duration = Duration::from_secs(60);
+
+    /// The maximum number of collections whose uppers the worker advances in one batch, when it
+    /// combines `Append` commands that only advance uppers.
+    ///
+    /// A command that advances more collections is still processed as one batch.
+    const MAX_COMBINED_ADVANCEMENTS: usize = 100;
+
+    /// Returns an indeterminate error if the `table_append_indeterminate` failpoint is enabled for
+    /// the collection `id`, simulating an append whose outcome persist cannot determine.
+    ///
+    /// The failpoint applies to every collection, unless its argument names one, as in
+    /// `table_append_indeterminate=return(u1)`.
+    fn indeterminate_append_failpoint(id: GlobalId) -> Option<Indeterminate> {
+        match fail::eval("table_append_indeterminate", |target| target)? {
+            Some(target) if target != id.to_string() => None,
+            _ => Some(Indeterminate::new(anyhow!(
+                "failpoint table_append_indeterminate"
+            ))),
+        }
+    }
+
+    /// Why an append to a collection failed.
+    #[derive(Clone, Debug)]
+    enum AppendFailure {
+        /// The expected upper was different from the actual upper of the collection.
+        InvalidUpper(GlobalId),
+        /// Whether the append succeeded could not be determined, even after retrying.
+        Indeterminate(GlobalId),
+    }
+
+    impl From<Vec<AppendFailure>> for StorageError {
+        fn from(failures: Vec<AppendFailure>) -> Self {
+            let (invalid_uppers, indeterminate): (Vec<_>, Vec<_>) =
+                failures.into_iter().partition_map(|failure| match failure {
+                    AppendFailure::InvalidUpper(id) => Either::Left(id),
+                    AppendFailure::Indeterminate(id) => Either::Right(id),
+                });
+            // An invalid upper means that another writer has taken over, which is the more
+            // severe failure.
+            if invalid_uppers.is_empty() {
+                StorageError::IndeterminateAppends(indeterminate)
+            } else {
+                StorageError::InvalidUppers(invalid_uppers)
+            }
+        }
+    }
+
     #[derive(Debug)]
     pub struct PersistWorker<T: Timestamp + Lattice + Codec64> {
         tx: UnboundedSender<(tracing::Span, PersistWorkerCmd<T>)>,
@@ -1663,6 +1797,30 @@ mod persist_write_handles {
         Shutdown,
     }
 
+    impl<T: Timestamp + Lattice + Codec64> PersistWorkerCmd<T> {
+        /// Whether the command is an `Append` that only advances uppers, or `None` if the
+        /// command is not an `Append`.
+        fn advances_only(&self) -> Option<bool> {
+            match self {
+                PersistWorkerCmd::Append(updates, _) => {
+                    Some(updates.iter().all(|(_, updates, _)| updates.is_empty()))
+                }
+                PersistWorkerCmd::Register(..) | PersistWorkerCmd::Shutdown => None,
+            }
+        }
+
+        /// The number of collections whose uppers the command advances, if it is an `Append`
+        /// that only advances uppers, and zero otherwise.
+        fn advanced_collections(&self) -> usize {
+            match self {
+                PersistWorkerCmd::Append(updates, _) if self.advances_only() == Some(true) => {
+                    updates.len()
+                }
+                _ => 0,
+            }
+        }
+    }
+
     impl<T: Timestamp + Lattice + Codec64> PersistWorker<T> {
         pub(crate) fn new(
             mut frontier_responses: tokio::sync::mpsc::UnboundedSender<StorageResponse<T>>,
@@ -1672,13 +1830,52 @@ mod persist_write_handles {
             mz_ore::task::spawn(|| "PersistWriteHandles", async move {
                 let mut write_handles = BTreeMap::new();
 
-                while let Some(cmd) = rx.recv().await {
+                // A command that was received but could not be combined with the previous
+                // batch.
+                let mut next_cmd = None;
+
+                loop {
+                    let cmd = match next_cmd.take() {
+                        Some(cmd) => cmd,
+                        None => match rx.recv().await {
+                            Some(cmd) => cmd,
+                            None => break,
+                        },
+                    };
+
                     // Peel off all available commands.
                     // We do this in case we can consolidate commands.
                     // It would be surprising to receive multiple concurrent `Append` commands,
                     // but we might receive multiple *empty* `Append` commands.
+                    //
+                    // `Append` commands that write updates are not combined with `Append`
+                    // commands that only advance uppers, so that advancing many collections
+                    // does not delay the responses to writes. Commands that only advance uppers
+                    // are combined up to `MAX_COMBINED_ADVANCEMENTS` collections, so that
+                    // advancements sent in chunks are also processed in chunks.
+                    let mut advances_only = cmd.1.advances_only();
+                    let mut advanced_collections = cmd.1.advanced_collections();
                     let mut commands = vec![cmd];
                     while let Ok(cmd) = rx.try_recv() {
+                        match (advances_only, cmd.1.advances_only()) {
+                            (Some(batch), Some(cmd_advances_only))
+                                if batch != cmd_advances_only =>
+                            {
+                                next_cmd = Some(cmd);
+                                break;
+                            }
+                            (_, Some(true))
+                                if advanced_collections > 0
+                                    && advanced_collections + cmd.1.advanced_collections()
+                                        > MAX_COMBINED_ADVANCEMENTS =>
+                            {
+                                next_cmd = Some(cmd);
+                                break;
+                            }
+                            (None, cmd_advances_only) => advances_only = cmd_advances_only,
+                            _ => (),
+                        }
+                        advanced_collections += cmd.1.advanced_collections();
                         commands.push(cmd);
                     }
 
@@ -1720,7 +1917,11 @@ mod persist_write_handles {
                                         // nothing.
                                         old_span.follows_from(span.id());
                                     }
-                                    updates.extend(update);
+                                    // Chunks are kept separate, rather than concatenated, so
+                                    // that each one can be freed as soon as it is written out.
+                                    if !update.is_empty() {
+                                        updates.push(update);
+                                    }
                                     old_upper.join_assign(&Antichain::from_elem(upper));
                                 }
                                 all_responses.push(response);
@@ -1741,9 +1942,9 @@ mod persist_write_handles {
                         >,
                         mut commands: BTreeMap<
                             GlobalId,
-                            (tracing::Span, Vec<Update<T2>>, Antichain<T2>),
+                            (tracing::Span, Vec<Vec<Update<T2>>>, Antichain<T2>),
                         >,
-                    ) -> Result<(), Vec<GlobalId>> {
+                    ) -> Result<(), Vec<AppendFailure>> {
                         let futs = FuturesUnordered::new();
 
                         // We cannot iterate through the updates and then set off a persist call
@@ -1758,40 +1959,67 @@ mod persist_write_handles {
                                 let persist_upper = write.upper().clone();
                                 let updates = updates
                                     .into_iter()
+                                    .flatten()
                                     .map(|u| ((SourceData(Ok(u.row)), ()), u.timestamp, u.diff));
 
                                 futs.push(async move {
                                     let persist_upper = persist_upper.clone();
-                                    let mut result =
-                                    write
-                                        .compare_and_append(
-                                            updates.clone(),
-                                            persist_upper.clone(),
-                                            new_upper.clone(),
-                                        )
+                                    // The updates are written out to blob storage once, in
+                                    // bounded parts as they are consumed, so that retries do not
+                                    // need to hold on to a copy of them.
+                                    let mut batch = write
+                                        .batch(updates, persist_upper.clone(), new_upper.clone())
                                         .instrument(span.clone())
-                                        .await;
+                                        .await
+                                        .expect("cannot append updates");
+                                    let mut result = match indeterminate_append_failpoint(*id) {
+                                        Some(indeterminate) => Err(indeterminate),
+                                        None => write
+                                            .compare_and_append_batch(
+                                                &mut [&mut batch],
+                                                persist_upper.clone(),
+                                                new_upper.clone(),
+                                            )
+                                            .instrument(span.clone())
+                                            .await,
+                                    };
 
                                     // Indeterminate results can occur when persist is not certain
                                     // whether the transaction has applied or not. We will attempt
                                     // to suss this out by looking at the recent `upper`, and retrying
                                     // if it is still appropriate, not retrying if it has advanced
-                                    // to `new_upper`, and panicking if it is anything else.
+                                    // to `new_upper`, and panicking if it is anything else. If the
+                                    // result remains indeterminate after retrying with backoff for
+                                    // a while, the append is reported as indeterminate.
+                                    let retries = Retry::default()
+                                        .clamp_backoff(Duration::from_secs(1))
+                                        .max_duration(INDETERMINATE_APPEND_RETRY_DURATION)
+                                        .into_retry_stream();
+                                    tokio::pin!(retries);
                                     while let Err(indeterminate) = result {
+                                        if retries.next().await.is_none() {
+                                            tracing::error!("Giving up on indeterminate table write: {:?}", indeterminate);
+                                            // The batch might have been appended, so it must not
+                                            // be deleted.
+                                            let _ = batch.into_hollow_batch();
+                                            return Err(AppendFailure::Indeterminate(*id));
+                                        }
                                         tracing::warn!("Retrying indeterminate table write: {:?}", indeterminate);
                                         write.fetch_recent_upper().await;
                                         if write.upper() == &persist_upper {
                                             // If the upper frontier is the prior frontier, the commit
                                             // did not happen and we should retry it.
-                                            result =
-                                            write
-                                                .compare_and_append(
-                                                    updates.clone(),
-                                                    persist_upper.clone(),
-                                                    new_upper.clone(),
-                                                )
-                                                .instrument(span.clone())
-                                                .await;
+                                            result = match indeterminate_append_failpoint(*id) {
+                                                Some(indeterminate) => Err(indeterminate),
+                                                None => write
+                                                    .compare_and_append_batch(
+                                                        &mut [&mut batch],
+                                                        persist_upper.clone(),
+                                                        new_upper.clone(),
+                                                    )
+                                                    .instrument(span.clone())
+                                                    .await,
+                                            };
 
                                         } else if write.upper() == &new_upper {
                                             // If the upper frontier is the new frontier, then because
@@ -1810,12 +2038,20 @@ mod persist_write_handles {
                                         }
                                     }
 
-                                    result
+                                    let result = result
                                         .expect("Indeterminate response not resolved")
-                                        .expect("cannot append updates")
-                                        .or(Err(*id))?;
+                                        .expect("cannot append updates");
+                                    match &result {
+                                        // Either the append consumed the batch, or an
+                                        // indeterminate attempt appended it.
+                                        Ok(()) => {
+                                            let _ = batch.into_hollow_batch();
+                                        }
+                                        Err(_) => batch.delete().await,
+                                    }
+                                    result.or(Err(AppendFailure::InvalidUpper(*id)))?;
 
-                                    Ok::<_, GlobalId>((*id, new_upper))
+                                    Ok::<_, AppendFailure>((*id, new_upper))
                                 })
                             }
                         }
@@ -1844,7 +2080,7 @@ mod persist_write_handles {
 
                     // It is not an error for the other end to hang up.
                     for response in all_responses {
-                        let _ = response.send(result.clone().map_err(StorageError::InvalidUppers));
+                        let _ = response.send(result.clone().map_err(StorageError::from));
                     }
 
                     if shutdown {
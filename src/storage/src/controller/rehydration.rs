@@ -308,6 +308,18 @@ where
                     None
                 }
             }
+            StorageResponse::SinkStatistics(list) => {
+                // Statistics may still arrive for sinks that were dropped in the meantime.
+                let updates: Vec<_> = list
+                    .into_iter()
+                    .filter(|(id, _)| self.uppers.contains_key(id))
+                    .collect();
+                if !updates.is_empty() {
+                    Some(StorageResponse::SinkStatistics(updates))
+                } else {
+                    None
+                }
+            }
         }
     }
 }
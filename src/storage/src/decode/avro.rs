@@ -27,10 +27,17 @@ impl AvroDecoderState {
         ccsr_client: Option<mz_ccsr::Client>,
         debug_name: String,
         confluent_wire_format: bool,
+        projection: Option<Vec<usize>>,
     ) -> Result<Self, anyhow::Error> {
         Ok(AvroDecoderState {
             tokio_handle: TokioHandle::current(),
-            decoder: Decoder::new(value_schema, ccsr_client, debug_name, confluent_wire_format)?,
+            decoder: Decoder::new(
+                value_schema,
+                ccsr_client,
+                debug_name,
+                confluent_wire_format,
+                projection,
+            )?,
             events_success: 0,
         })
     }
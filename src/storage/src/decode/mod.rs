@@ -152,12 +152,12 @@ impl PreDelimitedFormat {
             PreDelimitedFormat::Bytes => Ok(Some(Row::pack(Some(Datum::Bytes(bytes))))),
             PreDelimitedFormat::Text => {
                 let s = std::str::from_utf8(bytes)
-                    .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
+                    .map_err(|e| DecodeErrorKind::Text(format!("Failed to decode UTF-8: {}", e)))?;
                 Ok(Some(Row::pack(Some(Datum::String(s)))))
             }
             PreDelimitedFormat::Regex(regex, row_buf) => {
                 let s = std::str::from_utf8(bytes)
-                    .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
+                    .map_err(|e| DecodeErrorKind::Text(format!("Failed to decode UTF-8: {}", e)))?;
                 let captures = match regex.captures(s) {
                     Some(captures) => captures,
                     None => return Ok(None),
@@ -265,6 +265,7 @@ fn get_decoder(
             schema,
             csr_connection,
             confluent_wire_format,
+            projection,
         }) => {
             let csr_client = match csr_connection {
                 None => None,
@@ -279,6 +280,7 @@ fn get_decoder(
                 csr_client,
                 debug_name.to_string(),
                 confluent_wire_format,
+                projection,
             )
             .expect("Failed to create avro decoder, even though we validated ccsr client creation in purification.");
             DataDecoder {
@@ -368,6 +370,7 @@ pub fn render_decode_delimited<G>(
     value_encoding: DataEncoding,
     debug_name: &str,
     metadata_items: Vec<IncludedColumnSource>,
+    topic: Option<String>,
     metrics: DecodeMetrics,
     connection_context: &ConnectionContext,
 ) -> (Stream<G, DecodeResult>, Option<Box<dyn Any + Send + Sync>>)
@@ -449,6 +452,7 @@ where
                         partition: partition.clone(),
                         metadata: to_metadata_row(
                             &metadata_items,
+                            topic.as_deref(),
                             partition.clone(),
                             *position,
                             *upstream_time_millis,
@@ -555,6 +559,7 @@ where
                                     let position = n_seen.next().unwrap();
                                     let metadata = to_metadata_row(
                                         &metadata_items,
+                                        None,
                                         partition.clone(),
                                         position.into(),
                                         *upstream_time_millis,
@@ -621,6 +626,7 @@ where
                         let position = n_seen.next().unwrap();
                         let metadata = to_metadata_row(
                             &metadata_items,
+                            None,
                             partition.clone(),
                             position.into(),
                             *upstream_time_millis,
@@ -676,6 +682,7 @@ where
 
 fn to_metadata_row(
     metadata_items: &[IncludedColumnSource],
+    topic: Option<&str>,
     partition: PartitionId,
     position: MzOffset,
     upstream_time_millis: Option<i64>,
@@ -684,8 +691,13 @@ fn to_metadata_row(
     let position = position.offset;
     let mut row = Row::default();
     let mut packer = row.packer();
+    let (topic, partition) = match &partition {
+        PartitionId::Kafka(partition) => (topic, Some(*partition)),
+        PartitionId::KafkaTopic { topic, partition } => (Some(topic.as_str()), Some(*partition)),
+        PartitionId::None => (None, None),
+    };
     match partition {
-        PartitionId::Kafka(partition) => {
+        Some(partition) => {
             for item in metadata_items.iter() {
                 match item {
                     IncludedColumnSource::Partition => packer.push(Datum::from(partition)),
@@ -709,7 +721,9 @@ fn to_metadata_row(
                             millis * 1_000_000,
                         )))
                     }
-                    IncludedColumnSource::Topic => unreachable!("Topic is not implemented yet"),
+                    IncludedColumnSource::Topic => packer.push(Datum::from(
+                        topic.expect("kafka sources always have a topic"),
+                    )),
                     IncludedColumnSource::Headers => {
                         packer.push_list_with(|r| {
                             // If the source asked for headers, but we didn't get any, we still
@@ -736,7 +750,7 @@ fn to_metadata_row(
                 }
             }
         }
-        PartitionId::None => {
+        None => {
             if !metadata_items.is_empty() {
                 unreachable!("Only Kafka supports metadata items");
             }
@@ -23,6 +23,7 @@ use differential_dataflow::lattice::Lattice;
 use proptest::prelude::{any, Arbitrary};
 use proptest::prop_oneof;
 use proptest::strategy::{BoxedStrategy, Strategy};
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::progress::frontier::{Antichain, MutableAntichain};
 use timely::PartialOrder;
@@ -282,6 +283,8 @@ pub enum StorageResponse<T = mz_repr::Timestamp> {
     /// TODO(teskje): Consider also reporting the previous upper frontier and using that
     /// information to assert the correct implementation of our protocols at various places.
     FrontierUppers(Vec<(GlobalId, Antichain<T>)>),
+    /// A list of identifiers of sinks, with their current statistics.
+    SinkStatistics(Vec<(GlobalId, SinkStatistics)>),
 }
 
 impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
@@ -290,6 +293,7 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
         ProtoStorageResponse {
             kind: Some(match self {
                 StorageResponse::FrontierUppers(traces) => FrontierUppers(traces.into_proto()),
+                StorageResponse::SinkStatistics(updates) => SinkStatistics(updates.into_proto()),
             }),
         }
     }
@@ -300,6 +304,9 @@ impl RustType<ProtoStorageResponse> for StorageResponse<mz_repr::Timestamp> {
             Some(FrontierUppers(traces)) => {
                 Ok(StorageResponse::FrontierUppers(traces.into_rust()?))
             }
+            Some(SinkStatistics(updates)) => {
+                Ok(StorageResponse::SinkStatistics(updates.into_rust()?))
+            }
             None => Err(TryFromProtoError::missing_field(
                 "ProtoStorageResponse::kind",
             )),
@@ -315,11 +322,37 @@ impl Arbitrary for StorageResponse<mz_repr::Timestamp> {
         prop_oneof![
             proptest::collection::vec((any::<GlobalId>(), any_antichain()), 1..4)
                 .prop_map(StorageResponse::FrontierUppers),
+            proptest::collection::vec((any::<GlobalId>(), any::<SinkStatistics>()), 1..4)
+                .prop_map(StorageResponse::SinkStatistics),
         ]
         .boxed()
     }
 }
 
+/// Counters that a sink maintains about the data it has written.
+///
+/// Each worker reports the counters for its own part of the sink, and the
+/// partitioned client sums them. The counters start from zero when the sink
+/// is (re)started.
+#[derive(Arbitrary, Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SinkStatistics {
+    /// The number of messages the sink has sent.
+    pub messages_sent: u64,
+    /// The number of key and payload bytes in the messages the sink has sent.
+    pub bytes_sent: u64,
+    /// The number of errors the sink's producer encountered while sending or
+    /// delivering messages.
+    pub producer_errors: u64,
+}
+
+impl SinkStatistics {
+    fn add(&mut self, other: &SinkStatistics) {
+        self.messages_sent += other.messages_sent;
+        self.bytes_sent += other.bytes_sent;
+        self.producer_errors += other.producer_errors;
+    }
+}
+
 /// Maintained state for partitioned storage clients.
 ///
 /// This helper type unifies the responses of multiple partitioned
@@ -331,6 +364,8 @@ pub struct PartitionedStorageState<T> {
     /// Upper frontiers for sources and sinks, both unioned across all partitions and from each
     /// individual partition.
     uppers: HashMap<GlobalId, (MutableAntichain<T>, Vec<Antichain<T>>)>,
+    /// The most recent statistics that each partition reported for each sink.
+    sink_statistics: HashMap<GlobalId, Vec<SinkStatistics>>,
 }
 
 impl<T> Partitionable<StorageCommand<T>, StorageResponse<T>>
@@ -344,6 +379,7 @@ where
         PartitionedStorageState {
             parts,
             uppers: HashMap::new(),
+            sink_statistics: HashMap::new(),
         }
     }
 }
@@ -370,6 +406,8 @@ where
                     let part_frontiers = vec![Antichain::from_elem(T::minimum()); self.parts];
                     let previous = self.uppers.insert(export.id, (frontier, part_frontiers));
                     assert!(previous.is_none(), "Protocol error: starting frontier tracking for already present identifier {:?} due to command {:?}", export.id, command);
+                    // A newly rendered sink starts counting from zero.
+                    self.sink_statistics.remove(&export.id);
                 }
             }
             StorageCommand::AllowCompaction(_) | StorageCommand::InitializationComplete => {
@@ -420,6 +458,26 @@ where
                     Some(Ok(StorageResponse::FrontierUppers(new_uppers)))
                 }
             }
+            // Present the sum of the statistics of all partitions.
+            StorageResponse::SinkStatistics(list) => {
+                let parts = self.parts;
+                let updates = list
+                    .into_iter()
+                    .map(|(id, shard_statistics)| {
+                        let part_statistics = self
+                            .sink_statistics
+                            .entry(id)
+                            .or_insert_with(|| vec![SinkStatistics::default(); parts]);
+                        part_statistics[shard_id] = shard_statistics;
+                        let mut statistics = SinkStatistics::default();
+                        for s in part_statistics.iter() {
+                            statistics.add(s);
+                        }
+                        (id, statistics)
+                    })
+                    .collect();
+                Some(Ok(StorageResponse::SinkStatistics(updates)))
+            }
         }
     }
 }
@@ -460,6 +518,56 @@ impl RustType<ProtoFrontierUppersKind> for Vec<(GlobalId, Antichain<mz_repr::Tim
     }
 }
 
+impl RustType<ProtoSinkStatistics> for SinkStatistics {
+    fn into_proto(&self) -> ProtoSinkStatistics {
+        ProtoSinkStatistics {
+            messages_sent: self.messages_sent,
+            bytes_sent: self.bytes_sent,
+            producer_errors: self.producer_errors,
+        }
+    }
+
+    fn from_proto(proto: ProtoSinkStatistics) -> Result<Self, TryFromProtoError> {
+        Ok(SinkStatistics {
+            messages_sent: proto.messages_sent,
+            bytes_sent: proto.bytes_sent,
+            producer_errors: proto.producer_errors,
+        })
+    }
+}
+
+impl RustType<ProtoSinkStatisticsUpdate> for (GlobalId, SinkStatistics) {
+    fn into_proto(&self) -> ProtoSinkStatisticsUpdate {
+        ProtoSinkStatisticsUpdate {
+            id: Some(self.0.into_proto()),
+            statistics: Some(self.1.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoSinkStatisticsUpdate) -> Result<Self, TryFromProtoError> {
+        Ok((
+            proto
+                .id
+                .into_rust_if_some("ProtoSinkStatisticsUpdate::id")?,
+            proto
+                .statistics
+                .into_rust_if_some("ProtoSinkStatisticsUpdate::statistics")?,
+        ))
+    }
+}
+
+impl RustType<ProtoSinkStatisticsKind> for Vec<(GlobalId, SinkStatistics)> {
+    fn into_proto(&self) -> ProtoSinkStatisticsKind {
+        ProtoSinkStatisticsKind {
+            updates: self.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoSinkStatisticsKind) -> Result<Self, TryFromProtoError> {
+        proto.updates.into_rust()
+    }
+}
+
 impl RustType<ProtoCompaction> for (GlobalId, Antichain<u64>) {
     fn into_proto(&self) -> ProtoCompaction {
         ProtoCompaction {
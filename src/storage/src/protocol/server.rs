@@ -11,6 +11,7 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use anyhow::anyhow;
 use mz_build_info::BuildInfo;
@@ -110,6 +111,9 @@ pub fn serve(
                 persist_clients,
                 sink_tokens: HashMap::new(),
                 sink_write_frontiers: HashMap::new(),
+                sink_statistics: HashMap::new(),
+                reported_sink_statistics: HashMap::new(),
+                sink_statistics_reported_at: Instant::now(),
             },
         }
         .run()
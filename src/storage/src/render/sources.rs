@@ -29,8 +29,9 @@ use crate::controller::CollectionMetadata;
 use crate::decode::{render_decode, render_decode_cdcv2, render_decode_delimited};
 use crate::source::types::DecodeResult;
 use crate::source::{
-    self, persist_source, DelimitedValueSource, KafkaSourceReader, KinesisSourceReader,
-    LoadGeneratorSourceReader, PostgresSourceReader, RawSourceCreationConfig, S3SourceReader,
+    self, persist_source, DelimitedValueSource, FileSourceReader, KafkaSourceReader,
+    KinesisSourceReader, LoadGeneratorSourceReader, PostgresSourceReader, PubSubSourceReader,
+    RawSourceCreationConfig, S3SourceReader,
 };
 use crate::types::errors::{DataflowError, DecodeError, EnvelopeError};
 use crate::types::sources::{encoding::*, *};
@@ -109,7 +110,7 @@ where
     let source_name = format!("{}-{}", connection.name(), id);
     let base_source_config = RawSourceCreationConfig {
         name: source_name,
-        upstream_name: connection.upstream_name().map(ToOwned::to_owned),
+        upstream_name: connection.upstream_name(),
         id,
         scope,
         timestamp_interval,
@@ -123,6 +124,19 @@ where
         persist_clients: Arc::clone(&storage_state.persist_clients),
     };
 
+    // The topic is the same for every message of a single-topic Kafka source,
+    // so rather than carrying it along with each message we hand it to the
+    // decode operator once, and only if the source asked for it. Sources that
+    // read several topics identify the topic in each message's partition id.
+    let topic = match &connection {
+        SourceConnection::Kafka(KafkaSourceConnection {
+            topics: KafkaTopicSelection::Topic(topic),
+            include_topic: Some(_),
+            ..
+        }) => Some(topic.clone()),
+        _ => None,
+    };
+
     // Build the _raw_ ok and error sources using `create_raw_source` and the
     // correct `SourceReader` implementations
     let ((ok_source, err_source), capability) = match connection {
@@ -167,6 +181,23 @@ where
             );
             ((SourceType::Row(ok), err), cap)
         }
+        SourceConnection::PubSub(_) => {
+            let ((ok, err), cap) = source::create_raw_source::<_, PubSubSourceReader>(
+                base_source_config,
+                &connection,
+                storage_state.connection_context.clone(),
+            );
+            ((SourceType::Delimited(ok), err), cap)
+        }
+        SourceConnection::File(_) => {
+            let ((ok, err), cap) =
+                source::create_raw_source::<_, DelimitedValueSource<FileSourceReader>>(
+                    base_source_config,
+                    &connection,
+                    storage_state.connection_context.clone(),
+                );
+            ((SourceType::Delimited(ok), err), cap)
+        }
     };
 
     // Include any source errors.
@@ -192,6 +223,7 @@ where
                 schema,
                 csr_connection,
                 confluent_wire_format,
+                projection: _,
             } = match value_encoding.inner {
                 DataEncodingInner::Avro(enc) => enc,
                 _ => unreachable!("Attempted to create non-Avro CDCv2 source"),
@@ -228,6 +260,7 @@ where
                     value_encoding,
                     dataflow_debug_name,
                     metadata_columns,
+                    topic,
                     storage_state.decode_metrics.clone(),
                     &storage_state.connection_context,
                 ),
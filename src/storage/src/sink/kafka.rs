@@ -57,7 +57,7 @@ use mz_repr::{Datum, Diff, GlobalId, Row, RowPacker, Timestamp};
 use mz_timely_util::async_op;
 use mz_timely_util::operators_async_ext::OperatorBuilderExt;
 
-use super::KafkaBaseMetrics;
+use super::{KafkaBaseMetrics, SinkStatisticsCounters};
 use crate::controller::CollectionMetadata;
 use crate::render::sinks::SinkRender;
 use crate::storage_state::StorageState;
@@ -132,6 +132,7 @@ where
         } else {
             Antichain::new()
         }));
+        let statistics = Arc::new(SinkStatisticsCounters::default());
 
         let token = kafka(
             sinked_collection,
@@ -141,12 +142,14 @@ where
             sink.as_of.clone(),
             Rc::clone(&shared_frontier),
             &storage_state.sink_metrics.kafka,
+            Arc::clone(&statistics),
             &storage_state.connection_context,
         );
 
         storage_state
             .sink_write_frontiers
             .insert(sink_id, shared_frontier);
+        storage_state.sink_statistics.insert(sink_id, statistics);
 
         Some(token)
     }
@@ -155,14 +158,18 @@ where
 /// Per-Kafka sink metrics.
 pub struct SinkMetrics {
     messages_sent_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    bytes_sent_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     message_send_errors_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     message_delivery_errors_counter: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
     rows_queued: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    write_frontier: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    statistics: Arc<SinkStatisticsCounters>,
 }
 
 impl SinkMetrics {
     fn new(
         base: &KafkaBaseMetrics,
+        statistics: Arc<SinkStatisticsCounters>,
         topic_name: &str,
         sink_id: &str,
         worker_id: &str,
@@ -176,13 +183,18 @@ impl SinkMetrics {
             messages_sent_counter: base
                 .messages_sent_counter
                 .get_delete_on_drop_counter(labels.clone()),
+            bytes_sent_counter: base
+                .bytes_sent_counter
+                .get_delete_on_drop_counter(labels.clone()),
             message_send_errors_counter: base
                 .message_send_errors_counter
                 .get_delete_on_drop_counter(labels.clone()),
             message_delivery_errors_counter: base
                 .message_delivery_errors_counter
                 .get_delete_on_drop_counter(labels.clone()),
-            rows_queued: base.rows_queued.get_delete_on_drop_gauge(labels),
+            rows_queued: base.rows_queued.get_delete_on_drop_gauge(labels.clone()),
+            write_frontier: base.write_frontier.get_delete_on_drop_gauge(labels),
+            statistics,
         }
     }
 }
@@ -260,6 +272,10 @@ impl ProducerContext for SinkProducerContext {
             Ok(_) => self.retry_manager.blocking_lock().record_success(),
             Err((_e, msg)) => {
                 self.metrics.message_delivery_errors_counter.inc();
+                self.metrics
+                    .statistics
+                    .producer_errors
+                    .fetch_add(1, Ordering::Relaxed);
                 // TODO: figure out a good way to back these retries off.  Should be okay without
                 // because we seem to very rarely end up in a constant state where rdkafka::send
                 // works but everything is immediately rejected and hits this branch.
@@ -437,6 +453,7 @@ impl KafkaSinkState {
         activator: Activator,
         write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
         metrics: &KafkaBaseMetrics,
+        statistics: Arc<SinkStatisticsCounters>,
         connection_context: &ConnectionContext,
     ) -> Self {
         let transactional_id = if connection.exactly_once {
@@ -451,6 +468,7 @@ impl KafkaSinkState {
 
         let metrics = Arc::new(SinkMetrics::new(
             metrics,
+            statistics,
             &connection.topic,
             &sink_id.to_string(),
             &worker_id,
@@ -621,6 +639,8 @@ impl KafkaSinkState {
         P: ToBytes + ?Sized,
     {
         let mut last_error = KafkaError::Canceled;
+        let record_bytes = record.key.map_or(0, |k| k.to_bytes().len())
+            + record.payload.map_or(0, |p| p.to_bytes().len());
         // Only actually used for retriable errors.
         let tries = Retry::default()
             .max_tries(usize::MAX)
@@ -631,6 +651,14 @@ impl KafkaSinkState {
             match self.producer.send(record) {
                 Ok(_) => {
                     self.metrics.messages_sent_counter.inc();
+                    self.metrics
+                        .bytes_sent_counter
+                        .inc_by(u64::cast_from(record_bytes));
+                    let statistics = &self.metrics.statistics;
+                    statistics.messages_sent.fetch_add(1, Ordering::Relaxed);
+                    statistics
+                        .bytes_sent
+                        .fetch_add(u64::cast_from(record_bytes), Ordering::Relaxed);
                     self.retry_manager.lock().await.record_send();
                     return Ok(());
                 }
@@ -638,6 +666,10 @@ impl KafkaSinkState {
                     record = rec;
                     last_error = e;
                     self.metrics.message_send_errors_counter.inc();
+                    self.metrics
+                        .statistics
+                        .producer_errors
+                        .fetch_add(1, Ordering::Relaxed);
 
                     if let KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull) = last_error {
                         debug!(
@@ -952,6 +984,7 @@ impl KafkaSinkState {
             assert!(write_frontier.less_equal(&min_frontier));
             write_frontier.clear();
             write_frontier.insert(min_frontier);
+            self.metrics.write_frontier.set(min_frontier);
         } else {
             // If there's no longer an input frontier, we will no longer receive any data forever and, therefore, will
             // never output more data
@@ -978,6 +1011,7 @@ fn kafka<G>(
     as_of: SinkAsOf,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
     metrics: &KafkaBaseMetrics,
+    statistics: Arc<SinkStatisticsCounters>,
     connection_context: &ConnectionContext,
 ) -> Rc<dyn Any>
 where
@@ -1045,6 +1079,7 @@ where
         shared_gate_ts,
         write_frontier,
         metrics,
+        statistics,
         connection_context,
     )
 }
@@ -1069,6 +1104,7 @@ pub fn produce_to_kafka<G>(
     shared_gate_ts: Rc<Cell<Option<Timestamp>>>,
     write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
     metrics: &KafkaBaseMetrics,
+    statistics: Arc<SinkStatisticsCounters>,
     connection_context: &ConnectionContext,
 ) -> Rc<dyn Any>
 where
@@ -1089,6 +1125,7 @@ where
         activator,
         write_frontier,
         metrics,
+        statistics,
         connection_context,
     );
 
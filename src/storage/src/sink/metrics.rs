@@ -9,18 +9,24 @@
 
 //! Metrics that sinks report.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use mz_ore::{
     metric,
     metrics::{IntCounterVec, MetricsRegistry, UIntGaugeVec},
 };
 
+use crate::protocol::client::SinkStatistics;
+
 /// Metrics reported by each kafka sink.
 #[derive(Clone)]
 pub struct KafkaBaseMetrics {
     pub(crate) messages_sent_counter: IntCounterVec,
+    pub(crate) bytes_sent_counter: IntCounterVec,
     pub(crate) message_send_errors_counter: IntCounterVec,
     pub(crate) message_delivery_errors_counter: IntCounterVec,
     pub(crate) rows_queued: UIntGaugeVec,
+    pub(crate) write_frontier: UIntGaugeVec,
 }
 
 impl KafkaBaseMetrics {
@@ -31,6 +37,11 @@ impl KafkaBaseMetrics {
                 help: "The number of messages the Kafka producer successfully sent for this sink",
                 var_labels: ["topic", "sink_id", "worker_id"],
             )),
+            bytes_sent_counter: registry.register(metric!(
+                name: "mz_kafka_bytes_sent_total",
+                help: "The number of key and payload bytes the Kafka producer successfully sent for this sink",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
             message_send_errors_counter: registry.register(metric!(
                 name: "mz_kafka_message_send_errors_total",
                 help: "The number of times the Kafka producer encountered an error on send",
@@ -46,6 +57,31 @@ impl KafkaBaseMetrics {
                 help: "The current number of rows queued by the Kafka sink operator (note that one row can generate multiple Kafka messages)",
                 var_labels: ["topic", "sink_id", "worker_id"],
             )),
+            write_frontier: registry.register(metric!(
+                name: "mz_kafka_sink_write_frontier",
+                help: "The timestamp, in milliseconds since the Unix epoch, through which the Kafka sink has written all updates. Its distance from the current time is the sink's end-to-end lag",
+                var_labels: ["topic", "sink_id", "worker_id"],
+            )),
+        }
+    }
+}
+
+/// Counters that a sink updates as it writes, and from which its worker
+/// reports [`SinkStatistics`].
+#[derive(Debug, Default)]
+pub struct SinkStatisticsCounters {
+    pub(crate) messages_sent: AtomicU64,
+    pub(crate) bytes_sent: AtomicU64,
+    pub(crate) producer_errors: AtomicU64,
+}
+
+impl SinkStatisticsCounters {
+    /// Returns the current values of the counters.
+    pub fn snapshot(&self) -> SinkStatistics {
+        SinkStatistics {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            producer_errors: self.producer_errors.load(Ordering::Relaxed),
         }
     }
 }
@@ -14,4 +14,4 @@ mod metrics;
 
 pub(crate) use metrics::KafkaBaseMetrics;
 
-pub use metrics::SinkBaseMetrics;
+pub use metrics::{SinkBaseMetrics, SinkStatisticsCounters};
@@ -0,0 +1,189 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A source that reads the lines of a local file, optionally following the
+//! file as new lines are appended to it.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use timely::scheduling::SyncActivator;
+
+use mz_expr::PartitionId;
+use mz_repr::GlobalId;
+
+use crate::source::{
+    NextMessage, SourceMessage, SourceMessageType, SourceReader, SourceReaderError,
+};
+use crate::types::connections::ConnectionContext;
+use crate::types::errors::SourceErrorDetails;
+use crate::types::sources::encoding::SourceDataEncoding;
+use crate::types::sources::{FileSourceConnection, MzOffset, SourceConnection};
+
+use super::metrics::SourceBaseMetrics;
+
+/// Information required to read the lines of a local file.
+pub struct FileSourceReader {
+    /// The path of the file.
+    path: String,
+    /// Whether to wait for new lines after reaching the end of the file.
+    tail: bool,
+    /// The file, once the active reader has opened it.
+    reader: Option<BufReader<File>>,
+    /// The bytes read so far of a line whose terminating newline has not yet
+    /// been written to the file.
+    partial_line: Vec<u8>,
+    /// The 1-indexed number of the last line this source has emitted.
+    offset: MzOffset,
+    /// Whether this reader has hit an error from which it cannot recover.
+    failed: bool,
+    // File sources support single-threaded ingestion only, so only one of the
+    // `FileSourceReader`s will actually produce data.
+    active_read_worker: bool,
+    // The non-active reader (see above `active_read_worker`) has to report back
+    // that is is not consuming from the one [`PartitionId:None`] partition.
+    // Before it can return a [`NextMessage::Finished`]. This is keeping track
+    // of that.
+    reported_unconsumed_partitions: bool,
+}
+
+impl FileSourceReader {
+    /// Opens the file, skipping over the lines that were emitted before the
+    /// source was restarted.
+    fn open(&self) -> Result<BufReader<File>, io::Error> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        let mut line = Vec::new();
+        for _ in 0..self.offset.offset {
+            line.clear();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+        }
+        Ok(reader)
+    }
+
+    fn fail(&mut self, context: &str, e: io::Error) -> SourceReaderError {
+        self.failed = true;
+        SourceReaderError {
+            inner: SourceErrorDetails::FileIO(format!("{} {}: {}", context, self.path, e)),
+        }
+    }
+}
+
+impl SourceReader for FileSourceReader {
+    type Key = ();
+    type Value = Option<Vec<u8>>;
+    type Diff = ();
+
+    fn new(
+        _source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        _consumer_activator: SyncActivator,
+        connection: SourceConnection,
+        start_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        _metrics: SourceBaseMetrics,
+        _connection_context: ConnectionContext,
+    ) -> Result<Self, anyhow::Error> {
+        let FileSourceConnection { path, tail } = match connection {
+            SourceConnection::File(file) => file,
+            _ => {
+                panic!("File is the only legitimate SourceConnection for FileSourceReader")
+            }
+        };
+
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        // The restored offset is one past the number of the last line that was
+        // emitted.
+        let offset = start_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .map(|offset| MzOffset {
+                offset: offset.offset.saturating_sub(1),
+            })
+            .unwrap_or_default();
+
+        Ok(FileSourceReader {
+            path,
+            tail,
+            reader: None,
+            partial_line: Vec::new(),
+            offset,
+            failed: false,
+            active_read_worker,
+            reported_unconsumed_partitions: false,
+        })
+    }
+
+    fn get_next_message(
+        &mut self,
+    ) -> Result<NextMessage<Self::Key, Self::Value, Self::Diff>, SourceReaderError> {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return Ok(NextMessage::Ready(
+                    SourceMessageType::DropPartitionCapabilities(vec![PartitionId::None]),
+                ));
+            }
+            return Ok(NextMessage::Finished);
+        }
+
+        if self.failed {
+            return Ok(NextMessage::Finished);
+        }
+
+        if self.reader.is_none() {
+            match self.open() {
+                Ok(reader) => self.reader = Some(reader),
+                Err(e) => return Err(self.fail("opening file", e)),
+            }
+        }
+        let reader = self.reader.as_mut().expect("file opened above");
+
+        // Reading past the end of the file returns no bytes, but a later read
+        // observes any lines that have been appended in the meantime.
+        if let Err(e) = reader.read_until(b'\n', &mut self.partial_line) {
+            return Err(self.fail("reading file", e));
+        }
+        if self.partial_line.last() == Some(&b'\n') {
+            self.partial_line.pop();
+        } else if self.tail {
+            // Either there is no new data, or the last line in the file is
+            // still being written.
+            return Ok(NextMessage::Pending);
+        } else if self.partial_line.is_empty() {
+            return Ok(NextMessage::Finished);
+        }
+        // Otherwise, this is the last line of the file, which has no trailing
+        // newline.
+
+        self.offset += 1;
+        Ok(NextMessage::Ready(SourceMessageType::Finalized(
+            SourceMessage {
+                partition: PartitionId::None,
+                offset: self.offset,
+                upstream_time_millis: None,
+                key: (),
+                value: Some(std::mem::take(&mut self.partial_line)),
+                headers: None,
+                specific_diff: (),
+            },
+        )))
+    }
+}
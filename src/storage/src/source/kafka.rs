@@ -16,6 +16,7 @@ use rdkafka::consumer::base_consumer::PartitionQueue;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::error::KafkaError;
 use rdkafka::message::{BorrowedMessage, Headers};
+use rdkafka::metadata::MetadataTopic;
 use rdkafka::statistics::Statistics;
 use rdkafka::topic_partition_list::Offset;
 use rdkafka::{ClientConfig, ClientContext, Message, TopicPartitionList};
@@ -34,7 +35,9 @@ use crate::source::{
 };
 use crate::types::connections::{ConnectionContext, KafkaConnection, StringOrSecret};
 use crate::types::sources::encoding::SourceDataEncoding;
-use crate::types::sources::{KafkaOffset, KafkaSourceConnection, MzOffset, SourceConnection};
+use crate::types::sources::{
+    KafkaOffset, KafkaSourceConnection, KafkaTopicSelection, MzOffset, SourceConnection,
+};
 
 use self::metrics::KafkaPartitionMetrics;
 
@@ -42,8 +45,8 @@ mod metrics;
 
 /// Contains all information necessary to ingest data from Kafka
 pub struct KafkaSourceReader {
-    /// Name of the topic on which this source is backed on
-    topic_name: String,
+    /// The topics on which this source is backed on
+    topics: KafkaTopicSelection,
     /// Name of the source (will have format kafka-source-id)
     source_name: String,
     /// Source global ID
@@ -58,13 +61,13 @@ pub struct KafkaSourceReader {
     worker_count: usize,
     /// Map from partition -> most recently read offset. Can be -1,
     /// if we are starting at the beginning.
-    last_offsets: HashMap<i32, KafkaOffset>,
+    last_offsets: HashMap<PartitionId, KafkaOffset>,
     /// Map from partition -> offset to start reading at. 0-indexed
-    start_offsets: HashMap<i32, u64>,
+    start_offsets: HashMap<PartitionId, u64>,
     /// Channel to receive Kafka statistics JSON blobs from the stats callback.
     stats_rx: crossbeam_channel::Receiver<Jsonb>,
-    /// The last partition we received
-    partition_info: Arc<Mutex<Option<Vec<i32>>>>,
+    /// The last (topic, partition) pairs we received
+    partition_info: Arc<Mutex<Option<Vec<(String, i32)>>>>,
     /// A handle to the spawned metadata thread
     // Drop order is important here, we want the thread to be unparked after the `partition_info`
     // Arc has been dropped, so that the unpacked thread notices it and exits immediately
@@ -100,7 +103,7 @@ impl SourceReader for KafkaSourceReader {
         let KafkaSourceConnection {
             connection,
             options,
-            topic,
+            topics,
             group_id_prefix,
             cluster_id,
             ..
@@ -123,25 +126,28 @@ impl SourceReader for KafkaSourceReader {
         let consumer = Arc::new(consumer);
 
         // Start offsets is a map from pid -> next 0-indexed offset to read from,
-        // which is equivalent to 1 + the last 0-indexed offset read.
+        // which is equivalent to 1 + the last 0-indexed offset read. Sources
+        // that read several topics always start at the beginning of every
+        // partition, so only single-topic sources have any.
         let mut start_offsets: HashMap<_, u64> = kc
             .start_offsets
             .into_iter()
+            .map(|(pid, offset)| (PartitionId::Kafka(pid), offset.offset))
             .filter(|(pid, _offset)| {
-                let pid = PartitionId::Kafka(*pid);
-                crate::source::responsible_for(&source_id, worker_id, worker_count, &pid)
+                crate::source::responsible_for(&source_id, worker_id, worker_count, pid)
             })
-            .map(|(k, v)| (k, v.offset))
             .collect();
 
         // Restored offsets are 1-indexed, so convert to 0-indexed offsets by
         // subtracting 1. The bindings in sqlite already encode 1 offset past the
         // last read offset.
         for (pid, offset) in restored_offsets {
-            let pid = match pid {
-                PartitionId::Kafka(id) => id,
+            match (&pid, &topics) {
+                (PartitionId::Kafka(_), KafkaTopicSelection::Topic(_)) => {}
+                (PartitionId::KafkaTopic { .. }, KafkaTopicSelection::Topics(_))
+                | (PartitionId::KafkaTopic { .. }, KafkaTopicSelection::Pattern(_)) => {}
                 _ => panic!("unexpected partition id type"),
-            };
+            }
             if let Some(offset) = offset {
                 if let Some(start_offset) = start_offsets.get_mut(&pid) {
                     *start_offset = std::cmp::max(offset.offset - 1, *start_offset);
@@ -156,7 +162,7 @@ impl SourceReader for KafkaSourceReader {
         let partition_info = Arc::new(Mutex::new(None));
         let metadata_thread_handle = {
             let partition_info = Arc::downgrade(&partition_info);
-            let topic = topic.clone();
+            let topics = topics.clone();
             let consumer = Arc::clone(&consumer);
             let metadata_refresh_frequency = kafka_config
                 .get("topic.metadata.refresh.interval.ms")
@@ -170,7 +176,7 @@ impl SourceReader for KafkaSourceReader {
                 .name("kafka-metadata".to_string())
                 .spawn(move || {
                     while let Some(partition_info) = partition_info.upgrade() {
-                        match get_kafka_partitions(&consumer, &topic, Duration::from_secs(30)) {
+                        match get_kafka_partitions(&consumer, &topics, Duration::from_secs(30)) {
                             Ok(info) => {
                                 *partition_info.lock().unwrap() = Some(info);
                                 thread::park_timeout(metadata_refresh_frequency);
@@ -182,9 +188,13 @@ impl SourceReader for KafkaSourceReader {
                 .unwrap()
                 .unpark_on_drop()
         };
-        let partition_ids = start_offsets.keys().copied().collect();
+        let partition_ids = start_offsets
+            .keys()
+            .map(|pid| topic_partition(&topics, pid))
+            .map(|(topic, partition)| (topic.to_string(), partition))
+            .collect();
         Ok(KafkaSourceReader {
-            topic_name: topic.clone(),
+            topics,
             source_name,
             id: source_id,
             partition_consumers: VecDeque::new(),
@@ -197,7 +207,7 @@ impl SourceReader for KafkaSourceReader {
             partition_info,
             include_headers: kc.include_headers.is_some(),
             _metadata_thread_handle: metadata_thread_handle,
-            partition_metrics: KafkaPartitionMetrics::new(metrics, partition_ids, topic, source_id),
+            partition_metrics: KafkaPartitionMetrics::new(metrics, partition_ids, source_id),
         })
     }
 
@@ -216,8 +226,8 @@ impl SourceReader for KafkaSourceReader {
             // like. Shouldn't be a problem though, because we rarely hear about
             // new partitions.
             let mut unconsumed_partitions = Vec::new();
-            for pid in partitions {
-                let pid = PartitionId::Kafka(pid);
+            for (topic, partition) in partitions {
+                let pid = self.partition_id(&topic, partition);
                 if crate::source::responsible_for(&self.id, self.worker_id, self.worker_count, &pid)
                 {
                     self.add_partition(pid);
@@ -244,10 +254,12 @@ impl SourceReader for KafkaSourceReader {
             match result {
                 Err(e) => error!(
                     "kafka error when polling consumer for source: {} topic: {} : {}",
-                    self.source_name, self.topic_name, e
+                    self.source_name, self.topics, e
                 ),
                 Ok(message) => {
-                    let source_message = construct_source_message(&message, self.include_headers)?;
+                    let pid = self.partition_id(message.topic(), message.partition());
+                    let source_message =
+                        construct_source_message(&message, pid, self.include_headers)?;
                     next_message = self.handle_message(source_message);
                 }
             }
@@ -278,14 +290,26 @@ impl SourceReader for KafkaSourceReader {
 }
 
 impl KafkaSourceReader {
+    /// Returns the partition id that identifies `partition` of `topic`.
+    ///
+    /// Sources that read a single topic identify partitions by their number
+    /// alone, while sources that read several topics qualify it with the topic.
+    fn partition_id(&self, topic: &str, partition: i32) -> PartitionId {
+        match &self.topics {
+            KafkaTopicSelection::Topic(_) => PartitionId::Kafka(partition),
+            KafkaTopicSelection::Topics(_) | KafkaTopicSelection::Pattern(_) => {
+                PartitionId::KafkaTopic {
+                    topic: topic.to_string(),
+                    partition,
+                }
+            }
+        }
+    }
+
     /// Ensures that a partition queue for `pid` exists.
     /// In Kafka, partitions are assigned contiguously. This function consequently
     /// creates partition queues for every p <= pid
     fn add_partition(&mut self, pid: PartitionId) {
-        let pid = match pid {
-            PartitionId::Kafka(p) => p,
-            _ => unreachable!(),
-        };
         if self.last_offsets.contains_key(&pid) {
             return;
         }
@@ -296,7 +320,7 @@ impl KafkaSourceReader {
         };
 
         let start_offset: i64 = start_offset.try_into().expect("offset to be < i64::MAX");
-        self.create_partition_queue(pid, Offset::Offset(start_offset));
+        self.create_partition_queue(pid.clone(), Offset::Offset(start_offset));
 
         // Indicate a last offset of -1 if we have not been instructed to have a specific start
         // offset for this topic.
@@ -317,25 +341,27 @@ impl KafkaSourceReader {
         self.partition_consumers.len().try_into().unwrap()
     }
 
-    /// Creates a new partition queue for `partition_id`.
-    fn create_partition_queue(&mut self, partition_id: i32, initial_offset: Offset) {
+    /// Creates a new partition queue for `pid`.
+    fn create_partition_queue(&mut self, pid: PartitionId, initial_offset: Offset) {
+        let (topic, partition_id) = topic_partition(&self.topics, &pid);
+        let topic = topic.to_string();
         info!(
             "Activating Kafka queue for {} [{}] (source {}) on worker {}",
-            self.topic_name, partition_id, self.id, self.worker_id
+            topic, partition_id, self.id, self.worker_id
         );
 
         // Collect old partition assignments
         let tpl = self.consumer.assignment().unwrap();
         // Create list from assignments
         let mut partition_list = TopicPartitionList::new();
-        for partition in tpl.elements_for_topic(&self.topic_name) {
+        for partition in tpl.elements() {
             partition_list
                 .add_partition_offset(partition.topic(), partition.partition(), partition.offset())
                 .expect("offset known to be valid");
         }
         // Add new partition
         partition_list
-            .add_partition_offset(&self.topic_name, partition_id, initial_offset)
+            .add_partition_offset(&topic, partition_id, initial_offset)
             .expect("offset known to be valid");
         self.consumer
             .assign(&partition_list)
@@ -347,7 +373,7 @@ impl KafkaSourceReader {
         for pc in &mut self.partition_consumers {
             pc.partition_queue = self
                 .consumer
-                .split_partition_queue(&self.topic_name, pc.pid)
+                .split_partition_queue(&pc.topic, pc.partition)
                 .expect("partition known to be valid");
             pc.partition_queue.set_nonempty_callback({
                 let context = Arc::clone(&context);
@@ -357,20 +383,18 @@ impl KafkaSourceReader {
 
         let mut partition_queue = self
             .consumer
-            .split_partition_queue(&self.topic_name, partition_id)
+            .split_partition_queue(&topic, partition_id)
             .expect("partition known to be valid");
         partition_queue.set_nonempty_callback(move || context.activate());
         self.partition_consumers.push_front(PartitionConsumer::new(
+            pid,
+            topic,
             partition_id,
             partition_queue,
             self.include_headers,
         ));
         assert_eq!(
-            self.consumer
-                .assignment()
-                .unwrap()
-                .elements_for_topic(&self.topic_name)
-                .len(),
+            self.consumer.assignment().unwrap().count(),
             self.partition_consumers.len()
         );
     }
@@ -378,10 +402,11 @@ impl KafkaSourceReader {
     /// Fast-forward consumer to specified Kafka Offset. Prints a warning if failed to do so
     /// Assumption: if offset does not exist (for instance, because of compaction), will seek
     /// to the next available offset
-    fn fast_forward_consumer(&self, pid: i32, next_offset: i64) {
+    fn fast_forward_consumer(&self, pid: &PartitionId, next_offset: i64) {
+        let (topic, partition) = topic_partition(&self.topics, pid);
         let res = self.consumer.seek(
-            &self.topic_name,
-            pid,
+            topic,
+            partition,
             Offset::Offset(next_offset),
             Duration::from_secs(1),
         );
@@ -389,7 +414,7 @@ impl KafkaSourceReader {
             Ok(_) => {
                 let res = self.consumer.position().unwrap_or_default().to_topic_map();
                 let position = res
-                    .get(&(self.topic_name.clone(), pid))
+                    .get(&(topic.to_string(), partition))
                     .and_then(|p| match p {
                         Offset::Offset(o) => Some(o),
                         _ => None,
@@ -418,15 +443,19 @@ impl KafkaSourceReader {
         while let Ok(stats) = self.stats_rx.try_recv() {
             match serde_json::from_str::<Statistics>(&stats.to_string()) {
                 Ok(statistics) => {
-                    let topic = statistics.topics.get(&self.topic_name);
-                    match topic {
-                        Some(topic) => {
-                            for (id, partition) in &topic.partitions {
-                                self.partition_metrics
-                                    .set_offset_max(*id, partition.hi_offset);
-                            }
+                    if let KafkaTopicSelection::Topic(topic) = &self.topics {
+                        if !statistics.topics.contains_key(topic) {
+                            error!("No stats found for topic: {}", topic);
+                        }
+                    }
+                    for (name, topic) in &statistics.topics {
+                        if !self.topics.contains(name) {
+                            continue;
+                        }
+                        for (id, partition) in &topic.partitions {
+                            self.partition_metrics
+                                .set_offset_max(name, *id, partition.hi_offset);
                         }
-                        None => error!("No stats found for topic: {}", &self.topic_name),
                     }
                 }
                 Err(e) => {
@@ -448,17 +477,16 @@ impl KafkaSourceReader {
 
         let message = match partition_queue.get_next_message()? {
             Err(e) => {
-                let pid = partition_queue.pid();
                 let last_offset = self
                     .last_offsets
-                    .get(&pid)
+                    .get(partition_queue.pid())
                     .expect("partition known to be installed");
 
                 error!(
                         "kafka error consuming from source: {} topic: {}: partition: {} last processed offset: {} : {}",
                         self.source_name,
-                        self.topic_name,
-                        pid,
+                        partition_queue.topic,
+                        partition_queue.partition,
                         last_offset.offset,
                         e
                     );
@@ -478,10 +506,7 @@ impl KafkaSourceReader {
         &mut self,
         message: SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>, ()>,
     ) -> NextMessage<Option<Vec<u8>>, Option<Vec<u8>>, ()> {
-        let partition = match message.partition {
-            PartitionId::Kafka(pid) => pid,
-            _ => unreachable!(),
-        };
+        let partition = &message.partition;
 
         // Convert the received offset back from a 1-indexed MzOffset to the correct offset.
         let offset = message.offset.offset - 1;
@@ -496,23 +521,24 @@ impl KafkaSourceReader {
 
         // Given the explicit consumer to partition assignment, we should never receive a message
         // for a partition for which we have no metadata
-        assert!(self.last_offsets.contains_key(&partition));
+        assert!(self.last_offsets.contains_key(partition));
 
         let last_offset_ref = self
             .last_offsets
-            .get_mut(&partition)
+            .get_mut(partition)
             .expect("partition known to be installed");
 
         let last_offset = *last_offset_ref;
         let offset_as_i64: i64 = offset.try_into().expect("offset to be < i64::MAX");
         if offset_as_i64 <= last_offset.offset {
+            let (topic, partition_id) = topic_partition(&self.topics, partition);
             info!(
                 "Kafka message before expected offset, skipping: \
                              source {} (reading topic {}, partition {}) \
                              received offset {} expected offset {:?}",
                 self.source_name,
-                self.topic_name,
-                partition,
+                topic,
+                partition_id,
                 offset,
                 last_offset.offset + 1,
             );
@@ -546,6 +572,11 @@ async fn create_kafka_config(
 ) -> ClientConfig {
     let mut kafka_config = create_new_client_config(connection_context.librdkafka_log_level);
 
+    // Default to disabling Kafka auto commit. This can be explicitly enabled
+    // by the user if they want to use it for progress tracking, so it must be
+    // set before the user's options are applied.
+    kafka_config.set("enable.auto.commit", "false");
+
     crate::types::connections::populate_client_config(
         kafka_connection.clone(),
         options,
@@ -555,10 +586,6 @@ async fn create_kafka_config(
     )
     .await;
 
-    // Default to disabling Kafka auto commit. This can be explicitly enabled
-    // by the user if they want to use it for progress tracking.
-    kafka_config.set("enable.auto.commit", "false");
-
     // Always begin ingest at 0 when restarted, even if Kafka contains committed
     // consumer read offsets
     kafka_config.set("auto.offset.reset", "earliest");
@@ -602,6 +629,7 @@ async fn create_kafka_config(
 
 fn construct_source_message(
     msg: &BorrowedMessage<'_>,
+    partition: PartitionId,
     include_headers: bool,
 ) -> Result<SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>, ()>, anyhow::Error> {
     let kafka_offset = KafkaOffset {
@@ -617,7 +645,7 @@ fn construct_source_message(
         _ => None,
     };
     Ok(SourceMessage {
-        partition: PartitionId::Kafka(msg.partition()),
+        partition,
         offset: kafka_offset.try_into().map_err(|_| {
             anyhow::anyhow!(
                 "got negative offset ({}) from otherwise non-error'd kafka message",
@@ -635,7 +663,11 @@ fn construct_source_message(
 /// Wrapper around a partition containing the underlying consumer
 struct PartitionConsumer {
     /// the partition id with which this consumer is associated
-    pid: i32,
+    pid: PartitionId,
+    /// the topic of the partition
+    topic: String,
+    /// the number of the partition within its topic
+    partition: i32,
     /// The underlying Kafka partition queue
     partition_queue: PartitionQueue<GlueConsumerContext>,
     /// Whether or not to unpack and allocate headers and pass them through in the `SourceMessage`
@@ -645,12 +677,16 @@ struct PartitionConsumer {
 impl PartitionConsumer {
     /// Creates a new partition consumer from underlying Kafka consumer
     fn new(
-        pid: i32,
+        pid: PartitionId,
+        topic: String,
+        partition: i32,
         partition_queue: PartitionQueue<GlueConsumerContext>,
         include_headers: bool,
     ) -> Self {
         PartitionConsumer {
             pid,
+            topic,
+            partition,
             partition_queue,
             include_headers,
         }
@@ -670,8 +706,12 @@ impl PartitionConsumer {
     > {
         match self.partition_queue.poll(Duration::from_millis(0)) {
             Some(Ok(msg)) => {
-                let result = construct_source_message(&msg, self.include_headers)?;
-                assert_eq!(result.partition, PartitionId::Kafka(self.pid));
+                assert_eq!(
+                    (msg.topic(), msg.partition()),
+                    (self.topic.as_str(), self.partition)
+                );
+                let result =
+                    construct_source_message(&msg, self.pid.clone(), self.include_headers)?;
                 Ok(Ok(Some(result)))
             }
             Some(Err(err)) => Ok(Err(err)),
@@ -680,8 +720,8 @@ impl PartitionConsumer {
     }
 
     /// Return the partition id for this PartitionConsumer
-    fn pid(&self) -> i32 {
-        self.pid
+    fn pid(&self) -> &PartitionId {
+        &self.pid
     }
 }
 
@@ -725,18 +765,51 @@ impl GlueConsumerContext {
 
 impl ConsumerContext for GlueConsumerContext {}
 
-/// Return the list of partition ids associated with a specific topic
+/// Returns the topic and partition number that `pid` identifies.
+fn topic_partition<'a>(topics: &'a KafkaTopicSelection, pid: &'a PartitionId) -> (&'a str, i32) {
+    match (pid, topics) {
+        (PartitionId::Kafka(partition), KafkaTopicSelection::Topic(topic)) => (topic, *partition),
+        (PartitionId::KafkaTopic { topic, partition }, _) => (topic, *partition),
+        _ => unreachable!("unexpected partition id type"),
+    }
+}
+
+/// Return the list of (topic, partition) pairs associated with the selected
+/// topics
 fn get_kafka_partitions(
     consumer: &BaseConsumer<GlueConsumerContext>,
-    topic: &str,
+    topics: &KafkaTopicSelection,
     timeout: Duration,
-) -> Result<Vec<i32>, anyhow::Error> {
-    let metadata = consumer.fetch_metadata(Some(topic), timeout)?;
-    Ok(metadata.topics()[0]
-        .partitions()
-        .iter()
-        .map(|x| x.id())
-        .collect())
+) -> Result<Vec<(String, i32)>, anyhow::Error> {
+    let mut partitions = vec![];
+    let mut add_partitions = |topic: &MetadataTopic| {
+        for partition in topic.partitions() {
+            partitions.push((topic.name().to_string(), partition.id()));
+        }
+    };
+    match topics {
+        KafkaTopicSelection::Topic(topic) => {
+            let metadata = consumer.fetch_metadata(Some(topic), timeout)?;
+            add_partitions(&metadata.topics()[0]);
+        }
+        KafkaTopicSelection::Topics(topics) => {
+            for topic in topics {
+                let metadata = consumer.fetch_metadata(Some(topic), timeout)?;
+                add_partitions(&metadata.topics()[0]);
+            }
+        }
+        KafkaTopicSelection::Pattern(regex) => {
+            // Patterns can match topics that are created after the source, so
+            // every refresh looks at the metadata of the whole cluster.
+            let metadata = consumer.fetch_metadata(None, timeout)?;
+            for topic in metadata.topics() {
+                if regex.is_match(topic.name()) {
+                    add_partitions(topic);
+                }
+            }
+        }
+    }
+    Ok(partitions)
 }
 
 #[cfg(test)]
@@ -38,6 +38,7 @@ use crate::source::types::SourceReaderError;
 use crate::source::types::{NextMessage, SourceMessage, SourceReader};
 
 mod delimited_value_reader;
+mod file;
 pub mod generator;
 mod healthcheck;
 mod kafka;
@@ -45,6 +46,7 @@ mod kinesis;
 pub mod metrics;
 pub mod persist_source;
 mod postgres;
+mod pubsub;
 mod reclock;
 mod s3;
 mod source_reader_pipeline;
@@ -52,10 +54,12 @@ pub mod types;
 pub mod util;
 
 pub use delimited_value_reader::DelimitedValueSource;
+pub use file::FileSourceReader;
 pub use generator::LoadGeneratorSourceReader;
 pub use kafka::KafkaSourceReader;
 pub use kinesis::KinesisSourceReader;
 pub use postgres::PostgresSourceReader;
+pub use pubsub::PubSubSourceReader;
 pub use s3::S3SourceReader;
 pub use source_reader_pipeline::create_raw_source;
 pub use source_reader_pipeline::RawSourceCreationConfig;
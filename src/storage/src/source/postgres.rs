@@ -9,6 +9,7 @@
 
 use std::collections::HashMap;
 use std::error::Error;
+use std::pin::Pin;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, bail};
@@ -27,6 +28,7 @@ use tokio_postgres::SimpleQueryMessage;
 use tracing::{error, info, warn};
 
 use mz_expr::PartitionId;
+use mz_ore::retry::{Retry, RetryStream};
 use mz_ore::task;
 use mz_postgres_util::desc::PostgresTableDesc;
 use mz_repr::{Datum, Diff, GlobalId, Row};
@@ -358,7 +360,9 @@ async fn postgres_replication_loop_inner(
         }
     }
 
+    let mut retry = replication_retry();
     loop {
+        let lsn = task_info.lsn;
         match task_info.produce_replication().await {
             Err(ReplicationError::Recoverable(e)) => {
                 warn!(
@@ -368,7 +372,7 @@ async fn postgres_replication_loop_inner(
             }
             Err(ReplicationError::Fatal(e)) => {
                 return Err(SourceReaderError {
-                    inner: SourceErrorDetails::FileIO(e.to_string()),
+                    inner: SourceErrorDetails::Other(e.to_string()),
                 })
             }
             Ok(_) => {
@@ -377,12 +381,32 @@ async fn postgres_replication_loop_inner(
             }
         }
 
-        // TODO(petrosagg): implement exponential back-off
-        tokio::time::sleep(Duration::from_secs(3)).await;
+        // Only back off further if the upstream keeps failing without letting
+        // us make any progress.
+        if task_info.lsn != lsn {
+            retry = replication_retry();
+        }
+        // Don't keep a dropped source alive for the remainder of the backoff.
+        let closer = task_info.sender.clone();
+        tokio::select! {
+            _ = closer.closed() => return Ok(()),
+            _ = retry.next() => {}
+        }
         info!("resuming replication for source {}", task_info.source_id);
     }
 }
 
+/// The schedule on which an interrupted replication stream is restarted. The
+/// first restart is immediate; later ones back off exponentially.
+fn replication_retry() -> Pin<Box<RetryStream>> {
+    Box::pin(
+        Retry::default()
+            .initial_backoff(Duration::from_secs(1))
+            .clamp_backoff(Duration::from_secs(60))
+            .into_retry_stream(),
+    )
+}
+
 /// A type that makes it easy to correctly send inserts and deletes.
 ///
 /// Note: `RowSender::delete/insert` should be called with the same
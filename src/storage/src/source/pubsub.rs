@@ -0,0 +1,543 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A source that pulls messages from a Google Cloud Pub/Sub subscription.
+//!
+//! Messages are pulled and acknowledged with the Pub/Sub REST API. Pub/Sub
+//! forgets a message once it is acknowledged, so a message is only
+//! acknowledged once the ingestion framework reports that it has been durably
+//! recorded (see [`SourceReader::durable_frontier_sender`]). Until then, the
+//! reader keeps extending the message's acknowledgement deadline so that
+//! Pub/Sub does not redeliver it.
+//!
+//! When a source restarts, Pub/Sub redelivers the messages that had not been
+//! acknowledged once their deadline expires. Messages are therefore delivered
+//! at least once: a message that was recorded but whose acknowledgement had not
+//! been sent yet is ingested again.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::DateTime;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use timely::scheduling::SyncActivator;
+use tokio::runtime::Handle as TokioHandle;
+use tokio::sync::watch;
+use tracing::warn;
+
+use mz_expr::PartitionId;
+use mz_repr::GlobalId;
+
+use crate::source::{SourceMessage, SourceMessageType, SourceReader, SourceReaderError};
+use crate::types::connections::{ConnectionContext, GcpConnection};
+use crate::types::errors::SourceErrorDetails;
+use crate::types::sources::encoding::SourceDataEncoding;
+use crate::types::sources::{MzOffset, PubSubSourceConnection, SourceConnection};
+
+use super::metrics::SourceBaseMetrics;
+
+/// The endpoint of the Pub/Sub API in Google Cloud.
+const DEFAULT_ENDPOINT: &str = "https://pubsub.googleapis.com";
+
+/// The audience of the self-signed JWTs that authenticate requests to the
+/// Pub/Sub API.
+const PUBSUB_AUDIENCE: &str = "https://pubsub.googleapis.com/";
+
+/// How long each self-signed JWT is valid for. Google rejects tokens that are
+/// valid for more than an hour.
+const TOKEN_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// How long before its expiration a token is replaced.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum number of messages to request with each pull.
+const MAX_MESSAGES_PER_PULL: u32 = 1000;
+
+/// The maximum number of acknowledgement IDs to send with each request, which
+/// keeps requests well below Pub/Sub's request size limit.
+const MAX_ACK_IDS_PER_REQUEST: usize = 1000;
+
+/// The maximum number of messages that may have been emitted but not yet
+/// acknowledged. Once reached, the reader stops pulling until emitted messages
+/// have become durable.
+const MAX_PENDING_ACKS: usize = 10 * MAX_MESSAGES_PER_PULL as usize;
+
+/// How long to wait for a response to a request. Pulls wait on the server
+/// until messages are available, so this bounds how long a broken connection
+/// can go unnoticed.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The acknowledgement deadline that is requested for messages that have not
+/// yet been acknowledged.
+const ACK_DEADLINE: Duration = Duration::from_secs(120);
+
+/// How often the acknowledgement deadlines of messages that have not yet been
+/// acknowledged are extended. Deadlines are only extended between requests,
+/// so this plus [`REQUEST_TIMEOUT`] must stay well below [`ACK_DEADLINE`].
+const ACK_DEADLINE_EXTENSION_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Information required to pull messages from a Pub/Sub subscription.
+pub struct PubSubSourceReader {
+    /// The HTTP client used to make API requests.
+    client: reqwest::Client,
+    /// The URL of the subscription's `pull` method.
+    pull_url: String,
+    /// The URL of the subscription's `acknowledge` method.
+    acknowledge_url: String,
+    /// The URL of the subscription's `modifyAckDeadline` method.
+    modify_ack_deadline_url: String,
+    /// The signer of the tokens that authenticate requests, if the connection
+    /// specified credentials.
+    signer: Option<TokenSigner>,
+    /// The messages of the last pull that have not yet been emitted.
+    buffered_messages: VecDeque<SourceMessage<Option<Vec<u8>>, Option<Vec<u8>>, ()>>,
+    /// The offsets and acknowledgement IDs of the pulled messages that have not
+    /// yet been acknowledged, in offset order.
+    pending_acks: VecDeque<(MzOffset, String)>,
+    /// The frontier of the offsets that have been durably recorded.
+    durable_frontier: watch::Receiver<HashMap<PartitionId, MzOffset>>,
+    /// The sender for `durable_frontier`, until it is handed to the ingestion
+    /// framework.
+    durable_frontier_sender: Option<watch::Sender<HashMap<PartitionId, MzOffset>>>,
+    /// When the acknowledgement deadlines of `pending_acks` were last extended.
+    last_deadline_extension: Instant,
+    /// The offset of the last message this source has emitted. Pub/Sub has no
+    /// notion of offsets, so messages are simply numbered in the order in which
+    /// they are received.
+    offset: MzOffset,
+    /// Whether this reader has hit an error from which it cannot recover.
+    failed: bool,
+    // Pub/Sub sources support single-threaded ingestion only, so only one of
+    // the `PubSubSourceReader`s will actually produce data.
+    active_read_worker: bool,
+    // The non-active reader (see above `active_read_worker`) has to report back
+    // that is is not consuming from the one [`PartitionId:None`] partition.
+    // Before it can return a [`NextMessage::Finished`]. This is keeping track
+    // of that.
+    reported_unconsumed_partitions: bool,
+}
+
+/// The fields of a service account key that are needed to sign tokens.
+#[derive(Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key_id: String,
+    private_key: String,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+/// Signs the JWTs with which a service account authenticates to Google Cloud
+/// APIs, caching each token until shortly before it expires.
+struct TokenSigner {
+    client_email: String,
+    header: Header,
+    key: EncodingKey,
+    /// The current token and the time, in seconds since the Unix epoch, at
+    /// which it expires.
+    token: Option<(String, u64)>,
+}
+
+impl TokenSigner {
+    fn new(key: &str) -> Result<Self, anyhow::Error> {
+        let key: ServiceAccountKey =
+            serde_json::from_str(key).context("parsing service account key")?;
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(key.private_key_id);
+        Ok(TokenSigner {
+            client_email: key.client_email,
+            header,
+            key: EncodingKey::from_rsa_pem(key.private_key.as_bytes())
+                .context("parsing service account private key")?,
+            token: None,
+        })
+    }
+
+    fn token(&mut self) -> Result<&str, anyhow::Error> {
+        let now = jsonwebtoken::get_current_timestamp();
+        let fresh = matches!(
+            self.token,
+            Some((_, exp)) if now + TOKEN_REFRESH_MARGIN.as_secs() < exp
+        );
+        if !fresh {
+            let exp = now + TOKEN_LIFETIME.as_secs();
+            let claims = Claims {
+                iss: &self.client_email,
+                sub: &self.client_email,
+                aud: PUBSUB_AUDIENCE,
+                iat: now,
+                exp,
+            };
+            let token = jsonwebtoken::encode(&self.header, &claims, &self.key)
+                .context("signing access token")?;
+            self.token = Some((token, exp));
+        }
+        Ok(&self.token.as_ref().expect("token set above").0)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequest {
+    max_messages: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullResponse {
+    #[serde(default)]
+    received_messages: Vec<ReceivedMessage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReceivedMessage {
+    ack_id: String,
+    message: PubsubMessage,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PubsubMessage {
+    /// The base64-encoded message payload.
+    #[serde(default)]
+    data: String,
+    /// The time at which the message was published, in RFC 3339 format.
+    publish_time: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AcknowledgeRequest<'a> {
+    ack_ids: &'a [String],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ModifyAckDeadlineRequest<'a> {
+    ack_ids: &'a [String],
+    ack_deadline_seconds: u64,
+}
+
+/// An error from a request to the Pub/Sub API.
+enum RequestError {
+    /// The request may succeed if retried.
+    Transient(anyhow::Error),
+    /// The request will never succeed.
+    Fatal(anyhow::Error),
+}
+
+impl From<reqwest::Error> for RequestError {
+    fn from(e: reqwest::Error) -> RequestError {
+        let transient = match e.status() {
+            Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+            // Errors connecting to or hearing back from the server.
+            None => !e.is_decode() && !e.is_builder(),
+        };
+        if transient {
+            RequestError::Transient(e.into())
+        } else {
+            RequestError::Fatal(e.into())
+        }
+    }
+}
+
+/// Sends a request to the Pub/Sub API and returns the decoded response.
+async fn post<Req, Resp>(
+    client: &reqwest::Client,
+    signer: Option<&mut TokenSigner>,
+    url: &str,
+    body: &Req,
+) -> Result<Resp, RequestError>
+where
+    Req: Serialize,
+    Resp: DeserializeOwned,
+{
+    let mut request = client.post(url).json(body);
+    if let Some(signer) = signer {
+        request = request.bearer_auth(signer.token().map_err(RequestError::Fatal)?);
+    }
+    Ok(request.send().await?.error_for_status()?.json().await?)
+}
+
+impl PubSubSourceReader {
+    /// Pulls the next batch of messages from the subscription into the
+    /// buffer, returning the number of messages pulled.
+    async fn pull(&mut self) -> Result<usize, RequestError> {
+        let response: PullResponse = post(
+            &self.client,
+            self.signer.as_mut(),
+            &self.pull_url,
+            &PullRequest {
+                max_messages: MAX_MESSAGES_PER_PULL,
+            },
+        )
+        .await?;
+        let count = response.received_messages.len();
+        let mut ack_ids = Vec::with_capacity(count);
+        for ReceivedMessage { ack_id, message } in response.received_messages {
+            let data = base64::decode(&message.data)
+                .context("decoding message data")
+                .map_err(RequestError::Fatal)?;
+            let upstream_time_millis = message
+                .publish_time
+                .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                .map(|t| t.timestamp_millis());
+            self.offset += 1;
+            self.buffered_messages.push_back(SourceMessage {
+                partition: PartitionId::None,
+                offset: self.offset,
+                upstream_time_millis,
+                key: None,
+                value: Some(data),
+                headers: None,
+                specific_diff: (),
+            });
+            self.pending_acks.push_back((self.offset, ack_id.clone()));
+            ack_ids.push(ack_id);
+        }
+        // The subscription's own deadline might be too short for the messages
+        // to become durable.
+        self.extend_ack_deadlines(&ack_ids).await?;
+        Ok(count)
+    }
+
+    /// Acknowledges the pulled messages that have been durably recorded, so
+    /// that Pub/Sub does not redeliver them, and periodically extends the
+    /// deadlines of the ones that have not.
+    async fn maintain_acks(&mut self) -> Result<(), RequestError> {
+        let durable = self
+            .durable_frontier
+            .borrow()
+            .get(&PartitionId::None)
+            .copied()
+            .unwrap_or_default();
+        let durable_count = self
+            .pending_acks
+            .iter()
+            .take_while(|(offset, _)| *offset < durable)
+            .count();
+        if durable_count > 0 {
+            let ack_ids: Vec<_> = self
+                .pending_acks
+                .iter()
+                .take(durable_count)
+                .map(|(_, ack_id)| ack_id.clone())
+                .collect();
+            for ack_ids in ack_ids.chunks(MAX_ACK_IDS_PER_REQUEST) {
+                let _: serde_json::Value = post(
+                    &self.client,
+                    self.signer.as_mut(),
+                    &self.acknowledge_url,
+                    &AcknowledgeRequest { ack_ids },
+                )
+                .await?;
+            }
+            self.pending_acks.drain(..durable_count);
+        }
+
+        if !self.pending_acks.is_empty()
+            && self.last_deadline_extension.elapsed() >= ACK_DEADLINE_EXTENSION_INTERVAL
+        {
+            let ack_ids: Vec<_> = self
+                .pending_acks
+                .iter()
+                .map(|(_, ack_id)| ack_id.clone())
+                .collect();
+            self.extend_ack_deadlines(&ack_ids).await?;
+            self.last_deadline_extension = Instant::now();
+        }
+        Ok(())
+    }
+
+    /// Extends the acknowledgement deadlines of the given messages to
+    /// [`ACK_DEADLINE`] from now.
+    async fn extend_ack_deadlines(&mut self, ack_ids: &[String]) -> Result<(), RequestError> {
+        for ack_ids in ack_ids.chunks(MAX_ACK_IDS_PER_REQUEST) {
+            let _: serde_json::Value = post(
+                &self.client,
+                self.signer.as_mut(),
+                &self.modify_ack_deadline_url,
+                &ModifyAckDeadlineRequest {
+                    ack_ids,
+                    ack_deadline_seconds: ACK_DEADLINE.as_secs(),
+                },
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl SourceReader for PubSubSourceReader {
+    type Key = Option<Vec<u8>>;
+    type Value = Option<Vec<u8>>;
+    type Diff = ();
+
+    fn new(
+        _source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        _consumer_activator: SyncActivator,
+        connection: SourceConnection,
+        start_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        _metrics: SourceBaseMetrics,
+        connection_context: ConnectionContext,
+    ) -> Result<Self, anyhow::Error> {
+        let PubSubSourceConnection {
+            connection:
+                GcpConnection {
+                    credentials,
+                    endpoint,
+                },
+            subscription,
+        } = match connection {
+            SourceConnection::PubSub(pubsub) => pubsub,
+            _ => {
+                panic!("PubSub is the only legitimate SourceConnection for PubSubSourceReader")
+            }
+        };
+
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        let signer = match credentials {
+            Some(id) if active_read_worker => {
+                let key = TokioHandle::current()
+                    .block_on(connection_context.secrets_reader.read_string(id))?;
+                Some(TokenSigner::new(&key)?)
+            }
+            _ => None,
+        };
+
+        let endpoint = endpoint.map(|url| url.to_string());
+        let endpoint = endpoint
+            .as_deref()
+            .unwrap_or(DEFAULT_ENDPOINT)
+            .trim_end_matches('/');
+
+        // The restored offset is one past the offset of the last message that
+        // was emitted.
+        let offset = start_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .map(|offset| MzOffset {
+                offset: offset.offset.saturating_sub(1),
+            })
+            .unwrap_or_default();
+
+        let (durable_frontier_sender, durable_frontier) = watch::channel(HashMap::new());
+
+        Ok(PubSubSourceReader {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()?,
+            pull_url: format!("{}/v1/{}:pull", endpoint, subscription),
+            acknowledge_url: format!("{}/v1/{}:acknowledge", endpoint, subscription),
+            modify_ack_deadline_url: format!("{}/v1/{}:modifyAckDeadline", endpoint, subscription),
+            signer,
+            buffered_messages: VecDeque::new(),
+            pending_acks: VecDeque::new(),
+            durable_frontier,
+            durable_frontier_sender: Some(durable_frontier_sender),
+            last_deadline_extension: Instant::now(),
+            offset,
+            failed: false,
+            active_read_worker,
+            reported_unconsumed_partitions: false,
+        })
+    }
+
+    fn durable_frontier_sender(&mut self) -> Option<watch::Sender<HashMap<PartitionId, MzOffset>>> {
+        if self.active_read_worker {
+            self.durable_frontier_sender.take()
+        } else {
+            None
+        }
+    }
+
+    async fn next(
+        &mut self,
+        timestamp_granularity: Duration,
+    ) -> Option<Result<SourceMessageType<Self::Key, Self::Value, Self::Diff>, SourceReaderError>>
+    {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return Some(Ok(SourceMessageType::DropPartitionCapabilities(vec![
+                    PartitionId::None,
+                ])));
+            }
+            return None;
+        }
+
+        if self.failed {
+            return None;
+        }
+
+        loop {
+            if let Some(message) = self.buffered_messages.pop_front() {
+                return Some(Ok(SourceMessageType::Finalized(message)));
+            }
+
+            let result = match self.maintain_acks().await {
+                // Too many emitted messages are waiting to become durable;
+                // check again after a delay.
+                Ok(()) if self.pending_acks.len() >= MAX_PENDING_ACKS => {
+                    tokio::time::sleep(timestamp_granularity).await;
+                    continue;
+                }
+                Ok(()) => match self.pull().await {
+                    // There were no messages available; check again after a
+                    // delay.
+                    Ok(0) => {
+                        tokio::time::sleep(timestamp_granularity).await;
+                        continue;
+                    }
+                    result => result.map(|_| ()),
+                },
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => (),
+                Err(RequestError::Transient(e)) => {
+                    warn!("transient error communicating with Pub/Sub: {:#}", e);
+                    tokio::time::sleep(timestamp_granularity).await;
+                }
+                Err(RequestError::Fatal(e)) => {
+                    self.failed = true;
+                    return Some(Err(SourceReaderError {
+                        inner: SourceErrorDetails::Other(format!("{:#}", e)),
+                    }));
+                }
+            }
+        }
+    }
+}
@@ -409,6 +409,22 @@ impl ReclockOperator {
         source_upper
     }
 
+    /// Calculates the source upper frontier of the messages that are durable in a collection
+    /// reclocked with the bindings of this operator, given the upper of that collection.
+    ///
+    /// Returns `None` if no message is durable yet or if the collection is closed.
+    pub async fn durable_source_upper(
+        &mut self,
+        data_upper: &Antichain<Timestamp>,
+    ) -> Option<HashMap<PartitionId, MzOffset>> {
+        // A collection is never reclocked beyond the bindings in the remap shard, but be
+        // defensive and don't wait for bindings that might never be minted.
+        let remap_upper = self.write_handle.fetch_recent_upper().await;
+        let upper = std::cmp::min(*data_upper.as_option()?, *remap_upper.as_option()?);
+        self.sync(&Antichain::from_elem(upper)).await;
+        Some(self.source_upper_at(upper.checked_sub(1)?))
+    }
+
     /// Syncs the state of this operator to match that of the persist shard until the provided
     /// frontier
     async fn sync(
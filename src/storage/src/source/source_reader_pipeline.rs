@@ -51,7 +51,7 @@ use tracing::trace;
 use mz_expr::PartitionId;
 use mz_ore::now::NowFn;
 use mz_persist_client::cache::PersistClientCache;
-use mz_repr::{GlobalId, Timestamp};
+use mz_repr::{Diff, GlobalId, Timestamp};
 use mz_timely_util::operator::StreamExt as _;
 
 use crate::controller::CollectionMetadata;
@@ -66,7 +66,7 @@ use crate::source::util::source;
 use crate::types::connections::ConnectionContext;
 use crate::types::errors::SourceError;
 use crate::types::sources::encoding::SourceDataEncoding;
-use crate::types::sources::{MzOffset, SourceConnection};
+use crate::types::sources::{MzOffset, SourceConnection, SourceData};
 
 // Interval after which the source operator will yield control.
 const YIELD_INTERVAL: Duration = Duration::from_millis(10);
@@ -221,7 +221,7 @@ where
         let mut source_reader = Box::pin(async_stream::stream!({
             let upper_ts = resume_upper.as_option().copied().unwrap();
             let as_of = Antichain::from_elem(upper_ts.saturating_sub(1));
-            let timestamper = match ReclockOperator::new(
+            let mut timestamper = match ReclockOperator::new(
                 Arc::clone(&persist_clients),
                 storage_metadata.clone(),
                 now.clone(),
@@ -282,7 +282,7 @@ where
                 start_offsets.push((pid.clone(), Some(offset.clone())));
             }
 
-            let source_reader = S::new(
+            let mut source_reader = S::new(
                 name.clone(),
                 id,
                 worker_id,
@@ -293,12 +293,31 @@ where
                 encoding,
                 base_metrics,
                 connection_context.clone(),
-            );
+            )
+            .expect("Failed to create source");
+
+            // If the reader wants to know which messages are durable, watch the
+            // upper of the output collection.
+            let mut durable_frontier = match source_reader.durable_frontier_sender() {
+                Some(sender) => {
+                    let persist_client = persist_clients
+                        .lock()
+                        .await
+                        .open(storage_metadata.persist_location.clone())
+                        .await
+                        .expect("error creating persist client");
+                    let data_write = persist_client
+                        .open_writer::<SourceData, (), Timestamp, Diff>(storage_metadata.data_shard)
+                        .await
+                        .expect("error opening persist shard");
+                    Some((sender, data_write))
+                }
+                None => None,
+            };
+            let mut durability_interval = tokio::time::interval(timestamp_interval);
+            durability_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-            let source_stream = source_reader
-                .expect("Failed to create source")
-                .into_stream(timestamp_interval)
-                .fuse();
+            let source_stream = source_reader.into_stream(timestamp_interval).fuse();
 
             tokio::pin!(source_stream);
 
@@ -371,6 +390,17 @@ where
                             },
                         }
                     }
+                    // It's time to tell the reader which messages are durable
+                    _ = durability_interval.tick(), if durable_frontier.is_some() => {
+                        let (sender, data_write) = durable_frontier.as_mut().unwrap();
+                        let data_upper = data_write.fetch_recent_upper().await.clone();
+                        if let Some(frontier) = timestamper.durable_source_upper(&data_upper).await {
+                            trace!("source_reader({id}) {worker_id}/{worker_count}: \
+                                  durable frontier: {frontier:?}");
+                            // The reader might have finished already.
+                            let _ = sender.send(frontier);
+                        }
+                    }
                     // It's time to emit a batch of messages
                     _ = emission_interval.tick() => {
 
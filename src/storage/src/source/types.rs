@@ -27,6 +27,7 @@ use timely::dataflow::channels::pact::{Exchange, ParallelizationContract};
 use timely::scheduling::activate::SyncActivator;
 use timely::scheduling::ActivateOnDrop;
 use timely::Data;
+use tokio::sync::watch;
 
 use mz_avro::types::Value;
 use mz_expr::PartitionId;
@@ -132,6 +133,19 @@ pub trait SourceReader {
         Ok(NextMessage::Pending)
     }
 
+    /// Returns a sender through which the ingestion framework informs the reader
+    /// of the frontier of offsets that have been durably recorded, or `None` if
+    /// the reader does not need to know.
+    ///
+    /// Every message at an offset below the frontier sent for its partition has
+    /// been written to the source's output collection, so a restarted source
+    /// will not ask for it again. Readers whose upstream system forgets messages
+    /// once they are acknowledged use this to delay the acknowledgement until
+    /// the messages can no longer be lost.
+    fn durable_frontier_sender(&mut self) -> Option<watch::Sender<HashMap<PartitionId, MzOffset>>> {
+        None
+    }
+
     /// Returns an adapter that treats the source as a stream.
     ///
     /// The stream produces the messages that would be produced by repeated calls to `next`.
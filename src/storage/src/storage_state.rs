@@ -10,6 +10,7 @@ use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::TryRecvError;
 use mz_persist_client::cache::PersistClientCache;
@@ -24,8 +25,8 @@ use mz_ore::now::NowFn;
 use mz_repr::{GlobalId, Timestamp};
 
 use crate::controller::CollectionMetadata;
-use crate::protocol::client::{StorageCommand, StorageResponse};
-use crate::sink::SinkBaseMetrics;
+use crate::protocol::client::{SinkStatistics, StorageCommand, StorageResponse};
+use crate::sink::{SinkBaseMetrics, SinkStatisticsCounters};
 use crate::types::connections::ConnectionContext;
 use crate::types::sinks::StorageSinkDesc;
 use crate::types::sources::IngestionDescription;
@@ -36,6 +37,9 @@ use crate::source::metrics::SourceBaseMetrics;
 type CommandReceiver = crossbeam_channel::Receiver<StorageCommand>;
 type ResponseSender = mpsc::UnboundedSender<StorageResponse>;
 
+/// How often to report the statistics of sinks that have changed.
+const SINK_STATISTICS_INTERVAL: Duration = Duration::from_secs(1);
+
 /// State maintained for each worker thread.
 ///
 /// Much of this state can be viewed as local variables for the worker thread,
@@ -90,6 +94,12 @@ pub struct StorageState {
     /// Frontier of sink writes (all subsequent writes will be at times at or
     /// equal to this frontier)
     pub sink_write_frontiers: HashMap<GlobalId, Rc<RefCell<Antichain<Timestamp>>>>,
+    /// Counters of the data written by each sink on this worker.
+    pub sink_statistics: HashMap<GlobalId, Arc<SinkStatisticsCounters>>,
+    /// Tracks the sink statistics we have reported.
+    pub reported_sink_statistics: HashMap<GlobalId, SinkStatistics>,
+    /// When sink statistics were last reported.
+    pub sink_statistics_reported_at: Instant,
 }
 
 /// A token that keeps a sink alive.
@@ -137,6 +147,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
             }
 
             self.report_frontier_progress(&response_tx);
+            self.report_sink_statistics(&response_tx);
 
             // Handle any received commands.
             let mut cmds = vec![];
@@ -226,6 +237,8 @@ impl<'w, A: Allocate> Worker<'w, A> {
                         self.storage_state.reported_frontiers.remove(&id);
                         self.storage_state.source_tokens.remove(&id);
                         self.storage_state.sink_tokens.remove(&id);
+                        self.storage_state.sink_statistics.remove(&id);
+                        self.storage_state.reported_sink_statistics.remove(&id);
                     }
                 }
             }
@@ -273,6 +286,33 @@ impl<'w, A: Allocate> Worker<'w, A> {
         }
     }
 
+    /// Emit the statistics of sinks that changed since they were last reported, at most once
+    /// per [`SINK_STATISTICS_INTERVAL`].
+    pub fn report_sink_statistics(&mut self, response_tx: &ResponseSender) {
+        if self.storage_state.sink_statistics_reported_at.elapsed() < SINK_STATISTICS_INTERVAL {
+            return;
+        }
+        self.storage_state.sink_statistics_reported_at = Instant::now();
+
+        let mut updates = Vec::new();
+        for (id, counters) in &self.storage_state.sink_statistics {
+            let statistics = counters.snapshot();
+            let reported = self
+                .storage_state
+                .reported_sink_statistics
+                .entry(*id)
+                .or_default();
+            if *reported != statistics {
+                updates.push((*id, statistics));
+                *reported = statistics;
+            }
+        }
+
+        if !updates.is_empty() {
+            self.send_storage_response(response_tx, StorageResponse::SinkStatistics(updates));
+        }
+    }
+
     /// Send a response to the coordinator.
     fn send_storage_response(&self, response_tx: &ResponseSender, response: StorageResponse) {
         // Ignore send errors because the coordinator is free to ignore our
@@ -376,6 +416,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
         for (_, frontier) in &mut self.storage_state.reported_frontiers {
             *frontier = Antichain::from_elem(<_>::minimum());
         }
+        self.storage_state.reported_sink_statistics.clear();
 
         // Execute the modified commands.
         for command in commands {
@@ -150,6 +150,7 @@ pub enum Connection {
     Postgres(PostgresConnection),
     Ssh(SshConnection),
     Aws(AwsCredentials),
+    Gcp(GcpConnection),
 }
 
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -457,6 +458,48 @@ impl Arbitrary for CsrConnection {
     }
 }
 
+/// A connection to Google Cloud Platform.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct GcpConnection {
+    /// The ID of the secret containing the JSON key of the service account to
+    /// authenticate as. Requests are sent unauthenticated if absent, which is
+    /// only useful against an emulator.
+    pub credentials: Option<GlobalId>,
+    /// The endpoint to send API requests to, if not the service's default
+    /// Google Cloud endpoint.
+    pub endpoint: Option<Url>,
+}
+
+impl RustType<ProtoGcpConnection> for GcpConnection {
+    fn into_proto(&self) -> ProtoGcpConnection {
+        ProtoGcpConnection {
+            credentials: self.credentials.into_proto(),
+            endpoint: self.endpoint.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoGcpConnection) -> Result<Self, TryFromProtoError> {
+        Ok(GcpConnection {
+            credentials: proto.credentials.into_rust()?,
+            endpoint: proto.endpoint.into_rust()?,
+        })
+    }
+}
+
+impl Arbitrary for GcpConnection {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<Option<GlobalId>>(), proptest::option::of(any_url()))
+            .prop_map(|(credentials, endpoint)| GcpConnection {
+                credentials,
+                endpoint,
+            })
+            .boxed()
+    }
+}
+
 /// A TLS key pair used for client identity.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct TlsIdentity {
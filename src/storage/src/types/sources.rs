@@ -34,11 +34,14 @@ use uuid::Uuid;
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{any_uuid, TryFromProtoError};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType};
+use mz_repr::adt::regex::{any_regex, Regex};
 use mz_repr::{ColumnType, Diff, GlobalId, RelationDesc, RelationType, Row, ScalarType};
 
 use crate::controller::CollectionMetadata;
 use crate::types::connections::aws::AwsConfig;
-use crate::types::connections::{KafkaConnection, PostgresConnection, StringOrSecret};
+use crate::types::connections::{
+    GcpConnection, KafkaConnection, PostgresConnection, StringOrSecret,
+};
 use crate::types::errors::DataflowError;
 
 use self::encoding::{DataEncoding, DataEncodingInner, SourceDataEncoding};
@@ -1038,7 +1041,7 @@ impl UnplannedSourceEnvelope {
 pub struct KafkaSourceConnection {
     pub connection: KafkaConnection,
     pub options: BTreeMap<String, StringOrSecret>,
-    pub topic: String,
+    pub topics: KafkaTopicSelection,
     // Map from partition -> starting offset
     pub start_offsets: HashMap<i32, MzOffset>,
     pub group_id_prefix: Option<String>,
@@ -1062,7 +1065,7 @@ impl Arbitrary for KafkaSourceConnection {
         (
             any::<KafkaConnection>(),
             any::<BTreeMap<String, StringOrSecret>>(),
-            any::<String>(),
+            any::<KafkaTopicSelection>(),
             any::<HashMap<i32, MzOffset>>(),
             any::<Option<String>>(),
             any_uuid(),
@@ -1076,7 +1079,7 @@ impl Arbitrary for KafkaSourceConnection {
                 |(
                     connection,
                     options,
-                    topic,
+                    topics,
                     start_offsets,
                     group_id_prefix,
                     cluster_id,
@@ -1088,7 +1091,7 @@ impl Arbitrary for KafkaSourceConnection {
                 )| KafkaSourceConnection {
                     connection,
                     options,
-                    topic,
+                    topics,
                     start_offsets,
                     group_id_prefix,
                     cluster_id,
@@ -1112,7 +1115,7 @@ impl RustType<ProtoKafkaSourceConnection> for KafkaSourceConnection {
                 .iter()
                 .map(|(k, v)| (k.clone(), v.into_proto()))
                 .collect(),
-            topic: self.topic.clone(),
+            topics: Some(self.topics.into_proto()),
             start_offsets: self
                 .start_offsets
                 .iter()
@@ -1144,7 +1147,9 @@ impl RustType<ProtoKafkaSourceConnection> for KafkaSourceConnection {
                 .connection
                 .into_rust_if_some("ProtoKafkaSourceConnection::connection")?,
             options: options?,
-            topic: proto.topic,
+            topics: proto
+                .topics
+                .into_rust_if_some("ProtoKafkaSourceConnection::topics")?,
             start_offsets: start_offsets?,
             group_id_prefix: proto.group_id_prefix,
             cluster_id: proto
@@ -1159,6 +1164,68 @@ impl RustType<ProtoKafkaSourceConnection> for KafkaSourceConnection {
     }
 }
 
+/// The topics a Kafka source reads from.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaTopicSelection {
+    /// A single topic.
+    Topic(String),
+    /// A fixed set of topics.
+    Topics(Vec<String>),
+    /// Every topic whose name matches the regex, including topics that are
+    /// created after the source.
+    Pattern(#[proptest(strategy = "any_regex()")] Regex),
+}
+
+impl KafkaTopicSelection {
+    /// Reports whether the source reads from `topic`.
+    pub fn contains(&self, topic: &str) -> bool {
+        match self {
+            KafkaTopicSelection::Topic(t) => t == topic,
+            KafkaTopicSelection::Topics(ts) => ts.iter().any(|t| t == topic),
+            KafkaTopicSelection::Pattern(regex) => regex.is_match(topic),
+        }
+    }
+}
+
+impl std::fmt::Display for KafkaTopicSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KafkaTopicSelection::Topic(topic) => f.write_str(topic),
+            KafkaTopicSelection::Topics(topics) => f.write_str(&topics.join(", ")),
+            KafkaTopicSelection::Pattern(regex) => write!(f, "/{}/", regex.as_str()),
+        }
+    }
+}
+
+impl RustType<ProtoKafkaTopicSelection> for KafkaTopicSelection {
+    fn into_proto(&self) -> ProtoKafkaTopicSelection {
+        use proto_kafka_topic_selection::{Kind, ProtoTopicList};
+        ProtoKafkaTopicSelection {
+            kind: Some(match self {
+                KafkaTopicSelection::Topic(topic) => Kind::Topic(topic.clone()),
+                KafkaTopicSelection::Topics(topics) => Kind::Topics(ProtoTopicList {
+                    topics: topics.clone(),
+                }),
+                KafkaTopicSelection::Pattern(regex) => Kind::Pattern(regex.into_proto()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoKafkaTopicSelection) -> Result<Self, TryFromProtoError> {
+        use proto_kafka_topic_selection::Kind;
+        Ok(match proto.kind {
+            Some(Kind::Topic(topic)) => KafkaTopicSelection::Topic(topic),
+            Some(Kind::Topics(list)) => KafkaTopicSelection::Topics(list.topics),
+            Some(Kind::Pattern(regex)) => KafkaTopicSelection::Pattern(regex.into_rust()?),
+            None => {
+                return Err(TryFromProtoError::MissingField(
+                    "ProtoKafkaTopicSelection::kind".into(),
+                ))
+            }
+        })
+    }
+}
+
 #[derive(Arbitrary, Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Compression {
     Gzip,
@@ -1266,9 +1333,12 @@ impl SourceDesc {
         match self.connection {
             // TODO(guswynn): does postgres count here as well?
             SourceConnection::Kafka(_) => true,
+            SourceConnection::File(_) => true,
             // Currently, the Kinesis connection assigns "offsets" by counting the message in the order it was received
             // and this order is not replayable across different reads of the same Kinesis stream.
             SourceConnection::Kinesis(_) => false,
+            // Likewise for Pub/Sub, which acknowledges messages as it receives them.
+            SourceConnection::PubSub(_) => false,
             _ => false,
         }
     }
@@ -1301,7 +1371,11 @@ impl SourceDesc {
                 envelope:
                     SourceEnvelope::Debezium(_) | SourceEnvelope::Upsert(_) | SourceEnvelope::CdcV2,
                 connection:
-                    SourceConnection::S3(_) | SourceConnection::Kafka(_) | SourceConnection::Kinesis(_),
+                    SourceConnection::S3(_)
+                    | SourceConnection::Kafka(_)
+                    | SourceConnection::Kinesis(_)
+                    | SourceConnection::File(_)
+                    | SourceConnection::PubSub(_),
                 ..
             } => false,
         }
@@ -1323,6 +1397,8 @@ pub enum SourceConnection {
     S3(S3SourceConnection),
     Postgres(PostgresSourceConnection),
     LoadGenerator(LoadGeneratorSourceConnection),
+    File(FileSourceConnection),
+    PubSub(PubSubSourceConnection),
 }
 
 impl RustType<ProtoSourceConnection> for SourceConnection {
@@ -1335,6 +1411,8 @@ impl RustType<ProtoSourceConnection> for SourceConnection {
                 SourceConnection::S3(s3) => Kind::S3(s3.into_proto()),
                 SourceConnection::Postgres(postgres) => Kind::Postgres(postgres.into_proto()),
                 SourceConnection::LoadGenerator(loadgen) => Kind::Loadgen(loadgen.into_proto()),
+                SourceConnection::File(file) => Kind::File(file.into_proto()),
+                SourceConnection::PubSub(pubsub) => Kind::Pubsub(pubsub.into_proto()),
             }),
         }
     }
@@ -1350,6 +1428,8 @@ impl RustType<ProtoSourceConnection> for SourceConnection {
             Kind::S3(s3) => SourceConnection::S3(s3.into_rust()?),
             Kind::Postgres(postgres) => SourceConnection::Postgres(postgres.into_rust()?),
             Kind::Loadgen(loadgen) => SourceConnection::LoadGenerator(loadgen.into_rust()?),
+            Kind::File(file) => SourceConnection::File(file.into_rust()?),
+            Kind::Pubsub(pubsub) => SourceConnection::PubSub(pubsub.into_rust()?),
         })
     }
 }
@@ -1417,6 +1497,8 @@ impl SourceConnection {
             Self::S3(_) => vec![],
             Self::Postgres(_) => vec![],
             Self::LoadGenerator(_) => vec![],
+            Self::File(_) => vec![],
+            Self::PubSub(_) => vec![],
         }
     }
 
@@ -1452,7 +1534,9 @@ impl SourceConnection {
             SourceConnection::Kinesis(_)
             | SourceConnection::S3(_)
             | SourceConnection::Postgres(_)
-            | SourceConnection::LoadGenerator(_) => Vec::new(),
+            | SourceConnection::LoadGenerator(_)
+            | SourceConnection::File(_)
+            | SourceConnection::PubSub(_) => Vec::new(),
         }
     }
 
@@ -1464,21 +1548,29 @@ impl SourceConnection {
             SourceConnection::S3(_) => "s3",
             SourceConnection::Postgres(_) => "postgres",
             SourceConnection::LoadGenerator(_) => "loadgen",
+            SourceConnection::File(_) => "file",
+            SourceConnection::PubSub(_) => "pubsub",
         }
     }
 
     /// Optionally returns the name of the upstream resource this source corresponds to.
     /// (Currently only implemented for Kafka and Kinesis, to match old-style behavior
     ///  TODO: decide whether we want file paths and other upstream names to show up in metrics too.
-    pub fn upstream_name(&self) -> Option<&str> {
+    pub fn upstream_name(&self) -> Option<String> {
         match self {
-            SourceConnection::Kafka(KafkaSourceConnection { topic, .. }) => Some(topic.as_str()),
+            SourceConnection::Kafka(KafkaSourceConnection { topics, .. }) => {
+                Some(topics.to_string())
+            }
             SourceConnection::Kinesis(KinesisSourceConnection { stream_name, .. }) => {
-                Some(stream_name.as_str())
+                Some(stream_name.clone())
             }
             SourceConnection::S3(_) => None,
             SourceConnection::Postgres(_) => None,
             SourceConnection::LoadGenerator(_) => None,
+            SourceConnection::File(FileSourceConnection { path, .. }) => Some(path.clone()),
+            SourceConnection::PubSub(PubSubSourceConnection { subscription, .. }) => {
+                Some(subscription.clone())
+            }
         }
     }
 
@@ -1489,7 +1581,9 @@ impl SourceConnection {
             SourceConnection::Kafka(_)
             | SourceConnection::Kinesis(_)
             | SourceConnection::Postgres(_)
-            | SourceConnection::LoadGenerator(_) => false,
+            | SourceConnection::LoadGenerator(_)
+            | SourceConnection::File(_)
+            | SourceConnection::PubSub(_) => false,
         }
     }
 }
@@ -1573,6 +1667,56 @@ impl RustType<ProtoPostgresSourceDetails> for PostgresSourceDetails {
     }
 }
 
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FileSourceConnection {
+    /// The path to the file on the storage host.
+    pub path: String,
+    /// Whether to keep following the file for appended lines after reaching
+    /// its end.
+    pub tail: bool,
+}
+
+impl RustType<ProtoFileSourceConnection> for FileSourceConnection {
+    fn into_proto(&self) -> ProtoFileSourceConnection {
+        ProtoFileSourceConnection {
+            path: self.path.clone(),
+            tail: self.tail,
+        }
+    }
+
+    fn from_proto(proto: ProtoFileSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(FileSourceConnection {
+            path: proto.path,
+            tail: proto.tail,
+        })
+    }
+}
+
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PubSubSourceConnection {
+    pub connection: GcpConnection,
+    /// The fully qualified name of the subscription to pull messages from.
+    pub subscription: String,
+}
+
+impl RustType<ProtoPubSubSourceConnection> for PubSubSourceConnection {
+    fn into_proto(&self) -> ProtoPubSubSourceConnection {
+        ProtoPubSubSourceConnection {
+            connection: Some(self.connection.into_proto()),
+            subscription: self.subscription.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPubSubSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PubSubSourceConnection {
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPubSubSourceConnection::connection")?,
+            subscription: proto.subscription,
+        })
+    }
+}
+
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LoadGeneratorSourceConnection {
     pub load_generator: LoadGenerator,
@@ -227,9 +227,17 @@ impl DataEncoding {
             DataEncodingInner::Bytes => {
                 RelationDesc::empty().with_column("data", ScalarType::Bytes.nullable(false))
             }
-            DataEncodingInner::Avro(AvroEncoding { schema, .. }) => {
+            DataEncodingInner::Avro(AvroEncoding {
+                schema, projection, ..
+            }) => {
                 let parsed_schema = avro::parse_schema(schema).context("validating avro schema")?;
-                avro::schema_to_relationdesc(parsed_schema).context("validating avro schema")?
+                match projection {
+                    None => avro::schema_to_relationdesc(parsed_schema),
+                    Some(projection) => {
+                        avro::projected_schema_to_relationdesc(parsed_schema, projection)
+                    }
+                }
+                .context("validating avro schema")?
             }
             DataEncodingInner::Protobuf(ProtobufEncoding {
                 descriptors,
@@ -316,6 +324,9 @@ pub struct AvroEncoding {
     pub schema: String,
     pub csr_connection: Option<CsrConnection>,
     pub confluent_wire_format: bool,
+    /// The indexes of the top-level fields of `schema` to decode, in
+    /// ascending order, or `None` to decode every field.
+    pub projection: Option<Vec<usize>>,
 }
 
 impl RustType<ProtoAvroEncoding> for AvroEncoding {
@@ -324,6 +335,7 @@ impl RustType<ProtoAvroEncoding> for AvroEncoding {
             schema: self.schema.clone(),
             csr_connection: self.csr_connection.into_proto(),
             confluent_wire_format: self.confluent_wire_format,
+            projection: self.projection.into_proto(),
         }
     }
 
@@ -332,10 +344,23 @@ impl RustType<ProtoAvroEncoding> for AvroEncoding {
             schema: proto.schema,
             csr_connection: proto.csr_connection.into_rust()?,
             confluent_wire_format: proto.confluent_wire_format,
+            projection: proto.projection.into_rust()?,
         })
     }
 }
 
+impl RustType<proto_avro_encoding::ProtoProjection> for Vec<usize> {
+    fn into_proto(&self) -> proto_avro_encoding::ProtoProjection {
+        proto_avro_encoding::ProtoProjection {
+            fields: self.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: proto_avro_encoding::ProtoProjection) -> Result<Self, TryFromProtoError> {
+        proto.fields.into_rust()
+    }
+}
+
 /// Encoding in Protobuf format.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ProtobufEncoding {
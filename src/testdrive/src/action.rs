@@ -549,6 +549,9 @@ pub(crate) async fn build(
                     }
                     "kafka-ingest" => Box::new(kafka::build_ingest(builtin).map_err(wrap_err)?),
                     "kafka-verify" => Box::new(kafka::build_verify(builtin).map_err(wrap_err)?),
+                    "kafka-verify-commit" => {
+                        Box::new(kafka::build_verify_commit(builtin).map_err(wrap_err)?)
+                    }
                     "kafka-verify-schema" => {
                         Box::new(kafka::build_verify_schema(builtin).map_err(wrap_err)?)
                     }
@@ -11,9 +11,11 @@ mod add_partitions;
 mod create_topic;
 mod ingest;
 mod verify;
+mod verify_commit;
 
 pub use add_partitions::build_add_partitions;
 pub use create_topic::build_create_topic;
 pub use ingest::build_ingest;
 pub use verify::build_verify;
 pub use verify::build_verify_schema;
+pub use verify_commit::build_verify_commit;
@@ -0,0 +1,111 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::cmp;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use rdkafka::consumer::{BaseConsumer, Consumer};
+use rdkafka::{Offset, TopicPartitionList};
+
+use mz_ore::retry::Retry;
+
+use crate::action::{Action, ControlFlow, State};
+use crate::parser::BuiltinCommand;
+
+pub struct VerifyCommitAction {
+    consumer_group_id_prefix: String,
+    topic_prefix: String,
+    partition: i32,
+    expected_offset: i64,
+}
+
+pub fn build_verify_commit(mut cmd: BuiltinCommand) -> Result<VerifyCommitAction, anyhow::Error> {
+    let consumer_group_id_prefix = cmd.args.string("consumer-group-id-prefix")?;
+    let topic_prefix = format!("testdrive-{}", cmd.args.string("topic")?);
+    let partition = cmd.args.parse("partition")?;
+    cmd.args.done()?;
+    let expected_offset = match &cmd.input[..] {
+        [line] => line.parse().context("parsing expected offset")?,
+        _ => bail!("kafka-verify-commit requires a single expected offset"),
+    };
+    Ok(VerifyCommitAction {
+        consumer_group_id_prefix,
+        topic_prefix,
+        partition,
+        expected_offset,
+    })
+}
+
+#[async_trait]
+impl Action for VerifyCommitAction {
+    async fn undo(&self, _: &mut State) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    async fn redo(&self, state: &mut State) -> Result<ControlFlow, anyhow::Error> {
+        let topic_name = format!("{}-{}", self.topic_prefix, state.seed);
+        let consumer_group_id_prefix = &self.consumer_group_id_prefix;
+        println!(
+            "Verifying committed offset of consumer group {}* for Kafka topic {} [{}]",
+            consumer_group_id_prefix, topic_name, self.partition
+        );
+
+        let timeout = cmp::max(state.default_timeout, Duration::from_secs(1));
+        Retry::default()
+            .max_duration(state.default_timeout)
+            .retry_async_canceling(|_| async {
+                let consumer: BaseConsumer = state
+                    .kafka_config
+                    .create()
+                    .context("creating kafka consumer")?;
+                let groups = consumer
+                    .fetch_group_list(None, timeout)
+                    .context("listing consumer groups")?;
+                let group_ids: Vec<_> = groups
+                    .groups()
+                    .iter()
+                    .map(|group| group.name())
+                    .filter(|name| name.starts_with(consumer_group_id_prefix.as_str()))
+                    .collect();
+                let group_id = match &group_ids[..] {
+                    [group_id] => group_id.to_string(),
+                    _ => bail!(
+                        "expected exactly one consumer group with prefix {}, found {:?}",
+                        consumer_group_id_prefix,
+                        group_ids
+                    ),
+                };
+
+                let mut config = state.kafka_config.clone();
+                config.set("group.id", &group_id);
+                let consumer: BaseConsumer = config.create().context("creating kafka consumer")?;
+                let mut tpl = TopicPartitionList::new();
+                tpl.add_partition(&topic_name, self.partition);
+                let committed = consumer
+                    .committed_offsets(tpl, timeout)
+                    .context("fetching committed offsets")?;
+                let offset = committed
+                    .find_partition(&topic_name, self.partition)
+                    .map(|elem| elem.offset());
+                match offset {
+                    Some(Offset::Offset(offset)) if offset == self.expected_offset => Ok(()),
+                    offset => bail!(
+                        "expected committed offset {}, found {:?}",
+                        self.expected_offset,
+                        offset
+                    ),
+                }
+            })
+            .await?;
+
+        Ok(ControlFlow::Continue)
+    }
+}
@@ -184,6 +184,7 @@ impl Action for SqlAction {
             | ShowDatabases(_)
             | ShowObjects(_)
             | ShowIndexes(_)
+            | ShowStorageUsage(_)
             | ShowColumns(_)
             | ShowCreateView(_)
             | ShowCreateSource(_)
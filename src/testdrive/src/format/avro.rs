@@ -63,6 +63,26 @@ pub fn from_json(json: &JsonValue, schema: SchemaNode) -> Result<Value, anyhow::
                 ((ts % 1_000_000) * 1_000) as u32,
             )))
         }
+        (JsonValue::Number(ref n), SchemaPiece::TimeMilli) => {
+            let millis = n.as_i64().unwrap();
+            Ok(Value::Time(
+                chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+                    (millis / 1_000).try_into()?,
+                    ((millis % 1_000) * 1_000_000).try_into()?,
+                )
+                .ok_or_else(|| anyhow!("invalid time-millis value: {}", millis))?,
+            ))
+        }
+        (JsonValue::Number(ref n), SchemaPiece::TimeMicro) => {
+            let micros = n.as_i64().unwrap();
+            Ok(Value::Time(
+                chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+                    (micros / 1_000_000).try_into()?,
+                    ((micros % 1_000_000) * 1_000).try_into()?,
+                )
+                .ok_or_else(|| anyhow!("invalid time-micros value: {}", micros))?,
+            ))
+        }
         (JsonValue::Array(items), SchemaPiece::Array(inner)) => Ok(Value::Array(
             items
                 .iter()
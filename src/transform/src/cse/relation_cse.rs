@@ -13,6 +13,12 @@
 //! equivalent inputs, and identical parameters, will be placed behind `Let` bindings.
 //! The resulting expressions likely have an excess of `Let` expressions, and should be
 //! subjected to the `InlineLet` transformation to remove those that are not necessary.
+//!
+//! Only subexpressions of the same dataflow are shared. A subexpression is not replaced by
+//! a read of an unrelated existing view or index that happens to compute the same thing:
+//! the new dataflow would then depend on an object that its definition does not mention,
+//! which `DROP` and dependency tracking cannot account for. Existing work is reused only
+//! when a definition refers to an indexed view or a materialized view by name.
 
 use std::collections::HashMap;
 
@@ -24,7 +24,7 @@
 use std::error::Error;
 use std::fmt;
 use std::iter;
-use tracing::error;
+use tracing::{error, trace, Level};
 
 use mz_expr::visit::Visit;
 use mz_expr::{MirRelationExpr, MirScalarExpr};
@@ -87,6 +87,45 @@ pub trait Transform: std::fmt::Debug {
     fn debug(&self) -> String {
         format!("{:?}", self)
     }
+
+    /// A short name identifying the transform in optimizer traces.
+    ///
+    /// Defaults to the last segment of the implementing type's path.
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+}
+
+/// Applies `transform` to `relation` inside a per-pass tracing span.
+///
+/// When tracing is enabled for the `optimizer` target at `trace` level, this
+/// additionally records whether the pass changed the relation and, if so, the
+/// resulting plan.
+fn apply_transform(
+    transform: &dyn Transform,
+    relation: &mut MirRelationExpr,
+    args: TransformArgs,
+) -> Result<(), TransformError> {
+    let span = tracing::span!(
+        target: "optimizer",
+        Level::TRACE,
+        "transform",
+        transform = transform.name()
+    );
+    let _guard = span.enter();
+    if tracing::enabled!(target: "optimizer", Level::TRACE) {
+        let original = relation.clone();
+        transform.transform(relation, args)?;
+        if *relation == original {
+            trace!(target: "optimizer", changed = false);
+        } else {
+            trace!(target: "optimizer", changed = true, plan = %relation.pretty());
+        }
+        Ok(())
+    } else {
+        transform.transform(relation, args)
+    }
 }
 
 /// Errors that can occur during a transformation.
@@ -161,10 +200,11 @@ impl Transform for Fixpoint {
         loop {
             let mut original_count = 0;
             relation.try_visit_post::<_, TransformError>(&mut |_| Ok(original_count += 1))?;
-            for _ in 0..self.limit {
+            for iteration in 0..self.limit {
                 let original = relation.clone();
                 for transform in self.transforms.iter() {
-                    transform.transform(
+                    apply_transform(
+                        transform.as_ref(),
                         relation,
                         TransformArgs {
                             id_gen: args.id_gen,
@@ -173,6 +213,7 @@ impl Transform for Fixpoint {
                     )?;
                 }
                 if *relation == original {
+                    trace!(target: "optimizer", iterations = iteration + 1, "fixpoint reached");
                     return Ok(());
                 }
             }
@@ -183,7 +224,8 @@ impl Transform for Fixpoint {
             }
         }
         for transform in self.transforms.iter() {
-            transform.transform(
+            apply_transform(
+                transform.as_ref(),
                 relation,
                 TransformArgs {
                     id_gen: args.id_gen,
@@ -260,7 +302,8 @@ impl Transform for FuseAndCollapse {
         args: TransformArgs,
     ) -> Result<(), TransformError> {
         for transform in self.transforms.iter() {
-            transform.transform(
+            apply_transform(
+                transform.as_ref(),
                 relation,
                 TransformArgs {
                     id_gen: args.id_gen,
@@ -330,6 +373,8 @@ impl Optimizer {
                     // Replaces reduces with maps when the group keys are
                     // unique with maps
                     Box::new(crate::reduce_elision::ReduceElision),
+                    // Removes TopKs whose groups have at most one record
+                    Box::new(crate::topk_elision::TopKElision),
                     // Converts `Cross Join {Constant(Literal) + Input}` to
                     // `Map {Cross Join (Input, Constant()), Literal}`.
                     // Join fusion will clean this up to `Map{Input, Literal}`
@@ -461,7 +506,8 @@ impl Optimizer {
     ) -> Result<(), TransformError> {
         let mut id_gen = Default::default();
         for transform in self.transforms.iter() {
-            transform.transform(
+            apply_transform(
+                transform.as_ref(),
                 relation,
                 TransformArgs {
                     id_gen: &mut id_gen,
@@ -433,6 +433,46 @@ impl PredicatePushdown {
                                 self.action(input, get_predicates)?;
                             }
                         }
+                        MirRelationExpr::Threshold { input: inner } => {
+                            // Filtering commutes with thresholding, as predicates are
+                            // evaluated independently for each row. Only predicates that
+                            // cannot error are pushed down, because below the threshold
+                            // they would also be evaluated on rows that it removes.
+                            let (push_down, mut retain): (Vec<_>, Vec<_>) =
+                                predicates.drain(..).partition(|p| !p.could_error());
+
+                            if !push_down.is_empty() {
+                                **inner = inner.take_dangerous().filter(push_down);
+                            }
+                            self.action(inner, get_predicates)?;
+
+                            // remove all predicates that were pushed down from the current Filter node
+                            std::mem::swap(&mut retain, predicates);
+                        }
+                        MirRelationExpr::TopK {
+                            input: inner,
+                            group_key,
+                            ..
+                        } => {
+                            // Predicates that reference only grouping columns either keep
+                            // or discard entire groups, so they can be applied before the
+                            // TopK without changing which rows it selects. As above, only
+                            // predicates that cannot error are pushed down, because the
+                            // TopK may discard rows (or entire groups, with an offset).
+                            let (push_down, mut retain): (Vec<_>, Vec<_>) =
+                                predicates.drain(..).partition(|p| {
+                                    !p.could_error()
+                                        && p.support().iter().all(|c| group_key.contains(c))
+                                });
+
+                            if !push_down.is_empty() {
+                                **inner = inner.take_dangerous().filter(push_down);
+                            }
+                            self.action(inner, get_predicates)?;
+
+                            // remove all predicates that were pushed down from the current Filter node
+                            std::mem::swap(&mut retain, predicates);
+                        }
                         MirRelationExpr::Negate { input: inner } => {
                             let predicates = std::mem::replace(predicates, Vec::new());
                             *relation = inner.take_dangerous().filter(predicates).negate();
@@ -8,12 +8,17 @@
 // by the Apache License, Version 2.0.
 
 //! Remove TopK operators with both an offset of zero and no limit.
+//!
+//! Also removes TopK operators with an offset of zero and a non-zero limit
+//! whose grouping key contains a unique key of the input, as each group then
+//! contains at most one record, which is always retained.
 
 use crate::TransformArgs;
 use mz_expr::visit::Visit;
 use mz_expr::MirRelationExpr;
 
-/// Remove TopK operators with both an offset of zero and no limit.
+/// Remove TopK operators with both an offset of zero and no limit, or with
+/// groups of at most one record.
 #[derive(Debug)]
 pub struct TopKElision;
 
@@ -28,11 +33,12 @@ impl crate::Transform for TopKElision {
 }
 
 impl TopKElision {
-    /// Remove TopK operators with both an offset of zero and no limit.
+    /// Remove TopK operators with both an offset of zero and no limit, or with
+    /// groups of at most one record.
     pub fn action(&self, relation: &mut MirRelationExpr) -> Result<(), crate::TransformError> {
         if let MirRelationExpr::TopK {
             input,
-            group_key: _,
+            group_key,
             order_key: _,
             limit,
             offset,
@@ -43,6 +49,14 @@ impl TopKElision {
                 *relation = input.take_dangerous();
             } else if limit == &Some(0) {
                 relation.take_safely();
+            } else if *offset == 0
+                && input
+                    .typ()
+                    .keys
+                    .iter()
+                    .any(|key| key.iter().all(|k| group_key.contains(k)))
+            {
+                *relation = input.take_dangerous();
             }
         }
         Ok(())
@@ -264,6 +264,7 @@ mod tests {
                 mz_transform::redundant_join::RedundantJoin::default(),
             )),
             "RelationCSE" => Ok(Box::new(mz_transform::cse::relation_cse::RelationCSE)),
+            "TopKElision" => Ok(Box::new(mz_transform::topk_elision::TopKElision)),
             "TopKFusion" => Ok(Box::new(mz_transform::fusion::top_k::TopK)),
             "ThresholdElision" => Ok(Box::new(mz_transform::threshold_elision::ThresholdElision)),
             "UnionBranchCancellation" => Ok(Box::new(
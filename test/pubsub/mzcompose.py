@@ -0,0 +1,34 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+from materialize.mzcompose import Composition
+from materialize.mzcompose.services import Materialized, PubSubEmulator, Testdrive
+
+SERVICES = [
+    PubSubEmulator(),
+    Materialized(),
+    # Messages that were pulled but not acknowledged before a restart are only
+    # redelivered once their acknowledgement deadline expires.
+    Testdrive(default_timeout="300s", no_reset=True),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    c.start_and_wait_for_tcp(services=["pubsub-emulator", "materialized"])
+    c.wait_for_materialized()
+
+    c.run("testdrive", "setup.td", "ingest.td")
+
+    # Restart while the last messages are in flight.
+    c.run("testdrive", "publish-in-flight.td")
+    c.kill("materialized")
+    c.up("materialized")
+    c.wait_for_materialized()
+
+    c.run("testdrive", "verify-after-restart.td", "cleanup.td")